@@ -0,0 +1,214 @@
+//! Sound effects for game events.
+//! Gameplay and UI code trigger `SfxEvent`; this crate maps the kind to a bundled clip
+//! under `assets/audio/` and plays it once. Kinds whose clip is not bundled stay silent,
+//! which is logged as a warning at startup.
+
+use {
+    bevy::{
+        asset::{LoadState, io::file::FileAssetReader},
+        audio::Volume,
+        platform::collections::HashMap,
+        prelude::*,
+    },
+    std::path::Path,
+};
+
+/// Folder the clip paths are relative to, as configured by the default `AssetPlugin`
+const ASSET_FOLDER: &str = "assets";
+
+pub struct SfxPlugin;
+
+impl Plugin for SfxPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SfxSettings>()
+            .init_resource::<SfxHandles>()
+            .add_systems(Startup, load_sfx)
+            .add_observer(play_sfx);
+    }
+}
+
+/// What happened, which decides the clip that is played
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SfxKind {
+    Unlock,
+    Research,
+    Crafted,
+}
+
+impl SfxKind {
+    pub const ALL: [SfxKind; 3] = [SfxKind::Unlock, SfxKind::Research, SfxKind::Crafted];
+
+    /// Asset path of the clip, e.g. "audio/unlock.ogg"
+    pub fn path(&self) -> &'static str {
+        match self {
+            SfxKind::Unlock => "audio/unlock.ogg",
+            SfxKind::Research => "audio/research.ogg",
+            SfxKind::Crafted => "audio/crafted.ogg",
+        }
+    }
+}
+
+/// Plays the sound effect of `kind`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SfxEvent {
+    pub kind: SfxKind,
+}
+
+/// Player preferences for sound effects.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct SfxSettings {
+    /// Linear volume, 1.0 plays the clips as recorded
+    pub volume: f32,
+    pub enabled: bool,
+}
+
+impl Default for SfxSettings {
+    fn default() -> Self {
+        Self {
+            volume: 0.5,
+            enabled: true,
+        }
+    }
+}
+
+/// Clips loaded at startup, one per `SfxKind`
+#[derive(Resource, Default)]
+struct SfxHandles(HashMap<SfxKind, Handle<AudioSource>>);
+
+/// Kinds without a clip file under `root`, in `SfxKind::ALL` order.
+fn missing_clips(root: &Path) -> Vec<SfxKind> {
+    SfxKind::ALL
+        .into_iter()
+        .filter(|kind| !root.join(kind.path()).exists())
+        .collect()
+}
+
+fn load_sfx(asset_server: Res<AssetServer>, mut handles: ResMut<SfxHandles>) {
+    let reader = FileAssetReader::new(ASSET_FOLDER);
+    let missing = missing_clips(reader.root_path());
+    for kind in SfxKind::ALL {
+        // Loading a missing file only logs an error, skip it instead
+        if missing.contains(&kind) {
+            warn!("No clip at {}, {:?} sounds stay silent", kind.path(), kind);
+            continue;
+        }
+        handles.0.insert(kind, asset_server.load(kind.path()));
+    }
+}
+
+/// Spawns a one-shot `AudioPlayer`. Does nothing while muted or when the clip failed to load.
+fn play_sfx(
+    trigger: On<SfxEvent>,
+    mut commands: Commands,
+    settings: Res<SfxSettings>,
+    handles: Res<SfxHandles>,
+    asset_server: Res<AssetServer>,
+) {
+    if !settings.enabled || settings.volume <= 0.0 {
+        return;
+    }
+
+    let kind = trigger.event().kind;
+    let Some(handle) = handles.0.get(&kind) else {
+        return;
+    };
+    if let Some(LoadState::Failed(_)) = asset_server.get_load_state(handle) {
+        debug!("Skipping {:?} sound, its clip failed to load", kind);
+        return;
+    }
+
+    commands.spawn((
+        AudioPlayer(handle.clone()),
+        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(settings.volume)),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::sync::Arc};
+
+    fn setup() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<AudioSource>()
+            .init_resource::<SfxSettings>()
+            .init_resource::<SfxHandles>()
+            .add_observer(play_sfx);
+
+        let clip = app
+            .world_mut()
+            .resource_mut::<Assets<AudioSource>>()
+            .add(AudioSource {
+                bytes: Arc::from([]),
+            });
+        app.world_mut()
+            .resource_mut::<SfxHandles>()
+            .0
+            .insert(SfxKind::Unlock, clip);
+        app
+    }
+
+    fn players(app: &mut App) -> Vec<Volume> {
+        app.world_mut()
+            .query::<(&AudioPlayer, &PlaybackSettings)>()
+            .iter(app.world())
+            .map(|(_, settings)| settings.volume)
+            .collect()
+    }
+
+    #[test]
+    fn test_sfx_plays_at_configured_volume_unless_muted() {
+        let mut app = setup();
+        app.world_mut().resource_mut::<SfxSettings>().volume = 0.25;
+        app.world_mut().trigger(SfxEvent {
+            kind: SfxKind::Unlock,
+        });
+        app.world_mut().flush();
+        assert_eq!(players(&mut app), vec![Volume::Linear(0.25)]);
+
+        app.world_mut().resource_mut::<SfxSettings>().enabled = false;
+        app.world_mut().trigger(SfxEvent {
+            kind: SfxKind::Unlock,
+        });
+        app.world_mut().flush();
+        assert_eq!(players(&mut app).len(), 1);
+    }
+
+    #[test]
+    fn test_missing_clips_are_skipped() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<AudioSource>()
+            .init_resource::<SfxHandles>()
+            .add_systems(Startup, load_sfx);
+        app.update();
+
+        // No clip is bundled next to this crate
+        assert!(app.world().resource::<SfxHandles>().0.is_empty());
+    }
+
+    #[test]
+    fn test_missing_clips_lists_kinds_without_a_file() {
+        let root = std::env::temp_dir().join(format!("audio_clips_{}", std::process::id()));
+        std::fs::create_dir_all(root.join("audio")).unwrap();
+        std::fs::write(root.join(SfxKind::Research.path()), []).unwrap();
+
+        assert_eq!(
+            missing_clips(&root),
+            vec![SfxKind::Unlock, SfxKind::Crafted]
+        );
+        assert_eq!(missing_clips(&root.join("nowhere")), SfxKind::ALL.to_vec());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_sfx_without_a_clip_is_a_no_op() {
+        let mut app = setup();
+        app.world_mut().trigger(SfxEvent {
+            kind: SfxKind::Crafted,
+        });
+        app.world_mut().flush();
+        assert!(players(&mut app).is_empty());
+    }
+}
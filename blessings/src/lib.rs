@@ -1,12 +1,20 @@
 use {
     bevy::prelude::*,
     bevy_common_assets::ron::RonAssetPlugin,
+    growth::GrowthStrategy,
     serde::Deserialize,
     std::collections::{HashMap, HashSet},
     unlocks_assets::UnlockDefinition,
     unlocks_events::UnlockAchieved,
+    wallet::Wallet,
 };
 
+/// Resource blessings are paid with
+pub const BLESSING_CURRENCY: &str = "entropy";
+
+/// Upper bound of levels a single purchase may buy, so zero-cost blessings can't loop forever
+pub const MAX_LEVELS_PER_PURCHASE: u32 = 1000;
+
 pub struct BlessingsPlugin;
 
 impl Plugin for BlessingsPlugin {
@@ -23,12 +31,16 @@ impl Plugin for BlessingsPlugin {
 #[derive(Debug, Clone, Event)]
 pub struct BuyBlessing {
     pub blessing_id: String,
+    /// Levels to buy at once; `None` buys one. Fewer are bought when the max level or
+    /// the entropy runs out first.
+    pub count: Option<u32>,
 }
 
 fn purchase_blessing(
     trigger: On<BuyBlessing>,
     mut commands: Commands,
     mut blessings_query: Query<&mut Blessings>,
+    mut wallet: ResMut<Wallet>,
     blessing_definitions: Res<Assets<BlessingDefinition>>,
 ) {
     let event = trigger.event();
@@ -52,21 +64,35 @@ fn purchase_blessing(
                 _ => {}
             }
 
-            // Increment level
-            let new_level = current_level + 1;
+            let (levels, cost) = def.affordable_levels(
+                current_level,
+                event.count.unwrap_or(1),
+                wallet.amount(BLESSING_CURRENCY),
+            );
+            if levels == 0
+                || !wallet.try_spend(&HashMap::from([(BLESSING_CURRENCY.to_string(), cost)]))
+            {
+                info!(
+                    "Not enough {} for blessing {}",
+                    BLESSING_CURRENCY, event.blessing_id
+                );
+                return;
+            }
+
+            let new_level = current_level + levels;
             blessings
                 .unlocked
                 .insert(event.blessing_id.clone(), new_level);
 
             info!(
-                "Purchased blessing {}. New Level: {}",
-                event.blessing_id, new_level
+                "Purchased {} level(s) of blessing {} for {} {}. New Level: {}",
+                levels, event.blessing_id, cost, BLESSING_CURRENCY, new_level
             );
 
             // Trigger ValueChanged event so Value conditions (like in stats)
             // can react to the new blessing level.
             commands.trigger(unlocks_events::ValueChanged {
-                topic: format!("blessing:{}", event.blessing_id),
+                topic: unlocks_events::Topic::blessing(&event.blessing_id).into(),
                 value: new_level as f32,
             });
         }
@@ -109,6 +135,10 @@ pub struct BlessingDefinition {
     pub reward_id: String,
     /// Cost calculation strategy
     pub cost: growth::Growth,
+    /// Numeric effect shown on the card; level 1 gets the value at growth level 0.
+    /// Purely descriptive, the actual bonus comes from `reward_id`.
+    #[serde(default)]
+    pub effect: Option<growth::Growth>,
     #[serde(default)]
     pub limit: BlessingLimit,
 
@@ -117,6 +147,54 @@ pub struct BlessingDefinition {
     pub unlock: Option<UnlockDefinition>,
 }
 
+impl BlessingDefinition {
+    /// Cost of raising the blessing from `level` to `level + 1`.
+    pub fn level_cost(&self, level: u32) -> u32 {
+        self.cost.calculate(level) as u32
+    }
+
+    /// Effect value at `level`, 0 while not bought. `None` if the blessing has no effect.
+    pub fn effect_at(&self, level: u32) -> Option<f64> {
+        let effect = self.effect.as_ref()?;
+        Some(match level {
+            0 => 0.0,
+            _ => effect.calculate(level - 1),
+        })
+    }
+
+    /// Levels that can still be bought from `current_level`; `None` if unlimited.
+    pub fn remaining_levels(&self, current_level: u32) -> Option<u32> {
+        match self.limit {
+            BlessingLimit::MaxLevel(max) => Some(max.saturating_sub(current_level)),
+            BlessingLimit::Unlimited => None,
+        }
+    }
+
+    /// Cumulative cost of the next `count` levels from `current_level`, capped by the
+    /// max level. Returns the levels actually covered and their total cost.
+    pub fn cost_of_levels(&self, current_level: u32, count: u32) -> (u32, u32) {
+        self.affordable_levels(current_level, count, u32::MAX)
+    }
+
+    /// Buys as many of the next `count` levels as `budget` covers, in order, capped by the
+    /// max level and `MAX_LEVELS_PER_PURCHASE`. Returns the levels and their total cost.
+    pub fn affordable_levels(&self, current_level: u32, count: u32, budget: u32) -> (u32, u32) {
+        let count = count
+            .min(MAX_LEVELS_PER_PURCHASE)
+            .min(self.remaining_levels(current_level).unwrap_or(u32::MAX));
+
+        let mut total = 0u32;
+        for bought in 0..count {
+            let next = total.saturating_add(self.level_cost(current_level + bought));
+            if next > budget {
+                return (bought, total);
+            }
+            total = next;
+        }
+        (count, total)
+    }
+}
+
 /// Component attached to "The Maw" to track unlocked blessings.
 #[derive(Component, Reflect, Default, Debug)]
 #[reflect(Component)]
@@ -133,3 +211,111 @@ pub struct BlessingState {
     /// Set of blessing IDs that are currently available to buy
     pub available: HashSet<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use {super::*, growth::LinearGrowth};
+
+    fn blessing(limit: BlessingLimit) -> BlessingDefinition {
+        BlessingDefinition {
+            id: "hunger".to_string(),
+            name: "Hunger".to_string(),
+            description: String::new(),
+            reward_id: "stat:hunger".to_string(),
+            // 10, 20, 30, ...
+            cost: growth::Growth::Linear(LinearGrowth::new(10.0, 10.0)),
+            effect: Some(growth::Growth::Linear(LinearGrowth::new(5.0, 2.5))),
+            limit,
+            unlock: None,
+        }
+    }
+
+    fn setup(entropy: u32) -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<BlessingDefinition>()
+            .init_resource::<Wallet>()
+            .add_observer(purchase_blessing);
+        app.world_mut()
+            .resource_mut::<Assets<BlessingDefinition>>()
+            .add(blessing(BlessingLimit::MaxLevel(4)));
+        app.world_mut()
+            .resource_mut::<Wallet>()
+            .resources
+            .insert(BLESSING_CURRENCY.to_string(), entropy);
+        let maw = app.world_mut().spawn(Blessings::default()).id();
+        (app, maw)
+    }
+
+    fn buy(app: &mut App, count: Option<u32>) {
+        app.world_mut().trigger(BuyBlessing {
+            blessing_id: "hunger".to_string(),
+            count,
+        });
+        app.world_mut().flush();
+    }
+
+    fn level_and_entropy(app: &App, maw: Entity) -> (u32, u32) {
+        let level = app.world().get::<Blessings>(maw).unwrap().unlocked["hunger"];
+        let entropy = app.world().resource::<Wallet>().amount(BLESSING_CURRENCY);
+        (level, entropy)
+    }
+
+    #[test]
+    fn test_cumulative_cost_is_capped_by_max_level_and_budget() {
+        let def = blessing(BlessingLimit::MaxLevel(4));
+        assert_eq!(def.cost_of_levels(0, 1), (1, 10));
+        assert_eq!(def.cost_of_levels(1, 2), (2, 50));
+        assert_eq!(def.cost_of_levels(2, 5), (2, 70));
+        assert_eq!(def.cost_of_levels(4, 5), (0, 0));
+        assert_eq!(def.affordable_levels(0, 5, 59), (2, 30));
+
+        let unlimited = blessing(BlessingLimit::Unlimited);
+        assert_eq!(unlimited.cost_of_levels(0, 5), (5, 150));
+    }
+
+    #[test]
+    fn test_effect_starts_at_growth_base_on_level_one() {
+        let def = blessing(BlessingLimit::Unlimited);
+        assert_eq!(def.effect_at(0), Some(0.0));
+        assert_eq!(def.effect_at(1), Some(5.0));
+        assert_eq!(def.effect_at(3), Some(10.0));
+
+        let no_effect = BlessingDefinition {
+            effect: None,
+            ..def
+        };
+        assert_eq!(no_effect.effect_at(1), None);
+    }
+
+    #[test]
+    fn test_multi_level_purchase_deducts_cumulative_entropy() {
+        let (mut app, maw) = setup(100);
+
+        buy(&mut app, None);
+        assert_eq!(level_and_entropy(&app, maw), (1, 90));
+
+        // 20 + 30 + 40 would be 90, but level 4 is the cap
+        buy(&mut app, Some(5));
+        assert_eq!(level_and_entropy(&app, maw), (4, 0));
+
+        app.world_mut()
+            .resource_mut::<Wallet>()
+            .resources
+            .insert(BLESSING_CURRENCY.to_string(), 1000);
+        buy(&mut app, Some(5));
+        assert_eq!(level_and_entropy(&app, maw), (4, 1000));
+    }
+
+    #[test]
+    fn test_purchase_buys_only_affordable_levels() {
+        let (mut app, maw) = setup(35);
+
+        buy(&mut app, Some(3));
+        assert_eq!(level_and_entropy(&app, maw), (2, 5));
+
+        // Not even one level is affordable: nothing changes
+        buy(&mut app, Some(3));
+        assert_eq!(level_and_entropy(&app, maw), (2, 5));
+    }
+}
@@ -1,11 +1,13 @@
 use bevy::prelude::*;
 
+pub mod net_rates;
 pub mod the_maw;
 
 pub struct BuildingsPlugin;
 
 impl Plugin for BuildingsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(the_maw::TheMawPlugin);
+        app.add_plugins(the_maw::TheMawPlugin)
+            .add_systems(Update, net_rates::update_net_resource_rates);
     }
 }
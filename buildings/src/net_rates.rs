@@ -0,0 +1,49 @@
+use {
+    bevy::prelude::*,
+    buildings_components::{EntropyGenerator, passive_rates},
+    wallet::NetResourceRates,
+};
+
+/// Keeps `NetResourceRates` in sync with the passive producers in the world.
+/// Only writes when a source was added, removed or changed, and only marks the
+/// resource changed when the computed rates actually differ.
+pub fn update_net_resource_rates(
+    generators: Query<&EntropyGenerator>,
+    changed: Query<(), Changed<EntropyGenerator>>,
+    mut removed: RemovedComponents<EntropyGenerator>,
+    mut rates: ResMut<NetResourceRates>,
+) {
+    let any_removed = removed.read().count() > 0;
+    if changed.is_empty() && !any_removed {
+        return;
+    }
+
+    rates.set_if_neq(NetResourceRates {
+        per_second: passive_rates(generators.iter()),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generator_yields_expected_net_rate() {
+        let mut app = App::new();
+        app.init_resource::<NetResourceRates>()
+            .add_systems(Update, update_net_resource_rates);
+
+        // The default generator produces 1 entropy every 5 seconds
+        let generator = app.world_mut().spawn(EntropyGenerator::default()).id();
+        app.update();
+
+        let rate = app.world().resource::<NetResourceRates>().get("entropy");
+        assert!((rate - 0.2).abs() < f32::EPSILON, "rate was {}", rate);
+
+        app.world_mut().entity_mut(generator).despawn();
+        app.update();
+
+        let rates = app.world().resource::<NetResourceRates>();
+        assert!(rates.per_second.is_empty());
+    }
+}
@@ -3,7 +3,7 @@ use {
     blessings::Blessings,
     buildings_components::{EntropyGenerator, TheMaw},
     shared_components::IncludeInSave,
-    unlocks_events::{StatusCompleted, ValueChanged},
+    unlocks_events::{StatusCompleted, Topic, ValueChanged},
     wallet::Wallet,
 };
 
@@ -11,7 +11,7 @@ pub struct TheMawPlugin;
 
 impl Plugin for TheMawPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, generate_entropy)
+        app.add_systems(Update, generate_entropy.run_if(states::not_paused))
             .add_observer(on_construction_completed);
     }
 }
@@ -59,12 +59,15 @@ fn generate_entropy(
 ) {
     for mut generator in &mut query {
         if generator.timer.tick(time.delta()).just_finished() {
-            let entropy_amount = 1;
-            let current = wallet.resources.entry("entropy".to_string()).or_insert(0);
+            let entropy_amount = EntropyGenerator::AMOUNT_PER_TICK;
+            let current = wallet
+                .resources
+                .entry(EntropyGenerator::RESOURCE_ID.to_string())
+                .or_insert(0);
             *current += entropy_amount;
 
             commands.trigger(ValueChanged {
-                topic: "resource:entropy".to_string(),
+                topic: Topic::resource(EntropyGenerator::RESOURCE_ID).into(),
                 value: *current as f32,
             });
 
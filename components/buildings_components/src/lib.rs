@@ -1,4 +1,4 @@
-use {bevy::prelude::*, shared_components::IncludeInSave};
+use {bevy::prelude::*, shared_components::IncludeInSave, std::collections::HashMap};
 
 pub struct BuildingsComponentsPlugin;
 
@@ -22,6 +22,32 @@ pub struct EntropyGenerator {
     pub timer: Timer,
 }
 
+impl EntropyGenerator {
+    /// Resource produced by the generator.
+    pub const RESOURCE_ID: &'static str = "entropy";
+    /// Amount produced each time the timer finishes.
+    pub const AMOUNT_PER_TICK: u32 = 1;
+
+    /// Average production in resource units per second.
+    pub fn per_second(&self) -> f32 {
+        Self::AMOUNT_PER_TICK as f32 / self.timer.duration().as_secs_f32()
+    }
+}
+
+/// Sums the per-second output of `generators` by resource ID.
+pub fn passive_rates<'a>(
+    generators: impl IntoIterator<Item = &'a EntropyGenerator>,
+) -> HashMap<String, f32> {
+    generators
+        .into_iter()
+        .fold(HashMap::new(), |mut rates, generator| {
+            *rates
+                .entry(EntropyGenerator::RESOURCE_ID.to_string())
+                .or_insert(0.0) += generator.per_second();
+            rates
+        })
+}
+
 impl Default for EntropyGenerator {
     fn default() -> Self {
         Self {
@@ -37,6 +37,20 @@ impl Divinity {
         Self { tier, level }
     }
 
+    /// Prefix of unlock reward ids that grant a divinity, see `reward_id`.
+    pub const REWARD_PREFIX: &str = "divinity:";
+
+    /// Player-facing label, e.g. "Tier 2 - Level 15".
+    pub fn display(&self) -> String {
+        format!("Tier {} - Level {}", self.tier, self.level)
+    }
+
+    /// Unlock reward id granting this divinity, e.g. "divinity:2-15".
+    /// Parsed back with `from_dashed_str` after stripping `REWARD_PREFIX`.
+    pub fn reward_id(&self) -> String {
+        format!("{}{}-{}", Self::REWARD_PREFIX, self.tier, self.level)
+    }
+
     pub fn from_dashed_str(value: &str) -> Result<Self, String> {
         let Some((tier, level)) = value.split_once('-') else {
             return Err(format!("invalid value for divinity: '{value}'"));
@@ -104,4 +118,23 @@ mod tests {
         assert!(mid == same_mid);
         assert!(high > mid);
     }
+
+    #[test]
+    fn test_divinity_display() {
+        assert_eq!(Divinity::new(2, 15).display(), "Tier 2 - Level 15");
+        assert_eq!(Divinity::default().display(), "Tier 1 - Level 1");
+    }
+
+    #[test]
+    fn test_divinity_reward_id_round_trips() {
+        let divinity = Divinity::new(3, 7);
+        assert_eq!(divinity.reward_id(), "divinity:3-7");
+
+        let dashed = divinity
+            .reward_id()
+            .strip_prefix(Divinity::REWARD_PREFIX)
+            .map(str::to_string)
+            .unwrap();
+        assert_eq!(Divinity::from_dashed_str(&dashed), Ok(divinity));
+    }
 }
@@ -14,6 +14,42 @@ use {bevy::prelude::*, shared_components::IncludeInSave};
 #[require(EquippedWeaponId, IncludeInSave)]
 pub struct Hero;
 
+/// Player-facing name of a hero. Saved with the hero, and editable from the hero tab.
+///
+/// Heroes spawned without one are given the first free entry of `DEFAULT_NAMES`.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component)]
+pub struct HeroName(pub String);
+
+impl HeroName {
+    /// Names handed out to new heroes, in order.
+    pub const DEFAULT_NAMES: &[&str] = &[
+        "Aldric", "Brenna", "Corvin", "Dagny", "Edmund", "Freya", "Gareth", "Hilde",
+    ];
+    /// Shown for a hero that has not been named yet.
+    pub const FALLBACK: &str = "Hero";
+    /// Longest name the rename field accepts, in characters.
+    pub const MAX_LEN: usize = 20;
+
+    /// Picks the first default name not in `taken`, then numbered names ("Hero 9", ...).
+    pub fn generate<'a>(taken: impl IntoIterator<Item = &'a str> + Clone) -> Self {
+        let is_free = |name: &str| !taken.clone().into_iter().any(|t| t == name);
+        let name = Self::DEFAULT_NAMES
+            .iter()
+            .map(|name| name.to_string())
+            .chain((Self::DEFAULT_NAMES.len() + 1..).map(|n| format!("{} {}", Self::FALLBACK, n)))
+            .find(|name| is_free(name))
+            .expect("numbered names are unbounded");
+        Self(name)
+    }
+
+    /// The display name for a hero that may not have a `HeroName` yet.
+    pub fn display(name: Option<&HeroName>) -> String {
+        name.map(|n| n.0.clone())
+            .unwrap_or_else(|| Self::FALLBACK.to_string())
+    }
+}
+
 /// Stable identifier for weapons that persists across save/load.
 /// This ID is used to reference weapons by name rather than entity ID.
 #[derive(Component, Reflect, Default, Clone, Debug)]
@@ -209,3 +245,17 @@ pub struct ProjectileDamage {
     /// to apply conditional modifiers.
     pub source_tags: Vec<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_skips_taken_names() {
+        assert_eq!(HeroName::generate([]).0, "Aldric");
+        assert_eq!(HeroName::generate(["Aldric", "Corvin"]).0, "Brenna");
+
+        let all_taken = HeroName::DEFAULT_NAMES.to_vec();
+        assert_eq!(HeroName::generate(all_taken.iter().copied()).0, "Hero 9");
+    }
+}
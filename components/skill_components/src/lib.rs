@@ -1,4 +1,7 @@
-use {bevy::prelude::*, std::collections::{HashMap, HashSet}};
+use {
+    bevy::prelude::*,
+    std::collections::{HashMap, HashSet},
+};
 
 pub struct SkillComponentsPlugin;
 
@@ -45,10 +48,50 @@ pub struct UnlockedSkills(pub HashSet<String>);
 ///   from the `SkillMap` resource, allowing systems to access the actual skill logic and stats.
 /// - **UI Management**: Queried and mutated by `hero_ui` to display active skills and allow
 ///   the player to equip/unequip different abilities.
+///
+/// The vec index is the slot index, so order is significant and must survive save/load.
+/// Write through `set_slot` rather than pushing so a replaced skill keeps its position.
+/// A cleared slot in the middle is kept as an empty string so later slots don't move;
+/// iterate with `skill_ids` to skip those.
 #[derive(Component, Reflect, Default, Clone)]
 #[reflect(Component)]
 pub struct EquippedSkills(pub Vec<String>);
 
+impl EquippedSkills {
+    /// Returns the skill ID equipped in `slot`, if any.
+    pub fn slot(&self, slot: usize) -> Option<&str> {
+        self.0
+            .get(slot)
+            .map(String::as_str)
+            .filter(|id| !id.is_empty())
+    }
+
+    /// Iterates the IDs of all filled slots in slot order.
+    pub fn skill_ids(&self) -> impl Iterator<Item = &String> {
+        self.0.iter().filter(|id| !id.is_empty())
+    }
+
+    /// Equips `skill_id` into `slot`, replacing whatever was there.
+    /// Empty slots are padded in before a slot past the end, so it lands at its index.
+    pub fn set_slot(&mut self, slot: usize, skill_id: impl Into<String>) {
+        if self.0.len() <= slot {
+            self.0.resize(slot + 1, String::new());
+        }
+        self.0[slot] = skill_id.into();
+    }
+
+    /// Removes the skill in `slot`, returning its ID if the slot was filled.
+    /// Other skills keep their slot; only trailing empty slots are dropped.
+    pub fn clear_slot(&mut self, slot: usize) -> Option<String> {
+        let existing = self.0.get_mut(slot).filter(|id| !id.is_empty())?;
+        let skill_id = std::mem::take(existing);
+        while self.0.last().is_some_and(String::is_empty) {
+            self.0.pop();
+        }
+        Some(skill_id)
+    }
+}
+
 /// Tracks cooldown state for active skills
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)]
@@ -84,3 +127,83 @@ pub struct StatusEffect {
     pub status_id: String,
     pub timer: Timer,
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        bevy::reflect::{
+            FromReflect, TypeRegistry,
+            serde::{TypedReflectDeserializer, TypedReflectSerializer},
+        },
+        serde::de::DeserializeSeed,
+    };
+
+    #[test]
+    fn test_set_slot_replaces_in_place() {
+        let mut skills = EquippedSkills(vec!["fireball".to_string(), "heal".to_string()]);
+
+        skills.set_slot(0, "frost_nova");
+        skills.set_slot(3, "shield");
+
+        assert_eq!(skills.0, vec!["frost_nova", "heal", "", "shield"]);
+        assert_eq!(skills.slot(1), Some("heal"));
+        assert_eq!(skills.slot(2), None);
+        assert_eq!(skills.slot(3), Some("shield"));
+    }
+
+    #[test]
+    fn test_clear_slot_removes_filled_slot_only() {
+        let mut skills = EquippedSkills(vec!["fireball".to_string()]);
+
+        assert_eq!(skills.clear_slot(3), None);
+        assert_eq!(skills.clear_slot(0), Some("fireball".to_string()));
+        assert!(skills.0.is_empty());
+    }
+
+    #[test]
+    fn test_clear_middle_slot_keeps_later_slots_in_place() {
+        let mut skills = EquippedSkills(vec![
+            "fireball".to_string(),
+            "heal".to_string(),
+            "shield".to_string(),
+        ]);
+
+        assert_eq!(skills.clear_slot(1), Some("heal".to_string()));
+        assert_eq!(skills.slot(1), None);
+        assert_eq!(skills.slot(2), Some("shield"));
+        assert_eq!(skills.clear_slot(1), None);
+        assert_eq!(
+            skills.skill_ids().collect::<Vec<_>>(),
+            vec!["fireball", "shield"]
+        );
+
+        // Refilling the gap replaces it in place
+        skills.set_slot(1, "frost_nova");
+        assert_eq!(skills.0, vec!["fireball", "frost_nova", "shield"]);
+
+        // Clearing the last slot also drops the empty slots before it
+        skills.clear_slot(1);
+        skills.clear_slot(2);
+        assert_eq!(skills.0, vec!["fireball"]);
+    }
+
+    #[test]
+    fn test_equipped_skills_order_survives_round_trip() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<EquippedSkills>();
+
+        let mut skills = EquippedSkills::default();
+        skills.set_slot(0, "heal");
+        skills.set_slot(1, "fireball");
+
+        let serialized = ron::to_string(&TypedReflectSerializer::new(&skills, &registry)).unwrap();
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+        let reflected = TypedReflectDeserializer::of::<EquippedSkills>(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let restored = EquippedSkills::from_reflect(reflected.as_ref()).unwrap();
+
+        assert_eq!(restored.0, vec!["heal", "fireball"]);
+    }
+}
@@ -45,6 +45,13 @@ pub struct LogicGate {
     pub was_active: bool,
 }
 
+/// Marks a NOT gate whose initial output has not reached its parent yet.
+///
+/// A NOT is high while its child is low, but right after compilation its sensors are not
+/// hydrated. `evaluate_pending_not_gates` sends the output once they are.
+#[derive(Component)]
+pub struct PendingNotGate;
+
 /// Defines the boolean logic behavior for a `LogicGate`.
 ///
 /// This enum determines how signals from child entities (Conditions or other Gates)
@@ -62,9 +62,66 @@ pub struct WeaponInventory {
 #[require(EnemyEncyclopedia, WeaponInventory, Pickable, IncludeInSave)]
 pub struct Village;
 
+impl EncyclopediaEntry {
+    /// True once the enemy has been killed or has escaped at least once.
+    /// Entries added by [`EnemyEncyclopedia::merge_content`] start undiscovered.
+    pub fn is_discovered(&self) -> bool {
+        self.kill_count > 0 || self.escape_count > 0
+    }
+}
+
 impl EnemyEncyclopedia {
+    /// Reconciles a (possibly saved) encyclopedia with the enemies defined by the current content.
+    ///
+    /// Existing entries and their counts are kept, and their `encounter_order` is compacted
+    /// to `0..n` in the saved order. Enemies missing from the encyclopedia are appended as
+    /// undiscovered entries, sorted by id, so every load produces the same ordering.
+    pub fn merge_content<'a>(&mut self, enemy_ids: impl IntoIterator<Item = &'a str>) {
+        let mut saved: Vec<(&String, &mut EncyclopediaEntry)> = self.inner.iter_mut().collect();
+        saved.sort_by(|(a_id, a), (b_id, b)| {
+            (a.encounter_order, a_id.as_str()).cmp(&(b.encounter_order, b_id.as_str()))
+        });
+        for (order, (_, entry)) in saved.into_iter().enumerate() {
+            entry.encounter_order = order;
+        }
+
+        let mut new_ids: Vec<&str> = enemy_ids
+            .into_iter()
+            .filter(|id| !self.inner.contains_key(*id))
+            .collect();
+        new_ids.sort_unstable();
+        new_ids.dedup();
+
+        for id in new_ids {
+            let order = self.inner.len();
+            self.inner.insert(
+                id.to_string(),
+                EncyclopediaEntry {
+                    display_name: id.to_string(),
+                    kill_count: 0,
+                    escape_count: 0,
+                    encounter_order: order,
+                },
+            );
+        }
+    }
+
+    /// Returns the discovered entries sorted by `encounter_order`.
+    pub fn discovered_entries(&self) -> Vec<(&String, &EncyclopediaEntry)> {
+        let mut entries: Vec<_> = self
+            .inner
+            .iter()
+            .filter(|(_, entry)| entry.is_discovered())
+            .collect();
+        entries.sort_by_key(|(_, entry)| entry.encounter_order);
+        entries
+    }
+
     pub fn increment_kill_count(&mut self, enemy_id: &str, display_name: &str) {
         if let Some(entry) = self.inner.get_mut(enemy_id) {
+            if !entry.is_discovered() {
+                entry.display_name = display_name.to_string();
+            }
             entry.kill_count += 1;
         } else {
             let order = self.inner.len();
@@ -82,6 +139,9 @@ impl EnemyEncyclopedia {
 
     pub fn increment_escape_count(&mut self, enemy_id: &str, display_name: &str) {
         if let Some(entry) = self.inner.get_mut(enemy_id) {
+            if !entry.is_discovered() {
+                entry.display_name = display_name.to_string();
+            }
             entry.escape_count += 1;
         } else {
             let order = self.inner.len();
@@ -97,3 +157,48 @@ impl EnemyEncyclopedia {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_content_keeps_counts_and_appends_new_enemies() {
+        let mut encyclopedia = EnemyEncyclopedia::default();
+        encyclopedia.increment_kill_count("goblin", "Goblin");
+        encyclopedia.increment_kill_count("goblin", "Goblin");
+        encyclopedia.increment_escape_count("rat", "Rat");
+
+        encyclopedia.merge_content(["skeleton", "goblin", "rat", "bat"]);
+
+        let goblin = &encyclopedia.inner["goblin"];
+        assert_eq!((goblin.kill_count, goblin.encounter_order), (2, 0));
+        let rat = &encyclopedia.inner["rat"];
+        assert_eq!((rat.escape_count, rat.encounter_order), (1, 1));
+
+        // New enemies are undiscovered and ordered by id after saved entries
+        let bat = &encyclopedia.inner["bat"];
+        assert!(!bat.is_discovered());
+        assert_eq!(bat.encounter_order, 2);
+        assert_eq!(encyclopedia.inner["skeleton"].encounter_order, 3);
+
+        // Merging again is a no-op
+        encyclopedia.merge_content(["skeleton", "goblin", "rat", "bat"]);
+        assert_eq!(encyclopedia.inner.len(), 4);
+        assert_eq!(encyclopedia.inner["skeleton"].encounter_order, 3);
+
+        // Only enemies that were actually met are listed
+        let listed: Vec<&str> = encyclopedia
+            .discovered_entries()
+            .into_iter()
+            .map(|(id, _)| id.as_str())
+            .collect();
+        assert_eq!(listed, vec!["goblin", "rat"]);
+
+        // Discovering a merged enemy keeps its slot and picks up the real name
+        encyclopedia.increment_kill_count("bat", "Cave Bat");
+        let bat = &encyclopedia.inner["bat"];
+        assert_eq!((bat.display_name.as_str(), bat.kill_count), ("Cave Bat", 1));
+        assert_eq!(bat.encounter_order, 2);
+    }
+}
@@ -5,21 +5,25 @@ use {
     crafting_resources::CraftingResourcesPlugin, crafting_ui::CraftingUiPlugin,
     divinity_components::DivinityComponentsPlugin, enemy_encyclopedia::EnemyEncyclopediaUiPlugin,
     enemy_events::EnemyEventsPlugin, heroes::HeroesPlugin, hud::HudPlugin,
-    loading::LoadingManagerPlugin, notification_ui::NotificationUiPlugin,
-    portal_assets::PortalAssetsPlugin, portal_ui::PortalUiPlugin, portals::PortalsPlugin,
-    progress_bars::ProgressBarsPlugin, research::ResearchPlugin, research_ui::ResearchUiPlugin,
-    resources_ui::ResourcesUiPlugin, save_load::SaveLoadPlugin,
-    shared_components::SharedComponentsPlugin, states::GameState, system_schedule::GameSchedule::*,
-    unlocks::UnlocksPlugin, unlocks_assets::UnlocksAssetsPlugin, village::VillagePlugin,
-    village_ui::VillageUiPlugin, wallet::WalletPlugin, weapon_assets::WeaponAssetsPlugin,
-    weapon_factory::WeaponFactoryPlugin, widgets::WidgetsPlugin,
+    loading::LoadingManagerPlugin, log_console::LogConsolePlugin,
+    notification_ui::NotificationUiPlugin, portal_assets::PortalAssetsPlugin,
+    portal_ui::PortalUiPlugin, portals::PortalsPlugin, progress_bars::ProgressBarsPlugin,
+    research::ResearchPlugin, research_ui::ResearchUiPlugin, resources_ui::ResourcesUiPlugin,
+    save_load::SaveLoadPlugin, shared_components::SharedComponentsPlugin, states::GameState,
+    system_schedule::GameSchedule::*, unlocks::UnlocksPlugin, unlocks_assets::UnlocksAssetsPlugin,
+    village::VillagePlugin, village_ui::VillageUiPlugin, wallet::WalletPlugin,
+    weapon_assets::WeaponAssetsPlugin, weapon_factory::WeaponFactoryPlugin, widgets::WidgetsPlugin,
 };
 
+mod systems;
+
 pub struct CorePlugin;
 
 impl Plugin for CorePlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<GameState>()
+            .add_sub_state::<states::PauseState>()
+            .init_resource::<states::GameSpeed>()
             .configure_sets(
                 Update,
                 (FrameStart, ResolveIntent, PerformAction, Effect, FrameEnd).chain(),
@@ -68,8 +72,14 @@ impl Plugin for CorePlugin {
                 SaveLoadPlugin,
                 WeaponAssetsPlugin,
                 WeaponFactoryPlugin,
+                LogConsolePlugin,
+                audio::SfxPlugin,
             ))
-            .add_systems(Startup, setup_camera);
+            .add_systems(Startup, setup_camera)
+            .add_systems(
+                Update,
+                systems::close_topmost_panel_on_escape.run_if(in_state(GameState::Running)),
+            );
     }
 }
 
@@ -1 +1,135 @@
-// Module kept for potential future systems
+use {
+    bevy::prelude::*,
+    hero_ui::{EquipmentPopup, HeroUiState, RenameHeroPopup, SkillPopup},
+    portal_ui::PortalUiRoot,
+    states::{EnemyEncyclopediaState, VillageView},
+    village_ui::VillageUiRoot,
+    widgets::PanelWrapperRef,
+};
+
+/// Closes one panel per `Escape` press, topmost first: hero popups, then the portal
+/// panel, then the village panel. Repeated presses unwind nested UI.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn close_topmost_panel_on_escape(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    popups: Query<
+        Entity,
+        Or<(
+            With<EquipmentPopup>,
+            With<SkillPopup>,
+            With<RenameHeroPopup>,
+        )>,
+    >,
+    portal_panels: Query<(Entity, Option<&PanelWrapperRef>), With<PortalUiRoot>>,
+    village_panels: Query<(Entity, Option<&PanelWrapperRef>), With<VillageUiRoot>>,
+    mut next_village_view: ResMut<NextState<VillageView>>,
+    mut next_hero_ui: ResMut<NextState<HeroUiState>>,
+    next_encyclopedia: Option<ResMut<NextState<EnemyEncyclopediaState>>>,
+) {
+    if !keyboard.just_pressed(KeyCode::Escape) {
+        return;
+    }
+
+    if !popups.is_empty() {
+        for popup in popups.iter() {
+            commands.entity(popup).despawn();
+        }
+        return;
+    }
+
+    if close_panels(&mut commands, &portal_panels) {
+        return;
+    }
+
+    if close_panels(&mut commands, &village_panels) {
+        next_village_view.set(VillageView::Closed);
+        next_hero_ui.set(HeroUiState::Closed);
+        if let Some(mut next_encyclopedia) = next_encyclopedia {
+            next_encyclopedia.set(EnemyEncyclopediaState::Closed);
+        }
+    }
+}
+
+/// Despawns every panel matched by `panels` (via its wrapper where present).
+/// Returns false if there was nothing to close.
+fn close_panels<M: Component>(
+    commands: &mut Commands,
+    panels: &Query<(Entity, Option<&PanelWrapperRef>), With<M>>,
+) -> bool {
+    for (panel, wrapper_ref) in panels.iter() {
+        match wrapper_ref {
+            Some(wrapper) => commands.entity(wrapper.0).despawn(),
+            None => commands.entity(panel).despawn(),
+        };
+    }
+    !panels.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        bevy::state::app::StatesPlugin,
+        village_ui::{VillageContent, VillageUiRoot},
+    };
+
+    fn press_escape(app: &mut App) {
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.reset_all();
+        keyboard.press(KeyCode::Escape);
+        app.update();
+    }
+
+    #[test]
+    fn test_escape_closes_one_panel_per_press() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_state::<VillageView>()
+            .init_state::<HeroUiState>()
+            .add_systems(Update, close_topmost_panel_on_escape);
+
+        let village = app
+            .world_mut()
+            .spawn(VillageUiRoot {
+                content: VillageContent::Heroes,
+            })
+            .id();
+        let popup = app
+            .world_mut()
+            .spawn(EquipmentPopup {
+                hero_entity: Entity::PLACEHOLDER,
+            })
+            .id();
+        app.world_mut()
+            .resource_mut::<NextState<VillageView>>()
+            .set(VillageView::Heroes);
+        app.world_mut()
+            .resource_mut::<NextState<HeroUiState>>()
+            .set(HeroUiState::Open);
+        app.update();
+
+        // First press only closes the popup
+        press_escape(&mut app);
+        assert!(app.world().get_entity(popup).is_err());
+        assert!(app.world().get_entity(village).is_ok());
+        assert_eq!(
+            *app.world().resource::<State<VillageView>>().get(),
+            VillageView::Heroes
+        );
+
+        // Second press closes the village panel and resets its states
+        press_escape(&mut app);
+        app.update();
+        assert!(app.world().get_entity(village).is_err());
+        assert_eq!(
+            *app.world().resource::<State<VillageView>>().get(),
+            VillageView::Closed
+        );
+        assert_eq!(
+            *app.world().resource::<State<HeroUiState>>().get(),
+            HeroUiState::Closed
+        );
+    }
+}
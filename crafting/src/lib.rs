@@ -1,13 +1,21 @@
 use {
-    bevy::prelude::*, crafting_resources::CraftingOutcome, recipes_assets::RecipeDefinition,
-    shared_components::IncludeInSave, states::GameState, system_schedule::GameSchedule,
+    bevy::{platform::collections::HashMap, prelude::*},
+    crafting_resources::{CraftingOutcome, CraftingQueue},
+    recipes_assets::{RecipeCategory, RecipeDefinition},
+    shared_components::IncludeInSave,
+    states::GameState,
+    system_schedule::GameSchedule,
+    wallet::Wallet,
 };
 
+pub mod library;
 pub mod systems;
 
 // Re-export for convenience
 pub use {
     crafting_components::RecipeNode,
+    crafting_events::CraftingCompleted,
+    library::RecipeLibrary,
     unlock_states::{Available, Locked},
 };
 
@@ -18,8 +26,10 @@ pub use {
 /// # Usage
 /// - **Progression**: The `update_crafting_progress` system ticks the `timer` every frame.
 ///   Upon completion, it triggers a `StatusCompleted` event (Observer) for the unlock system
-///   and despawns this entity.
+///   and a `CraftingCompleted` event for player feedback, and despawns this entity.
 /// - **Initialization**: Spawns via the `start_crafting` observer when a `StartCraftingRequest` is received.
+/// - **Cancellation**: The `cancel_crafting` observer despawns it on `CancelCraftingRequest`
+///   and refunds `paid`.
 /// - **Persistence**: Automatically tagged with `IncludeInSave` to ensure crafting progress
 ///   is not lost when exiting the game.
 #[derive(Component, Debug, Reflect)]
@@ -31,6 +41,62 @@ pub struct CraftingInProgress {
     /// The progress tracker. Its duration defines the total time (in seconds) required to complete the craft.
     pub timer: Timer,
     pub category: recipes_assets::RecipeCategory,
+    /// Resources spent on this craft. Empty for saves written before crafts could be cancelled.
+    #[reflect(default)]
+    pub paid: HashMap<String, u32>,
+}
+
+/// Number of crafts of `recipe_id` that count towards its repeat limit and scaling:
+/// completed crafts plus crafts still in progress or queued.
+pub fn crafted_count<'a>(
+    state: &crafting_resources::CraftingState,
+    queue: &CraftingQueue,
+    in_progress: impl IntoIterator<Item = &'a CraftingInProgress>,
+    recipe_id: &str,
+) -> u32 {
+    started_count(state, in_progress, recipe_id) + queue.count(recipe_id)
+}
+
+/// Number of crafts of `recipe_id` completed or still in progress. Unlike `crafted_count`
+/// this leaves out queued crafts, which haven't been built yet.
+pub fn started_count<'a>(
+    state: &crafting_resources::CraftingState,
+    in_progress: impl IntoIterator<Item = &'a CraftingInProgress>,
+    recipe_id: &str,
+) -> u32 {
+    let completed = state.completion_counts.get(recipe_id).copied().unwrap_or(0);
+    let pending = in_progress
+        .into_iter()
+        .filter(|crafting| crafting.recipe_id == recipe_id)
+        .count() as u32;
+    completed + pending
+}
+
+/// True once no more crafts of `def` can be paid for, with `crafted` as counted by
+/// `crafted_count`. A construction is built only once: its recipe node is despawned when
+/// it completes, so a second one queued behind it would never start.
+pub fn is_recipe_maxed(def: &RecipeDefinition, crafted: u32) -> bool {
+    def.is_maxed(crafted) || (def.category == RecipeCategory::Construction && crafted > 0)
+}
+
+/// Prefix of unlock reward ids that make a recipe available, e.g. "recipe:bone_sword".
+pub const RECIPE_REWARD_PREFIX: &str = "recipe:";
+
+/// Upper bound for a single "Craft Max" batch, so free recipes can't flood the queue.
+pub const MAX_BATCH_CRAFTS: u32 = 100;
+
+/// How many crafts of `def` the wallet can pay for in a row, starting after `crafted`
+/// previous crafts. Accounts for cost scaling and the repeat limit.
+pub fn max_affordable_crafts(def: &RecipeDefinition, wallet: &Wallet, crafted: u32) -> u32 {
+    let mut remaining = wallet.clone();
+    let mut count = 0;
+    while count < MAX_BATCH_CRAFTS
+        && !is_recipe_maxed(def, crafted + count)
+        && remaining.try_spend(&def.scaled_cost(crafted + count))
+    {
+        count += 1;
+    }
+    count
 }
 
 pub struct CraftingPlugin;
@@ -42,11 +108,18 @@ impl Plugin for CraftingPlugin {
             .add_observer(systems::start_crafting)
             .add_observer(systems::on_recipe_unlock_achieved)
             .add_observer(systems::on_construction_completed)
+            .add_observer(systems::cancel_queued_crafts)
+            .add_observer(systems::clear_crafting_queue)
+            .add_observer(systems::cancel_crafting)
             .add_systems(
                 Update,
-                systems::update_crafting_progress
+                (
+                    systems::update_crafting_progress,
+                    systems::start_queued_crafts,
+                )
+                    .chain()
                     .in_set(GameSchedule::FrameStart)
-                    .run_if(in_state(GameState::Running)),
+                    .run_if(in_state(GameState::Running).and(states::not_paused)),
             )
             .add_systems(
                 OnExit(states::GameState::Running),
@@ -0,0 +1,133 @@
+use {
+    crate::{Available, RecipeNode},
+    bevy::{ecs::system::SystemParam, prelude::*},
+    crafting_resources::{RecipeCategory, RecipeMap},
+    recipes_assets::RecipeDefinition,
+};
+
+/// Read-only access to recipe definitions and their availability.
+///
+/// Wraps the `RecipeMap` -> `RecipeNode` -> `Assets<RecipeDefinition>` lookup chain
+/// so UIs don't have to repeat it.
+#[derive(SystemParam)]
+pub struct RecipeLibrary<'w, 's> {
+    recipe_map: Res<'w, RecipeMap>,
+    assets: Res<'w, Assets<RecipeDefinition>>,
+    nodes: Query<'w, 's, (&'static RecipeNode, Has<Available>)>,
+}
+
+impl RecipeLibrary<'_, '_> {
+    /// Returns the definition of a recipe, whether or not it is available yet.
+    pub fn get_def(&self, id: &str) -> Option<&RecipeDefinition> {
+        let entity = self.recipe_map.entities.get(id)?;
+        let (node, _) = self.nodes.get(*entity).ok()?;
+        self.assets.get(&node.handle)
+    }
+
+    /// Returns true if the recipe is unlocked and can be crafted.
+    pub fn is_available(&self, id: &str) -> bool {
+        self.recipe_map
+            .entities
+            .get(id)
+            .and_then(|entity| self.nodes.get(*entity).ok())
+            .is_some_and(|(_, available)| available)
+    }
+
+    /// Returns the definition of a recipe only if it is available.
+    pub fn get_available_def(&self, id: &str) -> Option<&RecipeDefinition> {
+        if self.is_available(id) {
+            self.get_def(id)
+        } else {
+            None
+        }
+    }
+
+    /// Returns all available recipes in `category`, sorted by id.
+    pub fn available_by_category(
+        &self,
+        category: &RecipeCategory,
+    ) -> Vec<(&str, &RecipeDefinition)> {
+        let mut recipes: Vec<_> = self
+            .nodes
+            .iter()
+            .filter(|(_, available)| *available)
+            .filter_map(|(node, _)| {
+                let def = self.assets.get(&node.handle)?;
+                (&def.category == category).then_some((node.id.as_str(), def))
+            })
+            .collect();
+        recipes.sort_by_key(|(id, _)| *id);
+        recipes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::Locked, bevy::ecs::system::RunSystemOnce};
+
+    fn definition(id: &str, category: RecipeCategory) -> RecipeDefinition {
+        RecipeDefinition {
+            id: id.to_string(),
+            display_name: id.to_string(),
+            category,
+            subcategory: None,
+            craft_time: 1.0,
+            cost: Default::default(),
+            outcomes: vec![],
+            max_repeats: None,
+            cost_scaling: 1.0,
+            time_scaling: 1.0,
+            unlock: None,
+        }
+    }
+
+    fn spawn_recipe(world: &mut World, id: &str, category: RecipeCategory, available: bool) {
+        let handle = world
+            .resource_mut::<Assets<RecipeDefinition>>()
+            .add(definition(id, category));
+        let node = RecipeNode {
+            id: id.to_string(),
+            handle,
+        };
+        let entity = if available {
+            world.spawn((node, Available)).id()
+        } else {
+            world.spawn((node, Locked)).id()
+        };
+        world
+            .resource_mut::<RecipeMap>()
+            .entities
+            .insert(id.to_string(), entity);
+    }
+
+    #[test]
+    fn test_available_by_category() {
+        let mut world = World::new();
+        world.init_resource::<RecipeMap>();
+        world.init_resource::<Assets<RecipeDefinition>>();
+
+        spawn_recipe(&mut world, "bone_sword", RecipeCategory::Weapons, true);
+        spawn_recipe(&mut world, "bone_bow", RecipeCategory::Weapons, true);
+        spawn_recipe(&mut world, "iron_sword", RecipeCategory::Weapons, false);
+        spawn_recipe(&mut world, "wall", RecipeCategory::Construction, true);
+
+        world
+            .run_system_once(|library: RecipeLibrary| {
+                let weapons: Vec<&str> = library
+                    .available_by_category(&RecipeCategory::Weapons)
+                    .into_iter()
+                    .map(|(id, _)| id)
+                    .collect();
+                assert_eq!(weapons, vec!["bone_bow", "bone_sword"]);
+
+                assert!(library.is_available("wall"));
+                assert!(!library.is_available("iron_sword"));
+                assert!(!library.is_available("missing"));
+
+                // Locked recipes still have a definition, but are not available
+                assert!(library.get_def("iron_sword").is_some());
+                assert!(library.get_available_def("iron_sword").is_none());
+            })
+            .unwrap();
+    }
+}
@@ -1,15 +1,21 @@
 use {
-    crate::{Available, CraftingInProgress, Locked, RecipeNode},
+    crate::{
+        Available, CraftingInProgress, Locked, RECIPE_REWARD_PREFIX, RecipeLibrary, RecipeNode,
+    },
     bevy::prelude::*,
-    crafting_events::StartCraftingRequest,
-    crafting_resources::RecipeMap,
+    crafting_events::{
+        CancelCraftingRequest, CancelQueuedCrafts, ClearCraftingQueue, CraftingCompleted,
+        StartCraftingRequest,
+    },
+    crafting_resources::{CraftingQueue, CraftingState, RecipeMap},
     recipes_assets::{CONSTRUCTION_TOPIC_PREFIX, RecipeDefinition},
-    unlocks_events::{StatusCompleted, ValueChanged},
+    states::{GameSpeed, scaled_delta},
+    unlocks_events::{StatusCompleted, Topic, ValueChanged},
     wallet::Wallet,
 };
 
 /// Observer that handles StartCraftingRequest events.
-/// Spawns a CraftingInProgress entity with a timer.
+/// Spawns a CraftingInProgress entity with a timer scaled by previous crafts.
 /// Note: Validation and resource deduction already handled by UI.
 pub fn start_crafting(
     trigger: On<StartCraftingRequest>,
@@ -17,8 +23,10 @@ pub fn start_crafting(
     recipe_map: Res<RecipeMap>,
     recipe_query: Query<&RecipeNode, With<Available>>,
     assets: Res<Assets<RecipeDefinition>>,
+    crafting_state: Res<CraftingState>,
+    in_progress: Query<&CraftingInProgress>,
 ) {
-    let recipe_id = &trigger.event().recipe_id;
+    let StartCraftingRequest { recipe_id, paid } = trigger.event();
 
     // Look up the recipe entity
     let Some(&entity) = recipe_map.entities.get(recipe_id) else {
@@ -38,12 +46,20 @@ pub fn start_crafting(
         return;
     };
 
+    // Crafts still waiting in the queue were paid for but not built, so they don't scale this one
+    let crafted = crate::started_count(&crafting_state, in_progress.iter(), recipe_id);
+    if def.is_maxed(crafted) {
+        warn!("Recipe '{}' reached its repeat limit", recipe_id);
+        return;
+    }
+
     // Spawn crafting entity with timer
     commands.spawn(CraftingInProgress {
         recipe_id: recipe_id.clone(),
         outcomes: def.outcomes.clone(),
-        timer: Timer::from_seconds(def.craft_time, TimerMode::Once),
+        timer: Timer::from_seconds(def.scaled_craft_time(crafted), TimerMode::Once),
         category: def.category,
+        paid: paid.clone(),
     });
 
     info!("Crafting started for: {}", def.display_name);
@@ -59,8 +75,6 @@ pub fn on_recipe_unlock_achieved(
 ) {
     let event = trigger.event();
 
-    const RECIPE_REWARD_PREFIX: &str = "recipe:";
-
     let Some(entity) = event
         .reward_id
         .strip_prefix(RECIPE_REWARD_PREFIX)
@@ -84,11 +98,14 @@ pub fn on_recipe_unlock_achieved(
 pub fn update_crafting_progress(
     mut commands: Commands,
     time: Res<Time>,
+    speed: Res<GameSpeed>,
     mut wallet: ResMut<Wallet>,
+    mut crafting_state: ResMut<CraftingState>,
     mut query: Query<(Entity, &mut CraftingInProgress)>,
 ) {
+    let delta = scaled_delta(&time, &speed);
     for (entity, mut crafting) in query.iter_mut() {
-        crafting.timer.tick(time.delta());
+        crafting.timer.tick(delta);
 
         if crafting.timer.is_finished() {
             info!(%crafting.recipe_id, ?crafting.category, "Crafting complete" );
@@ -104,26 +121,35 @@ pub fn update_crafting_progress(
                         // Notify that a resource was produced/changed
                         // The unlock system will trigger UnlockAchieved if conditions are met
                         commands.trigger(StatusCompleted {
-                            topic: format!("resource:{}", id),
+                            topic: Topic::resource(id).into(),
                         });
 
                         commands.trigger(ValueChanged {
-                            topic: format!("resource:{}", id),
+                            topic: Topic::resource(id).into(),
                             value: new_val as f32,
                         });
                         info!("Added {} {} to wallet via crafting", amount, id);
                     }
                     recipes_assets::CraftingOutcome::UnlockFeature(feature) => {
                         commands.trigger(StatusCompleted {
-                            topic: format!("feature:{}", feature),
+                            topic: Topic::feature(feature).into(),
                         });
                         info!("Signaled feature completion: {} via crafting", feature);
                     }
                 }
             }
 
+            *crafting_state
+                .completion_counts
+                .entry(crafting.recipe_id.clone())
+                .or_insert(0) += 1;
+
             // Despawn the crafting entity
             commands.entity(entity).despawn();
+            // Before StatusCompleted: completed constructions despawn their recipe node
+            commands.trigger(CraftingCompleted {
+                recipe_id: crafting.recipe_id.clone(),
+            });
             commands.trigger(StatusCompleted {
                 topic: format!(
                     "{}{}",
@@ -135,6 +161,111 @@ pub fn update_crafting_progress(
     }
 }
 
+/// System that starts the oldest queued craft of every recipe with no craft running.
+///
+/// Every paid craft goes through the queue, so crafts of one recipe run one after another.
+/// Recipes that stopped being available keep their queue until cancelled. Crafts of recipes
+/// whose node is gone, like a completed construction, can never start and are refunded.
+pub fn start_queued_crafts(
+    mut commands: Commands,
+    mut queue: ResMut<CraftingQueue>,
+    mut wallet: ResMut<Wallet>,
+    library: RecipeLibrary,
+    in_progress: Query<&CraftingInProgress>,
+) {
+    let orphaned: Vec<String> = queue
+        .queued
+        .keys()
+        .filter(|recipe_id| library.get_def(recipe_id).is_none())
+        .cloned()
+        .collect();
+    for recipe_id in orphaned {
+        let cancelled = queue.cancel(&recipe_id);
+        for paid in &cancelled {
+            wallet.refund(paid);
+        }
+        warn!(
+            "Refunded {} queued crafts of unknown recipe '{}'",
+            cancelled.len(),
+            recipe_id
+        );
+    }
+
+    let ready: Vec<String> = queue
+        .queued
+        .keys()
+        .filter(|recipe_id| library.is_available(recipe_id))
+        .filter(|recipe_id| !in_progress.iter().any(|p| &p.recipe_id == *recipe_id))
+        .cloned()
+        .collect();
+
+    for recipe_id in ready {
+        let paid = queue.pop(&recipe_id).unwrap_or_default();
+        debug!(%recipe_id, remaining = queue.count(&recipe_id), "Starting queued craft");
+        commands.trigger(StartCraftingRequest { recipe_id, paid });
+    }
+}
+
+/// Observer for CancelQueuedCrafts events. Refunds every dropped craft in full.
+pub fn cancel_queued_crafts(
+    trigger: On<CancelQueuedCrafts>,
+    mut queue: ResMut<CraftingQueue>,
+    mut wallet: ResMut<Wallet>,
+) {
+    let recipe_id = &trigger.event().recipe_id;
+    let cancelled = queue.cancel(recipe_id);
+    for paid in &cancelled {
+        wallet.refund(paid);
+    }
+    if !cancelled.is_empty() {
+        info!(
+            "Cancelled {} queued crafts of '{}'",
+            cancelled.len(),
+            recipe_id
+        );
+    }
+}
+
+/// Observer for ClearCraftingQueue events. Refunds every dropped craft in full.
+pub fn clear_crafting_queue(
+    _trigger: On<ClearCraftingQueue>,
+    mut queue: ResMut<CraftingQueue>,
+    mut wallet: ResMut<Wallet>,
+) {
+    let cancelled = queue.clear();
+    for paid in &cancelled {
+        wallet.refund(paid);
+    }
+    if !cancelled.is_empty() {
+        info!("Cleared {} queued crafts", cancelled.len());
+    }
+}
+
+/// Observer for CancelCraftingRequest events. Despawns the craft and refunds it in full.
+///
+/// A craft whose timer already finished is left alone; `update_crafting_progress` completes
+/// it, so the player never gets both the outcome and the refund.
+pub fn cancel_crafting(
+    trigger: On<CancelCraftingRequest>,
+    mut commands: Commands,
+    mut wallet: ResMut<Wallet>,
+    mut query: Query<&mut CraftingInProgress>,
+) {
+    let entity = trigger.event().crafting_entity;
+    let Ok(mut crafting) = query.get_mut(entity) else {
+        return;
+    };
+    if crafting.timer.is_finished() {
+        debug!(%crafting.recipe_id, "Ignoring cancel of a finished craft");
+        return;
+    }
+
+    // Taken so a second cancel before the despawn applies refunds nothing
+    wallet.refund(&std::mem::take(&mut crafting.paid));
+    commands.entity(entity).despawn();
+    info!("Cancelled crafting of '{}'", crafting.recipe_id);
+}
+
 /// Observer for StatusCompleted events.
 /// Handles construction completion (marking building as constructed, removing recipe from UI).
 pub fn on_construction_completed(
@@ -173,6 +304,7 @@ pub fn on_construction_completed(
 pub fn clean_up_crafting(
     mut commands: Commands,
     mut recipe_map: ResMut<RecipeMap>,
+    mut queue: ResMut<CraftingQueue>,
     recipes: Query<Entity, With<RecipeNode>>,
     in_progress: Query<Entity, With<CraftingInProgress>>,
 ) {
@@ -188,4 +320,215 @@ pub fn clean_up_crafting(
     }
 
     recipe_map.entities.clear();
+    queue.queued.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, bevy::ecs::system::RunSystemOnce, recipes_assets::RecipeCategory};
+
+    fn setup() -> World {
+        let mut world = World::new();
+        world.init_resource::<RecipeMap>();
+        world.init_resource::<Assets<RecipeDefinition>>();
+        world.init_resource::<CraftingState>();
+        world.init_resource::<CraftingQueue>();
+        world.init_resource::<Wallet>();
+        world.add_observer(start_crafting);
+        world.add_observer(cancel_queued_crafts);
+        world.add_observer(clear_crafting_queue);
+        world.add_observer(cancel_crafting);
+
+        let handle = world
+            .resource_mut::<Assets<RecipeDefinition>>()
+            .add(RecipeDefinition {
+                id: "bone_sword".to_string(),
+                display_name: "Bone Sword".to_string(),
+                category: RecipeCategory::Weapons,
+                subcategory: None,
+                craft_time: 1.0,
+                cost: Default::default(),
+                outcomes: vec![],
+                max_repeats: None,
+                cost_scaling: 1.0,
+                time_scaling: 1.0,
+                unlock: None,
+            });
+        let entity = world
+            .spawn((
+                RecipeNode {
+                    id: "bone_sword".to_string(),
+                    handle,
+                },
+                Available,
+            ))
+            .id();
+        world
+            .resource_mut::<RecipeMap>()
+            .entities
+            .insert("bone_sword".to_string(), entity);
+        world
+    }
+
+    fn running_crafts(world: &mut World) -> usize {
+        world.query::<&CraftingInProgress>().iter(world).count()
+    }
+
+    fn paid(bones: u32) -> bevy::platform::collections::HashMap<String, u32> {
+        [("bones".to_string(), bones)].into_iter().collect()
+    }
+
+    #[test]
+    fn test_queued_crafts_run_one_after_another() {
+        let mut world = setup();
+        {
+            let mut queue = world.resource_mut::<CraftingQueue>();
+            queue.push("bone_sword", paid(5));
+            queue.push("bone_sword", paid(5));
+        }
+
+        world.run_system_once(start_queued_crafts).unwrap();
+        assert_eq!(running_crafts(&mut world), 1);
+        assert_eq!(world.resource::<CraftingQueue>().count("bone_sword"), 1);
+
+        // Still running, so the second craft keeps waiting
+        world.run_system_once(start_queued_crafts).unwrap();
+        assert_eq!(running_crafts(&mut world), 1);
+
+        let running = world
+            .query_filtered::<Entity, With<CraftingInProgress>>()
+            .single(&world)
+            .unwrap();
+        world.despawn(running);
+        world.run_system_once(start_queued_crafts).unwrap();
+        assert_eq!(running_crafts(&mut world), 1);
+        assert_eq!(world.resource::<CraftingQueue>().count("bone_sword"), 0);
+    }
+
+    #[test]
+    fn test_queued_crafts_of_a_despawned_recipe_are_refunded() {
+        let mut world = setup();
+        {
+            let mut queue = world.resource_mut::<CraftingQueue>();
+            queue.push("bone_sword", paid(5));
+            queue.push("bone_sword", paid(5));
+        }
+        // Like a completed construction, the node is gone but the map entry stays
+        let recipe = world.resource::<RecipeMap>().entities["bone_sword"];
+        world.despawn(recipe);
+
+        world.run_system_once(start_queued_crafts).unwrap();
+
+        assert_eq!(running_crafts(&mut world), 0);
+        assert_eq!(world.resource::<CraftingQueue>().count("bone_sword"), 0);
+        assert_eq!(world.resource::<Wallet>().amount("bones"), 10);
+    }
+
+    #[test]
+    fn test_queued_crafts_do_not_scale_the_running_craft() {
+        let mut world = setup();
+        for (_, def) in world.resource_mut::<Assets<RecipeDefinition>>().iter_mut() {
+            def.time_scaling = 2.0;
+        }
+        {
+            let mut queue = world.resource_mut::<CraftingQueue>();
+            for _ in 0..3 {
+                queue.push("bone_sword", paid(5));
+            }
+        }
+
+        world.run_system_once(start_queued_crafts).unwrap();
+
+        let craft = world.query::<&CraftingInProgress>().single(&world).unwrap();
+        assert_eq!(craft.timer.duration().as_secs_f32(), 1.0);
+        assert_eq!(world.resource::<CraftingQueue>().count("bone_sword"), 2);
+    }
+
+    #[test]
+    fn test_cancel_refunds_every_queued_craft() {
+        let mut world = setup();
+        {
+            let mut queue = world.resource_mut::<CraftingQueue>();
+            queue.push("bone_sword", paid(5));
+            queue.push("bone_sword", paid(8));
+        }
+
+        world.trigger(CancelQueuedCrafts {
+            recipe_id: "bone_sword".to_string(),
+        });
+
+        assert_eq!(world.resource::<Wallet>().amount("bones"), 13);
+        assert_eq!(world.resource::<CraftingQueue>().count("bone_sword"), 0);
+
+        // Nothing left to refund twice
+        world.trigger(CancelQueuedCrafts {
+            recipe_id: "bone_sword".to_string(),
+        });
+        assert_eq!(world.resource::<Wallet>().amount("bones"), 13);
+    }
+
+    #[test]
+    fn test_clear_queue_refunds_queued_crafts_and_keeps_the_running_one() {
+        let mut world = setup();
+        {
+            let mut queue = world.resource_mut::<CraftingQueue>();
+            queue.push("bone_sword", paid(5));
+            queue.push("bone_sword", paid(8));
+            queue.push("bone_dust", paid(2));
+        }
+        world.run_system_once(start_queued_crafts).unwrap();
+        assert_eq!(running_crafts(&mut world), 1);
+
+        world.trigger(ClearCraftingQueue);
+
+        assert!(world.resource::<CraftingQueue>().queued.is_empty());
+        assert_eq!(world.resource::<Wallet>().amount("bones"), 10);
+        assert_eq!(running_crafts(&mut world), 1);
+    }
+
+    #[test]
+    fn test_cancel_crafting_refunds_once() {
+        let mut world = setup();
+        world.trigger(StartCraftingRequest {
+            recipe_id: "bone_sword".to_string(),
+            paid: paid(5),
+        });
+        world.flush();
+        let crafting = world
+            .query_filtered::<Entity, With<CraftingInProgress>>()
+            .single(&world)
+            .unwrap();
+
+        world.trigger(CancelCraftingRequest {
+            crafting_entity: crafting,
+        });
+        world.trigger(CancelCraftingRequest {
+            crafting_entity: crafting,
+        });
+        world.flush();
+
+        assert_eq!(running_crafts(&mut world), 0);
+        assert_eq!(world.resource::<Wallet>().amount("bones"), 5);
+    }
+
+    #[test]
+    fn test_cancel_ignores_finished_craft() {
+        let mut world = setup();
+        world.trigger(StartCraftingRequest {
+            recipe_id: "bone_sword".to_string(),
+            paid: paid(5),
+        });
+        world.flush();
+        let mut crafting = world.query::<(Entity, &mut CraftingInProgress)>();
+        let (entity, mut craft) = crafting.single_mut(&mut world).unwrap();
+        let duration = craft.timer.duration();
+        craft.timer.tick(duration);
+
+        world.trigger(CancelCraftingRequest {
+            crafting_entity: entity,
+        });
+
+        assert_eq!(running_crafts(&mut world), 1);
+        assert_eq!(world.resource::<Wallet>().amount("bones"), 0);
+    }
 }
@@ -18,7 +18,7 @@ pub struct AddStatBonus {
     pub key: String,
     /// The numeric value of the bonus.
     pub value: f32,
-    /// How this bonus aggregates with others (Additive, Percent, or Multiplicative).
+    /// How this bonus aggregates with others (Additive, Percent, Multiplicative, or DiminishingPercent).
     pub mode: StatMode,
 }
 
@@ -35,7 +35,7 @@ pub struct RemoveStatBonus {
     pub key: String,
     /// The numeric value of the bonus to remove.
     pub value: f32,
-    /// How this bonus aggregated with others (Additive, Percent, or Multiplicative).
+    /// How this bonus aggregated with others (Additive, Percent, Multiplicative, or DiminishingPercent).
     pub mode: StatMode,
 }
 
@@ -1,8 +1,38 @@
-use bevy::prelude::*;
+use bevy::{platform::collections::HashMap, prelude::*};
 
 /// Event to request starting a crafting operation.
 /// Used with observers via commands.trigger().
 #[derive(Event)]
 pub struct StartCraftingRequest {
     pub recipe_id: String,
+    /// Resources already spent on this craft, refunded if it is cancelled
+    pub paid: HashMap<String, u32>,
+}
+
+/// Request to abort a running craft and refund what it paid in full.
+/// Ignored if the craft already finished.
+#[derive(Event)]
+pub struct CancelCraftingRequest {
+    pub crafting_entity: Entity,
+}
+
+/// Request to drop every queued craft of a recipe and refund what they paid.
+/// Crafts already in progress are not affected.
+#[derive(Event)]
+pub struct CancelQueuedCrafts {
+    pub recipe_id: String,
+}
+
+/// Request to drop every queued craft of every recipe and refund what they paid.
+/// Crafts already in progress are not affected.
+#[derive(Event)]
+pub struct ClearCraftingQueue;
+
+/// Fired when a crafting operation finishes and its outcomes have been applied.
+///
+/// # Observers
+/// - `ui/notification_ui`: Queues a "Crafted" toast for the player.
+#[derive(Event)]
+pub struct CraftingCompleted {
+    pub recipe_id: String,
 }
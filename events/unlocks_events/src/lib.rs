@@ -41,3 +41,112 @@ pub struct StatusCompleted {
     /// The topic ID, e.g. "research:bone_sword", "quest:intro"
     pub topic: String,
 }
+
+// ============================================================================
+// Topics
+// ============================================================================
+
+/// Canonical topic key for `ValueChanged` / `StatusCompleted` and unlock conditions.
+///
+/// Build topics through these constructors instead of formatting strings by hand, so
+/// emitters and condition files agree on the naming convention.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Topic(String);
+
+impl Topic {
+    pub const RESOURCE_PREFIX: &'static str = "resource:";
+    pub const KILLS_PREFIX: &'static str = "kills:";
+    pub const ESCAPES_PREFIX: &'static str = "escapes:";
+    pub const RESEARCH_PREFIX: &'static str = "research:";
+    pub const BLESSING_PREFIX: &'static str = "blessing:";
+    pub const UNLOCK_PREFIX: &'static str = "unlock:";
+    pub const FEATURE_PREFIX: &'static str = "feature:";
+    pub const DIVINITY: &'static str = "divinity";
+
+    fn prefixed(prefix: &str, id: &str) -> Self {
+        Self(format!("{}{}", prefix, id))
+    }
+
+    /// Wallet amount of a resource, e.g. "resource:bones".
+    pub fn resource(resource_id: &str) -> Self {
+        Self::prefixed(Self::RESOURCE_PREFIX, resource_id)
+    }
+
+    /// Kill count of a monster type, e.g. "kills:goblin".
+    pub fn kills(monster_id: &str) -> Self {
+        Self::prefixed(Self::KILLS_PREFIX, monster_id)
+    }
+
+    /// Escape count of a monster type, e.g. "escapes:goblin".
+    pub fn escapes(monster_id: &str) -> Self {
+        Self::prefixed(Self::ESCAPES_PREFIX, monster_id)
+    }
+
+    /// Completion of a research, e.g. "research:bone_sword".
+    pub fn research(research_id: &str) -> Self {
+        Self::prefixed(Self::RESEARCH_PREFIX, research_id)
+    }
+
+    /// Level of a blessing, e.g. "blessing:swift_hands".
+    pub fn blessing(blessing_id: &str) -> Self {
+        Self::prefixed(Self::BLESSING_PREFIX, blessing_id)
+    }
+
+    /// Completion of another unlock, e.g. "unlock:recipe_x".
+    pub fn unlock(unlock_id: &str) -> Self {
+        Self::prefixed(Self::UNLOCK_PREFIX, unlock_id)
+    }
+
+    /// Feature unlocked by a crafting outcome, e.g. "feature:portal".
+    pub fn feature(feature_id: &str) -> Self {
+        Self::prefixed(Self::FEATURE_PREFIX, feature_id)
+    }
+
+    /// Village divinity, encoded as `tier * 100 + level`.
+    pub fn divinity() -> Self {
+        Self(Self::DIVINITY.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the research id if `topic` is a research topic.
+    pub fn research_id(topic: &str) -> Option<&str> {
+        topic.strip_prefix(Self::RESEARCH_PREFIX)
+    }
+}
+
+impl From<Topic> for String {
+    fn from(topic: Topic) -> Self {
+        topic.0
+    }
+}
+
+impl std::fmt::Display for Topic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topic_constructors_yield_canonical_strings() {
+        assert_eq!(Topic::resource("bones").as_str(), "resource:bones");
+        assert_eq!(Topic::kills("goblin").as_str(), "kills:goblin");
+        assert_eq!(Topic::escapes("goblin").as_str(), "escapes:goblin");
+        assert_eq!(Topic::research("autopsy").as_str(), "research:autopsy");
+        assert_eq!(Topic::blessing("swift").as_str(), "blessing:swift");
+        assert_eq!(Topic::unlock("recipe_x").as_str(), "unlock:recipe_x");
+        assert_eq!(Topic::feature("portal").as_str(), "feature:portal");
+        assert_eq!(Topic::divinity().as_str(), "divinity");
+
+        let topic: String = Topic::kills("orc").into();
+        assert_eq!(topic, "kills:orc");
+        assert_eq!(Topic::research_id("research:autopsy"), Some("autopsy"));
+        assert_eq!(Topic::research_id("resource:bones"), None);
+    }
+}
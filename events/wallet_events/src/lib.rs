@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+/// Fired the first time a resource enters `Wallet::unlocked_resources`.
+///
+/// Discovery side effects (encyclopedia drop names, notifications, quests) observe this
+/// instead of hooking into the code that grants the unlock.
+#[derive(Event, Debug, Clone)]
+pub struct ResourceUnlocked {
+    /// The resource ID, e.g. "bones".
+    pub id: String,
+}
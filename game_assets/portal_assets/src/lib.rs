@@ -28,6 +28,17 @@ pub enum SpawnCondition {
     Min(Divinity),
 }
 
+impl SpawnCondition {
+    /// Returns true if an entry with this condition can spawn at `divinity`.
+    pub fn matches(&self, divinity: Divinity) -> bool {
+        match self {
+            SpawnCondition::Min(req) => divinity >= *req,
+            SpawnCondition::Specific(req) => divinity == *req,
+            SpawnCondition::Range { min, max } => divinity >= *min && divinity <= *max,
+        }
+    }
+}
+
 impl Default for SpawnCondition {
     fn default() -> Self {
         Self::Min(Divinity::default())
@@ -72,3 +83,12 @@ pub struct SpawnTable {
     /// The list of potential spawn candidates and their conditions.
     pub entries: Vec<SpawnEntry>,
 }
+
+impl SpawnTable {
+    /// Entries that can spawn at `divinity`, in table order.
+    pub fn entries_for(&self, divinity: Divinity) -> impl Iterator<Item = &SpawnEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.condition.matches(divinity))
+    }
+}
@@ -36,18 +36,56 @@ pub struct RecipeDefinition {
     pub display_name: String,
     /// Category for tab-based organization
     pub category: RecipeCategory,
+    /// Optional grouping within a category tab (e.g., "Melee", "Ranged")
+    #[serde(default)]
+    pub subcategory: Option<String>,
     /// Time in seconds to craft
     pub craft_time: f32,
     /// Resource costs to craft
     pub cost: HashMap<String, u32>,
     /// Results when crafting completes
     pub outcomes: Vec<CraftingOutcome>,
+    /// Maximum times this recipe can be crafted. `None` means unlimited.
+    #[serde(default)]
+    pub max_repeats: Option<u32>,
+    /// Cost multiplier applied per completed craft (1.0 = no scaling)
+    #[serde(default = "default_scaling")]
+    pub cost_scaling: f32,
+    /// Craft time multiplier applied per completed craft (1.0 = no scaling)
+    #[serde(default = "default_scaling")]
+    pub time_scaling: f32,
 
     /// Optional inline unlock definition for when this recipe becomes available
     #[serde(default)]
     pub unlock: Option<UnlockDefinition>,
 }
 
+fn default_scaling() -> f32 {
+    1.0
+}
+
+impl RecipeDefinition {
+    /// Resource cost of the next craft after `crafted` previous ones.
+    /// Each amount is scaled by `cost_scaling^crafted` and rounded up.
+    pub fn scaled_cost(&self, crafted: u32) -> HashMap<String, u32> {
+        let factor = self.cost_scaling.powi(crafted as i32);
+        self.cost
+            .iter()
+            .map(|(res_id, amt)| (res_id.clone(), (*amt as f32 * factor).ceil() as u32))
+            .collect()
+    }
+
+    /// Craft time of the next craft after `crafted` previous ones.
+    pub fn scaled_craft_time(&self, crafted: u32) -> f32 {
+        self.craft_time * self.time_scaling.powi(crafted as i32)
+    }
+
+    /// Returns true once `crafted` has reached `max_repeats`.
+    pub fn is_maxed(&self, crafted: u32) -> bool {
+        self.max_repeats.is_some_and(|max| crafted >= max)
+    }
+}
+
 /// Category for organizing recipes into tabs.
 #[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RecipeCategory {
@@ -75,3 +113,70 @@ pub enum CraftingOutcome {
     /// Unlocks a specific tech or feature
     UnlockFeature(String),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wall() -> RecipeDefinition {
+        RecipeDefinition {
+            id: "wall".to_string(),
+            display_name: "Build Wall".to_string(),
+            category: RecipeCategory::Construction,
+            subcategory: None,
+            craft_time: 10.0,
+            cost: [("stone".to_string(), 10), ("wood".to_string(), 3)]
+                .into_iter()
+                .collect(),
+            outcomes: vec![],
+            max_repeats: Some(5),
+            cost_scaling: 1.5,
+            time_scaling: 2.0,
+            unlock: None,
+        }
+    }
+
+    #[test]
+    fn test_scaled_cost_and_time() {
+        let def = wall();
+
+        assert_eq!(def.scaled_cost(0), def.cost);
+        let second = def.scaled_cost(1);
+        assert_eq!(second.get("stone"), Some(&15));
+        // 3 * 1.5 = 4.5, rounded up
+        assert_eq!(second.get("wood"), Some(&5));
+        assert_eq!(def.scaled_cost(2).get("stone"), Some(&23));
+
+        assert_eq!(def.scaled_craft_time(0), 10.0);
+        assert_eq!(def.scaled_craft_time(2), 40.0);
+    }
+
+    #[test]
+    fn test_is_maxed() {
+        let mut def = wall();
+        assert!(!def.is_maxed(4));
+        assert!(def.is_maxed(5));
+
+        def.max_repeats = None;
+        assert!(!def.is_maxed(u32::MAX));
+    }
+
+    #[test]
+    fn test_repeat_fields_default_when_omitted() {
+        let def: RecipeDefinition = ron::from_str(
+            r#"(
+                id: "bone_sword",
+                display_name: "Bone Sword",
+                category: Weapons,
+                craft_time: 5.0,
+                cost: {"bones": 5},
+                outcomes: [],
+            )"#,
+        )
+        .unwrap();
+
+        assert_eq!(def.max_repeats, None);
+        assert_eq!(def.scaled_cost(3).get("bones"), Some(&5));
+        assert_eq!(def.scaled_craft_time(3), 5.0);
+    }
+}
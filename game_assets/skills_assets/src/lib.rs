@@ -24,6 +24,9 @@ pub struct SkillDefinition {
     pub id: String,
     /// Display name shown in UI
     pub display_name: String,
+    /// Flavor/explanation text shown when picking the skill
+    #[serde(default)]
+    pub description: Option<String>,
     /// Skill categorization
     pub skill_type: SkillType,
     /// Cooldown in milliseconds (0 = no cooldown, passive skills ignore this)
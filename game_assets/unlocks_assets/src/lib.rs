@@ -3,6 +3,7 @@ use {
     bevy_common_assets::ron::RonAssetPlugin,
     serde::{Deserialize, Serialize},
     unlocks_components::ComparisonOp,
+    unlocks_events::Topic,
 };
 
 pub struct UnlocksAssetsPlugin;
@@ -41,6 +42,33 @@ pub struct UnlockDefinition {
     pub repeat_mode: RepeatMode,
 }
 
+/// Reward id prefixes understood by the downstream reward handlers, with their UI labels.
+const REWARD_KINDS: &[(&str, &str)] = &[
+    (Topic::RESEARCH_PREFIX, "Research available"),
+    ("recipe:", "Recipe available"),
+    ("construction:", "Construction available"),
+    ("hero:", "New hero"),
+    ("skill:", "Skill unlocked"),
+    (Topic::BLESSING_PREFIX, "Blessing unlocked"),
+    (Topic::RESOURCE_PREFIX, "New resource"),
+    ("divinity:", "Divinity increased"),
+    ("stat:", "Bonus gained"),
+];
+
+/// Renders a reward id as a short, human-readable effect (e.g. "Recipe available: bone_bow").
+///
+/// Unknown prefixes fall back to the raw reward id.
+pub fn describe_reward(reward_id: &str) -> String {
+    REWARD_KINDS
+        .iter()
+        .find_map(|(prefix, label)| {
+            reward_id
+                .strip_prefix(prefix)
+                .map(|target| format!("{}: {}", label, target))
+        })
+        .unwrap_or_else(|| reward_id.to_string())
+}
+
 /// A node in the logical condition tree.
 ///
 /// This is a simplified, game-agnostic version that uses string-based topic IDs.
@@ -70,3 +98,52 @@ pub enum ConditionNode {
     /// Topic examples: "research:bone_sword", "quest:intro", "unlock:recipe_x"
     Completed { topic: String },
 }
+
+impl ConditionNode {
+    /// Renders the condition as a short, human-readable requirement
+    /// (e.g. "research reinforcement_i and kills:goblin >= 10").
+    pub fn describe(&self) -> String {
+        match self {
+            ConditionNode::And(nodes) => Self::describe_all(nodes, " and "),
+            ConditionNode::Or(nodes) => Self::describe_all(nodes, " or "),
+            ConditionNode::Not(node) => format!("not ({})", node.describe()),
+            ConditionNode::True => "nothing".to_string(),
+            ConditionNode::Value { topic, op, target } => {
+                let op = match op {
+                    ComparisonOp::Ge => ">=",
+                    ComparisonOp::Le => "<=",
+                    ComparisonOp::Eq => "=",
+                    ComparisonOp::Gt => ">",
+                    ComparisonOp::Lt => "<",
+                };
+                format!("{} {} {}", topic, op, target)
+            }
+            ConditionNode::Completed { topic } => match Topic::research_id(topic) {
+                Some(research_id) => format!("research {}", research_id),
+                None => format!("complete {}", topic),
+            },
+        }
+    }
+
+    /// Research ids this condition waits on, in order of appearance.
+    ///
+    /// Research below a `Not` is an exclusion rather than a prerequisite and is skipped.
+    pub fn required_research(&self) -> Vec<&str> {
+        match self {
+            ConditionNode::And(nodes) | ConditionNode::Or(nodes) => nodes
+                .iter()
+                .flat_map(ConditionNode::required_research)
+                .collect(),
+            ConditionNode::Completed { topic } => Topic::research_id(topic).into_iter().collect(),
+            ConditionNode::Not(_) | ConditionNode::True | ConditionNode::Value { .. } => Vec::new(),
+        }
+    }
+
+    fn describe_all(nodes: &[ConditionNode], separator: &str) -> String {
+        nodes
+            .iter()
+            .map(ConditionNode::describe)
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+}
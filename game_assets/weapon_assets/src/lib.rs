@@ -39,6 +39,37 @@ pub struct WeaponDefinition {
     pub attack_speed_ms: u32,
     /// Tags associated with this weapon (e.g., "melee", "bone_sword")
     pub tags: Vec<String>,
+    /// Progression a hero needs before this weapon can be equipped
+    #[serde(default)]
+    pub requirement: Option<WeaponRequirement>,
+}
+
+/// Progression gate for equipping a weapon.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum WeaponRequirement {
+    /// The village divinity must have reached at least this tier.
+    MinDivinityTier(u32),
+    /// The research must have been completed at least once.
+    Research(String),
+}
+
+impl WeaponRequirement {
+    /// Checks the requirement against the village divinity tier and
+    /// research completion counts (as stored in `ResearchState`).
+    pub fn is_met(&self, divinity_tier: u32, research_counts: &HashMap<String, u32>) -> bool {
+        match self {
+            WeaponRequirement::MinDivinityTier(tier) => divinity_tier >= *tier,
+            WeaponRequirement::Research(id) => research_counts.get(id).is_some_and(|&c| c > 0),
+        }
+    }
+
+    /// Short explanation shown when the requirement is not met.
+    pub fn describe(&self) -> String {
+        match self {
+            WeaponRequirement::MinDivinityTier(tier) => format!("Requires Divinity Tier {}", tier),
+            WeaponRequirement::Research(id) => format!("Requires research: {}", id),
+        }
+    }
 }
 
 /// Type of weapon with type-specific attributes.
@@ -55,3 +86,36 @@ pub enum WeaponType {
 pub struct WeaponMap {
     pub handles: HashMap<String, Handle<WeaponDefinition>>,
 }
+
+impl WeaponMap {
+    /// Looks up the equip requirement of a weapon by its ID.
+    pub fn requirement<'a>(
+        &self,
+        weapon_id: &str,
+        assets: &'a Assets<WeaponDefinition>,
+    ) -> Option<&'a WeaponRequirement> {
+        let handle = self.handles.get(weapon_id)?;
+        assets.get(handle)?.requirement.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requirement_checks() {
+        let research_counts: HashMap<String, u32> =
+            [("bone_sword".to_string(), 1), ("never_done".to_string(), 0)]
+                .into_iter()
+                .collect();
+
+        let tier = WeaponRequirement::MinDivinityTier(2);
+        assert!(!tier.is_met(1, &research_counts));
+        assert!(tier.is_met(2, &research_counts));
+
+        assert!(WeaponRequirement::Research("bone_sword".to_string()).is_met(1, &research_counts));
+        assert!(!WeaponRequirement::Research("never_done".to_string()).is_met(1, &research_counts));
+        assert!(!WeaponRequirement::Research("missing".to_string()).is_met(1, &research_counts));
+    }
+}
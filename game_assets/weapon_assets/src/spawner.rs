@@ -66,6 +66,7 @@ mod tests {
             attack_range: 2.0,
             attack_speed_ms: 1000,
             tags: vec!["tag1".to_string(), "tag2".to_string()],
+            requirement: None,
         };
 
         let entity = spawn_weapon(&mut app.world_mut().commands(), &def);
@@ -97,6 +98,7 @@ mod tests {
             attack_range: 300.0,
             attack_speed_ms: 1000,
             tags: vec![],
+            requirement: None,
         };
 
         let entity = spawn_weapon(&mut app.world_mut().commands(), &def);
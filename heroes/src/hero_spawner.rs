@@ -1,6 +1,6 @@
 use {
     bevy::prelude::*,
-    hero_components::{EquippedWeaponId, Hero},
+    hero_components::{EquippedWeaponId, Hero, HeroName},
     unlocks_events::UnlockAchieved,
     village_components::Village,
 };
@@ -9,6 +9,7 @@ pub fn hero_spawn_on_unlock(
     trigger: On<UnlockAchieved>,
     mut commands: Commands,
     hero_query: Query<&Name, With<Hero>>,
+    hero_names: Query<&HeroName>,
     village_query: Query<Entity, With<Village>>,
 ) {
     let event = trigger.event();
@@ -34,8 +35,28 @@ pub fn hero_spawn_on_unlock(
     info!("Spawning hero: {}", hero_name);
     commands.spawn((
         Name::new(hero_name.to_owned()),
+        HeroName::generate(hero_names.iter().map(|name| name.0.as_str())),
         Hero,
         EquippedWeaponId(Some("melee_rock".to_string())),
         ChildOf(village),
     ));
 }
+
+/// Gives a distinct default name to heroes that have none, e.g. heroes from
+/// saves made before heroes had names.
+pub fn name_unnamed_heroes(
+    mut commands: Commands,
+    unnamed: Query<Entity, (With<Hero>, Without<HeroName>)>,
+    named: Query<&HeroName>,
+) {
+    if unnamed.is_empty() {
+        return;
+    }
+
+    let mut taken: Vec<String> = named.iter().map(|name| name.0.clone()).collect();
+    for hero in unnamed.iter() {
+        let name = HeroName::generate(taken.iter().map(String::as_str));
+        taken.push(name.0.clone());
+        commands.entity(hero).insert(name);
+    }
+}
@@ -2,10 +2,12 @@ use {
     bevy::prelude::*,
     enemy_components::{Armor, Enemy, Health, MonsterTags, Shield},
     hero_components::{
-        AttackRange, AttackSpeed, Damage, Hero, MeleeArc, MeleeWeapon, Projectile,
+        AttackRange, AttackSpeed, Damage, Hero, HeroName, MeleeArc, MeleeWeapon, Projectile,
         ProjectileDamage, ProjectileSpeed, ProjectileTarget, RangedWeapon, Weapon, WeaponTags,
     },
-    hero_events::{AttackIntent, DamageRequest, HealRequest, ProjectileHit, ProjectileSpawnRequest},
+    hero_events::{
+        AttackIntent, DamageRequest, HealRequest, ProjectileHit, ProjectileSpawnRequest,
+    },
     shared_components::HitIndicator,
     states::GameState,
     system_schedule::GameSchedule,
@@ -20,17 +22,23 @@ pub struct HeroesPlugin;
 impl Plugin for HeroesPlugin {
     fn build(&self, app: &mut App) {
         // Only register types that derive Reflect (state components)
-        app.register_type::<Hero>().register_type::<Weapon>();
+        app.register_type::<Hero>()
+            .register_type::<HeroName>()
+            .register_type::<Weapon>();
 
         app.add_systems(
             Update,
             (
-                hero_attack_intent_system.in_set(GameSchedule::ResolveIntent),
-                skills::hero_auto_activate_skills_system.in_set(GameSchedule::ResolveIntent),
-                (projectile_movement_system, projectile_collision_system)
-                    .in_set(GameSchedule::PerformAction)
-                    .chain(),
-                hit_indicator_system.run_if(in_state(GameState::Running)),
+                (
+                    hero_attack_intent_system.in_set(GameSchedule::ResolveIntent),
+                    skills::hero_auto_activate_skills_system.in_set(GameSchedule::ResolveIntent),
+                    (projectile_movement_system, projectile_collision_system)
+                        .in_set(GameSchedule::PerformAction)
+                        .chain(),
+                    hit_indicator_system,
+                )
+                    .run_if(states::not_paused),
+                hero_spawner::name_unnamed_heroes,
             )
                 .run_if(in_state(GameState::Running)),
         );
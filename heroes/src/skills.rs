@@ -23,7 +23,7 @@ pub fn hero_auto_activate_skills_system(
     };
 
     for (hero_entity, equipped, cooldowns) in &heroes {
-        for skill_id in &equipped.0 {
+        for skill_id in equipped.skill_ids() {
             // Check if skill is on cooldown
             if let Some(timer) = cooldowns.timers.get(skill_id) {
                 if !timer.is_finished() {
@@ -0,0 +1,180 @@
+//! Hot reload of weapon, skill and recipe definitions while the game is running.
+//!
+//! Only compiled with the `hot_reload` feature, which also turns on Bevy's file watcher.
+//! The root crate enables it through its `dev` feature, so shipping builds neither watch
+//! the asset folder nor run these systems.
+//!
+//! What gets re-derived when a definition file changes:
+//! - Weapons: `WeaponMap` is re-keyed by the definition id, and every spawned weapon with
+//!   that `WeaponId` gets its `DisplayName`, `Damage`, `AttackRange`, `WeaponTags`,
+//!   `AttackSpeed` duration and `MeleeArc` / `ProjectileSpeed` rewritten. Equipped weapons
+//!   are children of their hero, so they are covered. Switching a weapon between melee and
+//!   ranged is not applied to live entities.
+//! - Skills: `SkillMap` is re-keyed, and the cooldown timers of that skill in every
+//!   `SkillCooldowns` take the new duration. Buffs already applied keep their old values.
+//! - Recipes: `RecipeNode`s read the definition through their handle, so edits show up
+//!   without any work. Recipes added while running get a node spawned, but their unlock
+//!   conditions are only compiled on the next load.
+
+use {
+    bevy::{asset::AssetEvent, prelude::*},
+    crafting_resources::RecipeMap,
+    hero_components::{
+        AttackRange, AttackSpeed, Damage, MeleeArc, ProjectileSpeed, Weapon, WeaponId, WeaponTags,
+    },
+    recipes_assets::RecipeDefinition,
+    shared_components::DisplayName,
+    skill_components::SkillCooldowns,
+    skills_assets::{SkillDefinition, SkillMap},
+    states::GameState,
+    std::time::Duration,
+    unlocks::UnlockState,
+    weapon_assets::{WeaponDefinition, WeaponMap, WeaponType},
+};
+
+pub struct HotReloadPlugin;
+
+impl Plugin for HotReloadPlugin {
+    fn build(&self, app: &mut App) {
+        // Loading populates the maps itself, so only react to changes made afterwards
+        app.add_systems(
+            Update,
+            (
+                reload_weapon_definitions,
+                reload_skill_definitions,
+                reload_recipe_definitions,
+            )
+                .run_if(in_state(GameState::Running)),
+        );
+    }
+}
+
+/// Ids of the assets added or modified since the last run.
+fn changed_ids<A: Asset>(events: &mut MessageReader<AssetEvent<A>>) -> Vec<AssetId<A>> {
+    events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Points `def_id` at the asset `id`, dropping entries left behind when the id in the
+/// file was renamed.
+fn rekey<A: Asset>(
+    handles: &mut bevy::platform::collections::HashMap<String, Handle<A>>,
+    assets: &mut Assets<A>,
+    id: AssetId<A>,
+    def_id: &str,
+) {
+    handles.retain(|key, handle| key == def_id || handle.id() != id);
+    if handles.get(def_id).is_none_or(|handle| handle.id() != id)
+        && let Some(handle) = assets.get_strong_handle(id)
+    {
+        handles.insert(def_id.to_string(), handle);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn reload_weapon_definitions(
+    mut events: MessageReader<AssetEvent<WeaponDefinition>>,
+    mut assets: ResMut<Assets<WeaponDefinition>>,
+    mut weapon_map: ResMut<WeaponMap>,
+    mut weapons: Query<
+        (
+            &WeaponId,
+            &mut DisplayName,
+            &mut Damage,
+            &mut AttackRange,
+            &mut WeaponTags,
+            &mut AttackSpeed,
+            Option<&mut MeleeArc>,
+            Option<&mut ProjectileSpeed>,
+        ),
+        With<Weapon>,
+    >,
+) {
+    for id in changed_ids(&mut events) {
+        let Some(def) = assets.get(id).cloned() else {
+            continue;
+        };
+        rekey(&mut weapon_map.handles, &mut assets, id, &def.id);
+
+        let mut updated = 0;
+        for (weapon_id, mut name, mut damage, mut range, mut tags, mut speed, arc, projectile) in
+            &mut weapons
+        {
+            if weapon_id.0 != def.id {
+                continue;
+            }
+
+            name.0 = def.display_name.clone();
+            damage.0 = def.damage;
+            range.0 = def.attack_range;
+            tags.0 = def.tags.clone();
+            speed
+                .timer
+                .set_duration(Duration::from_millis(def.attack_speed_ms as u64));
+            match (&def.weapon_type, arc, projectile) {
+                (WeaponType::Melee { arc_width }, Some(mut arc), _) => arc.width = *arc_width,
+                (WeaponType::Ranged { projectile_speed }, _, Some(mut projectile)) => {
+                    projectile.0 = *projectile_speed
+                }
+                _ => warn!(
+                    "Weapon '{}' changed its weapon type, respawn it to apply",
+                    def.id
+                ),
+            }
+            updated += 1;
+        }
+        info!("Reloaded weapon '{}' ({} live weapons)", def.id, updated);
+    }
+}
+
+fn reload_skill_definitions(
+    mut events: MessageReader<AssetEvent<SkillDefinition>>,
+    mut assets: ResMut<Assets<SkillDefinition>>,
+    mut skill_map: ResMut<SkillMap>,
+    mut cooldowns: Query<&mut SkillCooldowns>,
+) {
+    for id in changed_ids(&mut events) {
+        let Some((def_id, cooldown_ms)) =
+            assets.get(id).map(|def| (def.id.clone(), def.cooldown_ms))
+        else {
+            continue;
+        };
+        rekey(&mut skill_map.handles, &mut assets, id, &def_id);
+
+        for mut cooldowns in &mut cooldowns {
+            if let Some(timer) = cooldowns.get_timer_mut(&def_id) {
+                timer.set_duration(Duration::from_millis(cooldown_ms as u64));
+            }
+        }
+        info!("Reloaded skill '{}'", def_id);
+    }
+}
+
+fn reload_recipe_definitions(
+    mut commands: Commands,
+    mut events: MessageReader<AssetEvent<RecipeDefinition>>,
+    mut assets: ResMut<Assets<RecipeDefinition>>,
+    mut recipe_map: ResMut<RecipeMap>,
+    unlock_state: Res<UnlockState>,
+    constructed: Res<crafting_resources::ConstructedBuildings>,
+) {
+    if changed_ids(&mut events).is_empty() {
+        return;
+    }
+
+    // Existing nodes already see the new data through their handle; this only spawns
+    // nodes for recipes that were not there before
+    crafting::spawn_recipe_entities(
+        &mut commands,
+        &mut recipe_map,
+        &mut assets,
+        &unlock_state,
+        &constructed,
+    );
+    info!("Reloaded recipe definitions");
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "hot_reload")]
+mod hot_reload;
 mod resources;
 
 use {
@@ -7,7 +9,10 @@ use {
         WeaponsFolderHandle,
     },
     bevy::{
-        asset::LoadedFolder, ecs::system::SystemParam, platform::collections::HashMap, prelude::*,
+        asset::{LoadedFolder, RecursiveDependencyLoadState, UntypedAssetId},
+        ecs::system::SystemParam,
+        platform::collections::HashMap,
+        prelude::*,
     },
     blessings::BlessingDefinition,
     crafting_resources::RecipeMap,
@@ -24,10 +29,11 @@ use {
     std::{fs, path::Path},
     unlocks::{CompiledUnlock, TopicMap, UnlockProgress, UnlockState},
     unlocks_assets::UnlockDefinition,
-    unlocks_events::{StatusCompleted, ValueChanged},
+    unlocks_events::{StatusCompleted, Topic, ValueChanged},
     village_components::{EnemyEncyclopedia, Village},
     wallet::Wallet,
     weapon_assets::{WeaponDefinition, WeaponMap},
+    widgets::{UiTheme, set_progress_bar_fraction, spawn_progress_bar_ui},
 };
 
 pub struct LoadingManagerPlugin;
@@ -36,6 +42,7 @@ impl Plugin for LoadingManagerPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<LoadingManager>()
             .init_resource::<LoadingStatus>()
+            .init_resource::<LoadingErrors>()
             .init_resource::<SceneToLoad>()
             .init_state::<LoadingPhase>()
             // Phase: Assets - load all asset folders
@@ -60,25 +67,37 @@ impl Plugin for LoadingManagerPlugin {
                     .run_if(in_state(GameState::Loading).and(in_state(LoadingPhase::Assets))),
             )
             // Phase: SpawnEntities - spawn research and recipe entities
-            .add_systems(OnEnter(LoadingPhase::SpawnEntities), spawn_all_entities)
+            .add_systems(
+                OnEnter(LoadingPhase::SpawnEntities),
+                (merge_encyclopedia_content, spawn_all_entities),
+            )
             // Phase: CompileUnlocks - build unlock logic graphs
             .add_systems(
                 OnEnter(LoadingPhase::CompileUnlocks),
                 (
-                    compile_unlocks,
-                    compile_research_unlocks,
-                    compile_recipe_unlocks,
-                    compile_blessing_unlocks,
-                    compile_skill_unlocks,
-                    bonus_stats::plugin::compile_bonus_stats_unlocks,
-                ),
+                    // Drop leftovers from a previous session before deciding what to compile
+                    unlocks::despawn_orphaned_unlocks,
+                    (
+                        compile_unlocks,
+                        compile_research_unlocks,
+                        compile_recipe_unlocks,
+                        compile_blessing_unlocks,
+                        compile_skill_unlocks,
+                        bonus_stats::plugin::compile_bonus_stats_unlocks,
+                    ),
+                )
+                    .chain(),
             )
             .add_systems(
                 Update,
                 finish_compilation.run_if(in_state(LoadingPhase::CompileUnlocks)),
             )
             // Phase: EvaluateUnlocks - re-fire signals for satisfied conditions
-            .add_systems(OnEnter(LoadingPhase::EvaluateUnlocks), evaluate_unlocks)
+            .add_systems(
+                OnEnter(LoadingPhase::EvaluateUnlocks),
+                // NOT gates wait until the hydration events above have reached the sensors
+                (evaluate_unlocks, unlocks::evaluate_pending_not_gates).chain(),
+            )
             // Phase: SpawnScene - spawn scene (startup or save)
             .add_systems(OnEnter(LoadingPhase::SpawnScene), spawn_scene)
             .add_systems(
@@ -90,13 +109,23 @@ impl Plugin for LoadingManagerPlugin {
             // Loading UI
             .add_systems(
                 OnEnter(GameState::Loading),
-                (setup_loading_ui, reset_loading_phase, clear_unlock_state),
+                (
+                    setup_loading_ui,
+                    reset_loading_phase,
+                    clear_unlock_state,
+                    clear_loading_errors,
+                ),
             )
             .add_systems(
                 Update,
-                update_loading_ui.run_if(in_state(GameState::Loading)),
+                (sync_phase_progress, update_loading_ui)
+                    .chain()
+                    .run_if(in_state(GameState::Loading)),
             )
             .add_systems(OnExit(GameState::Loading), cleanup_loading_ui);
+
+        #[cfg(feature = "hot_reload")]
+        app.add_plugins(hot_reload::HotReloadPlugin);
     }
 }
 
@@ -124,6 +153,17 @@ pub struct LoadingManager {
     pub enemies: HashMap<String, Handle<DynamicScene>>,
 }
 
+impl LoadingManager {
+    /// Looks up a loaded spawn table by the id a portal's `SpawnTableId` refers to.
+    pub fn spawn_table<'a>(
+        &self,
+        table_id: &str,
+        tables: &'a Assets<SpawnTable>,
+    ) -> Option<&'a SpawnTable> {
+        tables.get(self.spawn_tables.get(table_id)?)
+    }
+}
+
 // Keep GameAssets as alias for backwards compatibility
 pub type GameAssets = LoadingManager;
 
@@ -131,6 +171,34 @@ pub type GameAssets = LoadingManager;
 pub struct LoadingStatus {
     pub current_phase: String,
     pub detail: String,
+    /// Share of the whole loading sequence done, from 0.0 to 1.0
+    pub progress: f32,
+}
+
+/// Assets skipped during loading because they were missing or malformed, and saves
+/// replaced by a fallback. Listed on the loading screen; the game continues without them.
+#[derive(Resource, Default, Debug)]
+pub struct LoadingErrors {
+    pub errors: Vec<String>,
+}
+
+impl LoadingErrors {
+    /// Logs and records `message`, ignoring repeats of an already recorded one.
+    pub fn record(&mut self, message: String) {
+        if !self.errors.contains(&message) {
+            error!("{}", message);
+            self.errors.push(message);
+        }
+    }
+}
+
+/// Save file, inside the saves directory, tried when the requested save is unreadable.
+/// Provided by the save system; without it a broken save starts a new game.
+#[derive(Resource, Debug, Clone)]
+pub struct FallbackSave(pub String);
+
+fn clear_loading_errors(mut errors: ResMut<LoadingErrors>) {
+    errors.errors.clear();
 }
 
 // --- Phase: Assets ---
@@ -212,6 +280,25 @@ struct FolderHandles<'w> {
     skills: Res<'w, SkillsFolderHandle>,
 }
 
+/// True once `id` is loaded with its dependencies, or failed with the failure recorded.
+fn asset_settled(
+    asset_server: &AssetServer,
+    id: UntypedAssetId,
+    name: &str,
+    errors: &mut LoadingErrors,
+) -> bool {
+    if asset_server.is_loaded_with_dependencies(id) {
+        return true;
+    }
+    match asset_server.get_recursive_dependency_load_state(id) {
+        Some(RecursiveDependencyLoadState::Failed(err)) => {
+            errors.record(format!("Failed to load {}: {}", name, err));
+            true
+        }
+        _ => false,
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn check_assets_loaded(
     mut next_phase: ResMut<NextState<LoadingPhase>>,
@@ -219,6 +306,7 @@ fn check_assets_loaded(
     mut weapon_map: ResMut<WeaponMap>,
     mut skill_map: ResMut<SkillMap>,
     mut status: ResMut<LoadingStatus>,
+    mut errors: ResMut<LoadingErrors>,
     asset_server: Res<AssetServer>,
     folders: FolderHandles,
     folder: Res<Assets<LoadedFolder>>,
@@ -227,35 +315,52 @@ fn check_assets_loaded(
     scenes: Res<Assets<DynamicScene>>,
     type_registry: Res<AppTypeRegistry>,
 ) {
-    status.current_phase = "Loading Assets".into();
-    status.detail = "Loading files from disk...".into();
-
-    let spawn_tables_loaded = loading_manager
-        .spawn_tables
-        .values()
-        .all(|handle| asset_server.is_loaded_with_dependencies(handle));
-
     // For the scene file, we only need to check if it's loaded.
     // However, if we are loading a save, we might have already loaded assets in a previous run.
     // But `asset_server.is_loaded_with_dependencies` is generally cheap if already loaded.
-    if asset_server.is_loaded_with_dependencies(&loading_manager.startup_scene)
-        && spawn_tables_loaded
-        && asset_server.is_loaded_with_dependencies(folders.enemy_prefabs.0.id())
-        && asset_server.is_loaded_with_dependencies(folders.unlocks.0.id())
-        && asset_server.is_loaded_with_dependencies(folders.research.0.id())
-        && asset_server.is_loaded_with_dependencies(folders.recipes.0.id())
-        && asset_server.is_loaded_with_dependencies(folders.weapons.0.id())
-        && asset_server.is_loaded_with_dependencies(folders.blessings.0.id())
-        && asset_server.is_loaded_with_dependencies(folders.bonus_stats.0.id())
-        && asset_server.is_loaded_with_dependencies(folders.skills.0.id())
-    {
+    // The scene is required; a failing spawn table or folder entry is recorded and skipped.
+    let mut loaded = vec![asset_server.is_loaded_with_dependencies(&loading_manager.startup_scene)];
+    let mut tracked: Vec<(String, UntypedAssetId)> = loading_manager
+        .spawn_tables
+        .iter()
+        .map(|(key, handle)| (format!("spawn table '{}'", key), handle.id().untyped()))
+        .collect();
+    tracked.extend(
+        [
+            ("prefabs/enemies", folders.enemy_prefabs.0.id()),
+            ("unlocks", folders.unlocks.0.id()),
+            ("research", folders.research.0.id()),
+            ("recipes", folders.recipes.0.id()),
+            ("weapons", folders.weapons.0.id()),
+            ("blessings", folders.blessings.0.id()),
+            ("stats", folders.bonus_stats.0.id()),
+            ("skills", folders.skills.0.id()),
+        ]
+        .map(|(path, id)| (format!("folder '{}'", path), id.untyped())),
+    );
+    loaded.extend(
+        tracked
+            .iter()
+            .map(|(name, id)| asset_settled(&asset_server, *id, name, &mut errors)),
+    );
+    let done = loaded.iter().filter(|is_loaded| **is_loaded).count();
+
+    status.current_phase = "Loading Assets".into();
+    status.detail = format!("Loading files from disk... ({}/{})", done, loaded.len());
+    status.progress = phase_progress(&LoadingPhase::Assets, done as f32 / loaded.len() as f32);
+
+    if done == loaded.len() {
         info!("assets loaded");
 
-        let Some(enemy_prefabs_folder) = folder.get(folders.enemy_prefabs.0.id()) else {
-            panic!("folder not loaded even tho asset server said it is")
-        };
+        let enemy_prefabs = folder.get(folders.enemy_prefabs.0.id());
+        if enemy_prefabs.is_none() {
+            errors.record("Enemy prefabs folder is missing".to_string());
+        }
 
-        for untyped_handle in enemy_prefabs_folder.handles.iter().cloned() {
+        for untyped_handle in enemy_prefabs
+            .into_iter()
+            .flat_map(|f| f.handles.iter().cloned())
+        {
             let Some(asset_path) = asset_server.get_path(untyped_handle.id()) else {
                 continue;
             };
@@ -267,20 +372,25 @@ fn check_assets_loaded(
             };
 
             // Extract MonsterId from the loaded scene
-            let key = extract_monster_id(&scenes, &handle, &type_registry).unwrap_or_else(|| {
-                panic!("MonsterId component not found in enemy prefab: {}", path)
-            });
+            let Some(key) = extract_monster_id(&scenes, &handle, &type_registry) else {
+                errors.record(format!(
+                    "Skipping enemy prefab {}: no MonsterId component",
+                    path
+                ));
+                continue;
+            };
 
             debug!(%key, %path, "loaded enemy prefab with MonsterId");
             loading_manager.enemies.insert(key, handle);
         }
 
         // Populate WeaponMap from loaded weapon assets
-        let Some(weapons_folder) = folder.get(folders.weapons.0.id()) else {
-            panic!("weapons folder not loaded even though asset server said it is")
-        };
+        let weapons = folder.get(folders.weapons.0.id());
+        if weapons.is_none() {
+            errors.record("Weapons folder is missing".to_string());
+        }
 
-        for untyped_handle in weapons_folder.handles.iter().cloned() {
+        for untyped_handle in weapons.into_iter().flat_map(|f| f.handles.iter().cloned()) {
             let Ok(handle) = untyped_handle.try_typed::<WeaponDefinition>() else {
                 continue;
             };
@@ -292,11 +402,12 @@ fn check_assets_loaded(
         }
 
         // Populate SkillMap from loaded skill assets
-        let Some(skills_folder) = folder.get(folders.skills.0.id()) else {
-            panic!("skills folder not loaded even though asset server said it is")
-        };
+        let skills = folder.get(folders.skills.0.id());
+        if skills.is_none() {
+            errors.record("Skills folder is missing".to_string());
+        }
 
-        for untyped_handle in skills_folder.handles.iter().cloned() {
+        for untyped_handle in skills.into_iter().flat_map(|f| f.handles.iter().cloned()) {
             let Ok(handle) = untyped_handle.try_typed::<SkillDefinition>() else {
                 continue;
             };
@@ -341,6 +452,17 @@ fn extract_monster_id(
 
 // --- Phase: SpawnEntities ---
 
+/// Adds enemies introduced by the current content to the (possibly saved) encyclopedia,
+/// keeping saved counts and a stable ordering.
+fn merge_encyclopedia_content(
+    loading_manager: Res<LoadingManager>,
+    mut encyclopedia_query: Query<&mut EnemyEncyclopedia, With<Village>>,
+) {
+    for mut encyclopedia in encyclopedia_query.iter_mut() {
+        encyclopedia.merge_content(loading_manager.enemies.keys().map(String::as_str));
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn spawn_all_entities(
     mut commands: Commands,
@@ -450,7 +572,6 @@ fn spawn_all_entities(
     next_phase.set(LoadingPhase::CompileUnlocks);
 }
 
-
 fn compile_unlocks(
     mut commands: Commands,
     unlock_assets: Res<Assets<UnlockDefinition>>,
@@ -629,7 +750,7 @@ fn evaluate_unlocks(
     // Trigger ValueChanged for all wallet resources
     for (resource_id, &amount) in wallet.resources.iter() {
         commands.trigger(ValueChanged {
-            topic: format!("resource:{}", resource_id),
+            topic: Topic::resource(resource_id).into(),
             value: amount as f32,
         });
     }
@@ -638,11 +759,11 @@ fn evaluate_unlocks(
     if let Ok(encyclopedia) = encyclopedia_query.single() {
         for (monster_id, entry) in encyclopedia.inner.iter() {
             commands.trigger(ValueChanged {
-                topic: format!("kills:{}", monster_id),
+                topic: Topic::kills(monster_id).into(),
                 value: entry.kill_count as f32,
             });
             commands.trigger(ValueChanged {
-                topic: format!("escapes:{}", monster_id),
+                topic: Topic::escapes(monster_id).into(),
                 value: entry.escape_count as f32,
             });
         }
@@ -652,7 +773,7 @@ fn evaluate_unlocks(
     if let Ok(divinity) = divinity_query.single() {
         // Encode divinity as tier*100 + level for comparison
         commands.trigger(ValueChanged {
-            topic: "divinity".to_string(),
+            topic: Topic::divinity().into(),
             value: (divinity.tier * 100 + divinity.level) as f32,
         });
     }
@@ -664,7 +785,7 @@ fn evaluate_unlocks(
         {
             debug!(research_id = %node.id, count = count, "Firing StatusCompleted for loaded research");
             commands.trigger(StatusCompleted {
-                topic: format!("research:{}", node.id),
+                topic: Topic::research(&node.id).into(),
             });
         }
     }
@@ -692,49 +813,113 @@ fn evaluate_unlocks(
 
 // --- Phase: SpawnScene ---
 
+#[allow(clippy::too_many_arguments)]
 fn spawn_scene(
     mut scene_spawner: ResMut<SceneSpawner>,
     mut dynamic_scenes: ResMut<Assets<DynamicScene>>,
-    loading_manager: Res<LoadingManager>,
+    mut loading_manager: ResMut<LoadingManager>,
     mut status: ResMut<LoadingStatus>,
-    scene_to_load: Res<SceneToLoad>,
+    mut scene_to_load: ResMut<SceneToLoad>,
+    mut errors: ResMut<LoadingErrors>,
+    fallback: Option<Res<FallbackSave>>,
     type_registry: Res<AppTypeRegistry>,
+    asset_server: Res<AssetServer>,
 ) {
     status.current_phase = "Spawning Scene".into();
     status.detail = "Loading world...".into();
 
     info!("spawning scene");
 
-    if scene_to_load.is_save {
-        // MANUAL LOAD for save files
-        // Bypasses AssetServer to prevent hot-reloading when the save file is overwritten
-        let path = Path::new("saves").join(&scene_to_load.path);
+    if !scene_to_load.is_save {
+        scene_spawner.spawn_dynamic(loading_manager.startup_scene.clone());
+        return;
+    }
+
+    // MANUAL LOAD for save files
+    // Bypasses AssetServer to prevent hot-reloading when the save file is overwritten
+    let requested = scene_to_load.path.clone();
+    if let Some((file_name, dynamic_scene)) = read_save_with_fallback(
+        Path::new("saves"),
+        &requested,
+        fallback.as_deref().map(|fallback| fallback.0.as_str()),
+        &type_registry.read(),
+        &mut errors,
+    ) {
+        scene_to_load.path = file_name;
+        let handle = dynamic_scenes.add(dynamic_scene);
+        scene_spawner.spawn_dynamic(handle);
+        return;
+    }
+
+    // Nothing usable: start over rather than waiting for a village that never spawns
+    errors.record(format!(
+        "Save '{}' could not be loaded and no autosave was usable, started a new game",
+        requested
+    ));
+    *scene_to_load = SceneToLoad::default();
+    loading_manager.startup_scene = asset_server.load(&scene_to_load.path);
+    scene_spawner.spawn_dynamic(loading_manager.startup_scene.clone());
+}
+
+/// Reads `requested` from `dir`, then `fallback` if the requested save is unreadable.
+/// Returns the file name that was actually loaded along with its scene.
+fn read_save_with_fallback(
+    dir: &Path,
+    requested: &str,
+    fallback: Option<&str>,
+    type_registry: &bevy::reflect::TypeRegistry,
+    errors: &mut LoadingErrors,
+) -> Option<(String, DynamicScene)> {
+    let mut candidates = vec![requested];
+    if let Some(fallback) = fallback.filter(|fallback| *fallback != requested) {
+        candidates.push(fallback);
+    }
+
+    for file_name in candidates {
+        let path = dir.join(file_name);
         info!("Manually loading save file from: {}", path.display());
 
-        match fs::read(&path) {
-            Ok(bytes) => {
-                let type_registry = type_registry.read();
-                let scene_deserializer = bevy::scene::serde::SceneDeserializer {
-                    type_registry: &type_registry,
-                };
-
-                let mut deserializer =
-                    ron::Deserializer::from_bytes(&bytes).expect("Failed to create deserializer");
-
-                match scene_deserializer.deserialize(&mut deserializer) {
-                    Ok(dynamic_scene) => {
-                        info!("Successfully manually deserialized save scene");
-                        let handle = dynamic_scenes.add(dynamic_scene);
-                        scene_spawner.spawn_dynamic(handle);
-                    }
-                    Err(e) => error!("Failed to deserialize save scene: {}", e),
+        match read_save_scene(&path, type_registry) {
+            Ok(dynamic_scene) => {
+                info!("Successfully manually deserialized save scene");
+                if file_name != requested {
+                    errors.record(format!(
+                        "Save '{}' could not be loaded, continued from '{}' instead",
+                        requested, file_name
+                    ));
                 }
+                return Some((file_name.to_string(), dynamic_scene));
             }
-            Err(e) => error!("Failed to read save file {}: {}", path.display(), e),
+            // A missing fallback is expected, only report saves that exist
+            Err(e) if path.exists() => errors.record(e),
+            Err(_) => {}
         }
-    } else {
-        scene_spawner.spawn_dynamic(loading_manager.startup_scene.clone());
     }
+    None
+}
+
+/// Reads and deserializes the save scene at `path`, describing any failure.
+fn read_save_scene(
+    path: &Path,
+    type_registry: &bevy::reflect::TypeRegistry,
+) -> Result<DynamicScene, String> {
+    let bytes = fs::read(path)
+        .map_err(|e| format!("Failed to read save file {}: {}", path.display(), e))?;
+    deserialize_save_scene(&bytes, type_registry)
+        .map_err(|e| format!("Save file {} is corrupt: {}", path.display(), e))
+}
+
+/// Deserializes a serialized save scene against `type_registry`, the same way saves are
+/// loaded. Fails on malformed RON and on types the registry does not know or match.
+pub fn deserialize_save_scene(
+    bytes: &[u8],
+    type_registry: &bevy::reflect::TypeRegistry,
+) -> Result<DynamicScene, String> {
+    let scene_deserializer = bevy::scene::serde::SceneDeserializer { type_registry };
+    let mut deserializer = ron::Deserializer::from_bytes(bytes).map_err(|e| e.to_string())?;
+    scene_deserializer
+        .deserialize(&mut deserializer)
+        .map_err(|e| e.to_string())
 }
 
 fn check_scene_spawned(
@@ -765,33 +950,112 @@ fn finish_loading(mut next_state: ResMut<NextState<GameState>>) {
 #[derive(Component)]
 struct LoadingUi;
 
+/// Phase/detail text inside the loading UI
+#[derive(Component)]
+struct LoadingUiText;
+
+/// Fill of the loading progress bar
+#[derive(Component)]
+struct LoadingProgressFill;
+
+/// Lists the `LoadingErrors` below the progress bar
+#[derive(Component)]
+struct LoadingErrorsText;
+
+/// Share of the loading sequence finished once `phase` is reached.
+fn phase_fraction(phase: &LoadingPhase) -> f32 {
+    phase_progress(phase, 0.0)
+}
+
+/// Share of the loading sequence finished when `phase` itself is `within` (0.0–1.0) done.
+fn phase_progress(phase: &LoadingPhase, within: f32) -> f32 {
+    let index = match phase {
+        LoadingPhase::Assets => 0,
+        LoadingPhase::SpawnScene => 1,
+        LoadingPhase::SpawnEntities => 2,
+        LoadingPhase::CompileUnlocks => 3,
+        LoadingPhase::EvaluateUnlocks => 4,
+        LoadingPhase::PostLoadReconstruction => 5,
+        LoadingPhase::Ready => 6,
+    };
+    ((index as f32 + within.clamp(0.0, 1.0)) / 6.0).min(1.0)
+}
+
+/// Moves `LoadingStatus::progress` to the start of each phase as it is entered.
+/// The Assets phase refines it further in `check_assets_loaded`.
+fn sync_phase_progress(phase: Res<State<LoadingPhase>>, mut status: ResMut<LoadingStatus>) {
+    if phase.is_changed() {
+        status.progress = phase_fraction(phase.get());
+    }
+}
+
 fn setup_loading_ui(mut commands: Commands) {
     info!("spawning loading ui");
-    commands.spawn((
-        Text::new("Loading..."),
-        TextFont {
-            font_size: 40.0,
-            ..default()
-        },
-        TextColor(Color::WHITE),
-        Node {
-            position_type: PositionType::Absolute,
-            bottom: Val::Px(20.0),
-            right: Val::Px(20.0),
-            flex_direction: FlexDirection::Column,
-            ..default()
-        },
-        LoadingUi,
-    ));
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(20.0),
+                right: Val::Px(20.0),
+                width: Val::Px(300.0),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            LoadingUi,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Loading..."),
+                TextFont {
+                    font_size: 40.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                LoadingUiText,
+            ));
+            let fill = spawn_progress_bar_ui(parent, "", 0.0);
+            parent.commands().entity(fill).insert(LoadingProgressFill);
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(UiTheme::NOT_AFFORDABLE),
+                LoadingErrorsText,
+            ));
+        });
 }
 
-fn update_loading_ui(status: Res<LoadingStatus>, mut query: Query<&mut Text, With<LoadingUi>>) {
-    if let Ok(mut text) = query.single_mut() {
+fn update_loading_ui(
+    status: Res<LoadingStatus>,
+    errors: Res<LoadingErrors>,
+    mut text_query: Query<&mut Text, (With<LoadingUiText>, Without<LoadingErrorsText>)>,
+    mut errors_query: Query<&mut Text, With<LoadingErrorsText>>,
+    mut fill_query: Query<&mut Node, With<LoadingProgressFill>>,
+) {
+    if let Ok(mut text) = text_query.single_mut() {
         *text = Text::new(format!(
-            "Loading...\n{}\n{}",
-            status.current_phase, status.detail
+            "Loading... {:.0}%\n{}\n{}",
+            status.progress * 100.0,
+            status.current_phase,
+            status.detail
         ));
     }
+
+    if let Ok(node) = fill_query.single_mut() {
+        set_progress_bar_fraction(node, status.progress);
+    }
+
+    if errors.is_changed()
+        && let Ok(mut text) = errors_query.single_mut()
+    {
+        *text = Text::new(if errors.errors.is_empty() {
+            String::new()
+        } else {
+            format!("Skipped assets:\n- {}", errors.errors.join("\n- "))
+        });
+    }
 }
 
 fn cleanup_loading_ui(mut commands: Commands, query: Query<Entity, With<LoadingUi>>) {
@@ -811,3 +1075,108 @@ fn clear_unlock_state(mut unlock_state: ResMut<UnlockState>) {
     unlock_state.completed.clear();
 }
 
+#[cfg(test)]
+mod tests {
+    use {super::*, bevy::reflect::TypeRegistry};
+
+    fn save_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("loading_{}_{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_record_ignores_repeated_messages() {
+        let mut errors = LoadingErrors::default();
+        errors.record("Weapons folder is missing".to_string());
+        errors.record("Skills folder is missing".to_string());
+        errors.record("Weapons folder is missing".to_string());
+
+        assert_eq!(
+            errors.errors,
+            vec!["Weapons folder is missing", "Skills folder is missing"]
+        );
+    }
+
+    #[test]
+    fn test_loading_errors_are_cleared_when_loading_starts() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::state::app::StatesPlugin))
+            .insert_state(GameState::Running)
+            .init_resource::<LoadingErrors>()
+            .add_systems(OnEnter(GameState::Loading), clear_loading_errors);
+        app.world_mut()
+            .resource_mut::<LoadingErrors>()
+            .record("Skills folder is missing".to_string());
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Loading);
+        app.update();
+
+        assert!(app.world().resource::<LoadingErrors>().errors.is_empty());
+    }
+
+    #[test]
+    fn test_unreadable_save_falls_back_to_autosave() {
+        let dir = save_dir("fallback");
+        let registry = TypeRegistry::default();
+        let scene = DynamicScene::default().serialize(&registry).unwrap();
+        fs::write(dir.join("slot1.scn.ron"), "not a scene").unwrap();
+        fs::write(dir.join("autosave.scn.ron"), scene).unwrap();
+
+        let mut errors = LoadingErrors::default();
+        let loaded = read_save_with_fallback(
+            &dir,
+            "slot1.scn.ron",
+            Some("autosave.scn.ron"),
+            &registry,
+            &mut errors,
+        );
+        fs::remove_dir_all(&dir).unwrap();
+
+        let (file_name, _) = loaded.expect("autosave should be loaded");
+        assert_eq!(file_name, "autosave.scn.ron");
+        assert_eq!(errors.errors.len(), 2);
+        assert!(errors.errors[0].contains("is corrupt"));
+        assert!(errors.errors[1].contains("continued from 'autosave.scn.ron'"));
+    }
+
+    #[test]
+    fn test_missing_save_without_fallback_loads_nothing() {
+        let dir = save_dir("missing");
+        let mut errors = LoadingErrors::default();
+        let loaded = read_save_with_fallback(
+            &dir,
+            "slot1.scn.ron",
+            None,
+            &TypeRegistry::default(),
+            &mut errors,
+        );
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(loaded.is_none());
+        assert!(errors.errors.is_empty());
+    }
+
+    #[test]
+    fn test_phase_progress_spans_the_loading_sequence() {
+        assert_eq!(phase_fraction(&LoadingPhase::Assets), 0.0);
+        assert_eq!(phase_fraction(&LoadingPhase::Ready), 1.0);
+        // Finishing the last phase before Ready completes the bar
+        assert_eq!(
+            phase_progress(&LoadingPhase::PostLoadReconstruction, 1.0),
+            1.0
+        );
+
+        // Half of the assets loaded is half of the first of six phases
+        assert_eq!(phase_progress(&LoadingPhase::Assets, 0.5), 0.5 / 6.0);
+        assert_eq!(
+            phase_progress(&LoadingPhase::Assets, 1.0),
+            phase_fraction(&LoadingPhase::SpawnScene)
+        );
+        // Out-of-range progress within a phase is clamped
+        assert_eq!(phase_progress(&LoadingPhase::Assets, -1.0), 0.0);
+        assert_eq!(phase_progress(&LoadingPhase::Ready, 1.0), 1.0);
+    }
+}
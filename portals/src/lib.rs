@@ -10,7 +10,7 @@ use {
     enemy_events::EnemyEscaped,
     hero_events::EnemyKilled,
     loading::GameAssets,
-    portal_assets::{SpawnCondition, SpawnTable, SpawnType},
+    portal_assets::{SpawnTable, SpawnType},
     portal_components::{Portal, SpawnTableId, SpawnTimer},
     rand::{distr::weighted::WeightedIndex, prelude::*},
     system_schedule::GameSchedule,
@@ -38,14 +38,22 @@ impl Plugin for PortalsPlugin {
         app.register_type::<EnemyRange>();
         app.register_type::<TargetDestination>();
 
-        app.add_systems(Update, enemy_spawn_system);
-        app.add_systems(Update, move_enemy.in_set(GameSchedule::PerformAction));
+        // Everything that moves or ages enemies stops while paused
+        app.add_systems(Update, enemy_spawn_system.run_if(states::not_paused));
         app.add_systems(
             Update,
-            manage_enemy_lifecycle.in_set(GameSchedule::FrameEnd),
+            move_enemy
+                .in_set(GameSchedule::PerformAction)
+                .run_if(states::not_paused),
+        );
+        app.add_systems(
+            Update,
+            manage_enemy_lifecycle
+                .in_set(GameSchedule::FrameEnd)
+                .run_if(states::not_paused),
         );
         app.add_systems(Update, draw_range_gizmos);
-        app.add_systems(Update, update_floating_text);
+        app.add_systems(Update, update_floating_text.run_if(states::not_paused));
 
         app.add_observer(assign_enemy_destination);
         app.add_observer(apply_blessing_to_lifetime);
@@ -89,25 +97,15 @@ fn enemy_spawn_system(
             .tick(time.delta().mul_f32(speed_modifier))
             .just_finished()
         {
-            let table_handle = if let Some(handle) = game_assets.spawn_tables.get(&table_id.0) {
-                handle
-            } else {
+            if !game_assets.spawn_tables.contains_key(&table_id.0) {
                 error!("Unknown spawn table: {}", table_id.0);
                 continue;
-            };
+            }
 
             // Get the asset data
-            if let Some(table) = spawn_tables.get(table_handle) {
+            if let Some(table) = game_assets.spawn_table(&table_id.0, &spawn_tables) {
                 // Find valid entries based on condition
-                let valid_entries: Vec<_> = table
-                    .entries
-                    .iter()
-                    .filter(|e| match &e.condition {
-                        SpawnCondition::Min(req) => divinity >= *req,
-                        SpawnCondition::Specific(req) => divinity == *req,
-                        SpawnCondition::Range { min, max } => divinity >= *min && divinity <= *max,
-                    })
-                    .collect();
+                let valid_entries: Vec<_> = table.entries_for(divinity).collect();
 
                 if valid_entries.is_empty() {
                     continue;
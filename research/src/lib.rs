@@ -6,8 +6,10 @@ use {
     system_schedule::GameSchedule,
 };
 
+pub mod library;
 pub mod systems;
 
+pub use library::ResearchLibrary;
 use research_assets::ResearchDefinition;
 // Re-export shared unlock states for backwards compatibility
 pub use unlock_states::{Available, Locked};
@@ -72,6 +74,39 @@ pub struct ResearchState {
     pub completion_counts: HashMap<String, u32>,
 }
 
+/// Research waiting for the running project to finish, oldest first.
+///
+/// Nothing is paid when a tech is queued; `start_queued_research` checks the cost again
+/// and pays it when the tech actually starts.
+#[derive(Resource, Default, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct ResearchQueue {
+    pub queued: Vec<String>,
+}
+
+impl ResearchQueue {
+    /// Returns true if `research_id` is waiting in the queue.
+    pub fn contains(&self, research_id: &str) -> bool {
+        self.queued.iter().any(|id| id == research_id)
+    }
+
+    /// Queues `research_id` unless it is already queued. Returns whether it was added.
+    pub fn push(&mut self, research_id: &str) -> bool {
+        if self.contains(research_id) {
+            return false;
+        }
+        self.queued.push(research_id.to_string());
+        true
+    }
+
+    /// Removes `research_id` from the queue. Returns whether it was queued.
+    pub fn remove(&mut self, research_id: &str) -> bool {
+        let len = self.queued.len();
+        self.queued.retain(|id| id != research_id);
+        self.queued.len() != len
+    }
+}
+
 // --- Events ---
 
 /// Represents the successful completion of a research project's timer.
@@ -90,6 +125,12 @@ pub struct ResearchCompleted {
     pub research_id: String,
 }
 
+/// Request to drop every research waiting in the `ResearchQueue`.
+/// The research currently in progress keeps running. Queued research was never paid for,
+/// so nothing is refunded.
+#[derive(Event)]
+pub struct ClearResearchQueue;
+
 /// Represents a request to begin a research project.
 ///
 /// This **Observer** event (triggered via `commands.trigger`) serves as the bridge between
@@ -114,18 +155,25 @@ impl Plugin for ResearchPlugin {
         app.add_plugins(RonAssetPlugin::<ResearchDefinition>::new(&["research.ron"]))
             .init_resource::<ResearchMap>()
             .init_resource::<ResearchState>()
+            .init_resource::<ResearchQueue>()
             .register_type::<ResearchState>()
+            .register_type::<ResearchQueue>()
             .register_type::<UnlockEffect>()
             .register_type::<ResearchCompletionCount>()
             .register_type::<InProgress>()
             .add_systems(
                 Update,
-                systems::update_research_progress
+                (
+                    systems::update_research_progress,
+                    systems::start_queued_research,
+                )
+                    .chain()
                     .in_set(GameSchedule::FrameStart)
-                    .run_if(in_state(states::GameState::Running)),
+                    .run_if(in_state(states::GameState::Running).and(states::not_paused)),
             )
             .add_observer(systems::on_unlock_achieved)
             .add_observer(systems::start_research)
+            .add_observer(systems::clear_research_queue)
             .add_systems(
                 OnExit(states::GameState::Running),
                 systems::clean_up_research,
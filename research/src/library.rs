@@ -0,0 +1,163 @@
+use {
+    crate::{Locked, ResearchCompletionCount, ResearchMap, ResearchNode},
+    bevy::{ecs::system::SystemParam, prelude::*},
+    research_assets::ResearchDefinition,
+};
+
+/// Read-only access to research definitions and their completion state.
+///
+/// Wraps the `ResearchMap` -> `ResearchNode` -> `Assets<ResearchDefinition>` lookup chain
+/// so UIs don't have to repeat it.
+#[derive(SystemParam)]
+pub struct ResearchLibrary<'w, 's> {
+    research_map: Res<'w, ResearchMap>,
+    assets: Res<'w, Assets<ResearchDefinition>>,
+    nodes: Query<
+        'w,
+        's,
+        (
+            &'static ResearchNode,
+            &'static ResearchCompletionCount,
+            Has<Locked>,
+        ),
+    >,
+}
+
+impl ResearchLibrary<'_, '_> {
+    /// Returns the definition of a research, whatever its state.
+    pub fn get_def(&self, id: &str) -> Option<&ResearchDefinition> {
+        let entity = self.research_map.entities.get(id)?;
+        let (node, ..) = self.nodes.get(*entity).ok()?;
+        self.assets.get(&node.handle)
+    }
+
+    /// Player-facing name of a research, falling back to its id.
+    pub fn display_name(&self, id: &str) -> String {
+        self.get_def(id)
+            .map_or_else(|| id.to_string(), |def| def.name.clone())
+    }
+
+    /// Returns true if the research was completed at least once.
+    pub fn is_researched(&self, id: &str) -> bool {
+        self.research_map
+            .entities
+            .get(id)
+            .and_then(|entity| self.nodes.get(*entity).ok())
+            .is_some_and(|(_, count, _)| count.0 > 0)
+    }
+
+    /// Display names of the research `id` still waits on before it unlocks.
+    pub fn missing_prerequisites(&self, id: &str) -> Vec<String> {
+        let Some(unlock) = self.get_def(id).and_then(|def| def.unlock.as_ref()) else {
+            return Vec::new();
+        };
+        unlock
+            .condition
+            .required_research()
+            .into_iter()
+            .filter(|prerequisite| !self.is_researched(prerequisite))
+            .map(|prerequisite| self.display_name(prerequisite))
+            .collect()
+    }
+
+    /// Returns all locked research, sorted by id.
+    pub fn locked(&self) -> Vec<(&str, &ResearchDefinition)> {
+        let mut research: Vec<_> = self
+            .nodes
+            .iter()
+            .filter(|(.., locked)| *locked)
+            .filter_map(|(node, ..)| Some((node.id.as_str(), self.assets.get(&node.handle)?)))
+            .collect();
+        research.sort_by_key(|(id, _)| *id);
+        research
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::Available,
+        bevy::ecs::system::RunSystemOnce,
+        unlocks_assets::{ConditionNode, RepeatMode, UnlockDefinition},
+    };
+
+    fn definition(id: &str, requires: &[&str]) -> ResearchDefinition {
+        let condition = ConditionNode::And(
+            requires
+                .iter()
+                .map(|r| ConditionNode::Completed {
+                    topic: format!("research:{}", r),
+                })
+                .chain([ConditionNode::Not(Box::new(ConditionNode::Completed {
+                    topic: "research:heresy".to_string(),
+                }))])
+                .collect(),
+        );
+        ResearchDefinition {
+            id: id.to_string(),
+            name: format!("{} name", id),
+            description: String::new(),
+            cost: Default::default(),
+            time_required: 1.0,
+            max_repeats: 1,
+            unlock: Some(UnlockDefinition {
+                id: format!("research_{}", id),
+                display_name: None,
+                condition,
+                reward_id: format!("research:{}", id),
+                repeat_mode: RepeatMode::Once,
+            }),
+            tags: vec![],
+        }
+    }
+
+    fn spawn_research(world: &mut World, def: ResearchDefinition, completions: u32) {
+        let id = def.id.clone();
+        let handle = world.resource_mut::<Assets<ResearchDefinition>>().add(def);
+        let node = ResearchNode {
+            id: id.clone(),
+            handle,
+        };
+        let entity = if completions > 0 {
+            world
+                .spawn((node, Available, ResearchCompletionCount(completions)))
+                .id()
+        } else {
+            world.spawn((node, Locked, ResearchCompletionCount(0))).id()
+        };
+        world
+            .resource_mut::<ResearchMap>()
+            .entities
+            .insert(id, entity);
+    }
+
+    #[test]
+    fn test_missing_prerequisites() {
+        let mut world = World::new();
+        world.init_resource::<ResearchMap>();
+        world.init_resource::<Assets<ResearchDefinition>>();
+
+        spawn_research(&mut world, definition("bone_pile", &[]), 1);
+        spawn_research(&mut world, definition("autopsy", &[]), 0);
+        spawn_research(
+            &mut world,
+            definition("bone_idol", &["bone_pile", "autopsy", "unknown"]),
+            0,
+        );
+
+        world
+            .run_system_once(|library: ResearchLibrary| {
+                // Completed prerequisites and the NOT-ed exclusion are not listed
+                assert_eq!(
+                    library.missing_prerequisites("bone_idol"),
+                    vec!["autopsy name".to_string(), "unknown".to_string()]
+                );
+                assert!(library.missing_prerequisites("bone_pile").is_empty());
+
+                let locked: Vec<&str> = library.locked().into_iter().map(|(id, _)| id).collect();
+                assert_eq!(locked, vec!["autopsy", "bone_idol"]);
+            })
+            .unwrap();
+    }
+}
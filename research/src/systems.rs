@@ -1,13 +1,15 @@
 use {
     crate::{
-        Available, Completed, InProgress, Locked, ResearchCompleted, ResearchCompletionCount,
-        ResearchDefinition, ResearchMap, ResearchNode, StartResearchRequest,
+        Available, ClearResearchQueue, Completed, InProgress, Locked, ResearchCompleted,
+        ResearchCompletionCount, ResearchDefinition, ResearchMap, ResearchNode, ResearchQueue,
+        StartResearchRequest,
     },
     bevy::prelude::*,
+    bonus_stats_resources::BonusStats,
+    states::{GameSpeed, scaled_delta},
     unlocks_events,
     unlocks_resources::UnlockState,
     wallet::Wallet,
-    bonus_stats_resources::BonusStats,
 };
 
 // TODO: Move this to a loading stage once asset loading is consolidated
@@ -110,6 +112,7 @@ pub fn on_unlock_achieved(
 /// Ticks timers for in-progress research and handles completion/repeat logic.
 pub fn update_research_progress(
     time: Res<Time>,
+    speed: Res<GameSpeed>,
     assets: Res<Assets<ResearchDefinition>>,
     mut commands: Commands,
     mut research_state: ResMut<crate::ResearchState>,
@@ -120,8 +123,9 @@ pub fn update_research_progress(
         &mut ResearchCompletionCount,
     )>,
 ) {
+    let delta = scaled_delta(&time, &speed);
     for (entity, node, mut progress, mut count) in query.iter_mut() {
-        progress.timer.tick(time.delta());
+        progress.timer.tick(delta);
         if progress.timer.just_finished() {
             // Increment completion count (on entity and in persisted state)
             count.0 += 1;
@@ -137,7 +141,7 @@ pub fn update_research_progress(
 
             // Notify unlock system about research completion
             commands.trigger(unlocks_events::StatusCompleted {
-                topic: format!("research:{}", node.id),
+                topic: unlocks_events::Topic::research(&node.id).into(),
             });
 
             // Check max_repeats from definition
@@ -224,9 +228,57 @@ pub fn start_research(
     info!("Started researching: {}", def.name);
 }
 
+/// System that starts the oldest queued research once no research is running.
+///
+/// Queued research is only paid for here, so the cost is checked again: an unaffordable
+/// tech waits at the front of the queue until the wallet can cover it. Entries that are
+/// no longer available (e.g. maxed out meanwhile) are dropped.
+pub fn start_queued_research(
+    mut commands: Commands,
+    mut queue: ResMut<ResearchQueue>,
+    research_map: Res<ResearchMap>,
+    assets: Res<Assets<ResearchDefinition>>,
+    available_query: Query<&ResearchNode, With<Available>>,
+    in_progress_query: Query<(), With<InProgress>>,
+    wallet: Res<Wallet>,
+) {
+    if !in_progress_query.is_empty() {
+        return;
+    }
+
+    while let Some(research_id) = queue.queued.first().cloned() {
+        let def = research_map
+            .entities
+            .get(&research_id)
+            .and_then(|entity| available_query.get(*entity).ok())
+            .and_then(|node| assets.get(&node.handle));
+        let Some(def) = def else {
+            debug!(%research_id, "Dropping queued research that is no longer available");
+            queue.queued.remove(0);
+            continue;
+        };
+
+        if wallet.can_afford(&def.cost) {
+            queue.queued.remove(0);
+            debug!(%research_id, remaining = queue.queued.len(), "Starting queued research");
+            commands.trigger(StartResearchRequest(research_id));
+        }
+        return;
+    }
+}
+
+/// Observer for ClearResearchQueue events. Leaves the running research alone.
+pub fn clear_research_queue(_trigger: On<ClearResearchQueue>, mut queue: ResMut<ResearchQueue>) {
+    if !queue.queued.is_empty() {
+        info!("Cleared {} queued research", queue.queued.len());
+        queue.queued.clear();
+    }
+}
+
 pub fn clean_up_research(
     mut commands: Commands,
     mut research_map: ResMut<ResearchMap>,
+    mut queue: ResMut<ResearchQueue>,
     nodes: Query<Entity, With<ResearchNode>>,
 ) {
     debug!("Cleaning up all research entities");
@@ -236,4 +288,127 @@ pub fn clean_up_research(
     }
     // Clear the map
     research_map.entities.clear();
+    queue.queued.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, bevy::ecs::system::RunSystemOnce};
+
+    fn setup() -> World {
+        let mut world = World::new();
+        world.init_resource::<ResearchMap>();
+        world.init_resource::<ResearchQueue>();
+        world.init_resource::<Assets<ResearchDefinition>>();
+        world.init_resource::<Wallet>();
+        world.init_resource::<BonusStats>();
+        world.add_observer(start_research);
+        world.add_observer(clear_research_queue);
+        world
+    }
+
+    fn spawn_research(world: &mut World, id: &str, bones: u32) -> Entity {
+        let handle = world
+            .resource_mut::<Assets<ResearchDefinition>>()
+            .add(ResearchDefinition {
+                id: id.to_string(),
+                name: id.to_string(),
+                description: String::new(),
+                cost: [("bones".to_string(), bones)].into_iter().collect(),
+                time_required: 1.0,
+                max_repeats: 1,
+                unlock: None,
+                tags: vec![],
+            });
+        let entity = world
+            .spawn((
+                ResearchNode {
+                    id: id.to_string(),
+                    handle,
+                },
+                Available,
+                ResearchCompletionCount(0),
+            ))
+            .id();
+        world
+            .resource_mut::<ResearchMap>()
+            .entities
+            .insert(id.to_string(), entity);
+        entity
+    }
+
+    fn set_bones(world: &mut World, bones: u32) {
+        world
+            .resource_mut::<Wallet>()
+            .resources
+            .insert("bones".to_string(), bones);
+    }
+
+    #[test]
+    fn test_queued_research_starts_when_affordable() {
+        let mut world = setup();
+        let running = spawn_research(&mut world, "autopsy", 0);
+        let queued = spawn_research(&mut world, "bone_idol", 10);
+        world
+            .entity_mut(running)
+            .remove::<Available>()
+            .insert(InProgress {
+                research_id: "autopsy".to_string(),
+                timer: Timer::from_seconds(1.0, TimerMode::Once),
+            });
+        {
+            let mut queue = world.resource_mut::<ResearchQueue>();
+            // Already researching, so it is dropped once its turn comes
+            queue.push("autopsy");
+            queue.push("bone_idol");
+        }
+        set_bones(&mut world, 5);
+
+        // Nothing starts while another research runs
+        world.run_system_once(start_queued_research).unwrap();
+        assert_eq!(world.resource::<ResearchQueue>().queued.len(), 2);
+
+        // The running research finished, but the next one can't be paid for yet
+        world
+            .entity_mut(running)
+            .remove::<InProgress>()
+            .insert(Completed);
+        world.run_system_once(start_queued_research).unwrap();
+        assert_eq!(
+            world.resource::<ResearchQueue>().queued,
+            vec!["bone_idol".to_string()]
+        );
+        assert!(world.get::<InProgress>(queued).is_none());
+
+        set_bones(&mut world, 12);
+        world.run_system_once(start_queued_research).unwrap();
+        assert!(world.resource::<ResearchQueue>().queued.is_empty());
+        assert!(world.get::<InProgress>(queued).is_some());
+        assert_eq!(world.resource::<Wallet>().amount("bones"), 2);
+    }
+
+    #[test]
+    fn test_clear_queue_keeps_running_research() {
+        let mut world = setup();
+        let running = spawn_research(&mut world, "autopsy", 0);
+        spawn_research(&mut world, "bone_idol", 10);
+        spawn_research(&mut world, "bone_totem", 10);
+        world
+            .entity_mut(running)
+            .remove::<Available>()
+            .insert(InProgress {
+                research_id: "autopsy".to_string(),
+                timer: Timer::from_seconds(1.0, TimerMode::Once),
+            });
+        {
+            let mut queue = world.resource_mut::<ResearchQueue>();
+            queue.push("bone_idol");
+            queue.push("bone_totem");
+        }
+
+        world.trigger(ClearResearchQueue);
+
+        assert!(world.resource::<ResearchQueue>().queued.is_empty());
+        assert!(world.get::<InProgress>(running).is_some());
+    }
 }
@@ -4,16 +4,16 @@ use {
     std::collections::HashMap,
 };
 
-
 /// Defines how a [`StatBonus`] value interacts with the base statistic.
 ///
 /// The bonus system aggregates values in three stages:
 /// 1. **Additive**: Bonuses are summed (e.g., +10).
 /// 2. **Percent**: Bonuses are summed and applied as a multiplier to the base (e.g., +10% and +20% = +30% -> x1.3).
+///    **DiminishingPercent** bonuses join this stage as `1 - product(1 - v)`.
 /// 3. **Multiplicative**: Bonuses are summed and applied as a *final* multiplier to the result (e.g., x2 and x2 = x4).
 ///
 /// The calculation formula is:
-/// `(Base + AdditiveSum) * (1.0 + PercentSum) * max(1.0, MultiplicativeSum)`
+/// `(Base + AdditiveSum) * (1.0 + PercentSum + Diminishing) * max(1.0, MultiplicativeSum)`
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, Reflect)]
 pub enum StatMode {
     /// Adds directly to the base value.
@@ -34,6 +34,11 @@ pub enum StatMode {
     /// two x1.5 bonuses result in x3.0 (1.5 + 1.5), not x2.25 (1.5 * 1.5).
     /// The final multiplier is clamped to a minimum of 1.0.
     Multiplicative,
+    /// Adds to the percentage stage with diminishing returns.
+    ///
+    /// Each source only improves the part the others left: two +50% give +75%
+    /// (`1 - 0.5 * 0.5`), not +100%. Values should stay below `1.0`.
+    DiminishingPercent,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, Reflect)]
@@ -42,13 +47,23 @@ pub struct StatBonus {
     pub mode: StatMode,
 }
 
-
 /// Aggregated bonuses for a specific key (e.g., "damage:melee").
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Reflect)]
 pub struct BonusStat {
     pub additive: f32,
     pub percent: f32,        // Sum of percentages (e.g., 0.1 + 0.2 = 0.3)
     pub multiplicative: f32, // Product of multipliers (starts at 1.0)
+    /// Product of `1 - v` over all `DiminishingPercent` bonuses below +100%, 1.0 when there are none
+    #[serde(default = "no_diminishing")]
+    pub diminishing_remainder: f32,
+    /// Number of `DiminishingPercent` bonuses of +100% or more, kept out of the product
+    /// so removing one doesn't divide by zero
+    #[serde(default)]
+    pub full_diminishing: u32,
+}
+
+fn no_diminishing() -> f32 {
+    1.0
 }
 
 impl Default for BonusStat {
@@ -57,6 +72,8 @@ impl Default for BonusStat {
             additive: 0.0,
             percent: 0.0,
             multiplicative: 0.0,
+            diminishing_remainder: no_diminishing(),
+            full_diminishing: 0,
         }
     }
 }
@@ -67,6 +84,8 @@ impl BonusStat {
             StatMode::Additive => self.additive += bonus.value,
             StatMode::Percent => self.percent += bonus.value,
             StatMode::Multiplicative => self.multiplicative += bonus.value,
+            StatMode::DiminishingPercent if bonus.value >= 1.0 => self.full_diminishing += 1,
+            StatMode::DiminishingPercent => self.diminishing_remainder *= 1.0 - bonus.value,
         }
     }
 
@@ -79,13 +98,42 @@ impl BonusStat {
                     self.multiplicative -= bonus.value;
                 }
             }
+            StatMode::DiminishingPercent if bonus.value >= 1.0 => {
+                self.full_diminishing = self.full_diminishing.saturating_sub(1);
+            }
+            StatMode::DiminishingPercent => self.diminishing_remainder /= 1.0 - bonus.value,
         }
     }
 
+    /// Combined `DiminishingPercent` bonus, e.g. `0.75` for two +50% sources.
+    pub fn diminishing(&self) -> f32 {
+        if self.full_diminishing > 0 {
+            1.0
+        } else {
+            1.0 - self.diminishing_remainder
+        }
+    }
+
+    /// Everything the percent stage adds: the linear sum plus the diminishing bonus.
+    pub fn total_percent(&self) -> f32 {
+        self.percent + self.diminishing()
+    }
+
+    /// True if this stat changes nothing (no flat, percent or multiplier bonus left).
+    pub fn is_empty(&self) -> bool {
+        self.additive == 0.0
+            && self.percent == 0.0
+            && self.multiplicative == 0.0
+            && self.diminishing_remainder == 1.0
+            && self.full_diminishing == 0
+    }
+
     pub fn reset(&mut self) {
         self.additive = 0.0;
         self.percent = 0.0;
         self.multiplicative = 1.0;
+        self.diminishing_remainder = no_diminishing();
+        self.full_diminishing = 0;
     }
 }
 
@@ -97,10 +145,58 @@ impl std::ops::Add for BonusStat {
             additive: self.additive + rhs.additive,
             percent: self.percent + rhs.percent,
             multiplicative: self.multiplicative + rhs.multiplicative,
+            diminishing_remainder: self.diminishing_remainder * rhs.diminishing_remainder,
+            full_diminishing: self.full_diminishing + rhs.full_diminishing,
+        }
+    }
+}
+
+/// Limits applied to a category's aggregated bonuses and final value.
+///
+/// `None` leaves that stage uncapped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, Reflect)]
+pub struct StatCap {
+    /// Maximum percent-stage bonus, diminishing bonuses included (e.g. `5.0` = +500%).
+    pub max_percent: Option<f32>,
+    /// Maximum final multiplier (e.g. `5.0` = x5).
+    pub max_multiplicative: Option<f32>,
+    /// Floor of the final calculated value.
+    pub min_value: Option<f32>,
+    /// Ceiling of the final calculated value (e.g. a damage cap).
+    pub max_value: Option<f32>,
+}
+
+impl StatCap {
+    /// Cap that only clamps the final value to `min..=max`.
+    pub fn clamp(min: f32, max: f32) -> Self {
+        Self {
+            min_value: Some(min),
+            max_value: Some(max),
+            ..default()
+        }
+    }
+
+    fn clamp_value(&self, value: f32) -> f32 {
+        let value = self.min_value.map_or(value, |min| value.max(min));
+        self.max_value.map_or(value, |max| value.min(max))
+    }
+
+    fn apply(&self, stat: &mut BonusStat) {
+        if let Some(max) = self.max_percent
+            && stat.total_percent() > max
+        {
+            // Trim the linear part so the whole percent stage lands on the cap
+            stat.percent = max - stat.diminishing();
+        }
+        if let Some(max) = self.max_multiplicative {
+            stat.multiplicative = stat.multiplicative.min(max);
         }
     }
 }
 
+/// Per-category bonus caps, keyed by category (e.g., "damage").
+pub type StatCaps = HashMap<String, StatCap>;
+
 /// Resource to manage global **bonuses**.
 #[derive(Resource, Default, Debug, Reflect)]
 #[reflect(Resource)]
@@ -109,14 +205,32 @@ pub struct BonusStats {
     /// Outer key: Category (e.g., "damage", "hp")
     /// Inner key: Sub-key (e.g., "melee", "fire", "" for exact matches)
     bonuses: HashMap<String, HashMap<String, BonusStat>>,
+    /// Caps applied in [`BonusStats::calculate_stat`] after aggregation.
+    /// Categories without an entry are uncapped.
+    caps: StatCaps,
 }
 
 impl BonusStats {
-    /// Clears all bonuses.
+    /// Clears all bonuses. Configured caps are kept.
     pub fn clear(&mut self) {
         self.bonuses.clear();
     }
 
+    /// Clamps the calculated value of a category to `min..=max`, replacing any previous cap.
+    pub fn set_cap(&mut self, category: &str, min: f32, max: f32) {
+        self.set_stat_cap(category, StatCap::clamp(min, max));
+    }
+
+    /// Sets the full cap for a category, replacing any previous one.
+    pub fn set_stat_cap(&mut self, category: &str, cap: StatCap) {
+        self.caps.insert(category.to_string(), cap);
+    }
+
+    /// Returns the cap configured for a category, if any.
+    pub fn cap(&self, category: &str) -> Option<&StatCap> {
+        self.caps.get(category)
+    }
+
     /// Adds a bonus to a specific key.
     pub fn add(&mut self, key: &str, bonus: StatBonus) {
         let (category, subkey) = key.split_once(':').unwrap_or((key, ""));
@@ -147,14 +261,28 @@ impl BonusStats {
         self.bonuses.get(category)?.get(key)
     }
 
-    /// Calculates a final value for a specific stat category and sub-stat.
-    ///
-    /// # Arguments
-    /// * `category` - The main stat category (e.g., "damage", "research").
-    /// * `base_value` - The starting value.
-    /// * `tags` - Tags to match for bonuses (e.g., "melee", "weapon:bone_sword").
-    ///            It will look for bonuses at `category:{tag}`.
-    pub fn calculate_stat(&self, category: &str, base_value: f32, tags: &[String]) -> f32 {
+    /// Iterates every aggregated bonus as `(category, sub-key, stat)`, in no particular order.
+    /// Each stat already sums all sources that contributed to that key.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str, &BonusStat)> {
+        self.bonuses.iter().flat_map(|(category, sub_bonuses)| {
+            sub_bonuses
+                .iter()
+                .map(move |(subkey, stat)| (category.as_str(), subkey.as_str(), stat))
+        })
+    }
+
+    /// Iterates the aggregated bonuses of one category as `(sub-key, stat)`, in no particular order.
+    pub fn iter_category(&self, category: &str) -> impl Iterator<Item = (&str, &BonusStat)> {
+        self.bonuses
+            .get(category)
+            .into_iter()
+            .flat_map(|sub_bonuses| sub_bonuses.iter())
+            .map(|(subkey, stat)| (subkey.as_str(), stat))
+    }
+
+    /// Sums the bonuses of `category` matched by `tags`, the way [`BonusStats::calculate_stat`]
+    /// matches them. Caps are not applied; they only limit the calculated value.
+    pub fn total_for_tags(&self, category: &str, tags: &[String]) -> BonusStat {
         let mut total_bonus = BonusStat::default();
 
         if let Some(category_bonuses) = self.bonuses.get(category) {
@@ -163,11 +291,7 @@ impl BonusStats {
                 // But the current convention for source tags seems to be "damage:melee"
                 // and they expect to match against the suffix.
                 let suffix = if let Some((cat, suf)) = tag.split_once(':') {
-                    if cat == category {
-                        suf
-                    } else {
-                        tag.as_str()
-                    }
+                    if cat == category { suf } else { tag.as_str() }
                 } else {
                     tag.as_str()
                 };
@@ -178,16 +302,36 @@ impl BonusStats {
             }
         }
 
-        // Calculation: (Base + Additive) * (1 + Percent) * Multiplicative
+        total_bonus
+    }
+
+    /// Calculates a final value for a specific stat category and sub-stat.
+    ///
+    /// # Arguments
+    /// * `category` - The main stat category (e.g., "damage", "research").
+    /// * `base_value` - The starting value.
+    /// * `tags` - Tags to match for bonuses (e.g., "melee", "weapon:bone_sword").
+    ///            It will look for bonuses at `category:{tag}`.
+    pub fn calculate_stat(&self, category: &str, base_value: f32, tags: &[String]) -> f32 {
+        let mut total_bonus = self.total_for_tags(category, tags);
+
+        let cap = self.caps.get(category);
+        if let Some(cap) = cap {
+            cap.apply(&mut total_bonus);
+        }
+
+        // Calculation: (Base + Additive) * (1 + Percent + Diminishing) * Multiplicative
         let final_value = (base_value + total_bonus.additive)
-            * (1.0 + total_bonus.percent)
+            * (1.0 + total_bonus.total_percent())
             * total_bonus.multiplicative.max(1.0);
 
-        final_value.max(0.0)
+        let final_value = final_value.max(0.0);
+        cap.map_or(final_value, |cap| cap.clamp_value(final_value))
     }
 }
 
 /// Calculates the final damage considering base damage, source tags, target tags, and active bonuses.
+/// Any cap configured for the "damage" category applies.
 ///
 /// # Arguments
 /// * `base_damage` - The base damage of the weapon or source.
@@ -426,4 +570,280 @@ mod tests {
         // Without tag -> no bonus
         assert_eq!(stats.calculate_stat("research", 100.0, &[]), 100.0);
     }
+
+    #[test]
+    fn test_iter_category_and_total_for_tags() {
+        let mut stats = BonusStats::default();
+        stats.add(
+            "damage:melee",
+            StatBonus {
+                value: 5.0,
+                mode: StatMode::Additive,
+            },
+        );
+        stats.add(
+            "damage:race:goblin",
+            StatBonus {
+                value: 0.5,
+                mode: StatMode::Percent,
+            },
+        );
+        stats.add(
+            "hp",
+            StatBonus {
+                value: 3.0,
+                mode: StatMode::Additive,
+            },
+        );
+
+        let mut subkeys: Vec<&str> = stats.iter_category("damage").map(|(k, _)| k).collect();
+        subkeys.sort();
+        assert_eq!(subkeys, vec!["melee", "race:goblin"]);
+        assert_eq!(stats.iter_category("research").count(), 0);
+
+        let tags = vec!["damage:melee".to_string(), "race:goblin".to_string()];
+        let total = stats.total_for_tags("damage", &tags);
+        assert_eq!(total.additive, 5.0);
+        assert_eq!(total.percent, 0.5);
+        // (10 + 5) * (1 + 0.5) = 22.5
+        assert_eq!(calculate_damage(10.0, &tags, &[], &stats), 22.5);
+        assert!(stats.total_for_tags("damage", &[]).is_empty());
+    }
+
+    #[test]
+    fn test_diminishing_percent() {
+        let mut stats = BonusStats::default();
+        let half = StatBonus {
+            value: 0.5, // +50%
+            mode: StatMode::DiminishingPercent,
+        };
+
+        stats.add("damage:melee", half.clone());
+        let melee = vec!["damage:melee".to_string()];
+        // 10 * (1 + 0.5) = 15
+        assert_eq!(calculate_damage(10.0, &melee, &[], &stats), 15.0);
+
+        // Two +50% give +75%: 10 * (1 + (1 - 0.5 * 0.5)) = 17.5
+        stats.add("damage:melee", half.clone());
+        assert_eq!(calculate_damage(10.0, &melee, &[], &stats), 17.5);
+
+        // Diminishing sources also combine across tags
+        stats.add("damage:fire", half.clone());
+        let tags = vec!["damage:melee".to_string(), "damage:fire".to_string()];
+        // 10 * (1 + (1 - 0.125)) = 18.75
+        assert_eq!(calculate_damage(10.0, &tags, &[], &stats), 18.75);
+
+        // Linear percent still adds on top: 10 * (1 + 0.25 + 0.75) = 20
+        stats.add(
+            "damage:melee",
+            StatBonus {
+                value: 0.25,
+                mode: StatMode::Percent,
+            },
+        );
+        assert_eq!(calculate_damage(10.0, &melee, &[], &stats), 20.0);
+
+        stats.remove("damage:melee", half.clone());
+        stats.remove("damage:melee", half);
+        let raw = stats.get("damage:melee").unwrap();
+        assert_eq!(raw.diminishing(), 0.0);
+    }
+
+    #[test]
+    fn test_removing_full_diminishing_source_restores_the_others() {
+        let mut stats = BonusStats::default();
+        let full = StatBonus {
+            value: 1.0, // +100%
+            mode: StatMode::DiminishingPercent,
+        };
+        let half = StatBonus {
+            value: 0.5,
+            mode: StatMode::DiminishingPercent,
+        };
+
+        stats.add("damage:melee", half.clone());
+        stats.add("damage:melee", full.clone());
+        assert_eq!(stats.get("damage:melee").unwrap().diminishing(), 1.0);
+
+        stats.remove("damage:melee", full);
+        let raw = stats.get("damage:melee").unwrap();
+        assert_eq!(raw.diminishing(), 0.5);
+        assert!(raw.diminishing_remainder.is_finite());
+
+        stats.remove("damage:melee", half);
+        assert!(stats.get("damage:melee").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_bonus_stat_without_diminishing_field_deserializes() {
+        let stat: BonusStat =
+            ron::from_str("(additive: 1.0, percent: 0.5, multiplicative: 2.0)").unwrap();
+        assert_eq!(stat.diminishing_remainder, 1.0);
+        assert_eq!(stat.diminishing(), 0.0);
+    }
+
+    #[test]
+    fn test_caps_clamp_aggregated_bonuses() {
+        let mut stats = BonusStats::default();
+        stats.set_stat_cap(
+            "damage",
+            StatCap {
+                max_percent: Some(5.0),
+                max_multiplicative: Some(5.0),
+                ..default()
+            },
+        );
+
+        for tag in ["damage:melee", "damage:fire"] {
+            stats.add(
+                tag,
+                StatBonus {
+                    value: 4.0, // +400% each
+                    mode: StatMode::Percent,
+                },
+            );
+            stats.add(
+                tag,
+                StatBonus {
+                    value: 3.0, // x3 each
+                    mode: StatMode::Multiplicative,
+                },
+            );
+        }
+
+        let tags = vec!["damage:melee".to_string(), "damage:fire".to_string()];
+        // Percent 8.0 -> 5.0, multiplier 6.0 -> 5.0: 10 * (1 + 5) * 5 = 300
+        assert_eq!(calculate_damage(10.0, &tags, &[], &stats), 300.0);
+
+        // Below the cap values pass through unchanged: 10 * (1 + 4) * 3 = 150
+        assert_eq!(
+            calculate_damage(10.0, &["damage:melee".to_string()], &[], &stats),
+            150.0
+        );
+    }
+
+    #[test]
+    fn test_percent_cap_includes_diminishing_bonuses() {
+        let mut stats = BonusStats::default();
+        stats.set_stat_cap(
+            "damage",
+            StatCap {
+                max_percent: Some(1.0),
+                ..default()
+            },
+        );
+        stats.add(
+            "damage:melee",
+            StatBonus {
+                value: 0.75,
+                mode: StatMode::Percent,
+            },
+        );
+        stats.add(
+            "damage:melee",
+            StatBonus {
+                value: 0.5,
+                mode: StatMode::DiminishingPercent,
+            },
+        );
+
+        // 0.75 + 0.5 = +125% -> +100%: 10 * (1 + 1) = 20
+        let melee = vec!["damage:melee".to_string()];
+        assert_eq!(calculate_damage(10.0, &melee, &[], &stats), 20.0);
+    }
+
+    #[test]
+    fn test_caps_leave_other_categories_unaffected() {
+        let mut stats = BonusStats::default();
+        stats.set_stat_cap(
+            "damage",
+            StatCap {
+                max_percent: Some(1.0),
+                max_multiplicative: None,
+                ..default()
+            },
+        );
+
+        stats.add(
+            "hp:base",
+            StatBonus {
+                value: 9.0, // +900%
+                mode: StatMode::Percent,
+            },
+        );
+        stats.add(
+            "damage:fire",
+            StatBonus {
+                value: 10.0, // x10, multiplier stage uncapped
+                mode: StatMode::Multiplicative,
+            },
+        );
+
+        assert_eq!(
+            stats.calculate_stat("hp", 10.0, &["hp:base".to_string()]),
+            100.0
+        );
+        assert_eq!(
+            calculate_damage(10.0, &["damage:fire".to_string()], &[], &stats),
+            100.0
+        );
+        assert!(stats.cap("hp").is_none());
+    }
+
+    #[test]
+    fn test_caps_clamp_final_value() {
+        let mut stats = BonusStats::default();
+        stats.add(
+            "damage:melee",
+            StatBonus {
+                value: 5.0, // +500%
+                mode: StatMode::Percent,
+            },
+        );
+        stats.add(
+            "hp:base",
+            StatBonus {
+                value: 5.0,
+                mode: StatMode::Percent,
+            },
+        );
+        let melee = vec!["damage:melee".to_string()];
+        // Uncapped: 10 * (1 + 5) = 60
+        assert_eq!(calculate_damage(10.0, &melee, &[], &stats), 60.0);
+
+        stats.set_cap("damage", 2.0, 40.0);
+        assert_eq!(calculate_damage(10.0, &melee, &[], &stats), 40.0);
+        // The floor lifts values below it
+        assert_eq!(calculate_damage(1.0, &[], &[], &stats), 2.0);
+
+        // Other categories are untouched
+        assert_eq!(
+            stats.calculate_stat("hp", 10.0, &["hp:base".to_string()]),
+            60.0
+        );
+    }
+
+    #[test]
+    fn test_min_max_cap_clamps_the_value_of_tagged_bonuses() {
+        let mut stats = BonusStats::default();
+        stats.add(
+            "damage:melee",
+            StatBonus {
+                value: 5.0, // +500%
+                mode: StatMode::Percent,
+            },
+        );
+        stats.set_cap("damage", 15.0, 40.0);
+        assert_eq!(stats.cap("damage"), Some(&StatCap::clamp(15.0, 40.0)));
+
+        // The cap limits the calculated value, not the aggregated bonus itself
+        let melee = vec!["damage:melee".to_string()];
+        assert_eq!(stats.total_for_tags("damage", &melee).percent, 5.0);
+        // 10 * (1 + 5) = 60 -> 40
+        assert_eq!(stats.calculate_stat("damage", 10.0, &melee), 40.0);
+        // 2 * (1 + 5) = 12 -> 15
+        assert_eq!(stats.calculate_stat("damage", 2.0, &melee), 15.0);
+        // 5 * (1 + 5) = 30 is within bounds
+        assert_eq!(stats.calculate_stat("damage", 5.0, &melee), 30.0);
+    }
 }
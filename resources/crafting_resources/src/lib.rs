@@ -44,6 +44,60 @@ pub struct ConstructedBuildings {
     pub ids: bevy::platform::collections::HashSet<String>,
 }
 
+/// Persisted crafting state - tracks how many times each recipe was crafted.
+/// Drives `max_repeats` limits and per-repeat cost/time scaling.
+#[derive(Resource, Default, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct CraftingState {
+    pub completion_counts: HashMap<String, u32>,
+}
+
+/// Crafts waiting for the running craft of the same recipe to finish.
+/// Their cost is paid when they are queued, so every entry keeps what it paid for refunds.
+#[derive(Resource, Default, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct CraftingQueue {
+    /// Paid cost of each queued craft, oldest first, keyed by recipe id
+    pub queued: HashMap<String, Vec<HashMap<String, u32>>>,
+}
+
+impl CraftingQueue {
+    /// Number of queued crafts of `recipe_id`.
+    pub fn count(&self, recipe_id: &str) -> u32 {
+        self.queued
+            .get(recipe_id)
+            .map_or(0, |crafts| crafts.len() as u32)
+    }
+
+    /// Queues one craft of `recipe_id` that already paid `paid`.
+    pub fn push(&mut self, recipe_id: &str, paid: HashMap<String, u32>) {
+        self.queued
+            .entry(recipe_id.to_string())
+            .or_default()
+            .push(paid);
+    }
+
+    /// Takes the oldest queued craft of `recipe_id`, returning what it paid.
+    pub fn pop(&mut self, recipe_id: &str) -> Option<HashMap<String, u32>> {
+        let crafts = self.queued.get_mut(recipe_id)?;
+        let paid = (!crafts.is_empty()).then(|| crafts.remove(0));
+        if crafts.is_empty() {
+            self.queued.remove(recipe_id);
+        }
+        paid
+    }
+
+    /// Removes every queued craft of `recipe_id`, returning what they paid.
+    pub fn cancel(&mut self, recipe_id: &str) -> Vec<HashMap<String, u32>> {
+        self.queued.remove(recipe_id).unwrap_or_default()
+    }
+
+    /// Removes every queued craft of every recipe, returning what they paid.
+    pub fn clear(&mut self) -> Vec<HashMap<String, u32>> {
+        self.queued.drain().flat_map(|(_, crafts)| crafts).collect()
+    }
+}
+
 // --- Plugin ---
 
 pub struct CraftingResourcesPlugin;
@@ -58,6 +112,10 @@ impl Plugin for CraftingResourcesPlugin {
         // New entity-based resources
         app.init_resource::<RecipeMap>()
             .register_type::<ConstructedBuildings>()
-            .init_resource::<ConstructedBuildings>();
+            .init_resource::<ConstructedBuildings>()
+            .register_type::<CraftingState>()
+            .init_resource::<CraftingState>()
+            .register_type::<CraftingQueue>()
+            .init_resource::<CraftingQueue>();
     }
 }
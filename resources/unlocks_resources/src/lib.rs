@@ -52,6 +52,40 @@ impl UnlockState {
     }
 }
 
+/// A single achieved unlock, as recorded in [`UnlockHistory`].
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+pub struct UnlockHistoryEntry {
+    pub unlock_id: String,
+    pub display_name: Option<String>,
+    pub reward_id: String,
+}
+
+/// Persistent, most-recent-last log of unlocks achieved during play.
+///
+/// Only the last [`UnlockHistory::MAX_ENTRIES`] unlocks are kept. Recording is left to the
+/// game so that unlocks re-fired while hydrating a save are not logged twice.
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+pub struct UnlockHistory {
+    pub entries: Vec<UnlockHistoryEntry>,
+}
+
+impl UnlockHistory {
+    pub const MAX_ENTRIES: usize = 20;
+
+    /// Appends an entry, dropping the oldest ones past [`Self::MAX_ENTRIES`].
+    pub fn record(&mut self, entry: UnlockHistoryEntry) {
+        self.entries.push(entry);
+        let overflow = self.entries.len().saturating_sub(Self::MAX_ENTRIES);
+        self.entries.drain(..overflow);
+    }
+
+    /// Returns up to `count` entries, newest first.
+    pub fn recent(&self, count: usize) -> impl Iterator<Item = &UnlockHistoryEntry> {
+        self.entries.iter().rev().take(count)
+    }
+}
+
 /// Persistent state tracking how many times each unlock has been triggered.
 ///
 /// **IMPORTANT**: This resource MUST be persisted in save files to support repeatable unlocks.
@@ -1,27 +1,72 @@
 //! Save/Load system for persisting game state.
 //!
 //! This crate provides:
-//! - F5 keyboard shortcut for manual saves
-//! - F9 keyboard shortcut to load the latest save
+//! - F5 keyboard shortcut to save into the quicksave slot
+//! - F9 keyboard shortcut to load the quicksave slot (F8 loads the autosave)
 //! - Automatic saves every 1 minute
-//! - DateTime-based save file naming
+//! - Fixed save slots (`SaveSlot`) that UI code can save to and load via `SaveGame`/`LoadGame`
+//! - Importing the newest legacy timestamped save into the quicksave slot
+//! - Metadata sidecars summarizing each save, listed with `list_saves`
+//! - Deleting saves with `delete_save` or the `DeleteSave` event
 //! - Scene-based serialization using Bevy's DynamicSceneBuilder
+//! - Offline progress credited on load, including in-progress research and crafting
+//! - Save format versioning, with migration of older saves on load
+//! - Export/import of a save as a base64 string (`export_save_to_string`, `import_save_from_string`)
+//! - Starting a new game from a running session with the `ResetGame` event
 
+mod meta;
+mod migration;
+mod offline_progress;
 mod reconstruction;
+mod reset;
+mod slots;
+mod transfer;
+
+pub use {
+    meta::{PlaySession, SaveMeta, SaveSummary, list_saves, list_saves_in},
+    migration::{CURRENT_SAVE_VERSION, MigrationError, SaveVersion, migrate, read_save_version},
+    offline_progress::{OfflineProgressSettings, SaveTimestamp, compute_offline_gains},
+    reset::{ResetGame, clear_session_state},
+    slots::SaveSlot,
+    transfer::{
+        SaveTransferError, decode_save_string, export_save_to_string, import_save_from_string,
+    },
+};
 
 use {
     bevy::prelude::*,
-    chrono::Local,
-    states::{GameState, LoadingPhase},
-    std::{fs, io::Write, path::Path},
-    unlocks_resources::UnlockProgress, // Ensure UnlockProgress is imported
+    notification_ui::NotificationQueue,
+    states::{GameState, LoadingPhase, PauseState},
+    std::{
+        fs,
+        io::{self, Write},
+        path::{Path, PathBuf},
+        time::SystemTime,
+    },
+    unlocks_resources::{UnlockHistory, UnlockProgress}, // Ensure UnlockProgress is imported
     wallet::Wallet,
 };
 
-/// Event to trigger loading the latest save file.
-#[derive(Event)]
+/// Directory, relative to the working directory, that holds all save files.
+pub const SAVES_DIR: &str = "saves";
+
+/// Event to trigger loading a save slot.
+#[derive(Event, Debug, Clone, Copy)]
 pub struct LoadGame {
-    is_autosave: bool,
+    pub slot: SaveSlot,
+}
+
+/// Event to trigger saving the current game into a slot, overwriting it.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SaveGame {
+    pub slot: SaveSlot,
+}
+
+/// Event to delete a save file (and its metadata sidecar) from the saves directory.
+#[derive(Event, Debug, Clone)]
+pub struct DeleteSave {
+    /// File name inside the saves directory, as in `SaveMeta::file_name`
+    pub file_name: String,
 }
 
 /// Timer resource for automatic saves.
@@ -40,7 +85,12 @@ pub struct SaveLoadPlugin;
 impl Plugin for SaveLoadPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<AutosaveTimer>()
-            // Save systems (only in Running state)
+            .init_resource::<OfflineProgressSettings>()
+            .init_resource::<PlaySession>()
+            .insert_resource(loading::FallbackSave(SaveSlot::Autosave.file_name()))
+            .register_type::<SaveTimestamp>()
+            .register_type::<SaveVersion>()
+            .add_systems(Startup, import_legacy_quicksave)
             // Save systems (only in Running state)
             .add_systems(
                 Update,
@@ -51,17 +101,27 @@ impl Plugin for SaveLoadPlugin {
                 execute_save.run_if(in_state(GameState::Running)),
             )
             .add_observer(execute_load)
+            .add_observer(on_save_game)
+            .add_observer(on_delete_save)
+            .add_observer(reset::on_reset_game)
             // Reconstruction phases - Unified Loading
             .add_systems(
                 OnEnter(LoadingPhase::PostLoadReconstruction),
                 (
+                    migration::migrate_loaded_save,
                     reconstruction::reconstruct_weapons_from_inventory,
                     reconstruction::relink_in_progress_research,
                     reconstruction::hydrate_research_unlocks,
                     reconstruction::hydrate_blessed_unlocks,
+                    offline_progress::apply_offline_progress,
+                    offline_progress::advance_offline_timers,
                 )
                     .chain(),
             )
+            .add_systems(
+                OnEnter(GameState::Running),
+                (offline_progress::show_offline_summary, show_loading_errors),
+            )
             .add_systems(
                 OnEnter(LoadingPhase::PostLoadReconstruction),
                 reconstruction::finish_reconstruction
@@ -71,28 +131,59 @@ impl Plugin for SaveLoadPlugin {
     }
 }
 
-/// Exclusive system that handles manual and automatic saves.
+/// Most loading problems listed in one notification; the rest are only counted.
+const MAX_LISTED_LOADING_ERRORS: usize = 3;
+
+/// Repeats the loading screen's `LoadingErrors` as a notification once gameplay starts,
+/// since the loading screen is gone before the player can read it.
+fn show_loading_errors(errors: Res<loading::LoadingErrors>, mut queue: ResMut<NotificationQueue>) {
+    if let Some(message) = loading_errors_message(&errors.errors) {
+        queue.push_info("Loading Problems", message);
+    }
+}
+
+/// Player-facing text for the loading `errors`, or None if there were none.
+fn loading_errors_message(errors: &[String]) -> Option<String> {
+    if errors.is_empty() {
+        return None;
+    }
+    let mut message = errors[..errors.len().min(MAX_LISTED_LOADING_ERRORS)].join("\n");
+    if errors.len() > MAX_LISTED_LOADING_ERRORS {
+        message.push_str(&format!(
+            "\n...and {} more",
+            errors.len() - MAX_LISTED_LOADING_ERRORS
+        ));
+    }
+    Some(message)
+}
+
+/// Exclusive system that handles quicksaves and automatic saves.
 fn execute_save(world: &mut World) {
-    let mut is_autosave = false;
+    let mut slot = SaveSlot::Quicksave;
     let mut manual_triggered = false;
 
-    // 1. Check Manual Save (F5)
+    // 1. Check Quicksave (F5)
     if let Some(keyboard) = world.get_resource::<ButtonInput<KeyCode>>()
         && keyboard.just_pressed(KeyCode::F5)
     {
-        info!("Manual save triggered (F5)");
+        info!("Quicksave triggered (F5)");
         manual_triggered = true;
-        is_autosave = false;
     }
 
-    // 2. Check Autosave Timer
+    // 2. Check Autosave Timer, which stands still while paused (quicksaves still work)
     if !manual_triggered {
+        let paused = world
+            .get_resource::<State<PauseState>>()
+            .is_some_and(|state| *state.get() == PauseState::Paused);
+        if paused {
+            return;
+        }
         let delta = world.get_resource::<Time>().map(|t| t.delta());
         if let Some(delta) = delta {
             if let Some(mut timer) = world.get_resource_mut::<AutosaveTimer>() {
                 if timer.0.tick(delta).just_finished() {
                     info!("Autosave triggered");
-                    is_autosave = true;
+                    slot = SaveSlot::Autosave;
                 } else {
                     return; // No save triggered
                 }
@@ -110,21 +201,61 @@ fn execute_save(world: &mut World) {
     }
 
     // 3. Process Save
-    let filename = if is_autosave {
-        "autosave.scn.ron".to_string()
+    let _ = save_to_slot(world, slot);
+}
+
+/// Observer that saves into the slot requested by UI code.
+fn on_save_game(trigger: On<SaveGame>, mut commands: Commands) {
+    let slot = trigger.event().slot;
+    commands.queue(move |world: &mut World| {
+        let _ = save_to_slot(world, slot);
+    });
+}
+
+/// Writes the current game state into `slot`, overwriting it, and notifies the player.
+pub fn save_to_slot(world: &mut World, slot: SaveSlot) -> Result<(), String> {
+    let result = if slot.is_valid() {
+        write_save(world, &slot.file_name())
     } else {
-        let timestamp = Local::now().format("%Y-%m-%d_%H-%M-%S");
-        format!("save_{}.scn.ron", timestamp)
+        warn!("Refusing to save into unknown slot {}", slot.id());
+        Err(format!("There is no save slot {}", slot.label()))
     };
 
-    let saves_dir = Path::new("saves");
-    let filepath = saves_dir.join(&filename);
+    if let Some(mut queue) = world.get_resource_mut::<NotificationQueue>() {
+        match &result {
+            Ok(()) if slot == SaveSlot::Autosave => queue.push_info("Autosaved", slot.label()),
+            Ok(()) => queue.push_info("Game Saved", slot.label()),
+            Err(e) => queue.push_info("Save Failed", e.clone()),
+        }
+    }
+    result
+}
+
+/// Serializes the current game state into `saves/<filename>`.
+/// Failures are logged and returned as a player-facing message.
+fn write_save(world: &mut World, filename: &str) -> Result<(), String> {
+    let saves_dir = Path::new(SAVES_DIR);
+    let filepath = saves_dir.join(filename);
 
     if let Err(e) = fs::create_dir_all(saves_dir) {
         error!("Failed to create saves directory: {}", e);
-        return;
+        return Err(format!("Could not create saves directory: {}", e));
     }
 
+    let serialized = serialize_save(world)?;
+    write_save_file(&filepath, &serialized)?;
+    // The save itself succeeded, a missing sidecar only hides its summary
+    if let Err(e) = meta::write_meta(saves_dir, filename, &meta::summarize(world)) {
+        warn!("{}", e);
+    }
+    Ok(())
+}
+
+/// Serializes the current game state into the RON save scene format.
+fn serialize_save(world: &mut World) -> Result<String, String> {
+    world.insert_resource(SaveTimestamp::now());
+    world.insert_resource(SaveVersion::current());
+
     // Collect saveable entities
     let mut query = world.query_filtered::<Entity, (
         With<shared_components::IncludeInSave>,
@@ -137,43 +268,49 @@ fn execute_save(world: &mut World) {
     let type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = type_registry.read();
 
-    let serialized = match scene.serialize(&type_registry) {
-        Ok(data) => data,
-        Err(e) => {
-            error!("Failed to serialize save scene: {}", e);
-            return;
-        }
-    };
+    scene.serialize(&type_registry).map_err(|e| {
+        error!("Failed to serialize save scene: {}", e);
+        format!("Could not serialize game state: {}", e)
+    })
+}
 
+/// Writes serialized save `contents` to `filepath`, replacing any previous file.
+fn write_save_file(filepath: &Path, contents: &str) -> Result<(), String> {
     match fs::File::options()
         .write(true)
         .truncate(true)
         .create(true)
-        .open(&filepath)
+        .open(filepath)
     {
         Ok(mut file) => {
-            if let Err(e) = file.write_all(serialized.as_bytes()) {
+            if let Err(e) = file.write_all(contents.as_bytes()) {
                 error!("Failed to write save file: {}", e);
-                return;
+                return Err(format!("Could not write save file: {}", e));
             }
             info!("Game saved to {}", filepath.display());
+            Ok(())
         }
         Err(e) => {
             error!("Failed to create save file: {}", e);
+            Err(format!("Could not create save file: {}", e))
         }
     }
 }
 
-/// Triggers a load when F9 is pressed.
+/// Loads the quicksave when F9 is pressed and the autosave on F8.
 fn trigger_load_on_keypress(keyboard: Res<ButtonInput<KeyCode>>, mut commands: Commands) {
     if keyboard.just_pressed(KeyCode::F9) {
         info!("Load triggered (F9)");
-        commands.trigger(LoadGame { is_autosave: false });
+        commands.trigger(LoadGame {
+            slot: SaveSlot::Quicksave,
+        });
     }
 
     if keyboard.just_pressed(KeyCode::F8) {
         info!("Load triggered (F8)");
-        commands.trigger(LoadGame { is_autosave: true });
+        commands.trigger(LoadGame {
+            slot: SaveSlot::Autosave,
+        });
     }
 }
 
@@ -183,31 +320,40 @@ fn execute_load(
     _commands: Commands,
     mut scene_to_load: ResMut<loading::SceneToLoad>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut queue: ResMut<NotificationQueue>,
 ) {
-    let LoadGame { is_autosave } = trigger.event();
-    let saves_dir = Path::new("saves");
-
-    // Find the latest save file
+    let LoadGame { slot } = *trigger.event();
+    if !slot.is_valid() {
+        warn!("Refusing to load unknown slot {}", slot.id());
+        queue.push_info(
+            "Load Failed",
+            format!("There is no save slot {}", slot.label()),
+        );
+        return;
+    }
+    let save_path = Path::new(SAVES_DIR).join(slot.file_name());
+    if !save_path.exists() {
+        warn!("No save file for slot {}", slot.id());
+        return;
+    }
 
-    let latest_save = if *is_autosave {
-        Path::new("saves/autosave.scn.ron").into()
-    } else {
-        match find_latest_save(saves_dir) {
-            Some(path) => path,
-            None => {
-                warn!("No save files found in saves directory");
-                return;
-            }
-        }
-    };
+    // Refuse saves from newer builds up front; unreadable files are reported by the loader
+    if let Ok(contents) = fs::read_to_string(&save_path)
+        && let Ok(version) = read_save_version(&contents)
+        && let Err(e) = migration::check_supported(version)
+    {
+        warn!("Refusing to load {}: {}", save_path.display(), e);
+        queue.push_info("Load Failed", e.to_string());
+        return;
+    }
 
-    info!("Loading save file: {}", latest_save.display());
+    info!("Loading save file: {}", save_path.display());
 
     // Despawn/Cleanup is now handled by OnExit(GameState::Running) systems in each plugin.
     info!("Manual cleanup delegated to OnExit(GameState::Running) systems");
 
     // Configure loading state
-    let relative_path = latest_save.strip_prefix("saves").unwrap_or(&latest_save);
+    let relative_path = save_path.strip_prefix(SAVES_DIR).unwrap_or(&save_path);
     scene_to_load.path = relative_path.to_string_lossy().to_string();
     scene_to_load.is_save = true;
 
@@ -216,28 +362,124 @@ fn execute_load(
     next_state.set(GameState::Loading);
 }
 
-/// Finds the most recent save file in the saves directory.
-fn find_latest_save(saves_dir: &Path) -> Option<std::path::PathBuf> {
-    let entries = fs::read_dir(saves_dir).ok()?;
+/// Removes the save at `path` together with its `.meta.ron` sidecar, if any.
+pub fn delete_save(path: &Path) -> io::Result<()> {
+    fs::remove_file(path)?;
 
-    entries
+    let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+        return Ok(());
+    };
+    match fs::remove_file(path.with_file_name(meta::meta_file_name(file_name))) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+/// True if `file_name` names a file directly inside the saves directory, so a request
+/// can't reach outside of it (no separators, no `..`).
+fn is_plain_file_name(file_name: &str) -> bool {
+    !file_name.is_empty()
+        && !file_name.contains(['/', '\\'])
+        && Path::new(file_name).file_name() == Some(file_name.as_ref())
+}
+
+/// False while `file_name` is the save being loaded, it must not disappear mid-load.
+fn can_delete(file_name: &str, scene_to_load: &loading::SceneToLoad, state: &GameState) -> bool {
+    let loading_it =
+        *state == GameState::Loading && scene_to_load.is_save && scene_to_load.path == file_name;
+    !loading_it
+}
+
+/// Observer that deletes the save requested by UI code and reports the outcome.
+fn on_delete_save(
+    trigger: On<DeleteSave>,
+    scene_to_load: Res<loading::SceneToLoad>,
+    state: Res<State<GameState>>,
+    mut queue: ResMut<NotificationQueue>,
+) {
+    let file_name = &trigger.event().file_name;
+    if !is_plain_file_name(file_name) {
+        warn!(
+            "Refusing to delete {:?}, it is not a save file name",
+            file_name
+        );
+        queue.push_info("Delete Failed", "Invalid save file name");
+        return;
+    }
+    if !can_delete(file_name, &scene_to_load, state.get()) {
+        warn!("Refusing to delete {} while it is being loaded", file_name);
+        queue.push_info("Delete Failed", "That save is currently loading");
+        return;
+    }
+
+    match delete_save(&Path::new(SAVES_DIR).join(file_name)) {
+        Ok(()) => {
+            info!("Deleted save {}", file_name);
+            queue.push_info("Save Deleted", file_name.clone());
+        }
+        Err(e) => {
+            error!("Failed to delete save {}: {}", file_name, e);
+            queue.push_info("Delete Failed", format!("Could not delete save: {}", e));
+        }
+    }
+}
+
+/// Legacy timestamped `save_*.scn.ron` files in the saves directory, newest first.
+fn legacy_saves(saves_dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(saves_dir) else {
+        return Vec::new();
+    };
+
+    let mut saves: Vec<(Option<SystemTime>, PathBuf)> = entries
         .filter_map(|e| e.ok())
-        .filter(|e| {
-            e.path()
-                .extension()
-                .and_then(|ext| ext.to_str())
-                .map(|ext| ext == "ron")
-                .unwrap_or(false)
-        })
         .filter(|e| {
             e.path()
                 .file_name()
                 .and_then(|name| name.to_str())
-                .map(|name| name.starts_with("save_"))
+                .map(|name| name.starts_with("save_") && name.ends_with(SaveSlot::EXTENSION))
                 .unwrap_or(false)
         })
-        .max_by_key(|e| e.metadata().and_then(|m| m.modified()).ok())
-        .map(|e| e.path())
+        .map(|e| (e.metadata().and_then(|m| m.modified()).ok(), e.path()))
+        .collect();
+    saves.sort_by(|a, b| b.cmp(a));
+    saves.into_iter().map(|(_, path)| path).collect()
+}
+
+/// Finds the most recent legacy timestamped save file in the saves directory.
+fn find_latest_save(saves_dir: &Path) -> Option<PathBuf> {
+    legacy_saves(saves_dir).into_iter().next()
+}
+
+/// Copies the newest legacy timestamped save, and its sidecar if any, into the empty
+/// quicksave slot. Legacy files are left in place and stay listed by `list_saves`.
+/// Returns the imported save, or `None` if there was nothing to import.
+pub fn import_legacy_saves(saves_dir: &Path) -> io::Result<Option<PathBuf>> {
+    let quicksave = SaveSlot::Quicksave.file_name();
+    if saves_dir.join(&quicksave).exists() {
+        return Ok(None);
+    }
+    let Some(legacy) = find_latest_save(saves_dir) else {
+        return Ok(None);
+    };
+
+    fs::copy(&legacy, saves_dir.join(&quicksave))?;
+    if let Some(file_name) = legacy.file_name().and_then(|name| name.to_str()) {
+        let sidecar = saves_dir.join(meta::meta_file_name(file_name));
+        if sidecar.exists() {
+            fs::copy(sidecar, saves_dir.join(meta::meta_file_name(&quicksave)))?;
+        }
+    }
+    Ok(Some(legacy))
+}
+
+/// Older builds wrote quicksaves and autosaves as timestamped files; make the newest
+/// of them loadable from the quicksave slot.
+fn import_legacy_quicksave() {
+    match import_legacy_saves(Path::new(SAVES_DIR)) {
+        Ok(Some(legacy)) => info!("Imported {} as the quicksave", legacy.display()),
+        Ok(None) => {}
+        Err(e) => warn!("Failed to import legacy saves: {}", e),
+    }
 }
 
 /// Builds a DynamicScene containing saveable components and resources.
@@ -257,9 +499,15 @@ fn build_save_scene(world: &World, saveable_entities: Vec<Entity>) -> DynamicSce
         // === Resources ===
         .allow_resource::<Wallet>()
         .allow_resource::<research::ResearchState>()
+        .allow_resource::<research::ResearchQueue>()
         .allow_resource::<village_resources::DivinityUnlockState>()
         .allow_resource::<crafting_resources::ConstructedBuildings>()
+        .allow_resource::<crafting_resources::CraftingState>()
+        .allow_resource::<crafting_resources::CraftingQueue>()
         .allow_resource::<UnlockProgress>()
+        .allow_resource::<UnlockHistory>()
+        .allow_resource::<SaveTimestamp>()
+        .allow_resource::<SaveVersion>()
         // === Entity extraction ===
         // Only include entities marked with IncludeInSave
         .extract_entities(saveable_entities.into_iter())
@@ -267,7 +515,158 @@ fn build_save_scene(world: &World, saveable_entities: Vec<Entity>) -> DynamicSce
         .build()
 }
 
-pub fn clean_up_save_load(mut timer: ResMut<AutosaveTimer>) {
+pub fn clean_up_save_load(mut commands: Commands, mut timer: ResMut<AutosaveTimer>) {
     // Reset timer to default (1 minute)
     *timer = AutosaveTimer::default();
+    // Drop the previous session's timestamp so it can't leak into the next load
+    commands.remove_resource::<SaveTimestamp>();
+    // Same for the version, a save without one must read as version 0
+    commands.remove_resource::<SaveVersion>();
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        hero_components::{Hero, HeroName},
+    };
+
+    #[test]
+    fn test_delete_save_removes_scene_and_sidecar() {
+        let dir = std::env::temp_dir().join(format!("delete_save_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for file in ["slot1.scn.ron", "slot1.meta.ron", "slot2.scn.ron"] {
+            fs::write(dir.join(file), "()").unwrap();
+        }
+
+        delete_save(&dir.join("slot1.scn.ron")).unwrap();
+        // A save without a sidecar is deleted too
+        delete_save(&dir.join("slot2.scn.ron")).unwrap();
+        let missing = delete_save(&dir.join("slot3.scn.ron"));
+
+        let left = fs::read_dir(&dir).unwrap().count();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(left, 0);
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn test_newest_legacy_save_is_imported_as_quicksave() {
+        let dir = std::env::temp_dir().join(format!("import_saves_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let base = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        for (i, file) in ["save_a.scn.ron", "save_b.scn.ron", "save_c.scn.ron"]
+            .into_iter()
+            .enumerate()
+        {
+            fs::write(dir.join(file), file).unwrap();
+            let save = fs::File::options()
+                .write(true)
+                .open(dir.join(file))
+                .unwrap();
+            save.set_modified(base + std::time::Duration::from_secs(i as u64 * 60))
+                .unwrap();
+        }
+        fs::write(dir.join("save_c.meta.ron"), "()").unwrap();
+
+        let imported = import_legacy_saves(&dir).unwrap();
+        // An existing quicksave is never overwritten
+        fs::write(dir.join("save_d.scn.ron"), "save_d.scn.ron").unwrap();
+        let again = import_legacy_saves(&dir).unwrap();
+
+        let quicksave = fs::read_to_string(dir.join("quicksave.scn.ron")).unwrap();
+        let has_sidecar = dir.join("quicksave.meta.ron").exists();
+        let legacy_left = legacy_saves(&dir).len();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(imported, Some(dir.join("save_c.scn.ron")));
+        assert_eq!(again, None);
+        assert_eq!(quicksave, "save_c.scn.ron");
+        assert!(has_sidecar);
+        assert_eq!(legacy_left, 4);
+    }
+
+    #[test]
+    fn test_save_being_loaded_cannot_be_deleted() {
+        let loading_slot1 = loading::SceneToLoad {
+            path: "slot1.scn.ron".to_string(),
+            is_save: true,
+        };
+
+        assert!(!can_delete(
+            "slot1.scn.ron",
+            &loading_slot1,
+            &GameState::Loading
+        ));
+        assert!(can_delete(
+            "slot2.scn.ron",
+            &loading_slot1,
+            &GameState::Loading
+        ));
+        // Once loaded, the save is no longer in use
+        assert!(can_delete(
+            "slot1.scn.ron",
+            &loading_slot1,
+            &GameState::Running
+        ));
+    }
+
+    #[test]
+    fn test_only_plain_file_names_can_be_deleted() {
+        assert!(is_plain_file_name("slot1.scn.ron"));
+        assert!(is_plain_file_name("save_2024.scn.ron"));
+
+        assert!(!is_plain_file_name(""));
+        assert!(!is_plain_file_name(".."));
+        assert!(!is_plain_file_name("."));
+        assert!(!is_plain_file_name("../Cargo.toml"));
+        assert!(!is_plain_file_name("sub/slot1.scn.ron"));
+        assert!(!is_plain_file_name("..\\slot1.scn.ron"));
+        assert!(!is_plain_file_name("/etc/passwd"));
+    }
+
+    #[test]
+    fn test_saving_into_unknown_manual_slot_fails() {
+        let mut world = World::new();
+        let result = save_to_slot(&mut world, SaveSlot::Manual(SaveSlot::MANUAL_SLOTS + 1));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_save_scene_keeps_hero_name() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let registry = world.resource::<AppTypeRegistry>();
+            let mut registry = registry.write();
+            registry.register::<Hero>();
+            registry.register::<HeroName>();
+        }
+        let hero = world.spawn((Hero, HeroName("Brenna".to_string()))).id();
+
+        let scene = build_save_scene(&world, vec![hero]);
+
+        let names: Vec<&HeroName> = scene.entities[0]
+            .components
+            .iter()
+            .filter_map(|component| component.try_downcast_ref::<HeroName>())
+            .collect();
+        assert_eq!(names, vec![&HeroName("Brenna".to_string())]);
+    }
+
+    #[test]
+    fn test_loading_errors_message_lists_the_first_few() {
+        assert_eq!(loading_errors_message(&[]), None);
+
+        let errors: Vec<String> = (1..=5).map(|n| format!("error {}", n)).collect();
+        assert_eq!(
+            loading_errors_message(&errors[..1]).as_deref(),
+            Some("error 1")
+        );
+        assert_eq!(
+            loading_errors_message(&errors).as_deref(),
+            Some("error 1\nerror 2\nerror 3\n...and 2 more")
+        );
+    }
 }
@@ -0,0 +1,198 @@
+//! Small sidecar files describing each save.
+//!
+//! Next to every `<slot>.scn.ron` a `<slot>.meta.ron` is written with a summary of
+//! the save, so a load menu can list saves without deserializing the whole scene.
+
+use {
+    crate::{SAVES_DIR, SaveSlot, SaveTimestamp},
+    bevy::prelude::*,
+    divinity_components::Divinity,
+    serde::{Deserialize, Serialize},
+    std::{fs, path::Path},
+    village_components::Village,
+    wallet::Wallet,
+};
+
+/// Extension of the metadata sidecar, replacing `SaveSlot::EXTENSION`
+const META_EXTENSION: &str = ".meta.ron";
+
+/// Identifies the run of the game that wrote a save: the unix time it was started.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlaySession(pub i64);
+
+impl Default for PlaySession {
+    fn default() -> Self {
+        Self(SaveTimestamp::now().0)
+    }
+}
+
+/// Human-readable summary stored in a save's sidecar.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SaveSummary {
+    /// Unix timestamp (seconds) of when the save was written
+    pub saved_at: i64,
+    /// Sum of all resource amounts in the wallet
+    pub total_resources: u64,
+    pub divinity: Divinity,
+    /// `PlaySession` that wrote the save
+    pub session: i64,
+}
+
+/// One save file found by `list_saves`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaveMeta {
+    pub file_name: String,
+    /// `None` for files that don't belong to a slot, e.g. legacy timestamped saves
+    pub slot: Option<SaveSlot>,
+    /// `None` when the sidecar is missing or can't be read
+    pub summary: Option<SaveSummary>,
+}
+
+/// Builds the summary of the current game state.
+pub fn summarize(world: &mut World) -> SaveSummary {
+    let total_resources = world.get_resource::<Wallet>().map_or(0, |wallet| {
+        wallet.resources.values().map(|&v| v as u64).sum()
+    });
+    let divinity = world
+        .query_filtered::<&Divinity, With<Village>>()
+        .iter(world)
+        .next()
+        .copied()
+        .unwrap_or_default();
+
+    SaveSummary {
+        saved_at: world
+            .get_resource::<SaveTimestamp>()
+            .copied()
+            .unwrap_or_else(SaveTimestamp::now)
+            .0,
+        total_resources,
+        divinity,
+        session: world
+            .get_resource::<PlaySession>()
+            .copied()
+            .unwrap_or_default()
+            .0,
+    }
+}
+
+/// Name of the sidecar belonging to `save_file_name`, e.g. "slot1.meta.ron".
+pub fn meta_file_name(save_file_name: &str) -> String {
+    let stem = save_file_name
+        .strip_suffix(SaveSlot::EXTENSION)
+        .unwrap_or(save_file_name);
+    format!("{}{}", stem, META_EXTENSION)
+}
+
+/// Writes the sidecar for `save_file_name` into `dir`.
+pub fn write_meta(dir: &Path, save_file_name: &str, summary: &SaveSummary) -> Result<(), String> {
+    let serialized = ron::ser::to_string_pretty(summary, ron::ser::PrettyConfig::default())
+        .map_err(|e| format!("Could not serialize save metadata: {}", e))?;
+    fs::write(dir.join(meta_file_name(save_file_name)), serialized)
+        .map_err(|e| format!("Could not write save metadata: {}", e))
+}
+
+fn read_meta(dir: &Path, save_file_name: &str) -> Option<SaveSummary> {
+    let path = dir.join(meta_file_name(save_file_name));
+    let contents = fs::read_to_string(&path).ok()?;
+    match ron::from_str(&contents) {
+        Ok(summary) => Some(summary),
+        Err(e) => {
+            warn!(
+                "Ignoring unreadable save metadata {}: {}",
+                path.display(),
+                e
+            );
+            None
+        }
+    }
+}
+
+/// Lists the saves in the saves directory with their summaries.
+pub fn list_saves() -> Vec<SaveMeta> {
+    list_saves_in(Path::new(SAVES_DIR))
+}
+
+/// Lists the `.scn.ron` saves in `dir`: slots in `SaveSlot::all` order first, then other
+/// files by name. Saves without a readable sidecar are listed with no summary.
+pub fn list_saves_in(dir: &Path) -> Vec<SaveMeta> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut saves: Vec<SaveMeta> = entries
+        .filter_map(|e| e.ok())
+        .filter_map(|e| e.file_name().into_string().ok())
+        .filter_map(|file_name| {
+            let id = file_name.strip_suffix(SaveSlot::EXTENSION)?;
+            Some(SaveMeta {
+                slot: SaveSlot::from_id(id),
+                summary: read_meta(dir, &file_name),
+                file_name,
+            })
+        })
+        .collect();
+
+    let slots = SaveSlot::all();
+    saves.sort_by_key(|save| {
+        let slot_rank = save
+            .slot
+            .and_then(|slot| slots.iter().position(|s| *s == slot))
+            .unwrap_or(slots.len());
+        (slot_rank, save.file_name.clone())
+    });
+    saves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_saves_falls_back_to_file_name_without_sidecar() {
+        let dir = std::env::temp_dir().join(format!("save_meta_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let summary = SaveSummary {
+            saved_at: 1_700_000_000,
+            total_resources: 42,
+            divinity: Divinity::new(2, 5),
+            session: 1_699_999_000,
+        };
+        for file in [
+            "slot2.scn.ron",
+            "quicksave.scn.ron",
+            "save_2024-01-01.scn.ron",
+        ] {
+            fs::write(dir.join(file), "()").unwrap();
+        }
+        write_meta(&dir, "slot2.scn.ron", &summary).unwrap();
+        fs::write(dir.join("quicksave.meta.ron"), "not ron {").unwrap();
+
+        let saves = list_saves_in(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            saves,
+            vec![
+                // Corrupt sidecar
+                SaveMeta {
+                    file_name: "quicksave.scn.ron".to_string(),
+                    slot: Some(SaveSlot::Quicksave),
+                    summary: None,
+                },
+                SaveMeta {
+                    file_name: "slot2.scn.ron".to_string(),
+                    slot: Some(SaveSlot::Manual(2)),
+                    summary: Some(summary),
+                },
+                // Missing sidecar
+                SaveMeta {
+                    file_name: "save_2024-01-01.scn.ron".to_string(),
+                    slot: None,
+                    summary: None,
+                },
+            ]
+        );
+    }
+}
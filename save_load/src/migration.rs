@@ -0,0 +1,171 @@
+//! Save format versioning.
+//!
+//! Every save carries a `SaveVersion` resource. Saves written before versioning was
+//! added have none and count as version 0. On load, `migrate` walks the loaded world
+//! forward one version at a time until it matches `CURRENT_SAVE_VERSION`; saves from a
+//! newer build are refused before loading starts, with a message instead of a
+//! deserialize failure halfway through.
+//!
+//! When a saved component or resource changes shape, bump `CURRENT_SAVE_VERSION` and
+//! add the step that upgrades the previous version to `migrate_step`.
+
+use {
+    bevy::{platform::collections::HashMap, prelude::*, reflect::TypePath},
+    serde::Deserialize,
+    std::fmt,
+};
+
+/// Version written into new saves.
+pub const CURRENT_SAVE_VERSION: u32 = 1;
+
+/// Save format version of the loaded (or about to be written) save.
+#[derive(Resource, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource, Default)]
+pub struct SaveVersion(pub u32);
+
+impl SaveVersion {
+    pub fn current() -> Self {
+        Self(CURRENT_SAVE_VERSION)
+    }
+}
+
+/// Why a save could not be brought up to the current version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MigrationError {
+    /// The save was written by a newer build than this one
+    TooNew { found: u32 },
+    /// The save file could not be read far enough to find its version
+    Unreadable(String),
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooNew { found } => write!(
+                f,
+                "Save version {} is newer than this game supports (up to {})",
+                found, CURRENT_SAVE_VERSION
+            ),
+            Self::Unreadable(reason) => write!(f, "Could not read save version: {}", reason),
+        }
+    }
+}
+
+/// Upgrades a world loaded from a save of `version` to `CURRENT_SAVE_VERSION`.
+pub fn migrate(version: u32, world: &mut World) -> Result<(), MigrationError> {
+    check_supported(version)?;
+    for from in version..CURRENT_SAVE_VERSION {
+        info!("Migrating save from version {} to {}", from, from + 1);
+        migrate_step(from, world);
+    }
+    world.insert_resource(SaveVersion::current());
+    Ok(())
+}
+
+/// Refuses versions written by a newer build.
+pub fn check_supported(version: u32) -> Result<(), MigrationError> {
+    if version > CURRENT_SAVE_VERSION {
+        return Err(MigrationError::TooNew { found: version });
+    }
+    Ok(())
+}
+
+/// Upgrades the world from `from` to `from + 1`.
+fn migrate_step(from: u32, _world: &mut World) {
+    match from {
+        // Version 1 only added the `SaveVersion` marker, the data is unchanged
+        0 => {}
+        _ => unreachable!("no migration from save version {}", from),
+    }
+}
+
+/// Only the resources of a save; entities are skipped without deserializing them.
+#[derive(Deserialize)]
+struct SaveHeader {
+    #[serde(default)]
+    resources: HashMap<String, ron::Value>,
+}
+
+/// Reads the `SaveVersion` of serialized save `contents` without a type registry,
+/// so a save can be checked before any of it is loaded. Missing means version 0.
+pub fn read_save_version(contents: &str) -> Result<u32, MigrationError> {
+    let header: SaveHeader =
+        ron::from_str(contents).map_err(|e| MigrationError::Unreadable(e.to_string()))?;
+    let Some(value) = header.resources.get(SaveVersion::type_path()) else {
+        return Ok(0);
+    };
+    value
+        .clone()
+        .into_rust::<(u32,)>()
+        .map(|(version,)| version)
+        .map_err(|e| MigrationError::Unreadable(e.to_string()))
+}
+
+/// Reads the loaded `SaveVersion` and migrates the world, right after the save spawned.
+/// New games load the startup scene, which is always current.
+pub fn migrate_loaded_save(world: &mut World) {
+    if !world.resource::<loading::SceneToLoad>().is_save {
+        world.insert_resource(SaveVersion::current());
+        return;
+    }
+
+    let version = world.get_resource::<SaveVersion>().map_or(0, |v| v.0);
+    if let Err(e) = migrate(version, world) {
+        // Too-new saves are refused before loading, so this only logs what slipped through
+        error!("{}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn serialize_with(version: Option<SaveVersion>) -> String {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<SaveVersion>();
+        if let Some(version) = version {
+            world.insert_resource(version);
+        }
+
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .allow_resource::<SaveVersion>()
+            .extract_resources()
+            .build();
+        let registry = world.resource::<AppTypeRegistry>().read();
+        scene.serialize(&registry).unwrap()
+    }
+
+    #[test]
+    fn test_read_save_version_from_scene() {
+        assert_eq!(
+            read_save_version(&serialize_with(Some(SaveVersion(7)))),
+            Ok(7)
+        );
+        // Saves from before versioning have no marker
+        assert_eq!(read_save_version(&serialize_with(None)), Ok(0));
+        assert!(matches!(
+            read_save_version("not a save"),
+            Err(MigrationError::Unreadable(_))
+        ));
+    }
+
+    #[test]
+    fn test_migrate_upgrades_old_and_refuses_newer() {
+        let mut world = World::new();
+        assert_eq!(migrate(0, &mut world), Ok(()));
+        assert_eq!(
+            world.get_resource::<SaveVersion>(),
+            Some(&SaveVersion::current())
+        );
+
+        let newer = CURRENT_SAVE_VERSION + 1;
+        assert_eq!(
+            migrate(newer, &mut World::new()),
+            Err(MigrationError::TooNew { found: newer })
+        );
+    }
+}
@@ -0,0 +1,289 @@
+//! Offline progress granted when a save is loaded.
+//!
+//! Saves record a `SaveTimestamp`. On load, the wall-clock time since that
+//! timestamp (capped by `OfflineProgressSettings`) is multiplied by the passive
+//! generation rates of the loaded world and credited to the `Wallet`, and the
+//! same time is added to in-progress research and crafting timers.
+
+use {
+    bevy::{platform::collections::HashMap, prelude::*},
+    buildings_components::EntropyGenerator,
+    chrono::Local,
+    crafting::{CraftingInProgress, RecipeLibrary},
+    notification_ui::NotificationQueue,
+    research::{InProgress, ResearchLibrary},
+    std::time::Duration,
+    unlocks_events::{Topic, ValueChanged},
+    wallet::Wallet,
+};
+
+/// Default cap on offline progress (8 hours).
+const DEFAULT_MAX_OFFLINE_SECS: f32 = 8.0 * 60.0 * 60.0;
+
+/// Configures whether and how much offline progress is granted on load.
+#[derive(Resource, Debug, Clone)]
+pub struct OfflineProgressSettings {
+    pub enabled: bool,
+    /// Maximum number of offline seconds that are credited.
+    pub max_offline_secs: f32,
+}
+
+impl Default for OfflineProgressSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_offline_secs: DEFAULT_MAX_OFFLINE_SECS,
+        }
+    }
+}
+
+/// Unix timestamp (seconds) of when the save was written.
+#[derive(Resource, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Resource, Default)]
+pub struct SaveTimestamp(pub i64);
+
+impl SaveTimestamp {
+    pub fn now() -> Self {
+        Self(Local::now().timestamp())
+    }
+}
+
+/// What happened while the game was closed, shown once gameplay resumes.
+#[derive(Resource, Default, Debug)]
+pub struct PendingOfflineSummary {
+    /// Offline seconds credited, after the cap
+    pub credited_secs: f32,
+    pub gains: Vec<(String, u32)>,
+    /// Display names of research and crafts whose timers ran out while away
+    pub completed: Vec<String>,
+}
+
+/// Clamps `elapsed_secs` to the `0..=max_secs` window that is credited.
+fn credited_secs(elapsed_secs: f32, max_secs: f32) -> f32 {
+    elapsed_secs.clamp(0.0, max_secs.max(0.0))
+}
+
+/// Computes resources gained over `elapsed_secs` (capped at `max_secs`) for
+/// per-second `rates`. Partial units are dropped and zero gains are omitted.
+pub fn compute_offline_gains(
+    rates: &HashMap<String, f32>,
+    elapsed_secs: f32,
+    max_secs: f32,
+) -> Vec<(String, u32)> {
+    let credited_secs = credited_secs(elapsed_secs, max_secs);
+
+    let mut gains: Vec<(String, u32)> = rates
+        .iter()
+        .map(|(id, rate)| (id.clone(), (rate * credited_secs).floor() as u32))
+        .filter(|(_, amount)| *amount > 0)
+        .collect();
+    gains.sort_by(|a, b| a.0.cmp(&b.0));
+    gains
+}
+
+/// Sums per-second rates of all passive generators in the loaded world.
+/// Shares its math with the live `NetResourceRates` so load and play agree.
+fn passive_rates(generators: &Query<&EntropyGenerator>) -> HashMap<String, f32> {
+    buildings_components::passive_rates(generators.iter())
+        .into_iter()
+        .collect()
+}
+
+/// Credits offline gains to the wallet based on the loaded `SaveTimestamp`.
+pub fn apply_offline_progress(
+    mut commands: Commands,
+    settings: Res<OfflineProgressSettings>,
+    saved_at: Option<Res<SaveTimestamp>>,
+    generators: Query<&EntropyGenerator>,
+    mut wallet: ResMut<Wallet>,
+) {
+    let Some(saved_at) = saved_at else {
+        debug!("No save timestamp found, skipping offline progress");
+        return;
+    };
+
+    if !settings.enabled {
+        return;
+    }
+
+    let elapsed_secs = (Local::now().timestamp() - saved_at.0) as f32;
+    let gains = compute_offline_gains(
+        &passive_rates(&generators),
+        elapsed_secs,
+        settings.max_offline_secs,
+    );
+
+    for (resource_id, amount) in &gains {
+        let current = wallet.resources.entry(resource_id.clone()).or_insert(0);
+        *current += amount;
+
+        commands.trigger(ValueChanged {
+            topic: Topic::resource(resource_id).into(),
+            value: *current as f32,
+        });
+    }
+
+    info!(
+        "Applied offline progress for {:.0}s: {:?}",
+        elapsed_secs, gains
+    );
+    commands.insert_resource(PendingOfflineSummary {
+        credited_secs: credited_secs(elapsed_secs, settings.max_offline_secs),
+        gains,
+        completed: Vec::new(),
+    });
+}
+
+/// Advances `timer` by `secs` and returns true if that runs it out.
+///
+/// A finished timer is held one tick short of its duration instead, so the
+/// research and crafting systems still see it finish and complete it as usual.
+pub fn advance_timer(timer: &mut Timer, secs: f32) -> bool {
+    if timer.is_finished() {
+        return false;
+    }
+    let advanced = timer.elapsed() + Duration::from_secs_f32(secs.max(0.0));
+    let last_tick = timer.duration().saturating_sub(Duration::from_nanos(1));
+    timer.set_elapsed(advanced.min(last_tick));
+    advanced >= timer.duration()
+}
+
+/// Adds the credited offline time to in-progress research and crafting timers.
+/// Runs after `apply_offline_progress`, which decides how much time is credited.
+pub fn advance_offline_timers(
+    summary: Option<ResMut<PendingOfflineSummary>>,
+    mut research_query: Query<&mut InProgress>,
+    mut crafting_query: Query<&mut CraftingInProgress>,
+    research: ResearchLibrary,
+    recipes: RecipeLibrary,
+) {
+    let Some(mut summary) = summary else {
+        return;
+    };
+    let secs = summary.credited_secs;
+
+    for mut progress in research_query.iter_mut() {
+        if advance_timer(&mut progress.timer, secs) {
+            summary
+                .completed
+                .push(research.display_name(&progress.research_id));
+        }
+    }
+
+    for mut crafting in crafting_query.iter_mut() {
+        if advance_timer(&mut crafting.timer, secs) {
+            let name = recipes.get_def(&crafting.recipe_id).map_or_else(
+                || crafting.recipe_id.clone(),
+                |def| def.display_name.clone(),
+            );
+            summary.completed.push(name);
+        }
+    }
+
+    info!(
+        "Advanced in-progress timers by {:.0}s, completing: {:?}",
+        secs, summary.completed
+    );
+}
+
+/// Shows the "While you were away" notification once gameplay resumes.
+/// Runs after loading so the notification is not cleared with loading notifications.
+pub fn show_offline_summary(
+    mut commands: Commands,
+    summary: Option<Res<PendingOfflineSummary>>,
+    mut queue: ResMut<NotificationQueue>,
+) {
+    let Some(summary) = summary else {
+        return;
+    };
+
+    if let Some(message) = summary_message(&summary) {
+        queue.push_info("While you were away", message);
+    }
+
+    commands.remove_resource::<PendingOfflineSummary>();
+}
+
+/// Player-facing text for `summary`, or None if nothing happened while away.
+fn summary_message(summary: &PendingOfflineSummary) -> Option<String> {
+    let mut lines = Vec::new();
+    if !summary.gains.is_empty() {
+        lines.push(
+            summary
+                .gains
+                .iter()
+                .map(|(id, amount)| format!("+{} {}", amount, id))
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+    }
+    if !summary.completed.is_empty() {
+        lines.push(format!("Completed: {}", summary.completed.join(", ")));
+    }
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rates() -> HashMap<String, f32> {
+        HashMap::from_iter([("entropy".to_string(), 0.2), ("bones".to_string(), 1.5)])
+    }
+
+    #[test]
+    fn test_offline_gains_without_cap() {
+        let gains = compute_offline_gains(&rates(), 100.0, DEFAULT_MAX_OFFLINE_SECS);
+
+        assert_eq!(
+            gains,
+            vec![("bones".to_string(), 150), ("entropy".to_string(), 20)]
+        );
+    }
+
+    #[test]
+    fn test_offline_gains_with_cap() {
+        let gains = compute_offline_gains(&rates(), 100_000.0, 10.0);
+
+        assert_eq!(
+            gains,
+            vec![("bones".to_string(), 15), ("entropy".to_string(), 2)]
+        );
+    }
+
+    #[test]
+    fn test_offline_gains_ignores_negative_elapsed() {
+        assert!(compute_offline_gains(&rates(), -50.0, 10.0).is_empty());
+    }
+
+    #[test]
+    fn test_advance_timer_leaves_finishing_to_the_next_tick() {
+        let mut timer = Timer::from_seconds(10.0, TimerMode::Once);
+
+        assert!(!advance_timer(&mut timer, 4.0));
+        assert_eq!(timer.elapsed_secs(), 4.0);
+
+        assert!(advance_timer(&mut timer, 100.0));
+        assert!(!timer.is_finished());
+        // The live system sees the timer finish on its next tick
+        assert!(timer.tick(Duration::from_millis(16)).just_finished());
+    }
+
+    #[test]
+    fn test_summary_lists_gains_and_completions() {
+        let mut summary = PendingOfflineSummary::default();
+        assert_eq!(summary_message(&summary), None);
+
+        summary.completed = vec!["Bone Tools".to_string()];
+        assert_eq!(
+            summary_message(&summary).as_deref(),
+            Some("Completed: Bone Tools")
+        );
+
+        summary.gains = vec![("entropy".to_string(), 20)];
+        assert_eq!(
+            summary_message(&summary).as_deref(),
+            Some("+20 entropy\nCompleted: Bone Tools")
+        );
+    }
+}
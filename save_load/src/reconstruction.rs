@@ -161,7 +161,7 @@ pub fn hydrate_research_unlocks(
 
     for (node, count) in research_query.iter() {
         if count.0 > 0 {
-            let topic = format!("research:{}", node.id);
+            let topic: String = unlocks_events::Topic::research(&node.id).into();
             debug!("Replaying '{}' completion {} times", topic, count.0);
 
             // Replay the completion event N times
@@ -192,7 +192,7 @@ pub fn hydrate_blessed_unlocks(mut commands: Commands, blessings_query: Query<&B
         for (blessing_id, level) in &blessings.unlocked {
             // Restore topic value
             commands.trigger(unlocks_events::ValueChanged {
-                topic: format!("blessing:{}", blessing_id),
+                topic: unlocks_events::Topic::blessing(blessing_id).into(),
                 value: *level as f32,
             });
             debug!(
@@ -0,0 +1,162 @@
+//! Starting a fresh game from a running session.
+
+use {
+    bevy::prelude::*,
+    blessings::BlessingState,
+    bonus_stats_resources::BonusStats,
+    crafting_resources::{ConstructedBuildings, CraftingQueue, CraftingState, RecipeMap},
+    research::{ResearchMap, ResearchQueue, ResearchState},
+    skill_components::UnlockedSkills,
+    states::GameState,
+    unlocks_resources::{UnlockHistory, UnlockProgress, UnlockState},
+    village_resources::DivinityUnlockState,
+    wallet::Wallet,
+};
+
+/// Event to throw away the current session and start a new game from the startup scene.
+///
+/// Nothing is deleted from disk; existing saves stay loadable.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ResetGame;
+
+/// Observer that handles the ResetGame event.
+pub(crate) fn on_reset_game(
+    _trigger: On<ResetGame>,
+    mut commands: Commands,
+    state: Res<State<GameState>>,
+) {
+    if *state.get() != GameState::Running {
+        warn!("Ignoring ResetGame outside of GameState::Running");
+        return;
+    }
+
+    info!("Starting a new game");
+    commands.queue(|world: &mut World| {
+        clear_session_state(world);
+
+        // Entities are despawned by the OnExit(GameState::Running) systems, as for a load
+        world.insert_resource(loading::SceneToLoad::default());
+        world
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Loading);
+    });
+}
+
+/// Resets every resource the startup scene does not overwrite, so nothing from the
+/// previous session leaks into the new game.
+pub fn clear_session_state(world: &mut World) {
+    reset::<Wallet>(world);
+    reset::<UnlockState>(world);
+    reset::<UnlockProgress>(world);
+    reset::<UnlockHistory>(world);
+    reset::<ResearchState>(world);
+    reset::<ResearchQueue>(world);
+    reset::<CraftingState>(world);
+    reset::<CraftingQueue>(world);
+    reset::<ConstructedBuildings>(world);
+    reset::<DivinityUnlockState>(world);
+    reset::<UnlockedSkills>(world);
+
+    // Entity lookups only; the entities themselves go with the OnExit cleanup
+    if let Some(mut map) = world.get_resource_mut::<ResearchMap>() {
+        map.entities.clear();
+    }
+    if let Some(mut map) = world.get_resource_mut::<RecipeMap>() {
+        map.entities.clear();
+    }
+    // Blessing handles come from the assets and survive the reset
+    if let Some(mut blessings) = world.get_resource_mut::<BlessingState>() {
+        blessings.available.clear();
+    }
+    // Caps are configuration, not progress
+    if let Some(mut stats) = world.get_resource_mut::<BonusStats>() {
+        stats.clear();
+    }
+}
+
+fn reset<R: Resource + Default>(world: &mut World) {
+    if let Some(mut resource) = world.get_resource_mut::<R>() {
+        *resource = R::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, bevy::state::app::StatesPlugin};
+
+    #[test]
+    fn test_reset_game_clears_progress_and_reloads_startup_scene() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::Running)
+            .init_resource::<Wallet>()
+            .init_resource::<UnlockState>()
+            .init_resource::<UnlockProgress>()
+            .init_resource::<ResearchState>()
+            .init_resource::<BonusStats>()
+            .init_resource::<UnlockedSkills>()
+            .init_resource::<BlessingState>()
+            .insert_resource(loading::SceneToLoad {
+                path: "slot1.scn.ron".to_string(),
+                is_save: true,
+            })
+            .add_observer(on_reset_game);
+        app.update();
+
+        {
+            let world = app.world_mut();
+            world
+                .resource_mut::<Wallet>()
+                .resources
+                .insert("bones".to_string(), 50);
+            world
+                .resource_mut::<UnlockState>()
+                .completed
+                .push("research_bone_tools".to_string());
+            world
+                .resource_mut::<UnlockProgress>()
+                .counts
+                .insert("kills".to_string(), 3);
+            world
+                .resource_mut::<ResearchState>()
+                .completion_counts
+                .insert("bone_tools".to_string(), 1);
+            world
+                .resource_mut::<UnlockedSkills>()
+                .0
+                .insert("bone_throw".to_string());
+            let mut blessings = world.resource_mut::<BlessingState>();
+            blessings
+                .blessings
+                .insert("hunger".to_string(), Handle::default());
+            blessings.available.insert("hunger".to_string());
+        }
+
+        app.world_mut().trigger(ResetGame);
+        app.update();
+        // NextState is set by a queued command, after this frame's StateTransition
+        app.update();
+
+        let world = app.world();
+        assert!(world.resource::<Wallet>().resources.is_empty());
+        assert!(world.resource::<UnlockState>().completed.is_empty());
+        assert!(world.resource::<UnlockProgress>().counts.is_empty());
+        assert!(
+            world
+                .resource::<ResearchState>()
+                .completion_counts
+                .is_empty()
+        );
+        assert!(world.resource::<UnlockedSkills>().0.is_empty());
+        let blessings = world.resource::<BlessingState>();
+        assert!(blessings.available.is_empty());
+        assert!(blessings.blessings.contains_key("hunger"));
+        let scene = world.resource::<loading::SceneToLoad>();
+        assert!(!scene.is_save);
+        assert_eq!(scene.path, loading::SceneToLoad::default().path);
+        assert_eq!(
+            *world.resource::<State<GameState>>().get(),
+            GameState::Loading
+        );
+    }
+}
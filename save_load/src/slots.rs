@@ -0,0 +1,117 @@
+/// A fixed save file the game can write to and load from.
+///
+/// Every slot maps to one deterministic file in the saves directory, so saving
+/// to a slot overwrites its previous contents in place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SaveSlot {
+    /// Written by the autosave timer, loaded with F8
+    Autosave,
+    /// Written with F5, loaded with F9
+    Quicksave,
+    /// Player-managed slot, numbered from 1 to `SaveSlot::MANUAL_SLOTS`.
+    /// Build it with `SaveSlot::manual`; saving or loading an out of range slot fails.
+    Manual(u8),
+}
+
+impl SaveSlot {
+    /// Number of player-managed `Manual` slots
+    pub const MANUAL_SLOTS: u8 = 3;
+
+    /// Extension shared by every save file
+    pub const EXTENSION: &str = ".scn.ron";
+
+    /// Every valid slot: autosave, quicksave, then the manual slots in order.
+    pub fn all() -> Vec<SaveSlot> {
+        let mut slots = vec![SaveSlot::Autosave, SaveSlot::Quicksave];
+        slots.extend((1..=Self::MANUAL_SLOTS).map(SaveSlot::Manual));
+        slots
+    }
+
+    /// Manual slot `n`, or `None` if `n` is outside `1..=MANUAL_SLOTS`.
+    pub fn manual(n: u8) -> Option<SaveSlot> {
+        (1..=Self::MANUAL_SLOTS)
+            .contains(&n)
+            .then_some(SaveSlot::Manual(n))
+    }
+
+    /// False for a `Manual` slot outside `1..=MANUAL_SLOTS`.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            SaveSlot::Manual(n) => SaveSlot::manual(*n).is_some(),
+            _ => true,
+        }
+    }
+
+    /// Stable identifier used in the file name, e.g. "autosave" or "slot2".
+    pub fn id(&self) -> String {
+        match self {
+            SaveSlot::Autosave => "autosave".to_string(),
+            SaveSlot::Quicksave => "quicksave".to_string(),
+            SaveSlot::Manual(n) => format!("slot{}", n),
+        }
+    }
+
+    /// Parses an identifier produced by `id`. Manual slots out of range are rejected.
+    pub fn from_id(id: &str) -> Option<SaveSlot> {
+        match id {
+            "autosave" => Some(SaveSlot::Autosave),
+            "quicksave" => Some(SaveSlot::Quicksave),
+            _ => id
+                .strip_prefix("slot")?
+                .parse()
+                .ok()
+                .and_then(SaveSlot::manual),
+        }
+    }
+
+    /// File name of this slot inside the saves directory, e.g. "slot2.scn.ron".
+    pub fn file_name(&self) -> String {
+        format!("{}{}", self.id(), Self::EXTENSION)
+    }
+
+    /// Player-facing name, e.g. "Slot 2".
+    pub fn label(&self) -> String {
+        match self {
+            SaveSlot::Autosave => "Autosave".to_string(),
+            SaveSlot::Quicksave => "Quicksave".to_string(),
+            SaveSlot::Manual(n) => format!("Slot {}", n),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slot_file_names_are_deterministic_and_round_trip() {
+        assert_eq!(SaveSlot::Autosave.file_name(), "autosave.scn.ron");
+        assert_eq!(SaveSlot::Quicksave.file_name(), "quicksave.scn.ron");
+        assert_eq!(SaveSlot::Manual(2).file_name(), "slot2.scn.ron");
+
+        for slot in SaveSlot::all() {
+            assert_eq!(SaveSlot::from_id(&slot.id()), Some(slot));
+        }
+        assert_eq!(SaveSlot::all().len(), 2 + SaveSlot::MANUAL_SLOTS as usize);
+
+        assert_eq!(SaveSlot::from_id("slot0"), None);
+        assert_eq!(SaveSlot::from_id("slot4"), None);
+        assert_eq!(SaveSlot::from_id("save_2024"), None);
+    }
+
+    #[test]
+    fn test_manual_slots_are_bounded() {
+        assert_eq!(SaveSlot::manual(1), Some(SaveSlot::Manual(1)));
+        assert_eq!(
+            SaveSlot::manual(SaveSlot::MANUAL_SLOTS),
+            Some(SaveSlot::Manual(SaveSlot::MANUAL_SLOTS))
+        );
+        assert_eq!(SaveSlot::manual(0), None);
+        assert_eq!(SaveSlot::manual(SaveSlot::MANUAL_SLOTS + 1), None);
+
+        assert!(SaveSlot::Autosave.is_valid());
+        assert!(SaveSlot::Manual(2).is_valid());
+        assert!(!SaveSlot::Manual(0).is_valid());
+        assert!(!SaveSlot::Manual(200).is_valid());
+    }
+}
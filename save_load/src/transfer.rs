@@ -0,0 +1,143 @@
+//! Export and import of saves as a single base64 string, for sharing or backups.
+
+use {
+    super::{LoadGame, SAVES_DIR, SaveSlot, meta, migration, serialize_save, write_save_file},
+    base64::{Engine, engine::general_purpose::STANDARD},
+    bevy::{prelude::*, reflect::TypeRegistry},
+    std::{fmt, fs, io, path::Path},
+};
+
+/// Why a save string could not be exported or imported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveTransferError {
+    /// The current game state could not be serialized
+    Serialize(String),
+    /// The string is not valid base64 or does not decode to text
+    InvalidEncoding(String),
+    /// The decoded text is not a save this game can load
+    InvalidSave(String),
+    /// The imported save could not be written to disk
+    Write(String),
+}
+
+impl fmt::Display for SaveTransferError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Serialize(reason) => write!(f, "Could not export the game: {}", reason),
+            Self::InvalidEncoding(reason) => {
+                write!(f, "This is not a save string (bad encoding: {})", reason)
+            }
+            Self::InvalidSave(reason) => {
+                write!(f, "The save string does not match this game: {}", reason)
+            }
+            Self::Write(reason) => write!(f, "Could not store the imported save: {}", reason),
+        }
+    }
+}
+
+/// Serializes the current game state and encodes it as base64.
+pub fn export_save_to_string(world: &mut World) -> Result<String, SaveTransferError> {
+    let serialized = serialize_save(world).map_err(SaveTransferError::Serialize)?;
+    Ok(STANDARD.encode(serialized))
+}
+
+/// Decodes a save string and checks it loads against `type_registry`, returning the
+/// serialized scene. Surrounding whitespace, e.g. from copy-pasting, is ignored.
+pub fn decode_save_string(
+    encoded: &str,
+    type_registry: &TypeRegistry,
+) -> Result<String, SaveTransferError> {
+    let bytes = STANDARD
+        .decode(encoded.trim())
+        .map_err(|e| SaveTransferError::InvalidEncoding(e.to_string()))?;
+    let serialized =
+        String::from_utf8(bytes).map_err(|e| SaveTransferError::InvalidEncoding(e.to_string()))?;
+
+    // Check the version first, a newer save would otherwise fail with an unknown type
+    let version = migration::read_save_version(&serialized)
+        .map_err(|e| SaveTransferError::InvalidSave(e.to_string()))?;
+    migration::check_supported(version)
+        .map_err(|e| SaveTransferError::InvalidSave(e.to_string()))?;
+    loading::deserialize_save_scene(serialized.as_bytes(), type_registry)
+        .map_err(SaveTransferError::InvalidSave)?;
+    Ok(serialized)
+}
+
+/// Validates a save string, writes it into `slot` (overwriting it) and loads it.
+pub fn import_save_from_string(
+    world: &mut World,
+    encoded: &str,
+    slot: SaveSlot,
+) -> Result<(), SaveTransferError> {
+    let serialized = {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        decode_save_string(encoded, &type_registry)?
+    };
+
+    let saves_dir = Path::new(SAVES_DIR);
+    fs::create_dir_all(saves_dir).map_err(|e| SaveTransferError::Write(e.to_string()))?;
+    let file_name = slot.file_name();
+    write_save_file(&saves_dir.join(&file_name), &serialized).map_err(SaveTransferError::Write)?;
+    // The slot's old summary describes a different game
+    match fs::remove_file(saves_dir.join(meta::meta_file_name(&file_name))) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => {
+            warn!("Failed to remove stale save metadata: {}", e)
+        }
+        _ => {}
+    }
+
+    info!("Imported save into slot {}", slot.id());
+    world.trigger(LoadGame { slot });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        hero_components::{Hero, HeroName},
+    };
+
+    fn save_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let registry = world.resource::<AppTypeRegistry>();
+            let mut registry = registry.write();
+            registry.register::<Hero>();
+            registry.register::<HeroName>();
+            registry.register::<migration::SaveVersion>();
+            registry.register::<crate::SaveTimestamp>();
+        }
+        world
+    }
+
+    #[test]
+    fn test_exported_string_round_trips() {
+        let mut world = save_world();
+        world.spawn((Hero, HeroName("Brenna".to_string())));
+
+        let encoded = export_save_to_string(&mut world).unwrap();
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let serialized = decode_save_string(&format!("  {}\n", encoded), &registry).unwrap();
+        assert!(serialized.contains("Brenna"));
+    }
+
+    #[test]
+    fn test_bad_strings_report_distinct_errors() {
+        let world = save_world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        assert!(matches!(
+            decode_save_string("not base64!", &registry),
+            Err(SaveTransferError::InvalidEncoding(_))
+        ));
+
+        let unknown_type = STANDARD
+            .encode(r#"(resources: {}, entities: { 0: (components: { "nope::Renamed": () }) })"#);
+        assert!(matches!(
+            decode_save_string(&unknown_type, &registry),
+            Err(SaveTransferError::InvalidSave(_))
+        ));
+    }
+}
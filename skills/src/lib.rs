@@ -17,7 +17,8 @@ impl Plugin for SkillsPlugin {
                     systems::tick_cooldowns,
                     systems::tick_buffs,
                     systems::enemy_auto_activate_skills_system,
-                ),
+                )
+                    .run_if(states::not_paused),
             )
             .add_observer(systems::process_skill_activation)
             .add_observer(unlocks::handle_skill_unlocks);
@@ -25,7 +25,7 @@ pub fn tick_cooldowns(
 
         // Initialize timers for skills that are equipped but not yet tracked
         if let Some(equipped) = equipped {
-            for skill_id in &equipped.0 {
+            for skill_id in equipped.skill_ids() {
                 let already_tracked = cooldowns
                     .as_ref()
                     .map_or(false, |c| c.timers.contains_key(skill_id));
@@ -319,7 +319,7 @@ pub fn enemy_auto_activate_skills_system(
     skills: Res<Assets<SkillDefinition>>,
 ) {
     for (enemy_entity, equipped, cooldowns, caster_transform) in &enemies {
-        for skill_id in &equipped.0 {
+        for skill_id in equipped.skill_ids() {
             // Check if skill is on cooldown
             if let Some(timer) = cooldowns.timers.get(skill_id) {
                 if !timer.is_finished() {
@@ -23,6 +23,7 @@ fn test_skill_activation_damage() {
     let skill_handle = skills.add(SkillDefinition {
         id: "test_fireball".to_string(),
         display_name: "Test Fireball".to_string(),
+        description: None,
         skill_type: SkillType::Active,
         cooldown_ms: 1000,
         target: TargetType::Identity, // Easier to test self-target
@@ -86,6 +87,7 @@ fn test_skill_stat_modifier() {
     let skill_handle = skills.add(SkillDefinition {
         id: skill_id.clone(),
         display_name: "Test Buff".to_string(),
+        description: None,
         skill_type: SkillType::Active,
         cooldown_ms: 0,
         target: TargetType::Identity,
@@ -149,6 +151,7 @@ fn test_skill_apply_status() {
     let skill_handle = skills.add(SkillDefinition {
         id: skill_id.clone(),
         display_name: "Test Stun".to_string(),
+        description: None,
         skill_type: SkillType::Active,
         cooldown_ms: 0,
         target: TargetType::Identity,
@@ -202,6 +205,7 @@ fn test_skill_cooldown() {
     let skill_handle = skills.add(SkillDefinition {
         id: skill_id.clone(),
         display_name: "Test Cooldown".to_string(),
+        description: None,
         skill_type: SkillType::Active,
         cooldown_ms: 1000,
         target: TargetType::Identity,
@@ -309,6 +313,7 @@ fn test_skill_target_single_enemy() {
     let skill_handle = skills.add(SkillDefinition {
         id: skill_id.clone(),
         display_name: "Test Single".to_string(),
+        description: None,
         skill_type: SkillType::Active,
         cooldown_ms: 0,
         target: TargetType::SingleEnemy { range: 100.0 },
@@ -371,6 +376,7 @@ fn test_skill_target_aoe_range() {
     let skill_handle = skills.add(SkillDefinition {
         id: skill_id.clone(),
         display_name: "Test AOE".to_string(),
+        description: None,
         skill_type: SkillType::Active,
         cooldown_ms: 0,
         target: TargetType::AllEnemiesInRange { radius: 10.0 },
@@ -440,6 +446,7 @@ fn test_skill_projectile_spawns() {
     let skill_handle = skills.add(SkillDefinition {
         id: skill_id.clone(),
         display_name: "Test Projectile".to_string(),
+        description: None,
         skill_type: SkillType::Active,
         cooldown_ms: 0,
         target: TargetType::SingleEnemy { range: 100.0 },
@@ -2,6 +2,7 @@ use {
     bevy::{log::LogPlugin, prelude::*},
     bevy_inspector_egui::{bevy_egui::EguiPlugin, quick::WorldInspectorPlugin},
     core::CorePlugin,
+    log_console::log_capture_layer,
 };
 
 fn main() {
@@ -21,6 +22,7 @@ fn main() {
                     village_ui=debug"
                     .into(),
                 level: bevy::log::Level::TRACE,
+                custom_layer: log_capture_layer,
                 ..Default::default()
             }),
         )
@@ -0,0 +1,80 @@
+//! Global multiplier for how fast progression timers run.
+
+use {bevy::prelude::*, std::time::Duration};
+
+/// Multiplier applied to the delta of progression timers, clamped to
+/// `GameSpeed::MIN..=GameSpeed::MAX`.
+///
+/// Honored by the systems that tick through `scaled_delta`:
+/// - crafting progress (`crafting::update_crafting_progress`)
+/// - research progress (`research::update_research_progress`)
+/// - notification dwell timers (`notification_ui`)
+///
+/// Combat, enemy movement, skill cooldowns and passive generation still run at real speed.
+/// Registered once by `CorePlugin`, next to the game states.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct GameSpeed(pub f32);
+
+impl Default for GameSpeed {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl GameSpeed {
+    pub const MIN: f32 = 0.25;
+    pub const MAX: f32 = 4.0;
+
+    /// Speed clamped to the supported range; a non-finite value counts as normal speed.
+    pub fn get(&self) -> f32 {
+        if self.0.is_finite() {
+            self.0.clamp(Self::MIN, Self::MAX)
+        } else {
+            1.0
+        }
+    }
+
+    /// Sets the speed, clamped to the supported range.
+    pub fn set(&mut self, speed: f32) {
+        self.0 = GameSpeed(speed).get();
+    }
+}
+
+/// Frame delta of `time` scaled by `speed`, for ticking progression timers.
+pub fn scaled_delta(time: &Time, speed: &GameSpeed) -> Duration {
+    time.delta().mul_f32(speed.get())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_speed_is_clamped_to_supported_range() {
+        assert_eq!(GameSpeed(10.0).get(), GameSpeed::MAX);
+        assert_eq!(GameSpeed(0.0).get(), GameSpeed::MIN);
+        assert_eq!(GameSpeed(f32::NAN).get(), 1.0);
+
+        let mut speed = GameSpeed::default();
+        speed.set(0.1);
+        assert_eq!(speed, GameSpeed(GameSpeed::MIN));
+        speed.set(2.0);
+        assert_eq!(speed, GameSpeed(2.0));
+    }
+
+    #[test]
+    fn test_scaled_delta_multiplies_frame_delta() {
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+
+        assert_eq!(
+            scaled_delta(&time, &GameSpeed(0.25)),
+            Duration::from_millis(250)
+        );
+        // Out of range speeds are clamped before scaling
+        assert_eq!(
+            scaled_delta(&time, &GameSpeed(100.0)),
+            Duration::from_secs(4)
+        );
+    }
+}
@@ -1,3 +1,7 @@
+mod game_speed;
+
+pub use game_speed::{GameSpeed, scaled_delta};
+
 use bevy::prelude::*;
 
 /// The high-level state machine controlling the application's execution flow.
@@ -29,6 +33,25 @@ pub enum GameState {
     LoadingSave,
 }
 
+/// Whether gameplay progression is halted while the game is running.
+///
+/// Only exists in `GameState::Running` and starts `Unpaused` on every entry, so a
+/// load never resumes paused. Systems that advance timers or move the world use the
+/// `not_paused` run condition; UI systems keep running so menus stay usable.
+#[derive(SubStates, Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[source(GameState = GameState::Running)]
+pub enum PauseState {
+    #[default]
+    Unpaused,
+    Paused,
+}
+
+/// Run condition: false while the game is paused.
+/// Also true outside `GameState::Running`, so it only adds the pause to other gating.
+pub fn not_paused(state: Option<Res<State<PauseState>>>) -> bool {
+    state.is_none_or(|state| *state.get() != PauseState::Paused)
+}
+
 #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum VillageView {
     #[default]
@@ -39,6 +62,8 @@ pub enum VillageView {
     Encyclopedia,
     Heroes,
     Blessings,
+    RecentUnlocks,
+    StatsSummary,
 }
 
 #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
@@ -664,6 +664,11 @@ impl EditorState {
                     }
                 });
         });
+        ui.horizontal(|ui| {
+            ui.label("Subcategory:");
+            ui.text_edit_singleline(&mut self.recipe_data_form.subcategory);
+        });
+        ui.small("Optional group within the tab (e.g., \"Melee\")");
         ui.add_space(8.0);
 
         // Time
@@ -677,6 +682,29 @@ impl EditorState {
         });
         ui.add_space(8.0);
 
+        // Repeats
+        ui.horizontal(|ui| {
+            ui.label("Max Repeats:");
+            ui.add(egui::DragValue::new(&mut self.recipe_data_form.max_repeats).range(0..=1000));
+        });
+        ui.small("0 = unlimited");
+        ui.horizontal(|ui| {
+            ui.label("Cost Scaling:");
+            ui.add(
+                egui::DragValue::new(&mut self.recipe_data_form.cost_scaling)
+                    .speed(0.01)
+                    .range(0.01..=10.0),
+            );
+            ui.label("Time Scaling:");
+            ui.add(
+                egui::DragValue::new(&mut self.recipe_data_form.time_scaling)
+                    .speed(0.01)
+                    .range(0.01..=10.0),
+            );
+        });
+        ui.small("Multiplier applied per completed craft (1.0 = no scaling)");
+        ui.add_space(8.0);
+
         // Costs
         ui.separator();
         ui.heading("Resource Costs");
@@ -1996,6 +2024,11 @@ impl EditorState {
                                 StatMode::Multiplicative,
                                 "Multiplicative",
                             );
+                            ui.selectable_value(
+                                &mut entry.bonus.mode,
+                                StatMode::DiminishingPercent,
+                                "Diminishing Percent",
+                            );
                         });
 
                     if ui.button("🗑").clicked() {
@@ -731,9 +731,17 @@ pub struct RecipeFormData {
     pub id: String, // Internal ID (e.g. "bone_sword")
     pub display_name: String,
     pub category: RecipeCategory,
+    /// Optional grouping within the category tab; empty means none
+    pub subcategory: String,
     pub craft_time: f32,
     pub costs: Vec<ResourceCost>,
     pub outcomes: Vec<CraftingOutcome>,
+    /// Maximum times the recipe can be crafted; 0 means unlimited
+    pub max_repeats: u32,
+    /// Cost multiplier per completed craft
+    pub cost_scaling: f32,
+    /// Craft time multiplier per completed craft
+    pub time_scaling: f32,
 
     /// Optional inline unlock condition
     pub unlock_condition: UnlockCondition,
@@ -747,6 +755,7 @@ impl RecipeFormData {
             id: String::new(),
             display_name: String::new(),
             category: RecipeCategory::Weapons,
+            subcategory: String::new(),
             craft_time: 5.0,
             costs: vec![ResourceCost {
                 resource_id: "bones".to_string(),
@@ -756,6 +765,9 @@ impl RecipeFormData {
                 id: "bone_sword_item".to_string(),
                 amount: 1,
             }],
+            max_repeats: 0,
+            cost_scaling: 1.0,
+            time_scaling: 1.0,
             unlock_condition: UnlockCondition::True,
             repeat_mode: unlocks_assets::RepeatMode::Once,
         }
@@ -786,6 +798,9 @@ impl RecipeFormData {
         if self.craft_time < 0.0 {
             errors.push("Craft time must be >= 0".to_string());
         }
+        if self.cost_scaling <= 0.0 || self.time_scaling <= 0.0 {
+            errors.push("Scaling factors must be > 0".to_string());
+        }
         for cost in &self.costs {
             if cost.resource_id.trim().is_empty() {
                 errors.push("Resource ID required in cost".to_string());
@@ -827,9 +842,13 @@ impl RecipeFormData {
             id: self.id.clone(),
             display_name: self.display_name.clone(),
             category: self.category.clone(),
+            subcategory: Some(self.subcategory.trim().to_string()).filter(|s| !s.is_empty()),
             craft_time: self.craft_time,
             cost,
             outcomes: self.outcomes.clone(),
+            max_repeats: Some(self.max_repeats).filter(|max| *max > 0),
+            cost_scaling: self.cost_scaling,
+            time_scaling: self.time_scaling,
             unlock,
         }
     }
@@ -854,9 +873,13 @@ impl RecipeFormData {
             id: def.id.clone(),
             display_name: def.display_name.clone(),
             category: def.category.clone(),
+            subcategory: def.subcategory.clone().unwrap_or_default(),
             craft_time: def.craft_time,
             costs,
             outcomes: def.outcomes.clone(),
+            max_repeats: def.max_repeats.unwrap_or(0),
+            cost_scaling: def.cost_scaling,
+            time_scaling: def.time_scaling,
             unlock_condition,
             repeat_mode,
         }
@@ -1032,7 +1055,7 @@ impl DivinityFormData {
     /// Derives the reward ID.
     /// Pattern: divinity:{tier}-{level}
     pub fn reward_id(&self) -> String {
-        format!("divinity:{}-{}", self.tier, self.level)
+        divinity_components::Divinity::new(self.tier, self.level).reward_id()
     }
 
     /// Derives the unlock file name.
@@ -1118,6 +1141,7 @@ impl WeaponDefinitionExt for WeaponDefinition {
             attack_range: 150.0,
             attack_speed_ms: 750,
             tags: Vec::new(),
+            requirement: None,
         }
     }
 
@@ -102,6 +102,9 @@ impl TtkTabState {
                     bonus_stats::StatMode::Multiplicative => {
                         ui.label(format!("x{}", bonus.value));
                     }
+                    bonus_stats::StatMode::DiminishingPercent => {
+                        ui.label(format!("+{}% (diminishing)", bonus.value * 100.0));
+                    }
                 }
                 if ui.button("🗑").clicked() {
                     remove_idx = Some(i);
@@ -137,6 +140,11 @@ impl TtkTabState {
                         bonus_stats::StatMode::Multiplicative,
                         "Multiplicative",
                     );
+                    ui.selectable_value(
+                        &mut self.new_bonus_mode,
+                        bonus_stats::StatMode::DiminishingPercent,
+                        "Diminishing Percent",
+                    );
                 });
 
             if ui.button("Add Bonus").clicked() {
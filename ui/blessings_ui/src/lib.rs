@@ -1,11 +1,13 @@
 use {
     bevy::prelude::*,
-    blessings::{BlessingDefinition, BlessingLimit, BlessingState, Blessings, BuyBlessing},
-    growth::GrowthStrategy,
+    blessings::{
+        BLESSING_CURRENCY, BlessingDefinition, BlessingLimit, BlessingState, Blessings, BuyBlessing,
+    },
     states::{GameState, VillageView},
     wallet::Wallet,
     widgets::{
-        ContentContainer, UiTheme, spawn_action_button, spawn_card_title, spawn_description_text,
+        ClearContentContainer, ContentContainer, RestoreScroll, UiTheme, format_number,
+        format_number_f32, spawn_action_button, spawn_card_title, spawn_description_text,
         spawn_menu_button, spawn_scrollable_container,
     },
 };
@@ -40,6 +42,24 @@ pub struct BlessingsItemsContainer;
 #[derive(Component)]
 pub struct BlessingButton {
     pub id: String,
+    /// Levels bought per click; `None` buys one
+    pub count: Option<u32>,
+}
+
+/// Upgrade button that can't be used right now
+#[derive(Component)]
+struct DisabledBlessingButton;
+
+/// Levels bought by the "x5" button
+const BULK_LEVELS: u32 = 5;
+
+/// Preview of buying several levels at once.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct MultiBuy {
+    /// Levels the purchase would add
+    pub levels: u32,
+    /// Cumulative entropy cost of those levels
+    pub cost: u32,
 }
 
 #[derive(PartialEq, Clone, Debug)]
@@ -52,21 +72,32 @@ pub struct BlessingDisplayData {
     pub can_afford: bool,
     pub is_locked: bool,
     pub limit: BlessingLimit,
+    /// Next `BULK_LEVELS` levels, capped by the max level
+    pub bulk: MultiBuy,
+    /// Every level the current entropy can pay for
+    pub max: MultiBuy,
+    /// Effect at the current level, if the blessing defines one
+    pub current_effect: Option<f64>,
+    /// Effect after the next purchase; `None` at max level or without an effect
+    pub next_effect: Option<f64>,
 }
 
-fn spawn_blessings_ui(
-    mut commands: Commands,
-    mut query: Query<(Entity, Option<&Children>), With<ContentContainer>>,
-) {
-    let Some((container, children)) = query.iter_mut().next() else {
+/// "Current: +X, Next: +Y", or only the current part when there is no next level.
+fn effect_text(current: f64, next: Option<f64>) -> String {
+    let current = format!("Current: +{}", format_number_f32(current as f32));
+    match next {
+        Some(next) => format!("{}, Next: +{}", current, format_number_f32(next as f32)),
+        None => current,
+    }
+}
+
+fn spawn_blessings_ui(mut commands: Commands, query: Query<Entity, With<ContentContainer>>) {
+    let Some(container) = query.iter().next() else {
         return;
     };
 
-    // Despawn existing children
-    let to_despawn: Vec<Entity> = children.map(|c| c.iter().collect()).unwrap_or_default();
-    for child in to_despawn {
-        commands.entity(child).despawn();
-    }
+    // Clear the previous view, cached village views are only hidden
+    commands.queue(ClearContentContainer(container));
 
     // Spawn back button and blessings content
     commands.entity(container).with_children(|parent| {
@@ -115,11 +146,11 @@ impl Command for PopulateBlessingsCommand {
             return;
         };
 
-        if let Some(children) = children {
-            let children_vec: Vec<_> = children.to_vec();
-            for child in children_vec {
-                world.commands().entity(child).despawn();
-            }
+        let children_vec: Vec<Entity> = children.map(|c| c.to_vec()).unwrap_or_default();
+        let scroll = widgets::scroll_offset::<BlessingsItemsContainer>(world);
+        widgets::record_ui_rebuild(world, "blessings");
+        for child in children_vec {
+            world.commands().entity(child).despawn();
         }
 
         world
@@ -136,6 +167,9 @@ impl Command for PopulateBlessingsCommand {
                         };
                         spawn_card_title(c, &title);
                         spawn_description_text(c, &item.description);
+                        if let Some(current) = item.current_effect {
+                            spawn_description_text(c, &effect_text(current, item.next_effect));
+                        }
 
                         if item.is_locked {
                             c.spawn((
@@ -148,7 +182,10 @@ impl Command for PopulateBlessingsCommand {
                             ));
                         } else {
                             c.spawn((
-                                Text::new(format!("Cost: {} Entropy", item.cost)),
+                                Text::new(format!(
+                                    "Cost: {} Entropy",
+                                    format_number(item.cost as u64)
+                                )),
                                 TextFont {
                                     font_size: 12.0,
                                     ..default()
@@ -175,26 +212,89 @@ impl Command for PopulateBlessingsCommand {
                                     TextColor(UiTheme::TEXT_DISABLED),
                                 ));
                             } else {
-                                spawn_action_button(
-                                    c,
-                                    "Upgrade",
-                                    if item.can_afford {
-                                        UiTheme::AFFORDABLE
-                                    } else {
-                                        UiTheme::BORDER_DISABLED
-                                    },
-                                    if item.can_afford {
-                                        UiTheme::BORDER_SUCCESS
-                                    } else {
-                                        UiTheme::BORDER_DISABLED
-                                    },
-                                    BlessingButton { id: item.id },
-                                );
+                                let bulk_affordable =
+                                    !item.is_locked && item.max.levels >= item.bulk.levels;
+                                let max_affordable = !item.is_locked && item.max.levels > 0;
+
+                                c.spawn(Node {
+                                    flex_direction: FlexDirection::Row,
+                                    column_gap: Val::Px(8.0),
+                                    ..default()
+                                })
+                                .with_children(|row| {
+                                    spawn_buy_button(
+                                        row,
+                                        "Upgrade".to_string(),
+                                        item.can_afford,
+                                        BlessingButton {
+                                            id: item.id.clone(),
+                                            count: None,
+                                        },
+                                    );
+                                    // Only worth offering when more than one level is left
+                                    if item.bulk.levels > 1 {
+                                        spawn_buy_button(
+                                            row,
+                                            format!(
+                                                "x{} ({})",
+                                                item.bulk.levels,
+                                                format_number(item.bulk.cost as u64)
+                                            ),
+                                            bulk_affordable,
+                                            BlessingButton {
+                                                id: item.id.clone(),
+                                                count: Some(item.bulk.levels),
+                                            },
+                                        );
+                                    }
+                                    spawn_buy_button(
+                                        row,
+                                        format!(
+                                            "Max x{} ({})",
+                                            item.max.levels,
+                                            format_number(item.max.cost as u64)
+                                        ),
+                                        max_affordable,
+                                        BlessingButton {
+                                            id: item.id,
+                                            count: Some(item.max.levels),
+                                        },
+                                    );
+                                });
                             }
                         }
                     });
                 }
             });
+        world
+            .commands()
+            .queue(RestoreScroll::<BlessingsItemsContainer>::new(scroll));
+    }
+}
+
+/// Spawns an upgrade button. Disabled ones get no `BlessingButton`, so clicks do nothing.
+fn spawn_buy_button(
+    parent: &mut ChildSpawnerCommands,
+    label: String,
+    enabled: bool,
+    button: BlessingButton,
+) {
+    if enabled {
+        spawn_action_button(
+            parent,
+            &label,
+            UiTheme::AFFORDABLE,
+            UiTheme::BORDER_SUCCESS,
+            button,
+        );
+    } else {
+        spawn_action_button(
+            parent,
+            &label,
+            UiTheme::BORDER_DISABLED,
+            UiTheme::BORDER_DISABLED,
+            DisabledBlessingButton,
+        );
     }
 }
 
@@ -206,6 +306,7 @@ fn handle_blessing_button(
         if *interaction == Interaction::Pressed {
             commands.trigger(BuyBlessing {
                 blessing_id: btn.id.clone(),
+                count: btn.count,
             });
         }
     }
@@ -230,13 +331,14 @@ fn update_blessings_ui(
     };
 
     let mut data = Vec::new();
-    let current_entropy = wallet.resources.get("entropy").copied().unwrap_or(0);
+    let current_entropy = wallet.amount(BLESSING_CURRENCY);
 
     for (id, def) in assets.iter() {
         let id_str = def.id.clone();
         let current_level = blessings.unlocked.get(&id_str).copied().unwrap_or(0);
-        let cost = def.cost.calculate(current_level) as u32;
+        let cost = def.level_cost(current_level);
         let is_locked = !blessing_state.available.contains(&id_str);
+        let is_maxed = def.remaining_levels(current_level) == Some(0);
 
         let can_afford = current_entropy >= cost && !is_locked;
 
@@ -253,6 +355,14 @@ fn update_blessings_ui(
             can_afford,
             is_locked,
             limit: def.limit.clone(),
+            current_effect: def.effect_at(current_level),
+            next_effect: def.effect_at(current_level + 1).filter(|_| !is_maxed),
+            bulk: multi_buy(def.cost_of_levels(current_level, BULK_LEVELS)),
+            max: multi_buy(def.affordable_levels(
+                current_level,
+                blessings::MAX_LEVELS_PER_PURCHASE,
+                current_entropy,
+            )),
         });
     }
 
@@ -275,3 +385,7 @@ fn update_blessings_ui(
 
     commands.queue(PopulateBlessingsCommand { data });
 }
+
+fn multi_buy((levels, cost): (u32, u32)) -> MultiBuy {
+    MultiBuy { levels, cost }
+}
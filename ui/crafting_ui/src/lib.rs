@@ -1,15 +1,19 @@
 use {
-    bevy::prelude::*,
-    crafting::{Available, RecipeNode},
-    crafting_events::StartCraftingRequest,
-    crafting_resources::RecipeCategory,
+    bevy::{platform::collections::HashMap, prelude::*},
+    crafting::{
+        CraftingInProgress, RecipeLibrary, crafted_count, is_recipe_maxed, max_affordable_crafts,
+    },
+    crafting_events::{CancelQueuedCrafts, ClearCraftingQueue},
+    crafting_resources::{CraftingQueue, CraftingState, RecipeCategory},
     recipes_assets::RecipeDefinition,
     states::{GameState, VillageView},
     wallet::Wallet,
     widgets::{
-        ContentContainer, UiTheme, spawn_action_button, spawn_card_title, spawn_cost_text,
-        spawn_menu_button, spawn_scrollable_container, spawn_tab_bar, spawn_tab_button,
-        spawn_timer_text,
+        AnimatedButton, ButtonStyle, ClearContentContainer, ContentContainer, RestoreScroll,
+        Tooltip, TooltipLine, TooltipLines, UiTheme, format_number, set_progress_bar_fraction,
+        spawn_action_button, spawn_action_button_with_style, spawn_card_title, spawn_cost_text,
+        spawn_menu_button, spawn_progress_bar_ui, spawn_scrollable_container_with_scrollbar,
+        spawn_tab_bar, spawn_tab_button, spawn_timer_text,
     },
 };
 
@@ -27,13 +31,17 @@ impl Plugin for CraftingUiPlugin {
                 (
                     handle_tab_switch,
                     handle_crafting_button,
+                    handle_craft_max_button,
+                    handle_cancel_queue_button,
+                    handle_clear_queue_button,
                     handle_back_button,
                 )
                     .run_if(in_state(GameState::Running)),
             )
             .add_systems(
                 Update,
-                update_recipes_ui.run_if(in_state(VillageView::Crafting)),
+                (update_recipes_ui, update_recipe_progress_bars)
+                    .run_if(in_state(VillageView::Crafting)),
             );
     }
 }
@@ -58,12 +66,34 @@ pub struct RecipeTabButton {
 #[derive(Component)]
 pub struct RecipesItemsContainer;
 
+/// Progress bar fill showing the most advanced in-progress craft of a recipe
+#[derive(Component)]
+pub struct RecipeProgressFill {
+    pub recipe_id: String,
+}
+
 /// Crafting action button
 #[derive(Component)]
 pub struct CraftingButton {
     pub recipe_id: String,
 }
 
+/// Button that queues as many crafts of a recipe as the wallet can pay for
+#[derive(Component)]
+pub struct CraftMaxButton {
+    pub recipe_id: String,
+}
+
+/// "x3 queued" badge next to a recipe title; pressing it cancels the queued crafts
+#[derive(Component)]
+pub struct CancelQueueButton {
+    pub recipe_id: String,
+}
+
+/// Button dropping the queued crafts of every recipe, see `ClearCraftingQueue`
+#[derive(Component)]
+pub struct ClearCraftingQueueButton;
+
 // ============================================================================
 // Crafting Data Builder (for external use)
 // ============================================================================
@@ -81,17 +111,92 @@ pub struct RecipeDisplayData {
     pub display_name: String,
     pub craft_time: f32,
     pub cost_str: String,
+    /// Per-resource "current/required" lines for the cost tooltip, colored by affordability
+    pub cost_lines: Vec<TooltipLine>,
     pub can_afford: bool,
+    /// Repeat progress for limited recipes (e.g., "2/5"), None for unlimited recipes
+    pub progress_info: Option<String>,
+    /// True once the recipe reached its `max_repeats`
+    pub maxed: bool,
+    /// Missing resources, e.g. "Need +3 bones". `None` when affordable.
+    pub deficit: Option<String>,
+    /// Crafts a "Craft Max" press would queue. `None` hides the button (maxed or construction).
+    pub craft_max: Option<u32>,
+    pub subcategory: Option<String>,
+    /// True while at least one craft of this recipe is in progress
+    pub crafting: bool,
+    /// Crafts waiting for the running one to finish
+    pub queued: u32,
+}
+
+/// Describes what the wallet lacks to pay `cost`, e.g. "Need +3 bones, +1 entropy".
+/// Resources are listed alphabetically; returns `None` if nothing is missing.
+pub fn format_deficit(cost: &HashMap<String, u32>, wallet: &Wallet) -> Option<String> {
+    let mut missing: Vec<(&String, u32)> = cost
+        .iter()
+        .filter_map(|(res_id, amt)| {
+            let current = wallet.amount(res_id);
+            (current < *amt).then(|| (res_id, amt - current))
+        })
+        .collect();
+
+    if missing.is_empty() {
+        return None;
+    }
+    missing.sort();
+
+    let parts: Vec<String> = missing
+        .iter()
+        .map(|(res_id, amt)| format!("+{} {}", format_number(*amt as u64), res_id))
+        .collect();
+    Some(format!("Need {}", parts.join(", ")))
+}
+
+/// Group label for recipes that don't declare a subcategory.
+const DEFAULT_SUBCATEGORY: &str = "Other";
+
+/// Groups recipes by subcategory, with named groups sorted alphabetically
+/// and recipes without a subcategory collected last under `DEFAULT_SUBCATEGORY`.
+/// Recipes keep their relative order within a group.
+fn group_by_subcategory(recipes: Vec<RecipeDisplayData>) -> Vec<(String, Vec<RecipeDisplayData>)> {
+    let mut groups = recipes.into_iter().fold(
+        Vec::<(String, Vec<RecipeDisplayData>)>::new(),
+        |mut groups, recipe| {
+            let label = recipe
+                .subcategory
+                .clone()
+                .unwrap_or_else(|| DEFAULT_SUBCATEGORY.to_string());
+            match groups.iter_mut().find(|(existing, _)| *existing == label) {
+                Some((_, items)) => items.push(recipe),
+                None => groups.push((label, vec![recipe])),
+            }
+            groups
+        },
+    );
+
+    groups.sort_by(|(a, _), (b, _)| {
+        (a == DEFAULT_SUBCATEGORY, a).cmp(&(b == DEFAULT_SUBCATEGORY, b))
+    });
+    groups
 }
 
 /// Builds crafting display data from available recipe entities.
 pub fn build_crafting_data(
-    recipe_query: &Query<&RecipeNode, With<Available>>,
-    assets: &Assets<RecipeDefinition>,
+    library: &RecipeLibrary,
     wallet: &Wallet,
+    crafting_state: &CraftingState,
+    queue: &CraftingQueue,
+    in_progress: &Query<&CraftingInProgress>,
 ) -> CraftingData {
     let active_tab = RecipeCategory::Weapons;
-    let recipes = build_recipe_list(recipe_query, assets, wallet, &active_tab);
+    let recipes = build_recipe_list(
+        library,
+        wallet,
+        crafting_state,
+        queue,
+        in_progress,
+        &active_tab,
+    );
     CraftingData {
         active_tab,
         recipes,
@@ -99,45 +204,87 @@ pub fn build_crafting_data(
 }
 
 fn build_recipe_list(
-    recipe_query: &Query<&RecipeNode, With<Available>>,
-    assets: &Assets<RecipeDefinition>,
+    library: &RecipeLibrary,
     wallet: &Wallet,
+    crafting_state: &CraftingState,
+    queue: &CraftingQueue,
+    in_progress: &Query<&CraftingInProgress>,
     category: &RecipeCategory,
 ) -> Vec<RecipeDisplayData> {
-    recipe_query
-        .iter()
-        .filter_map(|node| {
-            let def = assets.get(&node.handle)?;
+    library
+        .available_by_category(category)
+        .into_iter()
+        .map(|(id, def)| {
+            let crafted = crafted_count(crafting_state, queue, in_progress.iter(), id);
+            let mut data = build_recipe_display_data(id, def, wallet, crafted);
+            data.crafting = in_progress.iter().any(|p| p.recipe_id == id);
+            data.queued = queue.count(id);
+            data
+        })
+        .collect()
+}
 
-            // Filter by category
-            if &def.category != category {
-                return None;
-            }
+/// Builds display data for a recipe that has already been crafted `crafted` times.
+fn build_recipe_display_data(
+    id: &str,
+    def: &RecipeDefinition,
+    wallet: &Wallet,
+    crafted: u32,
+) -> RecipeDisplayData {
+    let maxed = is_recipe_maxed(def, crafted);
+    let cost = def.scaled_cost(crafted);
 
-            // Calculate cost string and affordability
-            let mut can_afford = true;
-            let mut cost_str = String::from("Cost: ");
+    // Calculate cost string and affordability
+    let mut can_afford = !maxed;
+    let mut cost_str = String::from("Cost: ");
+    let mut cost_lines = Vec::new();
 
-            let mut cost_items: Vec<_> = def.cost.iter().collect();
-            cost_items.sort_by_key(|(res_id, _)| *res_id);
+    let mut cost_items: Vec<_> = cost.iter().collect();
+    cost_items.sort_by_key(|(res_id, _)| *res_id);
 
-            for (res_id, amt) in cost_items {
-                let current = wallet.resources.get(res_id).copied().unwrap_or(0);
-                cost_str.push_str(&format!("{}: {}/{} ", res_id, current, amt));
-                if current < *amt {
-                    can_afford = false;
-                }
-            }
+    for (res_id, amt) in cost_items {
+        let current = wallet.amount(res_id);
+        let line = format!(
+            "{}: {}/{}",
+            res_id,
+            format_number(current as u64),
+            format_number(*amt as u64)
+        );
+        cost_str.push_str(&line);
+        cost_str.push(' ');
+        cost_lines.push(TooltipLine {
+            text: line,
+            color: if current < *amt {
+                UiTheme::NOT_AFFORDABLE
+            } else {
+                UiTheme::AFFORDABLE
+            },
+        });
+        if current < *amt {
+            can_afford = false;
+        }
+    }
 
-            Some(RecipeDisplayData {
-                id: node.id.clone(),
-                display_name: def.display_name.clone(),
-                craft_time: def.craft_time,
-                cost_str,
-                can_afford,
-            })
-        })
-        .collect()
+    RecipeDisplayData {
+        id: id.to_string(),
+        display_name: def.display_name.clone(),
+        craft_time: def.scaled_craft_time(crafted),
+        cost_str,
+        cost_lines,
+        can_afford,
+        progress_info: def.max_repeats.map(|max| format!("{}/{}", crafted, max)),
+        maxed,
+        deficit: if maxed {
+            None
+        } else {
+            format_deficit(&cost, wallet)
+        },
+        craft_max: (!maxed && def.category != RecipeCategory::Construction)
+            .then(|| max_affordable_crafts(def, wallet, crafted)),
+        subcategory: def.subcategory.clone(),
+        crafting: false,
+        queued: 0,
+    }
 }
 
 // ============================================================================
@@ -146,22 +293,22 @@ fn build_recipe_list(
 
 fn spawn_crafting_ui(
     mut commands: Commands,
-    mut query: Query<(Entity, Option<&Children>), With<ContentContainer>>,
-    recipe_query: Query<&RecipeNode, With<Available>>,
-    assets: Res<Assets<RecipeDefinition>>,
+    query: Query<Entity, With<ContentContainer>>,
+    library: RecipeLibrary,
     wallet: Res<Wallet>,
+    crafting_state: Res<CraftingState>,
+    queue: Res<CraftingQueue>,
+    in_progress: Query<&CraftingInProgress>,
 ) {
-    let Some((container, children)) = query.iter_mut().next() else {
+    let Some(container) = query.iter().next() else {
         return;
     };
 
-    // Despawn existing children
-    let to_despawn: Vec<Entity> = children.map(|c| c.iter().collect()).unwrap_or_default();
-    for child in to_despawn {
-        commands.entity(child).despawn();
-    }
+    // Clear the previous view, cached village views are only hidden
+    commands.queue(ClearContentContainer(container));
 
-    let crafting_data = build_crafting_data(&recipe_query, &assets, &wallet);
+    let crafting_data =
+        build_crafting_data(&library, &wallet, &crafting_state, &queue, &in_progress);
 
     // Spawn back button and crafting content
     commands.entity(container).with_children(|parent| {
@@ -238,17 +385,21 @@ fn spawn_crafting_content(parent: &mut ChildSpawnerCommands, data: CraftingData)
                 );
             });
 
+            spawn_action_button(
+                content,
+                "Clear Queue",
+                UiTheme::TEXT_PRIMARY,
+                UiTheme::CARD_BORDER,
+                ClearCraftingQueueButton,
+            );
+
             // Scrollable container for recipe items
-            spawn_scrollable_container(content, RecipesItemsContainer, |_| {});
+            spawn_scrollable_container_with_scrollbar(content, RecipesItemsContainer, |_| {});
         });
 
     // Populate with initial recipes (queue command)
     parent.commands().queue(PopulateRecipesDirectCommand {
-        recipes_data: data
-            .recipes
-            .into_iter()
-            .map(|r| (r.id, r.display_name, r.craft_time, r.cost_str, r.can_afford))
-            .collect(),
+        recipes_data: data.recipes,
     });
 }
 
@@ -272,7 +423,7 @@ fn handle_back_button(
 // Tab Switch Handler
 // ============================================================================
 
-#[allow(clippy::type_complexity)]
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 fn handle_tab_switch(
     mut commands: Commands,
     interaction_query: Query<
@@ -281,9 +432,11 @@ fn handle_tab_switch(
     >,
     mut ui_query: Query<&mut RecipesUiRoot>,
     mut tab_buttons: Query<(&RecipeTabButton, &mut BackgroundColor)>,
-    recipe_query: Query<&RecipeNode, With<Available>>,
-    assets: Res<Assets<RecipeDefinition>>,
+    library: RecipeLibrary,
     wallet: Res<Wallet>,
+    crafting_state: Res<CraftingState>,
+    queue: Res<CraftingQueue>,
+    in_progress: Query<&CraftingInProgress>,
 ) {
     for (interaction, tab_btn) in interaction_query.iter() {
         if *interaction == Interaction::Pressed {
@@ -303,12 +456,16 @@ fn handle_tab_switch(
                 }
 
                 // Repopulate recipes
-                let recipes = build_recipe_list(&recipe_query, &assets, &wallet, &tab_btn.category);
+                let recipes = build_recipe_list(
+                    &library,
+                    &wallet,
+                    &crafting_state,
+                    &queue,
+                    &in_progress,
+                    &tab_btn.category,
+                );
                 commands.queue(PopulateRecipesDirectCommand {
-                    recipes_data: recipes
-                        .into_iter()
-                        .map(|r| (r.id, r.display_name, r.craft_time, r.cost_str, r.can_afford))
-                        .collect(),
+                    recipes_data: recipes,
                 });
             }
         }
@@ -319,21 +476,31 @@ fn handle_tab_switch(
 // Update Recipes UI (on resource change)
 // ============================================================================
 
+#[allow(clippy::too_many_arguments)]
 fn update_recipes_ui(
     mut commands: Commands,
-    recipe_query: Query<&RecipeNode, With<Available>>,
-    assets: Res<Assets<RecipeDefinition>>,
+    library: RecipeLibrary,
     wallet: Res<Wallet>,
+    crafting_state: Res<CraftingState>,
+    queue: Res<CraftingQueue>,
+    in_progress: Query<&CraftingInProgress>,
     ui_query: Query<&RecipesUiRoot>,
     mut last_data: Local<Vec<RecipeDisplayData>>,
 ) {
-    // Only update if wallet changed
-    if !wallet.is_changed() {
+    // Only update if wallet, craft counts or the queue changed
+    if !wallet.is_changed() && !crafting_state.is_changed() && !queue.is_changed() {
         return;
     }
 
     if let Ok(ui_root) = ui_query.single() {
-        let recipes = build_recipe_list(&recipe_query, &assets, &wallet, &ui_root.active_tab);
+        let recipes = build_recipe_list(
+            &library,
+            &wallet,
+            &crafting_state,
+            &queue,
+            &in_progress,
+            &ui_root.active_tab,
+        );
 
         if *last_data == recipes {
             return;
@@ -341,21 +508,33 @@ fn update_recipes_ui(
         *last_data = recipes.clone();
 
         commands.queue(PopulateRecipesDirectCommand {
-            recipes_data: recipes
-                .into_iter()
-                .map(|r| (r.id, r.display_name, r.craft_time, r.cost_str, r.can_afford))
-                .collect(),
+            recipes_data: recipes,
         });
     }
 }
 
+/// Advances in-progress craft bars every frame without rebuilding the cards.
+fn update_recipe_progress_bars(
+    in_progress: Query<&CraftingInProgress>,
+    mut fill_query: Query<(&RecipeProgressFill, &mut Node)>,
+) {
+    for (fill, node) in fill_query.iter_mut() {
+        let fraction = in_progress
+            .iter()
+            .filter(|p| p.recipe_id == fill.recipe_id)
+            .map(|p| p.timer.fraction())
+            .fold(0.0, f32::max);
+        set_progress_bar_fraction(node, fraction);
+    }
+}
+
 // ============================================================================
 // Populate Recipes Command
 // ============================================================================
 
 /// Command to populate recipes (deferred execution)
 struct PopulateRecipesDirectCommand {
-    recipes_data: Vec<(String, String, f32, String, bool)>,
+    recipes_data: Vec<RecipeDisplayData>,
 }
 
 impl Command for PopulateRecipesDirectCommand {
@@ -371,95 +550,526 @@ impl Command for PopulateRecipesDirectCommand {
         // Collect children to despawn
         let children_to_despawn: Vec<Entity> =
             children.map(|c| c.iter().collect()).unwrap_or_default();
+        let scroll = widgets::scroll_offset::<RecipesItemsContainer>(world);
+        widgets::record_ui_rebuild(world, "crafting");
 
         // Despawn existing children
         for child in children_to_despawn {
             world.commands().entity(child).despawn();
         }
 
-        // Spawn new recipe cards
+        let groups = group_by_subcategory(self.recipes_data);
+        // A lone default group needs no subheader
+        let show_headers =
+            !matches!(groups.as_slice(), [(label, _)] if label == DEFAULT_SUBCATEGORY);
+
+        // Spawn new recipe cards, grouped under subheaders
         world
             .commands()
             .entity(container_entity)
             .with_children(|parent| {
-                for (recipe_id, display_name, craft_time, cost_str, can_afford) in self.recipes_data
-                {
-                    let card_entity = widgets::spawn_item_card(parent, ());
-                    parent.commands().entity(card_entity).with_children(|card| {
-                        spawn_card_title(card, &display_name);
-                        spawn_timer_text(card, craft_time);
-                        spawn_cost_text(card, &cost_str, can_afford);
-
-                        // Button
-                        let (btn_text, btn_color, btn_border) = if can_afford {
-                            ("Craft", UiTheme::AFFORDABLE, UiTheme::BORDER_SUCCESS)
-                        } else {
-                            ("Craft", UiTheme::BORDER_DISABLED, UiTheme::BORDER_DISABLED)
-                        };
-
-                        spawn_action_button(
-                            card,
-                            btn_text,
-                            btn_color,
-                            btn_border,
-                            CraftingButton {
-                                recipe_id: recipe_id.clone(),
-                            },
-                        );
-                    });
+                for (label, recipes) in groups {
+                    if show_headers {
+                        spawn_subcategory_header(parent, &label);
+                    }
+
+                    for recipe in recipes {
+                        spawn_recipe_card(parent, recipe);
+                    }
                 }
             });
+        world
+            .commands()
+            .queue(RestoreScroll::<RecipesItemsContainer>::new(scroll));
     }
 }
 
+fn spawn_subcategory_header(parent: &mut ChildSpawnerCommands, label: &str) {
+    parent.spawn((
+        Text::new(label),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(UiTheme::TEXT_HEADER),
+        Node {
+            margin: UiRect::vertical(Val::Px(6.0)),
+            ..default()
+        },
+    ));
+}
+
+fn spawn_recipe_card(parent: &mut ChildSpawnerCommands, recipe: RecipeDisplayData) {
+    let card_entity = widgets::spawn_item_card(parent, ());
+    parent.commands().entity(card_entity).with_children(|card| {
+        // Show title with repeat progress if limited
+        let title = match &recipe.progress_info {
+            Some(progress) => format!("{} ({})", recipe.display_name, progress),
+            None => recipe.display_name.clone(),
+        };
+        if recipe.queued > 0 {
+            card.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                ..default()
+            })
+            .with_children(|row| {
+                spawn_card_title(row, &title);
+                let badge = spawn_action_button_with_style(
+                    row,
+                    &format!("x{} queued", recipe.queued),
+                    UiTheme::TEXT_SECONDARY,
+                    UiTheme::CARD_BORDER,
+                    ButtonStyle {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    CancelQueueButton {
+                        recipe_id: recipe.id.clone(),
+                    },
+                );
+                row.commands().entity(badge).insert(Tooltip(
+                    "Click to cancel the queued crafts and refund their cost".to_string(),
+                ));
+            });
+        } else {
+            spawn_card_title(card, &title);
+        }
+        let card_recipe_id = recipe.id.clone();
+        spawn_timer_text(card, recipe.craft_time);
+        // Hovering the cost shows one line per resource
+        card.spawn((
+            Node::default(),
+            Interaction::default(),
+            TooltipLines(recipe.cost_lines.clone()),
+        ))
+        .with_children(|cost| spawn_cost_text(cost, &recipe.cost_str, recipe.can_afford));
+
+        if recipe.crafting {
+            let fill = spawn_progress_bar_ui(card, "Crafting", 0.0);
+            card.commands().entity(fill).insert(RecipeProgressFill {
+                recipe_id: recipe.id.clone(),
+            });
+        }
+
+        // Button
+        let (btn_text, btn_color, btn_border) = if recipe.maxed {
+            ("Maxed", UiTheme::TEXT_DISABLED, UiTheme::BORDER_DISABLED)
+        } else if recipe.can_afford {
+            ("Craft", UiTheme::AFFORDABLE, UiTheme::BORDER_SUCCESS)
+        } else {
+            ("Craft", UiTheme::BORDER_DISABLED, UiTheme::BORDER_DISABLED)
+        };
+
+        let button = spawn_action_button_with_style(
+            card,
+            btn_text,
+            btn_color,
+            btn_border,
+            ButtonStyle::default(),
+            CraftingButton {
+                recipe_id: recipe.id.clone(),
+            },
+        );
+
+        // Show what's missing on hover
+        if let Some(deficit) = recipe.deficit {
+            card.commands().entity(button).insert(Tooltip(deficit));
+        }
+
+        if let Some(count) = recipe.craft_max {
+            let (color, border) = if count > 0 {
+                (UiTheme::AFFORDABLE, UiTheme::BORDER_SUCCESS)
+            } else {
+                (UiTheme::BORDER_DISABLED, UiTheme::BORDER_DISABLED)
+            };
+            let button = spawn_action_button_with_style(
+                card,
+                &format!("Craft Max ({})", count),
+                color,
+                border,
+                ButtonStyle::default(),
+                CraftMaxButton {
+                    recipe_id: card_recipe_id,
+                },
+            );
+            if count == 0 {
+                card.commands()
+                    .entity(button)
+                    .entry::<AnimatedButton>()
+                    .and_modify(|mut anim| anim.disabled = true);
+            }
+        }
+    });
+}
+
 // ============================================================================
 // Crafting Button Handler
 // ============================================================================
 
-#[allow(clippy::type_complexity)]
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 fn handle_crafting_button(
-    mut commands: Commands,
     mut wallet: ResMut<Wallet>,
-    recipe_query: Query<&RecipeNode, With<Available>>,
-    recipe_map: Res<crafting_resources::RecipeMap>,
-    assets: Res<Assets<RecipeDefinition>>,
+    mut queue: ResMut<CraftingQueue>,
+    library: RecipeLibrary,
+    crafting_state: Res<CraftingState>,
+    in_progress: Query<&CraftingInProgress>,
     interaction_query: Query<(&Interaction, &CraftingButton), (Changed<Interaction>, With<Button>)>,
 ) {
     for (interaction, btn) in interaction_query.iter() {
         if *interaction == Interaction::Pressed {
-            // Find recipe entity and get definition
-            let Some(&entity) = recipe_map.entities.get(&btn.recipe_id) else {
+            let Some(def) = library.get_available_def(&btn.recipe_id) else {
                 continue;
             };
 
-            let Ok(node) = recipe_query.get(entity) else {
+            let crafted =
+                crafted_count(&crafting_state, &queue, in_progress.iter(), &btn.recipe_id);
+            if is_recipe_maxed(def, crafted) {
                 continue;
-            };
+            }
 
-            let Some(def) = assets.get(&node.handle) else {
-                continue;
-            };
+            // Deduct the scaled cost if affordable. The craft starts once no other craft
+            // of this recipe is running, see `start_queued_crafts`
+            let cost = def.scaled_cost(crafted);
+            if wallet.try_spend(&cost) {
+                queue.push(&btn.recipe_id, cost);
+                info!("Queued craft of: {}", def.display_name);
+            }
+        }
+    }
+}
 
-            // Check if can afford
-            let can_afford = def
-                .cost
-                .iter()
-                .all(|(res_id, amt)| wallet.resources.get(res_id).copied().unwrap_or(0) >= *amt);
-
-            if can_afford {
-                // Deduct resources
-                for (res_id, amt) in &def.cost {
-                    if let Some(current) = wallet.resources.get_mut(res_id) {
-                        *current -= *amt;
-                    }
-                }
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn handle_craft_max_button(
+    mut wallet: ResMut<Wallet>,
+    mut queue: ResMut<CraftingQueue>,
+    library: RecipeLibrary,
+    crafting_state: Res<CraftingState>,
+    in_progress: Query<&CraftingInProgress>,
+    interaction_query: Query<(&Interaction, &CraftMaxButton), (Changed<Interaction>, With<Button>)>,
+) {
+    for (interaction, btn) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
 
-                // Trigger the crafting request event (observer pattern)
-                commands.trigger(StartCraftingRequest {
-                    recipe_id: btn.recipe_id.clone(),
-                });
-                info!("Sent crafting request for: {}", def.display_name);
+        let Some(def) = library.get_available_def(&btn.recipe_id) else {
+            continue;
+        };
+
+        let crafted = crafted_count(&crafting_state, &queue, in_progress.iter(), &btn.recipe_id);
+        let count = max_affordable_crafts(def, &wallet, crafted);
+
+        // Each queued craft pays its own scaled cost
+        for n in 0..count {
+            let cost = def.scaled_cost(crafted + n);
+            if !wallet.try_spend(&cost) {
+                break;
+            }
+            queue.push(&btn.recipe_id, cost);
+        }
+
+        if count > 0 {
+            info!("Queued {} crafts of: {}", count, def.display_name);
+        }
+    }
+}
+
+fn handle_cancel_queue_button(
+    mut commands: Commands,
+    interaction_query: Query<(&Interaction, &CancelQueueButton), Changed<Interaction>>,
+) {
+    for (interaction, btn) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            commands.trigger(CancelQueuedCrafts {
+                recipe_id: btn.recipe_id.clone(),
+            });
+        }
+    }
+}
+
+fn handle_clear_queue_button(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<ClearCraftingQueueButton>)>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            commands.trigger(ClearCraftingQueue);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crafting::{Available, RecipeNode},
+    };
+
+    fn recipe(id: &str, subcategory: Option<&str>) -> RecipeDisplayData {
+        RecipeDisplayData {
+            id: id.to_string(),
+            display_name: id.to_string(),
+            craft_time: 1.0,
+            cost_str: String::new(),
+            cost_lines: Vec::new(),
+            can_afford: true,
+            progress_info: None,
+            maxed: false,
+            deficit: None,
+            craft_max: None,
+            subcategory: subcategory.map(str::to_string),
+            crafting: false,
+            queued: 0,
+        }
+    }
+
+    fn wall_definition() -> RecipeDefinition {
+        RecipeDefinition {
+            id: "wall".to_string(),
+            display_name: "Build Wall".to_string(),
+            // Repeatable, which a construction never is
+            category: RecipeCategory::Transformation,
+            subcategory: None,
+            craft_time: 10.0,
+            cost: [("stone".to_string(), 10)].into_iter().collect(),
+            outcomes: vec![],
+            max_repeats: Some(5),
+            cost_scaling: 2.0,
+            time_scaling: 1.0,
+            unlock: None,
+        }
+    }
+
+    #[test]
+    fn test_list_rebuilds_are_counted() {
+        let mut world = World::new();
+        world.init_resource::<widgets::UiRebuildStats>();
+        world.spawn((Node::default(), RecipesItemsContainer));
+
+        for _ in 0..2 {
+            PopulateRecipesDirectCommand {
+                recipes_data: vec![recipe("wall", None)],
             }
+            .apply(&mut world);
+            world.flush();
+        }
+
+        let stats = world.resource::<widgets::UiRebuildStats>();
+        assert_eq!(stats.count("crafting"), 2);
+        assert_eq!(stats.total(), 2);
+    }
+
+    #[test]
+    fn test_craft_max_is_disabled_when_nothing_is_affordable() {
+        let mut world = World::new();
+        world.init_resource::<widgets::UiRebuildStats>();
+        world.spawn((Node::default(), RecipesItemsContainer));
+
+        PopulateRecipesDirectCommand {
+            recipes_data: vec![
+                RecipeDisplayData {
+                    craft_max: Some(0),
+                    ..recipe("wall", None)
+                },
+                RecipeDisplayData {
+                    craft_max: Some(2),
+                    ..recipe("bone_dust", None)
+                },
+            ],
         }
+        .apply(&mut world);
+        world.flush();
+
+        let mut buttons = world.query::<(&CraftMaxButton, &AnimatedButton)>();
+        let mut disabled: Vec<_> = buttons
+            .iter(&world)
+            .map(|(btn, anim)| (btn.recipe_id.clone(), anim.disabled))
+            .collect();
+        disabled.sort();
+        assert_eq!(
+            disabled,
+            vec![("bone_dust".to_string(), false), ("wall".to_string(), true)]
+        );
+    }
+
+    #[test]
+    fn test_recipe_display_uses_scaled_cost() {
+        let mut wallet = Wallet::default();
+        wallet.resources.insert("stone".to_string(), 30);
+
+        let data = build_recipe_display_data("wall", &wall_definition(), &wallet, 2);
+
+        assert_eq!(data.progress_info.as_deref(), Some("2/5"));
+        assert_eq!(data.cost_str, "Cost: stone: 30/40 ");
+        assert_eq!(
+            data.cost_lines,
+            vec![TooltipLine {
+                text: "stone: 30/40".to_string(),
+                color: UiTheme::NOT_AFFORDABLE,
+            }]
+        );
+        assert!(!data.can_afford);
+        assert_eq!(data.deficit.as_deref(), Some("Need +10 stone"));
+    }
+
+    #[test]
+    fn test_recipe_at_max_repeats_cannot_be_crafted() {
+        let mut wallet = Wallet::default();
+        wallet.resources.insert("stone".to_string(), u32::MAX);
+
+        let data = build_recipe_display_data("wall", &wall_definition(), &wallet, 5);
+
+        assert!(data.maxed);
+        assert!(!data.can_afford);
+        assert_eq!(data.progress_info.as_deref(), Some("5/5"));
+        assert_eq!(data.deficit, None);
+    }
+
+    #[test]
+    fn test_construction_is_maxed_once_started_or_queued() {
+        let mut def = wall_definition();
+        def.category = RecipeCategory::Construction;
+        def.max_repeats = None;
+        let mut wallet = Wallet::default();
+        wallet.resources.insert("stone".to_string(), 100);
+
+        assert!(!build_recipe_display_data("wall", &def, &wallet, 0).maxed);
+        let queued = build_recipe_display_data("wall", &def, &wallet, 1);
+        assert!(queued.maxed);
+        assert!(!queued.can_afford);
+        assert_eq!(max_affordable_crafts(&def, &wallet, 0), 1);
+    }
+
+    #[test]
+    fn test_craft_max_queues_every_affordable_craft() {
+        let mut app = App::new();
+        app.init_resource::<Wallet>()
+            .init_resource::<CraftingState>()
+            .init_resource::<CraftingQueue>()
+            .init_resource::<crafting_resources::RecipeMap>()
+            .init_resource::<Assets<RecipeDefinition>>()
+            .add_observer(crafting::systems::start_crafting)
+            .add_systems(
+                Update,
+                (
+                    handle_craft_max_button,
+                    crafting::systems::start_queued_crafts,
+                )
+                    .chain(),
+            );
+
+        let mut def = wall_definition();
+        def.id = "bone_dust".to_string();
+        def.max_repeats = None;
+        def.cost_scaling = 1.0;
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<RecipeDefinition>>()
+            .add(def);
+        let recipe = app
+            .world_mut()
+            .spawn((
+                RecipeNode {
+                    id: "bone_dust".to_string(),
+                    handle,
+                },
+                Available,
+            ))
+            .id();
+        app.world_mut()
+            .resource_mut::<crafting_resources::RecipeMap>()
+            .entities
+            .insert("bone_dust".to_string(), recipe);
+
+        // Enough for exactly 3 crafts at 10 stone each
+        app.world_mut()
+            .resource_mut::<Wallet>()
+            .resources
+            .insert("stone".to_string(), 30);
+
+        app.world_mut().spawn((
+            Button,
+            Interaction::Pressed,
+            CraftMaxButton {
+                recipe_id: "bone_dust".to_string(),
+            },
+        ));
+        app.update();
+
+        // All three are paid for; one runs while the other two wait their turn
+        let running = app
+            .world_mut()
+            .query::<&CraftingInProgress>()
+            .iter(app.world())
+            .count();
+        assert_eq!(running, 1);
+        assert_eq!(
+            app.world().resource::<CraftingQueue>().count("bone_dust"),
+            2
+        );
+        assert_eq!(app.world().resource::<Wallet>().resources["stone"], 0);
+    }
+
+    #[test]
+    fn test_craft_max_is_limited_by_the_scarcest_resource() {
+        let mut def = wall_definition();
+        def.cost = [("stone".to_string(), 10), ("wood".to_string(), 4)]
+            .into_iter()
+            .collect();
+        def.max_repeats = None;
+        def.cost_scaling = 1.0;
+        let mut wallet = Wallet::default();
+        wallet.resources.insert("stone".to_string(), 100);
+        wallet.resources.insert("wood".to_string(), 9);
+
+        assert_eq!(max_affordable_crafts(&def, &wallet, 0), 2);
+
+        wallet.resources.insert("wood".to_string(), 3);
+        assert_eq!(max_affordable_crafts(&def, &wallet, 0), 0);
+    }
+
+    #[test]
+    fn test_format_deficit_lists_missing_resources() {
+        let mut wallet = Wallet::default();
+        wallet.resources.insert("bones".to_string(), 2);
+        wallet.resources.insert("wood".to_string(), 10);
+
+        let cost: HashMap<String, u32> = [
+            ("bones".to_string(), 5),
+            ("entropy".to_string(), 1),
+            ("wood".to_string(), 4),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            format_deficit(&cost, &wallet),
+            Some("Need +3 bones, +1 entropy".to_string())
+        );
+
+        wallet.resources.insert("bones".to_string(), 5);
+        wallet.resources.insert("entropy".to_string(), 1);
+        assert_eq!(format_deficit(&cost, &wallet), None);
+    }
+
+    #[test]
+    fn test_group_by_subcategory_sorts_headers() {
+        let recipes = vec![
+            recipe("bone_bow", Some("Ranged")),
+            recipe("bone_dust", None),
+            recipe("bone_sword", Some("Melee")),
+            recipe("primitive_sling", Some("Ranged")),
+        ];
+
+        let groups = group_by_subcategory(recipes);
+
+        let headers: Vec<_> = groups.iter().map(|(label, _)| label.as_str()).collect();
+        assert_eq!(headers, vec!["Melee", "Ranged", DEFAULT_SUBCATEGORY]);
+
+        let ranged: Vec<_> = groups[1].1.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ranged, vec!["bone_bow", "primitive_sling"]);
+        assert_eq!(groups[2].1[0].id, "bone_dust");
     }
 }
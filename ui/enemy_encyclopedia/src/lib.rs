@@ -1,22 +1,48 @@
 use {
-    bevy::prelude::*,
+    bevy::{
+        input::{
+            ButtonState,
+            keyboard::{Key, KeyboardInput},
+        },
+        prelude::*,
+    },
     bonus_stats_resources::{BonusStat, BonusStats},
-    enemy_resources::EnemyDetailsCache,
+    enemy_resources::{EnemyDetailsCache, EnemyStatBlock},
+    hero_components::{Weapon, WeaponTags},
+    shared_components::DisplayName,
     states::{GameState, VillageView},
     village_components::EnemyEncyclopedia,
     wallet::Wallet,
-    widgets::{ContentContainer, spawn_menu_button},
+    wallet_events::ResourceUnlocked,
+    widgets::{
+        ButtonStyle, ClearContentContainer, ContentContainer, RestoreScroll, Tooltip,
+        UiRebuildStats, UiTheme, spawn_action_button_with_style, spawn_menu_button,
+    },
 };
 
 pub struct EnemyEncyclopediaUiPlugin;
 
 impl Plugin for EnemyEncyclopediaUiPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(VillageView::Encyclopedia), spawn_encyclopedia_ui)
+        app.init_resource::<ThreatConfig>()
+            .init_resource::<BonusNotation>()
+            .init_resource::<EncyclopediaFilter>()
+            .add_observer(refresh_on_resource_unlocked)
+            .add_systems(OnEnter(VillageView::Encyclopedia), spawn_encyclopedia_ui)
             .add_systems(
                 Update,
                 handle_back_button.run_if(in_state(GameState::Running)),
             )
+            .add_systems(
+                Update,
+                (
+                    handle_notation_toggle,
+                    handle_encounter_filter_buttons,
+                    handle_sort_buttons,
+                    handle_search_input,
+                )
+                    .run_if(in_state(VillageView::Encyclopedia)),
+            )
             .add_systems(
                 Update,
                 update_encyclopedia_ui.run_if(in_state(VillageView::Encyclopedia)),
@@ -31,23 +57,202 @@ struct VillageBackButton;
 #[derive(Component)]
 pub struct EncyclopediaListContainer;
 
+/// Switches between compact and verbose bonus notation
+#[derive(Component)]
+pub struct BonusNotationToggle;
+
+/// Which encounter outcomes the card grid shows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EncounterFilter {
+    #[default]
+    All,
+    /// Enemies killed at least once
+    Killed,
+    /// Enemies that escaped but were never killed
+    EscapedOnly,
+}
+
+/// Order of the cards in the encyclopedia grid
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortMode {
+    /// First encountered first
+    #[default]
+    Order,
+    /// Most kills first
+    Kills,
+    /// Most escapes first
+    Escapes,
+    /// Alphabetical by display name
+    Name,
+}
+
+impl SortMode {
+    pub const ALL: [SortMode; 4] = [
+        SortMode::Order,
+        SortMode::Kills,
+        SortMode::Escapes,
+        SortMode::Name,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SortMode::Order => "Order",
+            SortMode::Kills => "Kills",
+            SortMode::Escapes => "Escapes",
+            SortMode::Name => "Name",
+        }
+    }
+
+    /// Sorts entries by this mode, ties keep `encounter_order`.
+    pub fn sort(self, entries: &mut [(&String, &village_components::EncyclopediaEntry)]) {
+        entries.sort_by_key(|(_, entry)| entry.encounter_order);
+        match self {
+            SortMode::Order => {}
+            SortMode::Kills => {
+                entries.sort_by_key(|(_, entry)| std::cmp::Reverse(entry.kill_count))
+            }
+            SortMode::Escapes => {
+                entries.sort_by_key(|(_, entry)| std::cmp::Reverse(entry.escape_count))
+            }
+            SortMode::Name => entries.sort_by_key(|(_, entry)| entry.display_name.to_lowercase()),
+        }
+    }
+}
+
+/// Active search text, encounter filter and sort order for the encyclopedia grid.
+/// The default (empty search, `All`, `Order`) shows every discovered entry in
+/// encounter order.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct EncyclopediaFilter {
+    pub search: String,
+    pub encounter: EncounterFilter,
+    pub sort: SortMode,
+}
+
+impl EncyclopediaFilter {
+    /// Longest search text accepted from the keyboard
+    pub const MAX_SEARCH_LEN: usize = 24;
+
+    /// True if `entry` matches the search (case-insensitive substring of its
+    /// display name) and the encounter filter.
+    pub fn matches(&self, entry: &village_components::EncyclopediaEntry) -> bool {
+        let encounter_matches = match self.encounter {
+            EncounterFilter::All => true,
+            EncounterFilter::Killed => entry.kill_count > 0,
+            EncounterFilter::EscapedOnly => entry.kill_count == 0 && entry.escape_count > 0,
+        };
+        encounter_matches
+            && entry
+                .display_name
+                .to_lowercase()
+                .contains(&self.search.to_lowercase())
+    }
+}
+
+/// Toggles the given encounter filter, pressing the active one shows all again
+#[derive(Component)]
+pub struct EncounterFilterButton(pub EncounterFilter);
+
+/// Selects the `SortMode` of the card grid
+#[derive(Component)]
+pub struct SortButton(pub SortMode);
+
+/// Shows the search text typed while the encyclopedia is open
+#[derive(Component)]
+pub struct EncyclopediaSearchBox;
+
+/// Explains the compact "+A/P%/*M" bonus notation on hover
+pub const BONUS_LEGEND: &str =
+    "+A/P%/*M: A = flat damage added, P% = percent increase, *M = damage multiplier";
+
+/// How damage bonuses are written on encyclopedia cards.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BonusNotation {
+    /// "+5 flat, +50%, x2"
+    #[default]
+    Verbose,
+    /// "+5/50%/*2"
+    Compact,
+}
+
+impl BonusNotation {
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Verbose => Self::Compact,
+            Self::Compact => Self::Verbose,
+        }
+    }
+
+    pub fn format(self, bonus: &BonusStat) -> String {
+        match self {
+            Self::Verbose => format_bonus_verbose(bonus),
+            Self::Compact => format_bonus(bonus),
+        }
+    }
+}
+
+/// Tunable weights for `threat_score`.
+#[derive(Resource, Debug, Clone)]
+pub struct ThreatConfig {
+    pub health_weight: f32,
+    pub speed_weight: f32,
+    pub armor_weight: f32,
+    pub shield_weight: f32,
+    /// Exponent applied to the player's damage multiplier against the enemy;
+    /// 0.0 ignores bonuses, 1.0 divides the threat by the full multiplier
+    pub bonus_weight: f32,
+    /// Score rendered fully red; lower scores fade towards green
+    pub max_threat: f32,
+}
+
+impl Default for ThreatConfig {
+    fn default() -> Self {
+        Self {
+            health_weight: 1.0,
+            speed_weight: 0.5,
+            armor_weight: 2.0,
+            shield_weight: 1.0,
+            bonus_weight: 1.0,
+            max_threat: 100.0,
+        }
+    }
+}
+
+/// Display name and tags of a weapon owned by the player.
+pub type OwnedWeapon = (String, Vec<String>);
+
+/// Collects the player's weapons, sorted by name.
+fn collect_owned_weapons(
+    weapons_query: &Query<(&DisplayName, &WeaponTags), With<Weapon>>,
+) -> Vec<OwnedWeapon> {
+    let mut weapons: Vec<OwnedWeapon> = weapons_query
+        .iter()
+        .map(|(name, tags)| (name.0.clone(), tags.0.clone()))
+        .collect();
+    weapons.sort();
+    weapons.dedup();
+    weapons
+}
+
+#[allow(clippy::too_many_arguments)]
 fn spawn_encyclopedia_ui(
     mut commands: Commands,
-    mut query: Query<(Entity, Option<&Children>), With<ContentContainer>>,
+    query: Query<Entity, With<ContentContainer>>,
     encyclopedia_query: Query<&EnemyEncyclopedia>,
     details_cache: Res<EnemyDetailsCache>,
     wallet: Res<Wallet>,
     bonus_stats: Res<BonusStats>,
+    threat_config: Res<ThreatConfig>,
+    notation: Res<BonusNotation>,
+    filter: Res<EncyclopediaFilter>,
+    weapons_query: Query<(&DisplayName, &WeaponTags), With<Weapon>>,
 ) {
-    let Some((container, children)) = query.iter_mut().next() else {
+    let Some(container) = query.iter().next() else {
         return;
     };
 
-    // Despawn existing children
-    let to_despawn: Vec<Entity> = children.map(|c| c.iter().collect()).unwrap_or_default();
-    for child in to_despawn {
-        commands.entity(child).despawn();
-    }
+    // Clear the previous view, cached village views are only hidden
+    commands.queue(ClearContentContainer(container));
 
     let Some(encyclopedia) = encyclopedia_query.iter().next() else {
         return;
@@ -65,6 +270,10 @@ fn spawn_encyclopedia_ui(
             &details_cache,
             &wallet,
             &bonus_stats,
+            &threat_config,
+            *notation,
+            &filter,
+            &collect_owned_weapons(&weapons_query),
         );
     });
 }
@@ -81,30 +290,48 @@ fn handle_back_button(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn spawn_enemy_encyclopedia_content(
     parent: &mut ChildSpawnerCommands,
     encyclopedia: &EnemyEncyclopedia,
     details_cache: &EnemyDetailsCache,
     wallet: &Wallet,
     bonus_stats: &BonusStats,
+    threat_config: &ThreatConfig,
+    notation: BonusNotation,
+    filter: &EncyclopediaFilter,
+    weapons: &[OwnedWeapon],
 ) {
     // Collect and sort entries
-    let mut entries: Vec<(&String, &village_components::EncyclopediaEntry)> =
-        encyclopedia.inner.iter().collect();
-    entries.sort_by_key(|(_, entry)| entry.encounter_order);
+    let entries = encyclopedia.discovered_entries();
 
     // Use widgets scrollable container
     widgets::spawn_scrollable_container(parent, EncyclopediaListContainer, |scroll_content| {
-        populate_encyclopedia_list(scroll_content, &entries, details_cache, wallet, bonus_stats);
+        populate_encyclopedia_list(
+            scroll_content,
+            &entries,
+            details_cache,
+            wallet,
+            bonus_stats,
+            threat_config,
+            notation,
+            filter,
+            weapons,
+        );
     });
 }
 
+#[allow(clippy::too_many_arguments)]
 fn populate_encyclopedia_list(
     parent: &mut ChildSpawnerCommands,
     entries: &[(&String, &village_components::EncyclopediaEntry)],
     details_cache: &EnemyDetailsCache,
     wallet: &Wallet,
     bonus_stats: &BonusStats,
+    threat_config: &ThreatConfig,
+    notation: BonusNotation,
+    filter: &EncyclopediaFilter,
+    weapons: &[OwnedWeapon],
 ) {
     parent
         .spawn((Node {
@@ -114,19 +341,46 @@ fn populate_encyclopedia_list(
             ..default()
         },))
         .with_children(|list| {
-            // Title
-            list.spawn((
-                Text::new("Enemy Encyclopedia"),
-                TextFont {
-                    font_size: 24.0,
-                    ..default()
-                },
-                TextColor(Color::WHITE),
-                Node {
-                    margin: UiRect::bottom(Val::Px(10.0)),
-                    ..default()
-                },
-            ));
+            // Title with the bonus notation toggle
+            list.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(10.0),
+                margin: UiRect::bottom(Val::Px(10.0)),
+                ..default()
+            })
+            .with_children(|title_row| {
+                title_row.spawn((
+                    Text::new("Enemy Encyclopedia"),
+                    TextFont {
+                        font_size: 24.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+                let label = match notation {
+                    BonusNotation::Verbose => "Compact",
+                    BonusNotation::Compact => "Verbose",
+                };
+                let toggle = spawn_action_button_with_style(
+                    title_row,
+                    label,
+                    UiTheme::TEXT_PRIMARY,
+                    UiTheme::CARD_BORDER,
+                    ButtonStyle {
+                        width: Val::Px(90.0),
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    BonusNotationToggle,
+                );
+                title_row
+                    .commands()
+                    .entity(toggle)
+                    .insert(Tooltip(BONUS_LEGEND.to_string()));
+            });
+
+            spawn_filter_row(list, filter);
 
             // Grid Container for cards
             list.spawn(Node {
@@ -139,13 +393,34 @@ fn populate_encyclopedia_list(
             })
             .with_children(|grid| {
                 // List of enemies
-                for (enemy_id, entry) in entries {
-                    spawn_enemy_card(grid, entry, enemy_id, details_cache, wallet, bonus_stats);
+                let mut visible: Vec<_> = entries
+                    .iter()
+                    .filter(|(_, entry)| filter.matches(entry))
+                    .copied()
+                    .collect();
+                filter.sort.sort(&mut visible);
+                for (enemy_id, entry) in &visible {
+                    spawn_enemy_card(
+                        grid,
+                        entry,
+                        enemy_id,
+                        details_cache,
+                        wallet,
+                        bonus_stats,
+                        threat_config,
+                        notation,
+                        weapons,
+                    );
                 }
 
-                if entries.is_empty() {
+                if visible.is_empty() {
+                    let message = if entries.is_empty() {
+                        "No enemies encountered yet."
+                    } else {
+                        "No enemies match the filter."
+                    };
                     grid.spawn((
-                        Text::new("No enemies encountered yet."),
+                        Text::new(message),
                         TextColor(Color::srgb(0.5, 0.5, 0.5)),
                         TextFont {
                             font_size: 16.0,
@@ -157,6 +432,115 @@ fn populate_encyclopedia_list(
         });
 }
 
+/// Search box, encounter filter toggles and sort buttons above the card grid.
+fn spawn_filter_row(parent: &mut ChildSpawnerCommands, filter: &EncyclopediaFilter) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(10.0),
+            margin: UiRect::bottom(Val::Px(10.0)),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Node {
+                    min_width: Val::Px(220.0),
+                    padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BackgroundColor(UiTheme::CARD_BG),
+                BorderColor::all(UiTheme::CARD_BORDER),
+            ))
+            .with_children(|search_box| {
+                search_box.spawn((
+                    Text::new(search_label(&filter.search)),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(if filter.search.is_empty() {
+                        UiTheme::TEXT_DISABLED
+                    } else {
+                        UiTheme::TEXT_PRIMARY
+                    }),
+                    EncyclopediaSearchBox,
+                ));
+            });
+
+            for (encounter, label) in [
+                (EncounterFilter::Killed, "Killed"),
+                (EncounterFilter::EscapedOnly, "Escaped only"),
+            ] {
+                let border = if filter.encounter == encounter {
+                    UiTheme::BORDER_SUCCESS
+                } else {
+                    UiTheme::CARD_BORDER
+                };
+                spawn_action_button_with_style(
+                    row,
+                    label,
+                    UiTheme::TEXT_PRIMARY,
+                    border,
+                    ButtonStyle {
+                        width: Val::Px(110.0),
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    EncounterFilterButton(encounter),
+                );
+            }
+        });
+
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(6.0),
+            margin: UiRect::bottom(Val::Px(10.0)),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Text::new("Sort:"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(UiTheme::TEXT_SECONDARY),
+            ));
+            for mode in SortMode::ALL {
+                let border = if filter.sort == mode {
+                    UiTheme::BORDER_SUCCESS
+                } else {
+                    UiTheme::CARD_BORDER
+                };
+                spawn_action_button_with_style(
+                    row,
+                    mode.label(),
+                    UiTheme::TEXT_PRIMARY,
+                    border,
+                    ButtonStyle {
+                        width: Val::Px(80.0),
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    SortButton(mode),
+                );
+            }
+        });
+}
+
+fn search_label(search: &str) -> String {
+    if search.is_empty() {
+        "Type to search...".to_string()
+    } else {
+        format!("Search: {}_", search)
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn spawn_enemy_card(
     parent: &mut ChildSpawnerCommands,
     entry: &village_components::EncyclopediaEntry,
@@ -164,6 +548,9 @@ fn spawn_enemy_card(
     details_cache: &EnemyDetailsCache,
     wallet: &Wallet,
     bonus_stats: &BonusStats,
+    threat_config: &ThreatConfig,
+    notation: BonusNotation,
+    weapons: &[OwnedWeapon],
 ) {
     parent
         .spawn(Node {
@@ -240,6 +627,16 @@ fn spawn_enemy_card(
                         },
                     ));
 
+                    let threat = threat_score(details, bonus_stats, threat_config);
+                    details_node.spawn((
+                        Text::new(format!("⚠ Threat: {:.0}", threat)),
+                        TextColor(threat_color(threat, threat_config)),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                    ));
+
                     if !details.drops.is_empty() {
                         details_node.spawn((
                             Text::new("Drops:"),
@@ -254,7 +651,7 @@ fn spawn_enemy_card(
                             },
                         ));
                         for drop in &details.drops {
-                            let drop_text = if wallet.unlocked_resources.contains(drop) {
+                            let drop_text = if wallet.is_discovered(drop) {
                                 format!(" • {}", drop)
                             } else {
                                 " • Unidentified".to_string()
@@ -271,33 +668,41 @@ fn spawn_enemy_card(
                     }
 
                     // Bonus Stats
-                    let total = details.tags.iter().fold(BonusStat::default(), |acc, tag| {
-                        acc + bonus_stats
-                            .get_with_prefix("damage", tag)
-                            .cloned()
-                            .unwrap_or_default()
-                    });
-
-                    let mult_val = total.multiplicative.max(1.0);
-                    let text = format!(
-                        "Bonus: +{}/{:.0}%/*{}",
-                        total.additive,
-                        total.percent * 100.0,
-                        mult_val
-                    );
+                    let total = bonus_stats.total_for_tags("damage", &details.tags);
+                    let text = format!("Bonus: {}", notation.format(&total));
 
-                    details_node.spawn((
-                        Text::new(text),
-                        TextColor(Color::srgb(1.0, 0.5, 0.5)), // Red-ish for damage?
-                        TextFont {
-                            font_size: 14.0,
-                            ..default()
-                        },
-                        Node {
-                            margin: UiRect::top(Val::Px(5.0)),
-                            ..default()
-                        },
-                    ));
+                    // Hovering the line explains the notation
+                    details_node
+                        .spawn((
+                            Node {
+                                margin: UiRect::top(Val::Px(5.0)),
+                                ..default()
+                            },
+                            Interaction::default(),
+                            Tooltip(BONUS_LEGEND.to_string()),
+                        ))
+                        .with_children(|bonus_line| {
+                            bonus_line.spawn((
+                                Text::new(text),
+                                TextColor(Color::srgb(1.0, 0.5, 0.5)), // Red-ish for damage?
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                            ));
+                        });
+
+                    // What the player's own weapons get against this enemy
+                    for note in weapon_bonus_notes(weapons, &details.tags, bonus_stats, notation) {
+                        details_node.spawn((
+                            Text::new(note),
+                            TextColor(Color::srgb(1.0, 0.7, 0.5)),
+                            TextFont {
+                                font_size: 12.0,
+                                ..default()
+                            },
+                        ));
+                    }
                 });
             } else {
                 // Locked info
@@ -317,33 +722,139 @@ fn spawn_enemy_card(
         });
 }
 
+/// Flips the bonus notation and redraws the cards with it.
+fn handle_notation_toggle(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<BonusNotationToggle>)>,
+    mut notation: ResMut<BonusNotation>,
+    mut encyclopedia_query: Query<&mut EnemyEncyclopedia>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            *notation = notation.toggled();
+            for mut encyclopedia in encyclopedia_query.iter_mut() {
+                encyclopedia.set_changed();
+            }
+        }
+    }
+}
+
+fn handle_encounter_filter_buttons(
+    interaction_query: Query<(&Interaction, &EncounterFilterButton), Changed<Interaction>>,
+    mut filter: ResMut<EncyclopediaFilter>,
+    mut encyclopedia_query: Query<&mut EnemyEncyclopedia>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            filter.encounter = if filter.encounter == button.0 {
+                EncounterFilter::All
+            } else {
+                button.0
+            };
+            for mut encyclopedia in encyclopedia_query.iter_mut() {
+                encyclopedia.set_changed();
+            }
+        }
+    }
+}
+
+fn handle_sort_buttons(
+    interaction_query: Query<(&Interaction, &SortButton), Changed<Interaction>>,
+    mut filter: ResMut<EncyclopediaFilter>,
+    mut encyclopedia_query: Query<&mut EnemyEncyclopedia>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed && filter.sort != button.0 {
+            filter.sort = button.0;
+            for mut encyclopedia in encyclopedia_query.iter_mut() {
+                encyclopedia.set_changed();
+            }
+        }
+    }
+}
+
+/// Typing while the encyclopedia is open edits the search text.
+fn handle_search_input(
+    mut keyboard_events: MessageReader<KeyboardInput>,
+    mut filter: ResMut<EncyclopediaFilter>,
+    mut encyclopedia_query: Query<&mut EnemyEncyclopedia>,
+) {
+    let mut search = filter.search.clone();
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Character(chars) => {
+                for c in chars.chars().filter(|c| !c.is_control()) {
+                    if search.chars().count() < EncyclopediaFilter::MAX_SEARCH_LEN {
+                        search.push(c);
+                    }
+                }
+            }
+            Key::Space if search.chars().count() < EncyclopediaFilter::MAX_SEARCH_LEN => {
+                search.push(' ');
+            }
+            Key::Backspace => {
+                search.pop();
+            }
+            _ => {}
+        }
+    }
+
+    if search != filter.search {
+        filter.search = search;
+        for mut encyclopedia in encyclopedia_query.iter_mut() {
+            encyclopedia.set_changed();
+        }
+    }
+}
+
+/// Newly unlocked resources reveal "Unidentified" drops, so redraw the cards.
+fn refresh_on_resource_unlocked(
+    _trigger: On<ResourceUnlocked>,
+    mut encyclopedia_query: Query<&mut EnemyEncyclopedia>,
+) {
+    for mut encyclopedia in encyclopedia_query.iter_mut() {
+        encyclopedia.set_changed();
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn update_encyclopedia_ui(
     mut commands: Commands,
     encyclopedia_query: Query<&EnemyEncyclopedia, Changed<EnemyEncyclopedia>>,
     details_cache: Res<EnemyDetailsCache>,
     wallet: Res<Wallet>,
     bonus_stats: Res<BonusStats>,
-    container_query: Query<(Entity, &Children), With<EncyclopediaListContainer>>,
+    threat_config: Res<ThreatConfig>,
+    notation: Res<BonusNotation>,
+    filter: Res<EncyclopediaFilter>,
+    weapons_query: Query<(&DisplayName, &WeaponTags), With<Weapon>>,
+    container_query: Query<
+        (Entity, &Children, Option<&ScrollPosition>),
+        With<EncyclopediaListContainer>,
+    >,
+    mut rebuild_stats: ResMut<UiRebuildStats>,
 ) {
     let Some(encyclopedia) = encyclopedia_query.iter().next() else {
         return;
     };
 
-    let Some((container, children)) = container_query.iter().next() else {
+    let Some((container, children, scroll)) = container_query.iter().next() else {
         return;
     };
 
     // Despawn old content
+    rebuild_stats.record("encyclopedia");
     for &child in children {
         commands.entity(child).despawn();
     }
 
     // Collect and sort entries
-    let mut entries: Vec<(&String, &village_components::EncyclopediaEntry)> =
-        encyclopedia.inner.iter().collect();
-    entries.sort_by_key(|(_, entry)| entry.encounter_order);
+    let entries = encyclopedia.discovered_entries();
 
     // Repopulate
+    let weapons = collect_owned_weapons(&weapons_query);
     commands.entity(container).with_children(|scroll_content| {
         populate_encyclopedia_list(
             scroll_content,
@@ -351,6 +862,274 @@ fn update_encyclopedia_ui(
             &details_cache,
             &wallet,
             &bonus_stats,
+            &threat_config,
+            *notation,
+            &filter,
+            &weapons,
         );
     });
+    let scroll = scroll.map_or(Vec2::ZERO, |position| position.0);
+    commands.queue(RestoreScroll::<EncyclopediaListContainer>::new(scroll));
+}
+
+/// Rates how dangerous an enemy is from its base stats, reduced by the player's
+/// damage bonuses against its tags.
+pub fn threat_score(
+    details: &EnemyStatBlock,
+    bonus_stats: &BonusStats,
+    config: &ThreatConfig,
+) -> f32 {
+    let base = details.health * config.health_weight
+        + details.speed * config.speed_weight
+        + details.armor * config.armor_weight
+        + details.shield * config.shield_weight;
+
+    let bonus = bonus_stats.total_for_tags("damage", &details.tags);
+    let damage_multiplier =
+        ((1.0 + bonus.total_percent()) * bonus.multiplicative.max(1.0)).max(1.0);
+
+    (base / damage_multiplier.powf(config.bonus_weight)).max(0.0)
+}
+
+/// Green for harmless enemies, fading to red at `ThreatConfig::max_threat`.
+fn threat_color(score: f32, config: &ThreatConfig) -> Color {
+    let t = (score / config.max_threat).clamp(0.0, 1.0);
+    Color::srgb(0.4, 1.0, 0.4).mix(&Color::srgb(1.0, 0.3, 0.3), t)
+}
+
+/// Compact "+A/P%/*M" notation, see `BONUS_LEGEND`.
+fn format_bonus(bonus: &BonusStat) -> String {
+    format!(
+        "+{}/{:.0}%/*{}",
+        bonus.additive,
+        bonus.total_percent() * 100.0,
+        bonus.multiplicative.max(1.0)
+    )
+}
+
+/// Spells out the non-zero parts of a bonus, e.g. "+5 flat, +50%, x2".
+pub fn format_bonus_verbose(bonus: &BonusStat) -> String {
+    let mut parts = Vec::new();
+    if bonus.additive != 0.0 {
+        parts.push(format!("{:+} flat", bonus.additive));
+    }
+    if bonus.total_percent() != 0.0 {
+        parts.push(format!("{:+.0}%", bonus.total_percent() * 100.0));
+    }
+    if bonus.multiplicative > 1.0 {
+        parts.push(format!("x{}", bonus.multiplicative));
+    }
+
+    if parts.is_empty() {
+        "none".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
+
+/// Builds one "Your {weapon} gets ... here" line per owned weapon that has any damage bonus
+/// against an enemy with the given tags.
+pub fn weapon_bonus_notes(
+    weapons: &[OwnedWeapon],
+    enemy_tags: &[String],
+    bonus_stats: &BonusStats,
+    notation: BonusNotation,
+) -> Vec<String> {
+    let enemy_bonus = bonus_stats.total_for_tags("damage", enemy_tags);
+
+    weapons
+        .iter()
+        .filter_map(|(name, tags)| {
+            let total = enemy_bonus + bonus_stats.total_for_tags("damage", tags);
+            let has_bonus =
+                total.additive != 0.0 || total.total_percent() != 0.0 || total.multiplicative > 1.0;
+            has_bonus.then(|| format!("Your {} gets {} here", name, notation.format(&total)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        bonus_stats_resources::{StatBonus, StatMode},
+    };
+
+    fn stat_block(health: f32, speed: f32) -> EnemyStatBlock {
+        EnemyStatBlock {
+            health,
+            speed,
+            armor: 0.0,
+            shield: 0.0,
+            drops: vec![],
+            tags: vec!["goblin".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_threat_score_ranks_stronger_enemies_higher() {
+        let bonus_stats = BonusStats::default();
+        let config = ThreatConfig::default();
+
+        let weak = threat_score(&stat_block(10.0, 20.0), &bonus_stats, &config);
+        let strong = threat_score(&stat_block(40.0, 60.0), &bonus_stats, &config);
+
+        assert!(strong > weak);
+        assert_eq!(
+            weak,
+            threat_score(&stat_block(10.0, 20.0), &bonus_stats, &config)
+        );
+    }
+
+    #[test]
+    fn test_threat_score_reduced_by_damage_bonus() {
+        let mut bonus_stats = BonusStats::default();
+        let config = ThreatConfig::default();
+        let enemy = stat_block(40.0, 60.0);
+        let before = threat_score(&enemy, &bonus_stats, &config);
+
+        bonus_stats.add(
+            "damage:goblin",
+            StatBonus {
+                value: 1.0,
+                mode: StatMode::Percent,
+            },
+        );
+
+        assert_eq!(threat_score(&enemy, &bonus_stats, &config), before / 2.0);
+    }
+
+    #[test]
+    fn test_weapon_bonus_notes_cross_reference() {
+        let mut bonus_stats = BonusStats::default();
+        bonus_stats.add(
+            "damage:fire",
+            StatBonus {
+                value: 0.5,
+                mode: StatMode::Percent,
+            },
+        );
+        bonus_stats.add(
+            "damage:undead",
+            StatBonus {
+                value: 2.0,
+                mode: StatMode::Additive,
+            },
+        );
+
+        let weapons = vec![
+            (
+                "Fire Staff".to_string(),
+                vec!["damage".to_string(), "damage:fire".to_string()],
+            ),
+            ("Stick".to_string(), vec!["damage".to_string()]),
+        ];
+
+        // Only the fire weapon benefits against a plain enemy
+        let notes = weapon_bonus_notes(
+            &weapons,
+            &["goblin".to_string()],
+            &bonus_stats,
+            BonusNotation::Compact,
+        );
+        assert_eq!(notes, vec!["Your Fire Staff gets +0/50%/*1 here"]);
+
+        // Enemy tag bonuses apply to every weapon
+        let notes = weapon_bonus_notes(
+            &weapons,
+            &["undead".to_string()],
+            &bonus_stats,
+            BonusNotation::Compact,
+        );
+        assert_eq!(
+            notes,
+            vec![
+                "Your Fire Staff gets +2/50%/*1 here",
+                "Your Stick gets +2/0%/*1 here",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verbose_bonus_notation() {
+        let bonus = BonusStat {
+            additive: 5.0,
+            percent: 0.5,
+            multiplicative: 2.0,
+            ..default()
+        };
+        assert_eq!(format_bonus_verbose(&bonus), "+5 flat, +50%, x2");
+        assert_eq!(BonusNotation::Compact.format(&bonus), "+5/50%/*2");
+
+        // Zero parts are left out
+        let percent_only = BonusStat {
+            percent: 0.25,
+            ..default()
+        };
+        assert_eq!(BonusNotation::Verbose.format(&percent_only), "+25%");
+        assert_eq!(format_bonus_verbose(&BonusStat::default()), "none");
+
+        let malus = BonusStat {
+            additive: -3.0,
+            ..default()
+        };
+        assert_eq!(format_bonus_verbose(&malus), "-3 flat");
+    }
+
+    fn entry(name: &str, kills: u64, escapes: u64) -> village_components::EncyclopediaEntry {
+        village_components::EncyclopediaEntry {
+            display_name: name.to_string(),
+            kill_count: kills,
+            escape_count: escapes,
+            encounter_order: 0,
+        }
+    }
+
+    #[test]
+    fn test_encyclopedia_filter_matches() {
+        let goblin = entry("Goblin Scout", 3, 1);
+        let bat = entry("Cave Bat", 0, 2);
+
+        // The empty filter keeps everything
+        let mut filter = EncyclopediaFilter::default();
+        assert!(filter.matches(&goblin) && filter.matches(&bat));
+
+        filter.search = "gOBlin".to_string();
+        assert!(filter.matches(&goblin));
+        assert!(!filter.matches(&bat));
+
+        filter.search.clear();
+        filter.encounter = EncounterFilter::Killed;
+        assert!(filter.matches(&goblin));
+        assert!(!filter.matches(&bat));
+
+        filter.encounter = EncounterFilter::EscapedOnly;
+        assert!(!filter.matches(&goblin));
+        assert!(filter.matches(&bat));
+    }
+
+    #[test]
+    fn test_sort_modes() {
+        let ids: Vec<String> = ["rat", "goblin", "bat"].map(String::from).to_vec();
+        let mut rat = entry("Rat", 2, 0);
+        rat.encounter_order = 0;
+        let mut goblin = entry("Goblin", 5, 1);
+        goblin.encounter_order = 1;
+        let mut bat = entry("bat", 2, 4);
+        bat.encounter_order = 2;
+        let entries = vec![(&ids[0], &rat), (&ids[1], &goblin), (&ids[2], &bat)];
+
+        let sorted = |mode: SortMode| {
+            let mut sorted = entries.clone();
+            sorted.reverse();
+            mode.sort(&mut sorted);
+            sorted.iter().map(|(id, _)| id.as_str()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(sorted(SortMode::Order), vec!["rat", "goblin", "bat"]);
+        // Equal kill counts keep encounter order
+        assert_eq!(sorted(SortMode::Kills), vec!["goblin", "rat", "bat"]);
+        assert_eq!(sorted(SortMode::Escapes), vec!["bat", "goblin", "rat"]);
+        assert_eq!(sorted(SortMode::Name), vec!["bat", "goblin", "rat"]);
+    }
 }
@@ -0,0 +1,356 @@
+//! Stat comparison between the equipped weapon and a candidate in the equipment popup.
+
+use {crate::WeaponDisplayData, bevy::prelude::*, widgets::UiTheme};
+
+/// Side panel in the equipment popup comparing the equipped weapon with a hovered one
+#[derive(Component)]
+pub struct WeaponComparisonPanel {
+    pub equipped: Option<WeaponDisplayData>,
+}
+
+/// Colored delta text on a candidate weapon card
+#[derive(Component)]
+pub struct WeaponStatDelta;
+
+/// Popup weapon card that fills the comparison panel while hovered
+#[derive(Component)]
+pub struct CompareCandidate(pub WeaponDisplayData);
+
+/// How a candidate's stat compares to the equipped weapon's.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatVerdict {
+    Better,
+    Worse,
+    Same,
+}
+
+impl StatVerdict {
+    /// Compares two values of an ordered stat, treating differences under 0.01 as equal.
+    fn of(equipped: f32, candidate: f32, higher_is_better: bool) -> Self {
+        if (candidate - equipped).abs() < 0.01 {
+            StatVerdict::Same
+        } else if (candidate > equipped) == higher_is_better {
+            StatVerdict::Better
+        } else {
+            StatVerdict::Worse
+        }
+    }
+
+    pub(crate) fn color(self) -> Color {
+        match self {
+            StatVerdict::Better => UiTheme::AFFORDABLE,
+            StatVerdict::Worse => UiTheme::NOT_AFFORDABLE,
+            StatVerdict::Same => UiTheme::TEXT_PRIMARY,
+        }
+    }
+}
+
+/// One row of the weapon comparison panel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatComparison {
+    pub label: &'static str,
+    pub equipped: Option<String>,
+    pub candidate: String,
+    /// `None` when there is nothing to compare against or the stat isn't ordered (tags).
+    pub verdict: Option<StatVerdict>,
+}
+
+/// Compares every stat of `candidate` against `equipped`.
+/// Higher is better except for attack speed, where a shorter interval wins.
+pub fn compare_weapons(
+    equipped: Option<&WeaponDisplayData>,
+    candidate: &WeaponDisplayData,
+) -> Vec<StatComparison> {
+    fn verdict(
+        equipped: Option<f32>,
+        candidate: f32,
+        higher_is_better: bool,
+    ) -> Option<StatVerdict> {
+        equipped.map(|equipped| StatVerdict::of(equipped, candidate, higher_is_better))
+    }
+
+    fn numeric(
+        label: &'static str,
+        equipped: Option<&WeaponDisplayData>,
+        candidate: &WeaponDisplayData,
+        stat: impl Fn(&WeaponDisplayData) -> f32,
+        format: impl Fn(f32) -> String,
+        higher_is_better: bool,
+    ) -> StatComparison {
+        let equipped_value = equipped.map(&stat);
+        let candidate_value = stat(candidate);
+        StatComparison {
+            label,
+            equipped: equipped_value.map(&format),
+            candidate: format(candidate_value),
+            verdict: verdict(equipped_value, candidate_value, higher_is_better),
+        }
+    }
+
+    let format_arc = |w: &WeaponDisplayData| {
+        w.melee_arc
+            .map(|arc| format!("{:.0}°", arc))
+            .unwrap_or_else(|| "-".to_string())
+    };
+    let arc_verdict = match (equipped.and_then(|w| w.melee_arc), candidate.melee_arc) {
+        (Some(e), Some(c)) => verdict(Some(e), c, true),
+        _ => None,
+    };
+    let format_tags = |w: &WeaponDisplayData| {
+        if w.tags.is_empty() {
+            "-".to_string()
+        } else {
+            w.tags.join(", ")
+        }
+    };
+
+    vec![
+        numeric(
+            "Damage",
+            equipped,
+            candidate,
+            |w| w.effective_damage,
+            |v| format!("{:.2}", v),
+            true,
+        ),
+        numeric(
+            "Range",
+            equipped,
+            candidate,
+            |w| w.range,
+            |v| format!("{:.1}", v),
+            true,
+        ),
+        numeric(
+            "Speed",
+            equipped,
+            candidate,
+            |w| w.speed_secs,
+            |v| format!("{:.2}s", v),
+            false,
+        ),
+        StatComparison {
+            label: "Arc",
+            equipped: equipped.map(format_arc),
+            candidate: format_arc(candidate),
+            verdict: arc_verdict,
+        },
+        numeric(
+            "DPS",
+            equipped,
+            candidate,
+            WeaponDisplayData::dps,
+            |v| format!("{:.2}", v),
+            true,
+        ),
+        StatComparison {
+            label: "Tags",
+            equipped: equipped.map(format_tags),
+            candidate: format_tags(candidate),
+            verdict: None,
+        },
+    ]
+}
+
+/// Change in one stat from the equipped weapon to a candidate, shown on the candidate's card.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatDelta {
+    /// Signed difference with an arrow pointing the way of improvement, e.g. "DMG +2.00 ↑".
+    pub text: String,
+    pub verdict: StatVerdict,
+}
+
+/// Damage, range and attack interval deltas of `candidate` relative to `equipped`.
+/// A shorter attack interval counts as an improvement.
+pub fn weapon_stat_deltas(
+    equipped: &WeaponDisplayData,
+    candidate: &WeaponDisplayData,
+) -> Vec<StatDelta> {
+    let delta = |label: &str,
+                 equipped: f32,
+                 candidate: f32,
+                 precision: usize,
+                 unit: &str,
+                 higher_is_better: bool| {
+        let verdict = StatVerdict::of(equipped, candidate, higher_is_better);
+        let arrow = match verdict {
+            StatVerdict::Same => {
+                return StatDelta {
+                    text: format!("{} ±0", label),
+                    verdict,
+                };
+            }
+            StatVerdict::Better => "↑",
+            StatVerdict::Worse => "↓",
+        };
+        let text = format!(
+            "{} {:+.*}{} {}",
+            label,
+            precision,
+            candidate - equipped,
+            unit,
+            arrow
+        );
+        StatDelta { text, verdict }
+    };
+
+    vec![
+        delta(
+            "DMG",
+            equipped.effective_damage,
+            candidate.effective_damage,
+            2,
+            "",
+            true,
+        ),
+        delta("RNG", equipped.range, candidate.range, 1, "", true),
+        delta(
+            "SPD",
+            equipped.speed_secs,
+            candidate.speed_secs,
+            2,
+            "s",
+            false,
+        ),
+    ]
+}
+
+pub(crate) fn spawn_comparison_hint(parent: &mut ChildSpawnerCommands) {
+    parent.spawn((
+        Text::new("Hover a weapon to compare"),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(UiTheme::TEXT_SECONDARY),
+    ));
+}
+
+/// Fills the comparison panel with an "Equipped | Candidate" table.
+/// Without an equipped weapon only the candidate column is shown.
+fn spawn_comparison_table(
+    parent: &mut ChildSpawnerCommands,
+    equipped: Option<&WeaponDisplayData>,
+    candidate: &WeaponDisplayData,
+) {
+    let cell = |text: String, color: Color| {
+        (
+            Text::new(text),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(color),
+            Node {
+                width: Val::Px(110.0),
+                ..default()
+            },
+        )
+    };
+
+    let spawn_row = |parent: &mut ChildSpawnerCommands,
+                     label: &str,
+                     equipped: Option<(String, Color)>,
+                     candidate: (String, Color),
+                     label_color: Color| {
+        parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                margin: UiRect::bottom(Val::Px(4.0)),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn(cell(label.to_string(), label_color));
+                if let Some((text, color)) = equipped {
+                    row.spawn(cell(text, color));
+                }
+                row.spawn(cell(candidate.0, candidate.1));
+            });
+    };
+
+    spawn_row(
+        parent,
+        "",
+        equipped.map(|w| (w.name.clone(), UiTheme::TEXT_HEADER)),
+        (candidate.name.clone(), UiTheme::TEXT_HEADER),
+        UiTheme::TEXT_HEADER,
+    );
+
+    for stat in compare_weapons(equipped, candidate) {
+        let candidate_color = stat
+            .verdict
+            .map(StatVerdict::color)
+            .unwrap_or(UiTheme::TEXT_PRIMARY);
+        spawn_row(
+            parent,
+            stat.label,
+            stat.equipped.map(|value| (value, UiTheme::TEXT_PRIMARY)),
+            (stat.candidate, candidate_color),
+            UiTheme::TEXT_SECONDARY,
+        );
+    }
+}
+
+/// Shows the hovered candidate in the comparison panel, reverting to the hint when unhovered.
+pub(crate) fn update_weapon_comparison(
+    mut commands: Commands,
+    candidates: Query<(&Interaction, &CompareCandidate), Changed<Interaction>>,
+    panels: Query<(Entity, &WeaponComparisonPanel)>,
+) {
+    let Some((interaction, candidate)) = candidates.iter().last() else {
+        return;
+    };
+
+    for (panel_entity, panel) in panels.iter() {
+        commands.entity(panel_entity).despawn_children();
+        commands.entity(panel_entity).with_children(|parent| {
+            if *interaction == Interaction::None {
+                spawn_comparison_hint(parent);
+            } else {
+                spawn_comparison_table(parent, panel.equipped.as_ref(), &candidate.0);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::tests::weapon_data};
+
+    #[test]
+    fn test_compare_weapons_marks_each_stat() {
+        let equipped = weapon_data("Bone Sword", 10.0, 1.5, 1.0);
+        let mut candidate = weapon_data("Bone Bow", 8.0, 5.0, 0.5);
+        candidate.tags = vec!["ranged".to_string()];
+
+        let rows = compare_weapons(Some(&equipped), &candidate);
+        let verdicts: Vec<_> = rows.iter().map(|r| (r.label, r.verdict)).collect();
+
+        assert_eq!(
+            verdicts,
+            vec![
+                ("Damage", Some(StatVerdict::Worse)),
+                ("Range", Some(StatVerdict::Better)),
+                // Shorter attack interval is better
+                ("Speed", Some(StatVerdict::Better)),
+                ("Arc", None),
+                // 16 DPS vs 10 DPS
+                ("DPS", Some(StatVerdict::Better)),
+                ("Tags", None),
+            ]
+        );
+        assert_eq!(rows[4].candidate, "16.00");
+        assert_eq!(rows[5].candidate, "ranged");
+        assert_eq!(rows[5].equipped.as_deref(), Some("-"));
+    }
+
+    #[test]
+    fn test_compare_weapons_without_equipped_has_no_verdicts() {
+        let rows = compare_weapons(None, &weapon_data("Bone Bow", 8.0, 5.0, 0.5));
+
+        assert!(
+            rows.iter()
+                .all(|r| r.equipped.is_none() && r.verdict.is_none())
+        );
+    }
+}
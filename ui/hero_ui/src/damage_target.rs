@@ -0,0 +1,184 @@
+//! Dropdown choosing the enemy tag the weapon section computes damage against.
+
+use {
+    crate::RefreshHeroUiEvent,
+    bevy::prelude::*,
+    enemy_resources::EnemyDetailsCache,
+    widgets::{UiTheme, despawn_all},
+};
+
+/// Enemy tag the hero screen computes effective damage against; `None` ignores target bonuses.
+#[derive(Resource, Default, Debug, Clone, PartialEq, Eq)]
+pub struct DamageTarget(pub Option<String>);
+
+impl DamageTarget {
+    /// Target tags to pass to `calculate_damage`.
+    pub fn tags(&self) -> &[String] {
+        self.0.as_slice()
+    }
+}
+
+/// Dropdown in the weapon section choosing the enemy tag damage is shown against
+#[derive(Component)]
+pub struct DamageTargetDropdown;
+
+/// Button opening and closing the target list
+#[derive(Component)]
+pub struct DamageTargetButton;
+
+/// Text on the dropdown button naming the current target
+#[derive(Component)]
+pub(crate) struct DamageTargetLabel;
+
+/// Open list of target choices under the dropdown button
+#[derive(Component)]
+pub(crate) struct DamageTargetOptions;
+
+/// One choice of the target list; `None` shows damage without a target
+#[derive(Component)]
+pub struct DamageTargetOption(pub Option<String>);
+
+/// Enemy tags known from the encyclopedia cache, sorted and without duplicates.
+pub fn known_target_tags(cache: &EnemyDetailsCache) -> Vec<String> {
+    let mut tags: Vec<String> = cache
+        .details
+        .values()
+        .flat_map(|block| block.tags.clone())
+        .collect();
+    tags.sort();
+    tags.dedup();
+    tags
+}
+
+/// Spawns the closed target dropdown; its label is filled in by `update_damage_target_label`.
+pub(crate) fn spawn_damage_target_dropdown(parent: &mut ChildSpawnerCommands) {
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::FlexStart,
+                margin: UiRect::vertical(Val::Px(5.0)),
+                ..default()
+            },
+            DamageTargetDropdown,
+        ))
+        .with_children(|dropdown| {
+            dropdown
+                .spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(10.0), Val::Px(5.0)),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor::all(UiTheme::TAB_BORDER),
+                    BackgroundColor(UiTheme::BUTTON_NORMAL),
+                    DamageTargetButton,
+                ))
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new(damage_target_label(None)),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(UiTheme::TEXT_PRIMARY),
+                        DamageTargetLabel,
+                    ));
+                });
+        });
+}
+
+fn damage_target_label(target: Option<&str>) -> String {
+    format!("Damage vs: {} ▾", target.unwrap_or("Any target"))
+}
+
+/// Opens the target list under the dropdown button, or closes it when already open.
+pub(crate) fn handle_damage_target_dropdown(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<DamageTargetButton>)>,
+    dropdowns: Query<Entity, With<DamageTargetDropdown>>,
+    open_options: Query<Entity, With<DamageTargetOptions>>,
+    details_cache: Res<EnemyDetailsCache>,
+    damage_target: Res<DamageTarget>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if !open_options.is_empty() {
+            despawn_all(&mut commands, &open_options);
+            continue;
+        }
+        let Ok(dropdown) = dropdowns.single() else {
+            continue;
+        };
+
+        let choices =
+            std::iter::once(None).chain(known_target_tags(&details_cache).into_iter().map(Some));
+        commands.entity(dropdown).with_children(|dropdown| {
+            dropdown
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BackgroundColor(UiTheme::POPUP_BG),
+                    BorderColor::all(UiTheme::TAB_BORDER),
+                    DamageTargetOptions,
+                ))
+                .with_children(|list| {
+                    for choice in choices {
+                        let bg_color = if choice == damage_target.0 {
+                            UiTheme::TAB_ACTIVE_BG
+                        } else {
+                            UiTheme::TAB_INACTIVE_BG
+                        };
+                        list.spawn((
+                            Button,
+                            Node {
+                                padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                                ..default()
+                            },
+                            BackgroundColor(bg_color),
+                            DamageTargetOption(choice.clone()),
+                        ))
+                        .with_children(|btn| {
+                            btn.spawn((
+                                Text::new(choice.as_deref().unwrap_or("Any target")),
+                                TextFont {
+                                    font_size: 13.0,
+                                    ..default()
+                                },
+                                TextColor(UiTheme::TEXT_PRIMARY),
+                            ));
+                        });
+                    }
+                });
+        });
+    }
+}
+
+/// Stores the chosen target and rebuilds the hero screen with the recomputed damage.
+pub(crate) fn handle_damage_target_option(
+    mut commands: Commands,
+    interaction_query: Query<(&Interaction, &DamageTargetOption), Changed<Interaction>>,
+    mut damage_target: ResMut<DamageTarget>,
+) {
+    for (interaction, option) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            damage_target.0 = option.0.clone();
+            commands.trigger(RefreshHeroUiEvent);
+        }
+    }
+}
+
+pub(crate) fn update_damage_target_label(
+    damage_target: Res<DamageTarget>,
+    mut labels: Query<&mut Text, Added<DamageTargetLabel>>,
+) {
+    for mut text in labels.iter_mut() {
+        text.0 = damage_target_label(damage_target.0.as_deref());
+    }
+}
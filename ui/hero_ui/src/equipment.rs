@@ -0,0 +1,838 @@
+//! Popup for equipping a hero with one of the available weapons.
+
+use {
+    crate::{
+        CompareCandidate, RefreshHeroUiEvent, WeaponComparisonPanel, WeaponDisplayData,
+        WeaponDisplayQuery, WeaponStack, WeaponStatDelta, comparison, weapon_stat_deltas,
+    },
+    bevy::prelude::*,
+    equipment_events::{EquipWeaponRequest, UnequipWeaponRequest},
+    widgets::{Modal, Tooltip, UiTheme, despawn_all, spawn_item_card},
+};
+
+/// Button to open equipment change popup
+#[derive(Component)]
+pub struct ChangeEquipmentButton {
+    pub hero_entity: Entity,
+}
+
+/// Marker for the equipment popup
+#[derive(Component)]
+#[require(Modal)]
+pub struct EquipmentPopup {
+    pub hero_entity: Entity,
+}
+
+/// Close button for equipment popup
+#[derive(Component)]
+pub struct CloseEquipmentPopupButton;
+
+/// Button to equip a specific weapon
+#[derive(Component)]
+pub struct EquipWeaponButton {
+    pub hero_entity: Entity,
+    pub weapon_entity: Entity,
+}
+
+/// Button to unequip current weapon
+#[derive(Component)]
+pub struct UnequipWeaponButton {
+    pub hero_entity: Entity,
+}
+
+/// Container for unequipped weapons list
+#[derive(Component)]
+pub struct UnequippedWeaponsList;
+
+/// Row of tag chips above the available weapons; `selected` hides weapons without that tag
+#[derive(Component, Default)]
+pub struct WeaponTagFilter {
+    pub selected: Option<String>,
+}
+
+/// Toggle chip of the tag filter row
+#[derive(Component)]
+pub struct WeaponTagChip(pub String);
+
+/// Spawns the equipment popup showing available weapons
+pub fn spawn_equipment_popup(
+    commands: &mut Commands,
+    hero_entity: Entity,
+    equipped_weapon: Option<&WeaponDisplayData>,
+    unequipped_weapons: Vec<WeaponStack>,
+) {
+    // Full-screen overlay
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                right: Val::Px(0.0),
+                top: Val::Px(0.0),
+                bottom: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            EquipmentPopup { hero_entity },
+            Interaction::default(),
+        ))
+        .with_children(|overlay| {
+            // Popup panel: weapon lists on the left, comparison on the right
+            overlay
+                .spawn((
+                    Node {
+                        width: Val::Px(700.0),
+                        max_height: Val::Vh(70.0),
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(15.0),
+                        padding: UiRect::all(Val::Px(15.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(UiTheme::POPUP_BG),
+                    BorderColor::all(UiTheme::POPUP_BORDER),
+                ))
+                .with_children(|panel| {
+                    let mut lists = panel.spawn(Node {
+                        width: Val::Px(380.0),
+                        flex_direction: FlexDirection::Column,
+                        ..default()
+                    });
+                    lists.with_children(|popup| {
+                        // Header row
+                        popup
+                            .spawn(Node {
+                                flex_direction: FlexDirection::Row,
+                                justify_content: JustifyContent::SpaceBetween,
+                                align_items: AlignItems::Center,
+                                margin: UiRect::bottom(Val::Px(10.0)),
+                                ..default()
+                            })
+                            .with_children(|header| {
+                                header.spawn((
+                                    Text::new("Equipment"),
+                                    TextFont {
+                                        font_size: 20.0,
+                                        ..default()
+                                    },
+                                    TextColor(UiTheme::TEXT_HEADER),
+                                ));
+
+                                // Close button
+                                header
+                                    .spawn((
+                                        Button,
+                                        Node {
+                                            width: Val::Px(24.0),
+                                            height: Val::Px(24.0),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            ..default()
+                                        },
+                                        BackgroundColor(UiTheme::CLOSE_BUTTON_BG),
+                                        CloseEquipmentPopupButton,
+                                    ))
+                                    .with_children(|btn| {
+                                        btn.spawn((
+                                            Text::new("X"),
+                                            TextFont {
+                                                font_size: 16.0,
+                                                ..default()
+                                            },
+                                            TextColor(Color::WHITE),
+                                        ));
+                                    });
+                            });
+
+                        // Currently equipped section
+                        popup.spawn((
+                            Text::new("Equipped"),
+                            TextFont {
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(UiTheme::TEXT_HEADER),
+                            Node {
+                                margin: UiRect::bottom(Val::Px(5.0)),
+                                ..default()
+                            },
+                        ));
+
+                        if let Some(weapon) = equipped_weapon {
+                            spawn_popup_weapon_card(popup, hero_entity, weapon, 1, true, None);
+                        } else {
+                            popup.spawn((
+                                Text::new("No weapon equipped"),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(UiTheme::TEXT_SECONDARY),
+                                Node {
+                                    margin: UiRect::bottom(Val::Px(10.0)),
+                                    ..default()
+                                },
+                            ));
+                        }
+
+                        // Available weapons section
+                        popup.spawn((
+                            Text::new("Available Weapons"),
+                            TextFont {
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(UiTheme::TEXT_HEADER),
+                            Node {
+                                margin: UiRect::vertical(Val::Px(10.0)),
+                                ..default()
+                            },
+                        ));
+
+                        if unequipped_weapons.is_empty() {
+                            popup.spawn((
+                                Text::new("No weapons available. Craft weapons to add them here."),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(UiTheme::TEXT_SECONDARY),
+                            ));
+                        } else {
+                            let tags = weapon_tag_set(unequipped_weapons.iter().map(|s| &s.weapon));
+                            if !tags.is_empty() {
+                                spawn_weapon_tag_filter(popup, &tags);
+                            }
+
+                            // Scrollable container for available weapons
+                            popup
+                                .spawn((
+                                    Node {
+                                        flex_direction: FlexDirection::Column,
+                                        max_height: Val::Vh(40.0),
+                                        overflow: Overflow::scroll_y(),
+                                        ..default()
+                                    },
+                                    UnequippedWeaponsList,
+                                ))
+                                .with_children(|scroll_container| {
+                                    // Spawn weapons directly here
+                                    for stack in &unequipped_weapons {
+                                        spawn_popup_weapon_card(
+                                            scroll_container,
+                                            hero_entity,
+                                            &stack.weapon,
+                                            stack.count,
+                                            false,
+                                            equipped_weapon,
+                                        );
+                                    }
+                                });
+                        }
+                    });
+
+                    panel
+                        .spawn((
+                            Node {
+                                flex_grow: 1.0,
+                                flex_direction: FlexDirection::Column,
+                                ..default()
+                            },
+                            WeaponComparisonPanel {
+                                equipped: equipped_weapon.cloned(),
+                            },
+                        ))
+                        .with_children(comparison::spawn_comparison_hint);
+                });
+        });
+}
+
+/// "x3" badge next to the name of a stacked weapon
+#[derive(Component)]
+struct WeaponCountBadge;
+
+fn spawn_weapon_count_badge(parent: &mut ChildSpawnerCommands, count: usize) {
+    parent
+        .spawn((
+            Node {
+                padding: UiRect::axes(Val::Px(5.0), Val::Px(1.0)),
+                border: UiRect::all(Val::Px(1.0)),
+                border_radius: BorderRadius::all(Val::Px(4.0)),
+                ..default()
+            },
+            BorderColor::all(UiTheme::CARD_BORDER),
+            BackgroundColor(UiTheme::TAB_INACTIVE_BG),
+            WeaponCountBadge,
+        ))
+        .with_child((
+            Text::new(format!("x{}", count)),
+            TextFont {
+                font_size: 11.0,
+                ..default()
+            },
+            TextColor(UiTheme::TEXT_SECONDARY),
+        ));
+}
+
+/// Every tag carried by `weapons`, sorted and without duplicates.
+fn weapon_tag_set<'a>(weapons: impl IntoIterator<Item = &'a WeaponDisplayData>) -> Vec<String> {
+    let mut tags: Vec<String> = weapons.into_iter().flat_map(|w| w.tags.clone()).collect();
+    tags.sort();
+    tags.dedup();
+    tags
+}
+
+/// Whether `weapon` passes the tag filter; no selection lets every weapon through.
+fn matches_tag_filter(weapon: &WeaponDisplayData, selected: Option<&str>) -> bool {
+    selected.is_none_or(|tag| weapon.tags.iter().any(|t| t == tag))
+}
+
+fn spawn_weapon_tag_filter(parent: &mut ChildSpawnerCommands, tags: &[String]) {
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
+                column_gap: Val::Px(5.0),
+                row_gap: Val::Px(5.0),
+                margin: UiRect::bottom(Val::Px(8.0)),
+                ..default()
+            },
+            WeaponTagFilter::default(),
+        ))
+        .with_children(|row| {
+            for tag in tags {
+                row.spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(8.0), Val::Px(3.0)),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BackgroundColor(UiTheme::TAB_INACTIVE_BG),
+                    BorderColor::all(UiTheme::TAB_BORDER),
+                    WeaponTagChip(tag.clone()),
+                ))
+                .with_children(|chip| {
+                    chip.spawn((
+                        Text::new(tag),
+                        TextFont {
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(UiTheme::TEXT_PRIMARY),
+                    ));
+                });
+            }
+        });
+}
+
+/// Small read-only chips listing a weapon's tags.
+fn spawn_weapon_tags(parent: &mut ChildSpawnerCommands, tags: &[String]) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            column_gap: Val::Px(4.0),
+            row_gap: Val::Px(2.0),
+            margin: UiRect::vertical(Val::Px(2.0)),
+            ..default()
+        })
+        .with_children(|row| {
+            for tag in tags {
+                row.spawn((
+                    Node {
+                        padding: UiRect::axes(Val::Px(5.0), Val::Px(1.0)),
+                        ..default()
+                    },
+                    BackgroundColor(UiTheme::CARD_BORDER),
+                ))
+                .with_children(|chip| {
+                    chip.spawn((
+                        Text::new(tag),
+                        TextFont {
+                            font_size: 10.0,
+                            ..default()
+                        },
+                        TextColor(UiTheme::TEXT_SECONDARY),
+                    ));
+                });
+            }
+        });
+}
+
+/// Spawns one weapon card of the equipment popup. Candidate cards get a row of
+/// colored deltas against `equipped`; without an equipped weapon only absolute stats show.
+fn spawn_popup_weapon_card(
+    parent: &mut ChildSpawnerCommands,
+    hero_entity: Entity,
+    weapon: &WeaponDisplayData,
+    count: usize,
+    is_equipped: bool,
+    equipped: Option<&WeaponDisplayData>,
+) {
+    let deltas = equipped
+        .map(|equipped| weapon_stat_deltas(equipped, weapon))
+        .unwrap_or_default();
+    let weapon_card = spawn_item_card(parent, ());
+    let weapon_entity = weapon.entity;
+    let weapon_name = weapon.name.clone();
+    let locked_reason = weapon.locked_reason.clone();
+
+    if !is_equipped {
+        parent.commands().entity(weapon_card).insert((
+            CompareCandidate(weapon.clone()),
+            Interaction::default(),
+            bevy::ui::FocusPolicy::Pass,
+        ));
+    }
+
+    parent.commands().entity(weapon_card).with_children(|card| {
+        // Weapon info row
+        card.spawn(Node {
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            width: Val::Percent(100.0),
+            ..default()
+        })
+        .with_children(|row| {
+            // Weapon info
+            row.spawn(Node {
+                flex_direction: FlexDirection::Column,
+                ..default()
+            })
+            .with_children(|info| {
+                let name = (
+                    Text::new(&weapon_name),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(UiTheme::TEXT_PRIMARY),
+                );
+                if count > 1 {
+                    info.spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(6.0),
+                        ..default()
+                    })
+                    .with_children(|name_row| {
+                        name_row.spawn(name);
+                        spawn_weapon_count_badge(name_row, count);
+                    });
+                } else {
+                    info.spawn(name);
+                }
+
+                if !weapon.tags.is_empty() {
+                    spawn_weapon_tags(info, &weapon.tags);
+                }
+
+                info.spawn((
+                    Text::new(format!(
+                        "DMG: {:.2} | RNG: {:.1} | SPD: {:.2}s",
+                        weapon.effective_damage, weapon.range, weapon.speed_secs
+                    )),
+                    TextFont {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(UiTheme::TEXT_SECONDARY),
+                ));
+
+                if !deltas.is_empty() {
+                    info.spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(8.0),
+                        ..default()
+                    })
+                    .with_children(|delta_row| {
+                        for delta in &deltas {
+                            delta_row.spawn((
+                                Text::new(&delta.text),
+                                TextFont {
+                                    font_size: 12.0,
+                                    ..default()
+                                },
+                                TextColor(delta.verdict.color()),
+                                WeaponStatDelta,
+                            ));
+                        }
+                    });
+                }
+            });
+
+            // Action button
+            if is_equipped {
+                row.spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(10.0), Val::Px(5.0)),
+                        border: UiRect::all(Val::Px(1.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BorderColor::all(UiTheme::BORDER_ERROR),
+                    BackgroundColor(UiTheme::BUTTON_NORMAL),
+                    UnequipWeaponButton { hero_entity },
+                ))
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new("Unequip"),
+                        TextFont {
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(UiTheme::TEXT_PRIMARY),
+                    ));
+                });
+            } else if let Some(reason) = locked_reason {
+                // Greyed out: no equip action, hovering explains the requirement
+                row.spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(10.0), Val::Px(5.0)),
+                        border: UiRect::all(Val::Px(1.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BorderColor::all(UiTheme::BORDER_DISABLED),
+                    BackgroundColor(UiTheme::BUTTON_NORMAL),
+                    Tooltip(reason),
+                ))
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new("Equip"),
+                        TextFont {
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(UiTheme::TEXT_DISABLED),
+                    ));
+                });
+            } else {
+                row.spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(10.0), Val::Px(5.0)),
+                        border: UiRect::all(Val::Px(1.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BorderColor::all(UiTheme::BORDER_SUCCESS),
+                    BackgroundColor(UiTheme::BUTTON_NORMAL),
+                    EquipWeaponButton {
+                        hero_entity,
+                        weapon_entity,
+                    },
+                ))
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new("Equip"),
+                        TextFont {
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(UiTheme::TEXT_PRIMARY),
+                    ));
+                });
+            }
+        });
+    });
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn handle_change_equipment_button(
+    mut commands: Commands,
+    interaction_query: Query<
+        (&Interaction, &ChangeEquipmentButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    existing_popup: Query<Entity, With<EquipmentPopup>>,
+    hero_children_query: Query<&Children, With<hero_components::Hero>>,
+    weapons: WeaponDisplayQuery,
+) {
+    for (interaction, btn) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            // Close existing popup if any
+            despawn_all(&mut commands, &existing_popup);
+
+            let hero_entity = btn.hero_entity;
+
+            // Build equipped weapon data
+            let equipped_weapon = hero_children_query
+                .get(hero_entity)
+                .ok()
+                .and_then(|children| children.iter().find_map(|child| weapons.get(child)));
+
+            // Spawn popup (weapons are spawned directly inside the popup)
+            spawn_equipment_popup(
+                &mut commands,
+                hero_entity,
+                equipped_weapon.as_ref(),
+                weapons.available_stacks(),
+            );
+        }
+    }
+}
+
+pub(crate) fn handle_close_equipment_popup(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<CloseEquipmentPopupButton>)>,
+    popup_query: Query<Entity, With<EquipmentPopup>>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            despawn_all(&mut commands, &popup_query);
+        }
+    }
+}
+
+/// Toggles the pressed tag chip, then restyles the chips and hides filtered-out weapons.
+pub(crate) fn handle_weapon_tag_filter(
+    interaction_query: Query<(&Interaction, &WeaponTagChip), Changed<Interaction>>,
+    mut filters: Query<&mut WeaponTagFilter>,
+    mut chips: Query<(&WeaponTagChip, &mut BackgroundColor)>,
+    mut cards: Query<(&CompareCandidate, &mut Node)>,
+) {
+    for (interaction, chip) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(mut filter) = filters.single_mut() else {
+            continue;
+        };
+
+        filter.selected = if filter.selected.as_ref() == Some(&chip.0) {
+            None
+        } else {
+            Some(chip.0.clone())
+        };
+        let selected = filter.selected.as_deref();
+
+        for (chip, mut background) in chips.iter_mut() {
+            background.0 = if selected == Some(chip.0.as_str()) {
+                UiTheme::TAB_ACTIVE_BG
+            } else {
+                UiTheme::TAB_INACTIVE_BG
+            };
+        }
+        for (candidate, mut node) in cards.iter_mut() {
+            node.display = if matches_tag_filter(&candidate.0, selected) {
+                Display::Flex
+            } else {
+                Display::None
+            };
+        }
+    }
+}
+
+pub(crate) fn handle_equip_button(
+    mut commands: Commands,
+    interaction_query: Query<
+        (&Interaction, &EquipWeaponButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    popup_query: Query<Entity, With<EquipmentPopup>>,
+) {
+    for (interaction, btn) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            // Trigger equip event
+            commands.trigger(EquipWeaponRequest {
+                hero: btn.hero_entity,
+                weapon: btn.weapon_entity,
+            });
+
+            // Close popup and trigger UI refresh
+            despawn_all(&mut commands, &popup_query);
+            commands.trigger(RefreshHeroUiEvent);
+        }
+    }
+}
+
+pub(crate) fn handle_unequip_button(
+    mut commands: Commands,
+    interaction_query: Query<
+        (&Interaction, &UnequipWeaponButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    popup_query: Query<Entity, With<EquipmentPopup>>,
+) {
+    for (interaction, btn) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            // Trigger unequip event
+            commands.trigger(UnequipWeaponRequest {
+                hero: btn.hero_entity,
+            });
+
+            // Close popup and trigger UI refresh
+            despawn_all(&mut commands, &popup_query);
+            commands.trigger(RefreshHeroUiEvent);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{StatVerdict, stack_weapons, tests::weapon_data},
+    };
+
+    #[test]
+    fn test_candidate_card_shows_deltas_against_equipped() {
+        let equipped = weapon_data("Bone Sword", 10.0, 1.5, 1.0);
+        let candidate = weapon_data("Bone Bow", 8.0, 5.0, 0.5);
+
+        let mut world = World::new();
+        world
+            .commands()
+            .spawn(Node::default())
+            .with_children(|parent| {
+                spawn_popup_weapon_card(
+                    parent,
+                    Entity::PLACEHOLDER,
+                    &candidate,
+                    1,
+                    false,
+                    Some(&equipped),
+                );
+                // Without an equipped weapon there is nothing to diff against
+                spawn_popup_weapon_card(parent, Entity::PLACEHOLDER, &candidate, 1, false, None);
+            });
+        world.flush();
+
+        let deltas: Vec<(String, Color)> = world
+            .query_filtered::<(&Text, &TextColor), With<WeaponStatDelta>>()
+            .iter(&world)
+            .map(|(text, color)| (text.0.clone(), color.0))
+            .collect();
+        assert_eq!(
+            deltas,
+            vec![
+                ("DMG -2.00 ↓".to_string(), UiTheme::NOT_AFFORDABLE),
+                ("RNG +3.5 ↑".to_string(), UiTheme::AFFORDABLE),
+                // Attacking 0.5s sooner is an improvement
+                ("SPD -0.50s ↑".to_string(), UiTheme::AFFORDABLE),
+            ]
+        );
+
+        let same = weapon_stat_deltas(&equipped, &equipped);
+        assert!(same.iter().all(|d| d.verdict == StatVerdict::Same));
+    }
+
+    #[test]
+    fn test_tag_chip_toggles_weapon_filter() {
+        let mut world = World::new();
+        let mut sword = weapon_data("Bone Sword", 10.0, 1.5, 1.0);
+        sword.tags = vec!["melee".to_string(), "bone".to_string()];
+        let mut bow = weapon_data("Bone Bow", 8.0, 5.0, 0.5);
+        bow.tags = vec!["ranged".to_string(), "bone".to_string()];
+        assert_eq!(
+            weapon_tag_set(&[sword.clone(), bow.clone()]),
+            vec!["bone", "melee", "ranged"]
+        );
+
+        let filter = world.spawn(WeaponTagFilter::default()).id();
+        let melee_chip = world
+            .spawn((
+                WeaponTagChip("melee".to_string()),
+                BackgroundColor(UiTheme::TAB_INACTIVE_BG),
+                Interaction::None,
+            ))
+            .id();
+        let sword_card = world.spawn((CompareCandidate(sword), Node::default())).id();
+        let bow_card = world.spawn((CompareCandidate(bow), Node::default())).id();
+        let system = world.register_system(handle_weapon_tag_filter);
+        let display = |world: &World, card| world.get::<Node>(card).unwrap().display;
+
+        let click = |world: &mut World| {
+            *world.get_mut::<Interaction>(melee_chip).unwrap() = Interaction::Pressed;
+            world.run_system(system).unwrap();
+            *world.get_mut::<Interaction>(melee_chip).unwrap() = Interaction::None;
+            world.run_system(system).unwrap();
+        };
+
+        click(&mut world);
+        assert_eq!(
+            world
+                .get::<WeaponTagFilter>(filter)
+                .unwrap()
+                .selected
+                .as_deref(),
+            Some("melee")
+        );
+        assert_eq!(display(&world, sword_card), Display::Flex);
+        assert_eq!(display(&world, bow_card), Display::None);
+        assert_eq!(
+            world.get::<BackgroundColor>(melee_chip).unwrap().0,
+            UiTheme::TAB_ACTIVE_BG
+        );
+
+        // Pressing the selected chip again clears the filter
+        click(&mut world);
+        assert_eq!(world.get::<WeaponTagFilter>(filter).unwrap().selected, None);
+        assert_eq!(display(&world, bow_card), Display::Flex);
+    }
+
+    #[test]
+    fn test_identical_weapons_share_one_card() {
+        let mut world = World::new();
+        let mut sword = || {
+            let mut weapon = weapon_data("Bone Sword", 10.0, 1.5, 1.0);
+            weapon.entity = world.spawn_empty().id();
+            (Some("bone_sword".to_string()), weapon)
+        };
+        let first = sword();
+        let first_entity = first.1.entity;
+        let weapons = vec![
+            first,
+            (None, weapon_data("Relic", 5.0, 1.0, 1.0)),
+            sword(),
+            (None, weapon_data("Relic", 5.0, 1.0, 1.0)),
+            sword(),
+        ];
+
+        let stacks = stack_weapons(weapons);
+        let listed: Vec<(&str, usize)> = stacks
+            .iter()
+            .map(|s| (s.weapon.name.as_str(), s.count))
+            .collect();
+        assert_eq!(listed, vec![("Bone Sword", 3), ("Relic", 1), ("Relic", 1)]);
+        // Equipping the stack picks its first weapon
+        assert_eq!(stacks[0].weapon.entity, first_entity);
+
+        world
+            .commands()
+            .spawn(Node::default())
+            .with_children(|parent| {
+                for stack in &stacks {
+                    spawn_popup_weapon_card(
+                        parent,
+                        Entity::PLACEHOLDER,
+                        &stack.weapon,
+                        stack.count,
+                        false,
+                        None,
+                    );
+                }
+            });
+        world.flush();
+
+        let badges: Vec<&str> = world
+            .query_filtered::<&Children, With<WeaponCountBadge>>()
+            .iter(&world)
+            .map(|children| world.get::<Text>(children[0]).unwrap().0.as_str())
+            .collect();
+        assert_eq!(badges, vec!["x3"]);
+    }
+}
@@ -1,12 +1,51 @@
+mod comparison;
+mod damage_target;
+mod equipment;
+mod range_preview;
+mod rename;
+mod skills;
+
+pub use {
+    comparison::{
+        CompareCandidate, StatComparison, StatDelta, StatVerdict, WeaponComparisonPanel,
+        WeaponStatDelta, compare_weapons, weapon_stat_deltas,
+    },
+    damage_target::{
+        DamageTarget, DamageTargetButton, DamageTargetDropdown, DamageTargetOption,
+        known_target_tags,
+    },
+    equipment::{
+        ChangeEquipmentButton, CloseEquipmentPopupButton, EquipWeaponButton, EquipmentPopup,
+        UnequipWeaponButton, UnequippedWeaponsList, WeaponTagChip, WeaponTagFilter,
+        spawn_equipment_popup,
+    },
+    range_preview::{RangeShape, WeaponRangePreview},
+    rename::{RenameHeroButton, RenameHeroPopup, spawn_rename_popup},
+    skills::{
+        ChangeSkillButton, CloseSkillPopupButton, EquipSkillButton, HERO_SKILL_SLOTS,
+        SkillDisplayData, SkillOptionData, SkillPopup, UnequipSkillButton, equipped_skill_slots,
+        skill_display_data, spawn_skill_popup, spawn_skills_section,
+    },
+};
+
 use {
-    bevy::prelude::*,
-    equipment_events::{EquipWeaponRequest, UnequipWeaponRequest},
-    hero_components::{AttackRange, AttackSpeed, Damage, Hero, MeleeArc, MeleeWeapon, Weapon},
+    bevy::{
+        ecs::system::{SystemParam, SystemState},
+        prelude::*,
+    },
+    divinity_components::Divinity,
+    hero_components::{
+        AttackRange, AttackSpeed, Damage, Hero, HeroName, MeleeArc, MeleeWeapon, Weapon, WeaponId,
+    },
+    research::ResearchState,
     shared_components::DisplayName,
-    skill_components::{EquippedSkills, UnlockedSkills},
+    skill_components::EquippedSkills,
     skills_assets::{SkillDefinition, SkillMap},
     states::GameState,
-    widgets::{UiTheme, spawn_action_button, spawn_card_title, spawn_item_card},
+    unlocks_assets::UnlockDefinition,
+    village_components::Village,
+    weapon_assets::{WeaponDefinition, WeaponMap},
+    widgets::{UiTheme, spawn_action_button, spawn_card_title, spawn_empty_state, spawn_item_card},
 };
 
 pub struct HeroUiPlugin;
@@ -14,22 +53,47 @@ pub struct HeroUiPlugin;
 impl Plugin for HeroUiPlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<HeroUiState>()
+            .init_resource::<WeaponRangePreview>()
+            .init_resource::<SelectedHeroTab>()
+            .init_resource::<DamageTarget>()
             .add_observer(on_hero_ui_added)
             .add_observer(on_hero_ui_removed)
             .add_observer(on_hero_ui_refresh)
             .add_systems(
                 Update,
                 (
-                    handle_change_equipment_button,
-                    handle_close_equipment_popup,
-                    handle_equip_button,
-                    handle_unequip_button,
+                    equipment::handle_change_equipment_button,
+                    equipment::handle_close_equipment_popup,
+                    equipment::handle_weapon_tag_filter,
+                    damage_target::handle_damage_target_dropdown,
+                    damage_target::handle_damage_target_option,
+                    damage_target::update_damage_target_label,
+                    equipment::handle_equip_button,
+                    equipment::handle_unequip_button,
                     handle_hero_tab_interaction,
-                    handle_change_skill_button,
-                    handle_close_skill_popup,
-                    handle_equip_skill_button,
+                    skills::handle_change_skill_button,
+                    skills::handle_close_skill_popup,
+                    skills::handle_equip_skill_button,
+                    skills::handle_unequip_skill_button,
+                    rename::handle_rename_hero_button,
+                    (
+                        rename::handle_rename_text_input,
+                        rename::handle_rename_popup_buttons,
+                    )
+                        .chain(),
+                    comparison::update_weapon_comparison,
                 )
                     .run_if(in_state(HeroUiState::Open).and(in_state(GameState::Running))),
+            )
+            // The popup can outlive the hero tab, so the preview is tracked independently
+            .add_systems(
+                Update,
+                (
+                    range_preview::update_weapon_range_preview,
+                    range_preview::draw_weapon_range_preview,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Running)),
             );
     }
 }
@@ -63,68 +127,16 @@ pub struct HeroTabButton {
 #[derive(Component)]
 pub struct SelectedHero(pub Entity);
 
-/// Button to open equipment change popup
-#[derive(Component)]
-pub struct ChangeEquipmentButton {
-    pub hero_entity: Entity,
-}
-
-/// Marker for the equipment popup
-#[derive(Component)]
-pub struct EquipmentPopup {
-    pub hero_entity: Entity,
-}
-
-/// Close button for equipment popup
-#[derive(Component)]
-pub struct CloseEquipmentPopupButton;
-
-/// Button to equip a specific weapon
-#[derive(Component)]
-pub struct EquipWeaponButton {
-    pub hero_entity: Entity,
-    pub weapon_entity: Entity,
-}
-
-/// Button to unequip current weapon
-#[derive(Component)]
-pub struct UnequipWeaponButton {
-    pub hero_entity: Entity,
-}
-
-/// Container for unequipped weapons list
-#[derive(Component)]
-pub struct UnequippedWeaponsList;
-
-/// Button to open skill change popup
-#[derive(Component)]
-pub struct ChangeSkillButton {
-    pub hero_entity: Entity,
-}
-
-/// Marker for the skill popup
-#[derive(Component)]
-pub struct SkillPopup {
-    pub hero_entity: Entity,
-}
-
-/// Close button for skill popup
-#[derive(Component)]
-pub struct CloseSkillPopupButton;
-
-/// Button to equip a specific skill
-#[derive(Component)]
-pub struct EquipSkillButton {
-    pub hero_entity: Entity,
-    pub skill_id: String,
-}
-
-/// Marker for the hero content container that can be refreshed
 /// Marker for the hero content container that can be refreshed
 #[derive(Component, Default)]
-pub struct HeroContentContainer {
-    pub selected_index: usize,
-}
+pub struct HeroContentContainer;
+
+/// Index of the hero tab last selected by the player.
+///
+/// Kept as a resource so it survives both refreshes and the container being
+/// respawned when the heroes screen is reopened.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectedHeroTab(pub usize);
 
 // ============================================================================
 // Events
@@ -158,16 +170,15 @@ fn on_hero_ui_removed(
 // Update Observer
 // ============================================================================
 
+#[allow(clippy::too_many_arguments)]
 fn on_hero_ui_refresh(
     _trigger: On<RefreshHeroUiEvent>,
     mut commands: Commands,
-    content_container_query: Query<
-        (Entity, Option<&Children>, &HeroContentContainer),
-        With<HeroContentContainer>,
-    >,
+    content_container_query: Query<(Entity, Option<&Children>), With<HeroContentContainer>>,
     hero_query: Query<Entity, With<Hero>>,
     children_query: Query<&Children>,
     equipped_skills_query: Query<&EquippedSkills>,
+    hero_names: Query<&HeroName>,
     skill_map: Res<SkillMap>,
     skill_definitions: Res<Assets<SkillDefinition>>,
     weapon_query: Query<
@@ -184,10 +195,12 @@ fn on_hero_ui_refresh(
     >,
     melee_query: Query<(), With<MeleeWeapon>>,
     bonus_stats: Res<bonus_stats::BonusStats>,
+    unlock_definitions: Res<Assets<UnlockDefinition>>,
+    selected_tab: Res<SelectedHeroTab>,
+    damage_target: Res<DamageTarget>,
 ) {
     // Get the content container
-    let Ok((container_entity, container_children, container)) = content_container_query.single()
-    else {
+    let Ok((container_entity, container_children)) = content_container_query.single() else {
         return;
     };
 
@@ -209,16 +222,19 @@ fn on_hero_ui_refresh(
             &weapon_query,
             &melee_query,
             &equipped_skills_query,
+            &hero_names,
             &skill_map,
             &skill_definitions,
             &bonus_stats,
+            damage_target.tags(),
         );
         heroes_data.push((*hero_entity, data));
     }
 
     // Respawn updated hero content
+    let hint = recruit_hint(&unlock_definitions);
     commands.entity(container_entity).with_children(|parent| {
-        spawn_hero_content(parent, heroes_data, container.selected_index);
+        spawn_hero_content(parent, heroes_data, selected_tab.0, &hint);
     });
 }
 
@@ -236,13 +252,46 @@ pub struct WeaponDisplayData {
     pub range: f32,
     pub speed_secs: f32,
     pub melee_arc: Option<f32>, // In degrees, only for melee weapons
+    pub tags: Vec<String>,
+    /// Why the weapon cannot be equipped yet, e.g. "Requires Divinity Tier 2"
+    pub locked_reason: Option<String>,
 }
 
-/// Data for displaying skill info
+impl WeaponDisplayData {
+    /// Effective damage per second.
+    pub fn dps(&self) -> f32 {
+        if self.speed_secs > 0.0 {
+            self.effective_damage / self.speed_secs
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Unequipped weapons sharing a `WeaponId`, listed as a single card
 #[derive(Clone)]
-pub struct SkillDisplayData {
-    pub id: String,
-    pub name: String,
+pub struct WeaponStack {
+    /// The weapon equipped when the stack is picked
+    pub weapon: WeaponDisplayData,
+    pub count: usize,
+}
+
+/// Groups weapons by definition id, in order of first appearance. The first weapon of
+/// each id represents its stack; weapons without an id are never grouped.
+fn stack_weapons(
+    weapons: impl IntoIterator<Item = (Option<String>, WeaponDisplayData)>,
+) -> Vec<WeaponStack> {
+    let mut stacks: Vec<(Option<String>, WeaponStack)> = Vec::new();
+    for (id, weapon) in weapons {
+        match stacks
+            .iter_mut()
+            .find(|(stack_id, _)| id.is_some() && *stack_id == id)
+        {
+            Some((_, stack)) => stack.count += 1,
+            None => stacks.push((id, WeaponStack { weapon, count: 1 })),
+        }
+    }
+    stacks.into_iter().map(|(_, stack)| stack).collect()
 }
 
 /// Data for displaying a hero
@@ -250,20 +299,48 @@ pub struct HeroDisplayData {
     pub entity: Entity,
     pub name: String,
     pub weapon: Option<WeaponDisplayData>,
-    pub equipped_skills: Vec<SkillDisplayData>,
+    /// Indexed by slot; `None` marks an empty slot
+    pub equipped_skills: Vec<Option<SkillDisplayData>>,
 }
 
 // ============================================================================
 // UI Spawn Functions
 // ============================================================================
 
+/// Builds the message shown when there are no heroes, naming the requirement
+/// of the first hero unlock so players know how to recruit one.
+pub fn recruit_hint(unlock_definitions: &Assets<UnlockDefinition>) -> String {
+    let first_hero_unlock = unlock_definitions
+        .iter()
+        .map(|(_, def)| def)
+        .filter(|def| def.reward_id.starts_with("hero:"))
+        .min_by(|a, b| a.id.cmp(&b.id));
+
+    match first_hero_unlock {
+        Some(def) => format!(
+            "No heroes yet — {} to recruit your first hero.",
+            def.condition.describe()
+        ),
+        None => "No heroes yet.".to_string(),
+    }
+}
+
 /// Spawns the hero content UI.
 /// This is called by village_ui when Heroes content is selected.
+/// Shows `recruit_hint` instead when there are no heroes. A `selected_index` past the
+/// last hero (e.g. after a hero was removed) selects the last one.
 pub fn spawn_hero_content(
     parent: &mut ChildSpawnerCommands,
     heroes: Vec<(Entity, HeroDisplayData)>,
     selected_index: usize,
+    recruit_hint: &str,
 ) {
+    if heroes.is_empty() {
+        spawn_empty_state(parent, recruit_hint);
+        return;
+    }
+    let selected_index = selected_index.min(heroes.len() - 1);
+
     // Hero tabs container
     if heroes.len() > 1 {
         parent
@@ -334,6 +411,13 @@ fn spawn_hero_details(
     let name_card = spawn_item_card(parent, ());
     parent.commands().entity(name_card).with_children(|card| {
         spawn_card_title(card, &hero.name);
+        spawn_action_button(
+            card,
+            "Rename",
+            UiTheme::TEXT_PRIMARY,
+            UiTheme::TAB_BORDER,
+            RenameHeroButton { hero_entity },
+        );
     });
 
     // Skills section
@@ -413,6 +497,8 @@ fn spawn_weapon_section(
         }
     });
 
+    damage_target::spawn_damage_target_dropdown(parent);
+
     // Change equipment button
     spawn_action_button(
         parent,
@@ -455,808 +541,144 @@ fn spawn_stat_row(parent: &mut ChildSpawnerCommands, label: &str, value: &str) {
         });
 }
 
-pub fn spawn_skills_section(
-    parent: &mut ChildSpawnerCommands,
-    hero_entity: Entity,
-    equipped_skills: &[SkillDisplayData],
-) {
-    // Skills header
-    parent.spawn((
-        Text::new("Skills"),
-        TextFont {
-            font_size: 18.0,
-            ..default()
-        },
-        TextColor(UiTheme::TEXT_HEADER),
-        Node {
-            margin: UiRect::vertical(Val::Px(8.0)),
-            ..default()
-        },
-    ));
-
-    // Skill slots container
-    parent
-        .spawn(Node {
-            flex_direction: FlexDirection::Row,
-            width: Val::Percent(100.0),
-            margin: UiRect::bottom(Val::Px(10.0)),
-            ..default()
-        })
-        .with_children(|container| {
-            // For now, let's just show one slot
-            let skill = equipped_skills.first().cloned();
-
-            spawn_skill_slot(container, hero_entity, skill);
-        });
-}
-
-fn spawn_skill_slot(
-    parent: &mut ChildSpawnerCommands,
-    hero_entity: Entity,
-    skill: Option<SkillDisplayData>,
-) {
-    let (label, border_color) = if let Some(s) = skill {
-        (s.name, UiTheme::TAB_BORDER)
-    } else {
-        ("[ Empty Slot ]".to_string(), UiTheme::TEXT_SECONDARY)
-    };
-
-    spawn_action_button(
-        parent,
-        &label,
-        UiTheme::TEXT_PRIMARY,
-        border_color,
-        ChangeSkillButton { hero_entity },
-    );
-}
-
-// ============================================================================
-// Skill Popup
-// ============================================================================
-
-pub fn spawn_skill_popup(
-    commands: &mut Commands,
-    hero_entity: Entity,
-    available_skills: Vec<(String, String)>, // (id, display_name)
-) {
-    // Full-screen overlay
-    commands
-        .spawn((
-            Node {
-                position_type: PositionType::Absolute,
-                left: Val::Px(0.0),
-                right: Val::Px(0.0),
-                top: Val::Px(0.0),
-                bottom: Val::Px(0.0),
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                ..default()
-            },
-            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
-            SkillPopup { hero_entity },
-            Interaction::default(),
-        ))
-        .with_children(|overlay| {
-            // Popup panel
-            overlay
-                .spawn((
-                    Node {
-                        width: Val::Px(400.0),
-                        max_height: Val::Vh(70.0),
-                        flex_direction: FlexDirection::Column,
-                        padding: UiRect::all(Val::Px(15.0)),
-                        border: UiRect::all(Val::Px(2.0)),
-                        ..default()
-                    },
-                    BackgroundColor(UiTheme::POPUP_BG),
-                    BorderColor::all(UiTheme::POPUP_BORDER),
-                ))
-                .with_children(|popup| {
-                    // Header row
-                    popup
-                        .spawn(Node {
-                            flex_direction: FlexDirection::Row,
-                            justify_content: JustifyContent::SpaceBetween,
-                            align_items: AlignItems::Center,
-                            margin: UiRect::bottom(Val::Px(10.0)),
-                            ..default()
-                        })
-                        .with_children(|header| {
-                            header.spawn((
-                                Text::new("Select Skill"),
-                                TextFont {
-                                    font_size: 20.0,
-                                    ..default()
-                                },
-                                TextColor(UiTheme::TEXT_HEADER),
-                            ));
-
-                            // Close button
-                            header
-                                .spawn((
-                                    Button,
-                                    Node {
-                                        width: Val::Px(24.0),
-                                        height: Val::Px(24.0),
-                                        justify_content: JustifyContent::Center,
-                                        align_items: AlignItems::Center,
-                                        ..default()
-                                    },
-                                    BackgroundColor(UiTheme::CLOSE_BUTTON_BG),
-                                    CloseSkillPopupButton,
-                                ))
-                                .with_children(|btn| {
-                                    btn.spawn((
-                                        Text::new("X"),
-                                        TextFont {
-                                            font_size: 16.0,
-                                            ..default()
-                                        },
-                                        TextColor(Color::WHITE),
-                                    ));
-                                });
-                        });
-
-                    // Scrollable container for available skills
-                    popup
-                        .spawn(Node {
-                            flex_direction: FlexDirection::Column,
-                            max_height: Val::Vh(40.0),
-                            overflow: Overflow::scroll_y(),
-                            ..default()
-                        })
-                        .with_children(|scroll_container| {
-                            for (skill_id, display_name) in available_skills {
-                                spawn_skill_selection_card(
-                                    scroll_container,
-                                    hero_entity,
-                                    skill_id,
-                                    display_name,
-                                );
-                            }
-                        });
-                });
-        });
-}
-
-fn spawn_skill_selection_card(
-    parent: &mut ChildSpawnerCommands,
-    hero_entity: Entity,
-    skill_id: String,
-    display_name: String,
-) {
-    let card = spawn_item_card(parent, ());
-    parent.commands().entity(card).with_children(|card| {
-        card.spawn(Node {
-            flex_direction: FlexDirection::Row,
-            justify_content: JustifyContent::SpaceBetween,
-            align_items: AlignItems::Center,
-            width: Val::Percent(100.0),
-            ..default()
-        })
-        .with_children(|row| {
-            row.spawn((
-                Text::new(&display_name),
-                TextFont {
-                    font_size: 14.0,
-                    ..default()
-                },
-                TextColor(UiTheme::TEXT_PRIMARY),
-            ));
-
-            row.spawn((
-                Button,
-                Node {
-                    padding: UiRect::axes(Val::Px(10.0), Val::Px(5.0)),
-                    border: UiRect::all(Val::Px(1.0)),
-                    justify_content: JustifyContent::Center,
-                    align_items: AlignItems::Center,
-                    ..default()
-                },
-                BorderColor::all(UiTheme::BORDER_SUCCESS),
-                BackgroundColor(UiTheme::BUTTON_NORMAL),
-                EquipSkillButton {
-                    hero_entity,
-                    skill_id,
-                },
-            ))
-            .with_children(|btn| {
-                btn.spawn((
-                    Text::new("Equip"),
-                    TextFont {
-                        font_size: 12.0,
-                        ..default()
-                    },
-                    TextColor(UiTheme::TEXT_PRIMARY),
-                ));
-            });
-        });
-    });
-}
-
-// ============================================================================
-// Equipment Popup
-// ============================================================================
-
-/// Spawns the equipment popup showing available weapons
-pub fn spawn_equipment_popup(
-    commands: &mut Commands,
-    hero_entity: Entity,
-    equipped_weapon: Option<&WeaponDisplayData>,
-    unequipped_weapons: Vec<WeaponDisplayData>,
-) {
-    // Full-screen overlay
-    commands
-        .spawn((
-            Node {
-                position_type: PositionType::Absolute,
-                left: Val::Px(0.0),
-                right: Val::Px(0.0),
-                top: Val::Px(0.0),
-                bottom: Val::Px(0.0),
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                ..default()
-            },
-            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
-            EquipmentPopup { hero_entity },
-            Interaction::default(),
-        ))
-        .with_children(|overlay| {
-            // Popup panel
-            overlay
-                .spawn((
-                    Node {
-                        width: Val::Px(400.0),
-                        max_height: Val::Vh(70.0),
-                        flex_direction: FlexDirection::Column,
-                        padding: UiRect::all(Val::Px(15.0)),
-                        border: UiRect::all(Val::Px(2.0)),
-                        ..default()
-                    },
-                    BackgroundColor(UiTheme::POPUP_BG),
-                    BorderColor::all(UiTheme::POPUP_BORDER),
-                ))
-                .with_children(|popup| {
-                    // Header row
-                    popup
-                        .spawn(Node {
-                            flex_direction: FlexDirection::Row,
-                            justify_content: JustifyContent::SpaceBetween,
-                            align_items: AlignItems::Center,
-                            margin: UiRect::bottom(Val::Px(10.0)),
-                            ..default()
-                        })
-                        .with_children(|header| {
-                            header.spawn((
-                                Text::new("Equipment"),
-                                TextFont {
-                                    font_size: 20.0,
-                                    ..default()
-                                },
-                                TextColor(UiTheme::TEXT_HEADER),
-                            ));
-
-                            // Close button
-                            header
-                                .spawn((
-                                    Button,
-                                    Node {
-                                        width: Val::Px(24.0),
-                                        height: Val::Px(24.0),
-                                        justify_content: JustifyContent::Center,
-                                        align_items: AlignItems::Center,
-                                        ..default()
-                                    },
-                                    BackgroundColor(UiTheme::CLOSE_BUTTON_BG),
-                                    CloseEquipmentPopupButton,
-                                ))
-                                .with_children(|btn| {
-                                    btn.spawn((
-                                        Text::new("X"),
-                                        TextFont {
-                                            font_size: 16.0,
-                                            ..default()
-                                        },
-                                        TextColor(Color::WHITE),
-                                    ));
-                                });
-                        });
-
-                    // Currently equipped section
-                    popup.spawn((
-                        Text::new("Equipped"),
-                        TextFont {
-                            font_size: 16.0,
-                            ..default()
-                        },
-                        TextColor(UiTheme::TEXT_HEADER),
-                        Node {
-                            margin: UiRect::bottom(Val::Px(5.0)),
-                            ..default()
-                        },
-                    ));
-
-                    if let Some(weapon) = equipped_weapon {
-                        spawn_popup_weapon_card(popup, hero_entity, weapon, true);
-                    } else {
-                        popup.spawn((
-                            Text::new("No weapon equipped"),
-                            TextFont {
-                                font_size: 14.0,
-                                ..default()
-                            },
-                            TextColor(UiTheme::TEXT_SECONDARY),
-                            Node {
-                                margin: UiRect::bottom(Val::Px(10.0)),
-                                ..default()
-                            },
-                        ));
-                    }
-
-                    // Available weapons section
-                    popup.spawn((
-                        Text::new("Available Weapons"),
-                        TextFont {
-                            font_size: 16.0,
-                            ..default()
-                        },
-                        TextColor(UiTheme::TEXT_HEADER),
-                        Node {
-                            margin: UiRect::vertical(Val::Px(10.0)),
-                            ..default()
-                        },
-                    ));
-
-                    if unequipped_weapons.is_empty() {
-                        popup.spawn((
-                            Text::new("No weapons available. Craft weapons to add them here."),
-                            TextFont {
-                                font_size: 14.0,
-                                ..default()
-                            },
-                            TextColor(UiTheme::TEXT_SECONDARY),
-                        ));
-                    } else {
-                        // Scrollable container for available weapons
-                        popup
-                            .spawn((
-                                Node {
-                                    flex_direction: FlexDirection::Column,
-                                    max_height: Val::Vh(40.0),
-                                    overflow: Overflow::scroll_y(),
-                                    ..default()
-                                },
-                                UnequippedWeaponsList,
-                            ))
-                            .with_children(|scroll_container| {
-                                // Spawn weapons directly here
-                                for weapon in &unequipped_weapons {
-                                    spawn_popup_weapon_card(
-                                        scroll_container,
-                                        hero_entity,
-                                        weapon,
-                                        false,
-                                    );
-                                }
-                            });
-                    }
-                });
-        });
-}
-
-fn spawn_popup_weapon_card(
-    parent: &mut ChildSpawnerCommands,
-    hero_entity: Entity,
-    weapon: &WeaponDisplayData,
-    is_equipped: bool,
-) {
-    let weapon_card = spawn_item_card(parent, ());
-    let weapon_entity = weapon.entity;
-    let weapon_name = weapon.name.clone();
-
-    parent.commands().entity(weapon_card).with_children(|card| {
-        // Weapon info row
-        card.spawn(Node {
-            flex_direction: FlexDirection::Row,
-            justify_content: JustifyContent::SpaceBetween,
-            align_items: AlignItems::Center,
-            width: Val::Percent(100.0),
-            ..default()
-        })
-        .with_children(|row| {
-            // Weapon info
-            row.spawn(Node {
-                flex_direction: FlexDirection::Column,
-                ..default()
-            })
-            .with_children(|info| {
-                info.spawn((
-                    Text::new(&weapon_name),
-                    TextFont {
-                        font_size: 14.0,
-                        ..default()
-                    },
-                    TextColor(UiTheme::TEXT_PRIMARY),
-                ));
-
-                info.spawn((
-                    Text::new(format!(
-                        "DMG: {:.2} | RNG: {:.1}",
-                        weapon.effective_damage, weapon.range
-                    )),
-                    TextFont {
-                        font_size: 12.0,
-                        ..default()
-                    },
-                    TextColor(UiTheme::TEXT_SECONDARY),
-                ));
-            });
-
-            // Action button
-            if is_equipped {
-                row.spawn((
-                    Button,
-                    Node {
-                        padding: UiRect::axes(Val::Px(10.0), Val::Px(5.0)),
-                        border: UiRect::all(Val::Px(1.0)),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        ..default()
-                    },
-                    BorderColor::all(UiTheme::BORDER_ERROR),
-                    BackgroundColor(UiTheme::BUTTON_NORMAL),
-                    UnequipWeaponButton { hero_entity },
-                ))
-                .with_children(|btn| {
-                    btn.spawn((
-                        Text::new("Unequip"),
-                        TextFont {
-                            font_size: 12.0,
-                            ..default()
-                        },
-                        TextColor(UiTheme::TEXT_PRIMARY),
-                    ));
-                });
-            } else {
-                row.spawn((
-                    Button,
-                    Node {
-                        padding: UiRect::axes(Val::Px(10.0), Val::Px(5.0)),
-                        border: UiRect::all(Val::Px(1.0)),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        ..default()
-                    },
-                    BorderColor::all(UiTheme::BORDER_SUCCESS),
-                    BackgroundColor(UiTheme::BUTTON_NORMAL),
-                    EquipWeaponButton {
-                        hero_entity,
-                        weapon_entity,
-                    },
-                ))
-                .with_children(|btn| {
-                    btn.spawn((
-                        Text::new("Equip"),
-                        TextFont {
-                            font_size: 12.0,
-                            ..default()
-                        },
-                        TextColor(UiTheme::TEXT_PRIMARY),
-                    ));
-                });
-            }
-        });
-    });
-}
-
 // ============================================================================
 // Button Handlers
 // ============================================================================
 
-#[allow(clippy::type_complexity)]
-fn handle_change_equipment_button(
+fn handle_hero_tab_interaction(
     mut commands: Commands,
-    interaction_query: Query<
-        (&Interaction, &ChangeEquipmentButton),
-        (Changed<Interaction>, With<Button>),
-    >,
-    existing_popup: Query<Entity, With<EquipmentPopup>>,
-    // Queries for building weapon data
-    hero_children_query: Query<&Children, With<hero_components::Hero>>,
-    unequipped_weapons_query: Query<
-        (
-            Entity,
-            Option<&DisplayName>,
-            &Damage,
-            &AttackRange,
-            &AttackSpeed,
-            Option<&MeleeArc>,
-            Option<&hero_components::WeaponTags>,
-        ),
-        (With<Weapon>, Without<ChildOf>),
-    >,
-    equipped_weapon_query: Query<
-        (
-            Entity,
-            Option<&DisplayName>,
-            &Damage,
-            &AttackRange,
-            &AttackSpeed,
-            Option<&MeleeArc>,
-            Option<&hero_components::WeaponTags>,
-        ),
-        With<Weapon>,
-    >,
-    melee_query: Query<(), With<MeleeWeapon>>,
-    bonus_stats: Res<bonus_stats::BonusStats>,
+    interaction_query: Query<(&Interaction, &HeroTabButton), (Changed<Interaction>, With<Button>)>,
+    mut selected_tab: ResMut<SelectedHeroTab>,
+    hero_query: Query<Entity, With<Hero>>,
 ) {
-    // Log all button interactions for debugging
     for (interaction, btn) in interaction_query.iter() {
         if *interaction == Interaction::Pressed {
-            // Close existing popup if any
-
-            for entity in existing_popup.iter() {
-                commands.entity(entity).despawn();
+            // Find index of this hero
+            let heroes: Vec<Entity> = hero_query.iter().collect();
+            if let Some(index) = heroes.iter().position(|e| *e == btn.hero_entity) {
+                selected_tab.0 = index;
+                commands.trigger(RefreshHeroUiEvent);
             }
-
-            let hero_entity = btn.hero_entity;
-
-            // Build equipped weapon data
-            let equipped_weapon = hero_children_query
-                .get(hero_entity)
-                .ok()
-                .and_then(|children| {
-                    children.iter().find_map(|child| {
-                        equipped_weapon_query.get(child).ok().map(
-                            |(entity, display_name, damage, range, speed, melee_arc, tags)| {
-                                let name = display_name
-                                    .map(|d| d.0.clone())
-                                    .unwrap_or_else(|| "Unknown Weapon".to_string());
-                                let speed_secs = speed.timer.duration().as_secs_f32();
-                                let arc = if melee_query.get(child).is_ok() {
-                                    melee_arc.map(|a| a.width.to_degrees())
-                                } else {
-                                    None
-                                };
-                                let raw_tags = tags.map(|t| t.0.clone()).unwrap_or_default();
-                                let effective_damage = bonus_stats::calculate_damage(
-                                    damage.0,
-                                    &raw_tags,
-                                    &[],
-                                    &bonus_stats,
-                                );
-                                WeaponDisplayData {
-                                    entity,
-                                    name,
-                                    damage: damage.0,
-                                    effective_damage,
-                                    range: range.0,
-                                    speed_secs,
-                                    melee_arc: arc,
-                                }
-                            },
-                        )
-                    })
-                });
-
-            // Build unequipped weapons list
-
-            let unequipped_weapons: Vec<WeaponDisplayData> = unequipped_weapons_query
-                .iter()
-                .map(
-                    |(entity, display_name, damage, range, speed, melee_arc, tags)| {
-                        let name = display_name
-                            .map(|d| d.0.clone())
-                            .unwrap_or_else(|| "Unknown Weapon".to_string());
-                        let speed_secs = speed.timer.duration().as_secs_f32();
-                        let arc = if melee_query.get(entity).is_ok() {
-                            melee_arc.map(|a| a.width.to_degrees())
-                        } else {
-                            None
-                        };
-                        let raw_tags = tags.map(|t| t.0.clone()).unwrap_or_default();
-                        let effective_damage =
-                            bonus_stats::calculate_damage(damage.0, &raw_tags, &[], &bonus_stats);
-                        WeaponDisplayData {
-                            entity,
-                            name,
-                            damage: damage.0,
-                            effective_damage,
-                            range: range.0,
-                            speed_secs,
-                            melee_arc: arc,
-                        }
-                    },
-                )
-                .collect();
-
-            // Spawn popup (weapons are spawned directly inside the popup)
-            spawn_equipment_popup(
-                &mut commands,
-                hero_entity,
-                equipped_weapon.as_ref(),
-                unequipped_weapons,
-            );
         }
     }
 }
 
-fn handle_close_equipment_popup(
-    mut commands: Commands,
-    interaction_query: Query<&Interaction, (Changed<Interaction>, With<CloseEquipmentPopupButton>)>,
-    popup_query: Query<Entity, With<EquipmentPopup>>,
-) {
-    for interaction in interaction_query.iter() {
-        if *interaction == Interaction::Pressed {
-            for entity in popup_query.iter() {
-                commands.entity(entity).despawn();
-            }
-        }
-    }
-}
+// ============================================================================
+// Query Helpers
+// ============================================================================
 
-fn handle_equip_button(
-    mut commands: Commands,
-    interaction_query: Query<
-        (&Interaction, &EquipWeaponButton),
-        (Changed<Interaction>, With<Button>),
+/// Queries needed to build `WeaponDisplayData` for any weapon entity.
+#[derive(SystemParam)]
+pub struct WeaponDisplayQuery<'w, 's> {
+    #[allow(clippy::type_complexity)]
+    weapons: Query<
+        'w,
+        's,
+        (
+            Entity,
+            Option<&'static DisplayName>,
+            &'static Damage,
+            &'static AttackRange,
+            &'static AttackSpeed,
+            Option<&'static MeleeArc>,
+            Option<&'static hero_components::WeaponTags>,
+            Option<&'static ChildOf>,
+            Option<&'static WeaponId>,
+        ),
+        With<Weapon>,
     >,
-    popup_query: Query<Entity, With<EquipmentPopup>>,
-) {
-    for (interaction, btn) in interaction_query.iter() {
-        if *interaction == Interaction::Pressed {
-            // Trigger equip event
-            commands.trigger(EquipWeaponRequest {
-                hero: btn.hero_entity,
-                weapon: btn.weapon_entity,
-            });
+    heroes: Query<'w, 's, (), With<Hero>>,
+    melee: Query<'w, 's, (), With<MeleeWeapon>>,
+    bonus_stats: Res<'w, bonus_stats::BonusStats>,
+    weapon_map: Res<'w, WeaponMap>,
+    weapon_definitions: Res<'w, Assets<WeaponDefinition>>,
+    research_state: Res<'w, ResearchState>,
+    village: Query<'w, 's, &'static Divinity, With<Village>>,
+    damage_target: Res<'w, DamageTarget>,
+}
+
+impl WeaponDisplayQuery<'_, '_> {
+    /// Builds display data for a single weapon entity.
+    pub fn get(&self, entity: Entity) -> Option<WeaponDisplayData> {
+        let (entity, display_name, damage, range, speed, melee_arc, tags, _, weapon_id) =
+            self.weapons.get(entity).ok()?;
+
+        let name = display_name
+            .map(|d| d.0.clone())
+            .unwrap_or_else(|| "Unknown Weapon".to_string());
+        let speed_secs = speed.timer.duration().as_secs_f32();
+        let arc = if self.melee.contains(entity) {
+            melee_arc.map(|a| a.width.to_degrees())
+        } else {
+            None
+        };
+        let raw_tags = tags.map(|t| t.0.clone()).unwrap_or_default();
+        let effective_damage = bonus_stats::calculate_damage(
+            damage.0,
+            &raw_tags,
+            self.damage_target.tags(),
+            &self.bonus_stats,
+        );
 
-            // Close popup and trigger UI refresh
-            for entity in popup_query.iter() {
-                commands.entity(entity).despawn();
-            }
-            commands.trigger(RefreshHeroUiEvent);
-        }
+        Some(WeaponDisplayData {
+            entity,
+            name,
+            damage: damage.0,
+            effective_damage,
+            range: range.0,
+            speed_secs,
+            melee_arc: arc,
+            tags: raw_tags,
+            locked_reason: weapon_id.and_then(|id| self.unmet_requirement(&id.0)),
+        })
     }
-}
 
-fn handle_unequip_button(
-    mut commands: Commands,
-    interaction_query: Query<
-        (&Interaction, &UnequipWeaponButton),
-        (Changed<Interaction>, With<Button>),
-    >,
-    popup_query: Query<Entity, With<EquipmentPopup>>,
-) {
-    for (interaction, btn) in interaction_query.iter() {
-        if *interaction == Interaction::Pressed {
-            // Trigger unequip event
-            commands.trigger(UnequipWeaponRequest {
-                hero: btn.hero_entity,
-            });
-
-            // Close popup and trigger UI refresh
-            for entity in popup_query.iter() {
-                commands.entity(entity).despawn();
-            }
-            commands.trigger(RefreshHeroUiEvent);
-        }
+    /// Describes the weapon's equip requirement if the player has not met it yet.
+    fn unmet_requirement(&self, weapon_id: &str) -> Option<String> {
+        let requirement = self
+            .weapon_map
+            .requirement(weapon_id, &self.weapon_definitions)?;
+        let divinity_tier = self.village.iter().next().map_or(1, |d| d.tier);
+        (!requirement.is_met(divinity_tier, &self.research_state.completion_counts))
+            .then(|| requirement.describe())
     }
-}
 
-fn handle_hero_tab_interaction(
-    mut commands: Commands,
-    interaction_query: Query<(&Interaction, &HeroTabButton), (Changed<Interaction>, With<Button>)>,
-    mut container_query: Query<&mut HeroContentContainer>,
-    hero_query: Query<Entity, With<Hero>>,
-) {
-    for (interaction, btn) in interaction_query.iter() {
-        if *interaction == Interaction::Pressed {
-            if let Ok(mut container) = container_query.single_mut() {
-                // Find index of this hero
-                let heroes: Vec<Entity> = hero_query.iter().collect();
-                if let Some(index) = heroes.iter().position(|e| *e == btn.hero_entity) {
-                    container.selected_index = index;
-                    commands.trigger(RefreshHeroUiEvent);
-                }
-            }
-        }
+    /// Returns all weapons not currently equipped by any hero.
+    ///
+    /// A weapon counts as equipped only when its parent is a `Hero`, so weapons
+    /// parented to other entities are still available.
+    pub fn available(&self) -> Vec<WeaponDisplayData> {
+        self.unequipped()
+            .filter_map(|(entity, _)| self.get(entity))
+            .collect()
     }
-}
-
-fn handle_change_skill_button(
-    mut commands: Commands,
-    interaction_query: Query<
-        (&Interaction, &ChangeSkillButton),
-        (Changed<Interaction>, With<Button>),
-    >,
-    skill_map: Res<SkillMap>,
-    skill_definitions: Res<Assets<SkillDefinition>>,
-    unlocked_skills: Res<UnlockedSkills>,
-) {
-    for (interaction, btn) in interaction_query.iter() {
-        if *interaction == Interaction::Pressed {
-            let hero_entity = btn.hero_entity;
-
-            // Collect available skills (only those that are unlocked)
-            let mut available_skills = Vec::new();
-            for (id, handle) in skill_map.handles.iter() {
-                if unlocked_skills.0.contains(id) {
-                    if let Some(def) = skill_definitions.get(handle) {
-                        available_skills.push((id.clone(), def.display_name.clone()));
-                    }
-                }
-            }
 
-            spawn_skill_popup(&mut commands, hero_entity, available_skills);
-        }
+    /// Like `available`, with weapons of the same definition stacked into one entry.
+    pub fn available_stacks(&self) -> Vec<WeaponStack> {
+        stack_weapons(self.unequipped().filter_map(|(entity, weapon_id)| {
+            Some((weapon_id.map(|id| id.0.clone()), self.get(entity)?))
+        }))
     }
-}
 
-fn handle_close_skill_popup(
-    mut commands: Commands,
-    interaction_query: Query<&Interaction, (Changed<Interaction>, With<CloseSkillPopupButton>)>,
-    popup_query: Query<Entity, With<SkillPopup>>,
-) {
-    for interaction in interaction_query.iter() {
-        if *interaction == Interaction::Pressed {
-            for entity in popup_query.iter() {
-                commands.entity(entity).despawn();
-            }
-        }
+    fn unequipped(&self) -> impl Iterator<Item = (Entity, Option<&WeaponId>)> {
+        self.weapons
+            .iter()
+            .filter(|(.., parent, _)| !parent.is_some_and(|p| self.heroes.contains(p.parent())))
+            .map(|(entity, .., weapon_id)| (entity, weapon_id))
     }
 }
 
-fn handle_equip_skill_button(
-    mut commands: Commands,
-    interaction_query: Query<
-        (&Interaction, &EquipSkillButton),
-        (Changed<Interaction>, With<Button>),
-    >,
-    mut hero_query: Query<&mut EquippedSkills>,
-    popup_query: Query<Entity, With<SkillPopup>>,
-) {
-    for (interaction, btn) in interaction_query.iter() {
-        if *interaction == Interaction::Pressed {
-            info!(
-                "Skill {} equipped to hero {:?}",
-                btn.skill_id, btn.hero_entity
-            );
-
-            if let Ok(mut equipped) = hero_query.get_mut(btn.hero_entity) {
-                // For now, let's just replace the first skill or add if empty
-                if equipped.0.is_empty() {
-                    equipped.0.push(btn.skill_id.clone());
-                } else {
-                    equipped.0[0] = btn.skill_id.clone();
-                }
-            } else {
-                // If the hero doesn't have the component, something is wrong, but we can add it
-                commands
-                    .entity(btn.hero_entity)
-                    .insert(EquippedSkills(vec![btn.skill_id.clone()]));
-            }
-
-            // Close popup and refresh UI
-            for entity in popup_query.iter() {
-                commands.entity(entity).despawn();
-            }
-            commands.trigger(RefreshHeroUiEvent);
-        }
-    }
+/// Returns all weapons not currently equipped by any hero.
+/// Convenience entry point for tests and tools that only have a `World`.
+pub fn available_weapons(world: &mut World) -> Vec<WeaponDisplayData> {
+    let mut state = SystemState::<WeaponDisplayQuery>::new(world);
+    state.get(world).available()
 }
 
-// ============================================================================
-// Query Helpers
-// ============================================================================
-
 /// Builds HeroDisplayData from hero entity and its weapon children.
 /// Call this from village_ui when building the heroes content.
+/// Effective damage is computed against `target_tags`, usually `DamageTarget::tags`.
+#[allow(clippy::too_many_arguments)]
 pub fn build_hero_display_data(
     hero_entity: Entity,
     children_query: &Query<&Children>,
@@ -1274,32 +696,18 @@ pub fn build_hero_display_data(
     >,
     is_melee_query: &Query<(), With<MeleeWeapon>>,
     equipped_skills_query: &Query<&EquippedSkills>,
+    hero_names: &Query<&HeroName>,
     skill_map: &SkillMap,
     skill_definitions: &Assets<SkillDefinition>,
     bonus_stats: &bonus_stats::BonusStats,
+    target_tags: &[String],
 ) -> HeroDisplayData {
-    // Placeholder hero name (heroes don't have names yet)
-    let name = "Hero".to_string();
+    let name = HeroName::display(hero_names.get(hero_entity).ok());
 
     // Fetch equipped skills
     let equipped_skills = equipped_skills_query
         .get(hero_entity)
-        .map(|s| {
-            s.0.iter()
-                .map(|id| {
-                    let name = skill_map
-                        .handles
-                        .get(id)
-                        .and_then(|h| skill_definitions.get(h))
-                        .map(|def| def.display_name.clone())
-                        .unwrap_or_else(|| id.clone());
-                    SkillDisplayData {
-                        id: id.clone(),
-                        name,
-                    }
-                })
-                .collect()
-        })
+        .map(|s| equipped_skill_slots(s, skill_map, skill_definitions))
         .unwrap_or_default();
 
     // Find weapon child
@@ -1323,8 +731,12 @@ pub fn build_hero_display_data(
 
                     // Calculate effective damage
                     let raw_tags = tags.map(|t| t.0.clone()).unwrap_or_default();
-                    let effective_damage =
-                        bonus_stats::calculate_damage(damage.0, &raw_tags, &[], bonus_stats);
+                    let effective_damage = bonus_stats::calculate_damage(
+                        damage.0,
+                        &raw_tags,
+                        target_tags,
+                        bonus_stats,
+                    );
 
                     WeaponDisplayData {
                         entity,
@@ -1334,6 +746,8 @@ pub fn build_hero_display_data(
                         range: range.0,
                         speed_secs,
                         melee_arc: arc_degrees,
+                        tags: raw_tags,
+                        locked_reason: None,
                     }
                 },
             )
@@ -1347,3 +761,244 @@ pub fn build_hero_display_data(
         equipped_skills,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {super::*, bevy::ecs::system::RunSystemOnce, std::time::Duration};
+
+    fn spawn_weapon(world: &mut World, name: &str) -> Entity {
+        world
+            .spawn((
+                Weapon,
+                DisplayName(name.to_string()),
+                Damage(5.0),
+                AttackRange(1.0),
+                AttackSpeed {
+                    timer: Timer::new(Duration::from_secs(1), TimerMode::Repeating),
+                },
+            ))
+            .id()
+    }
+
+    fn weapon_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<bonus_stats::BonusStats>();
+        world.init_resource::<WeaponMap>();
+        world.init_resource::<Assets<WeaponDefinition>>();
+        world.init_resource::<ResearchState>();
+        world.init_resource::<DamageTarget>();
+        world
+    }
+
+    #[test]
+    fn test_available_weapons_excludes_hero_equipped() {
+        let mut world = weapon_world();
+
+        let hero = world.spawn(Hero).id();
+        let stash = world.spawn_empty().id();
+
+        let equipped = spawn_weapon(&mut world, "Equipped");
+        world.entity_mut(equipped).insert(ChildOf(hero));
+        let loose = spawn_weapon(&mut world, "Loose");
+        let stashed = spawn_weapon(&mut world, "Stashed");
+        world.entity_mut(stashed).insert(ChildOf(stash));
+
+        let mut available: Vec<Entity> = available_weapons(&mut world)
+            .into_iter()
+            .map(|w| w.entity)
+            .collect();
+        available.sort();
+
+        let mut expected = vec![loose, stashed];
+        expected.sort();
+        assert_eq!(available, expected);
+    }
+
+    #[test]
+    fn test_effective_damage_follows_damage_target() {
+        let mut world = weapon_world();
+        world.resource_mut::<bonus_stats::BonusStats>().add(
+            "damage:boss",
+            bonus_stats::StatBonus {
+                value: 5.0,
+                mode: bonus_stats::StatMode::Additive,
+            },
+        );
+        spawn_weapon(&mut world, "Sword");
+
+        // Without a target, target bonuses stay out of the number
+        let damage = |world: &mut World| available_weapons(world)[0].effective_damage;
+        assert_eq!(damage(&mut world), 5.0);
+
+        world.resource_mut::<DamageTarget>().0 = Some("boss".to_string());
+        assert_eq!(damage(&mut world), 10.0);
+
+        world.resource_mut::<DamageTarget>().0 = Some("siled".to_string());
+        assert_eq!(damage(&mut world), 5.0);
+    }
+
+    #[test]
+    fn test_available_weapon_reports_unmet_requirement() {
+        let mut world = weapon_world();
+
+        let handle = world
+            .resource_mut::<Assets<WeaponDefinition>>()
+            .add(WeaponDefinition {
+                id: "relic_bow".to_string(),
+                display_name: "Relic Bow".to_string(),
+                weapon_type: weapon_assets::WeaponType::Ranged {
+                    projectile_speed: 400.0,
+                },
+                damage: 5.0,
+                attack_range: 300.0,
+                attack_speed_ms: 1000,
+                tags: vec![],
+                requirement: Some(weapon_assets::WeaponRequirement::Research(
+                    "relic_lore".to_string(),
+                )),
+            });
+        world
+            .resource_mut::<WeaponMap>()
+            .handles
+            .insert("relic_bow".to_string(), handle);
+
+        let weapon = spawn_weapon(&mut world, "Relic Bow");
+        world
+            .entity_mut(weapon)
+            .insert(WeaponId("relic_bow".to_string()));
+
+        let locked = available_weapons(&mut world);
+        assert_eq!(
+            locked[0].locked_reason.as_deref(),
+            Some("Requires research: relic_lore")
+        );
+
+        world
+            .resource_mut::<ResearchState>()
+            .completion_counts
+            .insert("relic_lore".to_string(), 1);
+        let unlocked = available_weapons(&mut world);
+        assert_eq!(unlocked[0].locked_reason, None);
+    }
+
+    pub(crate) fn weapon_data(
+        name: &str,
+        damage: f32,
+        range: f32,
+        speed_secs: f32,
+    ) -> WeaponDisplayData {
+        WeaponDisplayData {
+            entity: Entity::PLACEHOLDER,
+            name: name.to_string(),
+            damage,
+            effective_damage: damage,
+            range,
+            speed_secs,
+            melee_arc: None,
+            tags: vec![],
+            locked_reason: None,
+        }
+    }
+
+    #[test]
+    fn test_no_heroes_shows_recruit_hint() {
+        let mut world = World::new();
+        world.init_resource::<bonus_stats::BonusStats>();
+        world.init_resource::<SkillMap>();
+        world.init_resource::<Assets<SkillDefinition>>();
+
+        let mut unlock_definitions = Assets::<UnlockDefinition>::default();
+        unlock_definitions.add(UnlockDefinition {
+            id: "second_hero".to_string(),
+            display_name: None,
+            condition: unlocks_assets::ConditionNode::And(vec![
+                unlocks_assets::ConditionNode::Completed {
+                    topic: "research:reinforcement_i".to_string(),
+                },
+            ]),
+            reward_id: "hero:second_hero".to_string(),
+            repeat_mode: Default::default(),
+        });
+        world.insert_resource(unlock_definitions);
+
+        world.init_resource::<SelectedHeroTab>();
+        world.init_resource::<DamageTarget>();
+        world.add_observer(on_hero_ui_refresh);
+        let container = world.spawn(HeroContentContainer).id();
+
+        world.trigger(RefreshHeroUiEvent);
+        world.flush();
+
+        let texts: Vec<String> = world
+            .query::<(&Text, &ChildOf)>()
+            .iter(&world)
+            .filter_map(|(text, child_of)| {
+                let parent = world.get::<ChildOf>(child_of.parent())?;
+                (parent.parent() == container).then(|| text.0.clone())
+            })
+            .collect();
+
+        assert_eq!(
+            texts,
+            vec![
+                "No heroes yet — research reinforcement_i to recruit your first hero.".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_selected_hero_tab_survives_refresh_and_reopen() {
+        let mut world = World::new();
+        world.init_resource::<bonus_stats::BonusStats>();
+        world.init_resource::<SkillMap>();
+        world.init_resource::<Assets<SkillDefinition>>();
+        world.init_resource::<Assets<UnlockDefinition>>();
+        world.init_resource::<SelectedHeroTab>();
+        world.init_resource::<DamageTarget>();
+        world.add_observer(on_hero_ui_refresh);
+
+        let heroes: Vec<Entity> = ["Aldric", "Brenna", "Corvin"]
+            .into_iter()
+            .map(|name| world.spawn((Hero, HeroName(name.to_string()))).id())
+            .collect();
+        let shown_hero = |world: &mut World| {
+            let mut details = world.query::<&RenameHeroButton>();
+            details.single(world).unwrap().hero_entity
+        };
+
+        let container = world.spawn(HeroContentContainer).id();
+        world.trigger(RefreshHeroUiEvent);
+        world.flush();
+        assert_eq!(shown_hero(&mut world), heroes[0]);
+
+        // Select the second hero's tab
+        let tab = world
+            .query::<(Entity, &HeroTabButton)>()
+            .iter(&world)
+            .find(|(_, btn)| btn.hero_entity == heroes[1])
+            .map(|(entity, _)| entity)
+            .unwrap();
+        world.entity_mut(tab).insert(Interaction::Pressed);
+        world.run_system_once(handle_hero_tab_interaction).unwrap();
+        world.flush();
+        assert_eq!(shown_hero(&mut world), heroes[1]);
+
+        world.trigger(RefreshHeroUiEvent);
+        world.flush();
+        assert_eq!(shown_hero(&mut world), heroes[1]);
+
+        // Reopening the screen spawns a fresh container
+        world.entity_mut(container).despawn();
+        world.spawn(HeroContentContainer);
+        world.trigger(RefreshHeroUiEvent);
+        world.flush();
+        assert_eq!(shown_hero(&mut world), heroes[1]);
+
+        // With fewer heroes the stale index falls back to the last one
+        world.entity_mut(heroes[1]).despawn();
+        world.entity_mut(heroes[2]).despawn();
+        world.trigger(RefreshHeroUiEvent);
+        world.flush();
+        assert_eq!(shown_hero(&mut world), heroes[0]);
+    }
+}
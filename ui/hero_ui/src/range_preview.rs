@@ -0,0 +1,171 @@
+//! Gizmo preview of the equipped and hovered weapon's reach around the village.
+
+use {
+    crate::{CompareCandidate, EquipmentPopup, WeaponComparisonPanel, WeaponDisplayData},
+    bevy::prelude::*,
+    village_components::Village,
+};
+
+/// Gizmo color for the equipped weapon's reach
+const EQUIPPED_RANGE_COLOR: Color = Color::srgba(0.6, 0.6, 0.6, 0.8);
+
+/// Gizmo color for the hovered candidate's reach
+const CANDIDATE_RANGE_COLOR: Color = Color::srgba(0.3, 0.9, 0.4, 1.0);
+
+/// Reach of a weapon as drawn around the village: range radius and optional melee arc.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RangeShape {
+    pub range: f32,
+    pub arc_degrees: Option<f32>,
+}
+
+impl From<&WeaponDisplayData> for RangeShape {
+    fn from(weapon: &WeaponDisplayData) -> Self {
+        Self {
+            range: weapon.range,
+            arc_degrees: weapon.melee_arc,
+        }
+    }
+}
+
+/// Equipped vs hovered candidate reach, drawn while a popup weapon card is hovered.
+#[derive(Resource, Default, Debug, PartialEq)]
+pub struct WeaponRangePreview {
+    pub equipped: Option<RangeShape>,
+    pub candidate: Option<RangeShape>,
+}
+
+/// Follows popup hover: a hovered candidate fills the preview, hover-out or closing clears it.
+pub(crate) fn update_weapon_range_preview(
+    mut preview: ResMut<WeaponRangePreview>,
+    candidates: Query<(&Interaction, &CompareCandidate), Changed<Interaction>>,
+    panels: Query<&WeaponComparisonPanel>,
+    popups: Query<(), With<EquipmentPopup>>,
+) {
+    if popups.is_empty() {
+        if preview.candidate.is_some() {
+            *preview = WeaponRangePreview::default();
+        }
+        return;
+    }
+
+    let Some((interaction, candidate)) = candidates.iter().last() else {
+        return;
+    };
+
+    *preview = if *interaction == Interaction::None {
+        WeaponRangePreview::default()
+    } else {
+        WeaponRangePreview {
+            equipped: panels
+                .iter()
+                .next()
+                .and_then(|panel| panel.equipped.as_ref())
+                .map(RangeShape::from),
+            candidate: Some(RangeShape::from(&candidate.0)),
+        }
+    };
+}
+
+/// Draws the preview around the village, where hero attacks originate.
+/// Melee arcs are shown facing up the lanes, towards incoming enemies.
+pub(crate) fn draw_weapon_range_preview(
+    preview: Res<WeaponRangePreview>,
+    villages: Query<&Transform, With<Village>>,
+    mut gizmos: Gizmos,
+) {
+    if preview.candidate.is_none() {
+        return;
+    }
+    let Ok(village) = villages.single() else {
+        return;
+    };
+    let center = village.translation.truncate();
+
+    let shapes = [
+        (preview.equipped, EQUIPPED_RANGE_COLOR),
+        (preview.candidate, CANDIDATE_RANGE_COLOR),
+    ];
+    for (shape, color) in shapes {
+        let Some(shape) = shape else {
+            continue;
+        };
+
+        match shape.arc_degrees {
+            Some(arc_degrees) => {
+                let arc = arc_degrees.to_radians();
+                // Arcs sweep counter-clockwise from +Y; rotate back so it is centered on +Y
+                let isometry = Isometry2d::new(center, Rot2::radians(-arc / 2.0));
+                gizmos.arc_2d(isometry, arc, shape.range, color);
+                for side in [-0.5, 0.5] {
+                    let edge = Rot2::radians(arc * side) * Vec2::Y * shape.range;
+                    gizmos.line_2d(center, center + edge, color);
+                }
+            }
+            None => {
+                gizmos.circle_2d(center, shape.range, color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::tests::weapon_data, bevy::ecs::system::RunSystemOnce};
+
+    #[test]
+    fn test_hovered_candidate_drives_range_preview() {
+        let mut world = World::new();
+        world.init_resource::<WeaponRangePreview>();
+
+        let mut sword = weapon_data("Bone Sword", 10.0, 1.5, 1.0);
+        sword.melee_arc = Some(90.0);
+        let bow = weapon_data("Bone Bow", 8.0, 5.0, 0.5);
+
+        let popup = world
+            .spawn(EquipmentPopup {
+                hero_entity: Entity::PLACEHOLDER,
+            })
+            .id();
+        world.spawn(WeaponComparisonPanel {
+            equipped: Some(sword),
+        });
+        let card = world
+            .spawn((CompareCandidate(bow), Interaction::Hovered))
+            .id();
+
+        world.run_system_once(update_weapon_range_preview).unwrap();
+        assert_eq!(
+            *world.resource::<WeaponRangePreview>(),
+            WeaponRangePreview {
+                equipped: Some(RangeShape {
+                    range: 1.5,
+                    arc_degrees: Some(90.0),
+                }),
+                candidate: Some(RangeShape {
+                    range: 5.0,
+                    arc_degrees: None,
+                }),
+            }
+        );
+
+        // Hover-out clears the preview
+        *world.get_mut::<Interaction>(card).unwrap() = Interaction::None;
+        world.run_system_once(update_weapon_range_preview).unwrap();
+        assert_eq!(
+            *world.resource::<WeaponRangePreview>(),
+            WeaponRangePreview::default()
+        );
+
+        // Closing the popup while hovering clears it too
+        *world.get_mut::<Interaction>(card).unwrap() = Interaction::Hovered;
+        world.run_system_once(update_weapon_range_preview).unwrap();
+        assert!(world.resource::<WeaponRangePreview>().candidate.is_some());
+        world.entity_mut(popup).despawn();
+        world.run_system_once(update_weapon_range_preview).unwrap();
+        assert_eq!(
+            *world.resource::<WeaponRangePreview>(),
+            WeaponRangePreview::default()
+        );
+    }
+}
@@ -0,0 +1,283 @@
+//! Popup for typing a new hero name.
+
+use {
+    crate::RefreshHeroUiEvent,
+    bevy::{
+        input::{
+            ButtonState,
+            keyboard::{Key, KeyboardInput},
+        },
+        prelude::*,
+    },
+    hero_components::HeroName,
+    widgets::{Modal, UiTheme, despawn_all, spawn_action_button},
+};
+
+/// Button on the hero name card that opens the rename popup
+#[derive(Component)]
+pub struct RenameHeroButton {
+    pub hero_entity: Entity,
+}
+
+/// Text-entry popup for renaming a hero; `buffer` holds the name typed so far
+#[derive(Component)]
+#[require(Modal)]
+pub struct RenameHeroPopup {
+    pub hero_entity: Entity,
+    pub buffer: String,
+}
+
+/// Text showing the rename popup's current input
+#[derive(Component)]
+pub(crate) struct RenameInputText;
+
+/// Saves the typed name
+#[derive(Component)]
+pub(crate) struct ConfirmRenameButton;
+
+/// Closes the rename popup without changing the name
+#[derive(Component)]
+pub(crate) struct CancelRenameButton;
+
+/// Text shown in the rename field, with a trailing cursor
+fn rename_input_label(buffer: &str) -> String {
+    format!("{}_", buffer)
+}
+
+pub fn spawn_rename_popup(commands: &mut Commands, hero_entity: Entity, current_name: &str) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            RenameHeroPopup {
+                hero_entity,
+                buffer: current_name.to_string(),
+            },
+            Interaction::default(),
+        ))
+        .with_children(|overlay| {
+            overlay
+                .spawn((
+                    Node {
+                        width: Val::Px(320.0),
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(Val::Px(15.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        row_gap: Val::Px(8.0),
+                        ..default()
+                    },
+                    BackgroundColor(UiTheme::POPUP_BG),
+                    BorderColor::all(UiTheme::POPUP_BORDER),
+                ))
+                .with_children(|popup| {
+                    popup.spawn((
+                        Text::new("Rename Hero"),
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(UiTheme::TEXT_HEADER),
+                    ));
+
+                    popup
+                        .spawn((
+                            Node {
+                                padding: UiRect::axes(Val::Px(8.0), Val::Px(5.0)),
+                                border: UiRect::all(Val::Px(1.0)),
+                                ..default()
+                            },
+                            BorderColor::all(UiTheme::CARD_BORDER),
+                            BackgroundColor(UiTheme::BUTTON_NORMAL),
+                        ))
+                        .with_child((
+                            Text::new(rename_input_label(current_name)),
+                            TextFont {
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(UiTheme::TEXT_PRIMARY),
+                            RenameInputText,
+                        ));
+
+                    popup.spawn((
+                        Text::new("Type a name, Enter to save, Esc to cancel"),
+                        TextFont {
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(UiTheme::TEXT_SECONDARY),
+                    ));
+
+                    popup
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            column_gap: Val::Px(8.0),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            spawn_action_button(
+                                row,
+                                "Save",
+                                UiTheme::TEXT_PRIMARY,
+                                UiTheme::BORDER_SUCCESS,
+                                ConfirmRenameButton,
+                            );
+                            spawn_action_button(
+                                row,
+                                "Cancel",
+                                UiTheme::TEXT_PRIMARY,
+                                UiTheme::BORDER_ERROR,
+                                CancelRenameButton,
+                            );
+                        });
+                });
+        });
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn handle_rename_hero_button(
+    mut commands: Commands,
+    interaction_query: Query<
+        (&Interaction, &RenameHeroButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    existing_popup: Query<Entity, With<RenameHeroPopup>>,
+    hero_names: Query<&HeroName>,
+) {
+    for (interaction, btn) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            despawn_all(&mut commands, &existing_popup);
+            let current = HeroName::display(hero_names.get(btn.hero_entity).ok());
+            spawn_rename_popup(&mut commands, btn.hero_entity, &current);
+        }
+    }
+}
+
+/// Saves the popup's name on its hero and closes it. Blank names are ignored.
+fn confirm_rename(commands: &mut Commands, popup_entity: Entity, popup: &RenameHeroPopup) {
+    let name = popup.buffer.trim();
+    if name.is_empty() {
+        return;
+    }
+
+    info!("Hero {:?} renamed to '{}'", popup.hero_entity, name);
+    commands
+        .entity(popup.hero_entity)
+        .insert(HeroName(name.to_string()));
+    commands.entity(popup_entity).despawn();
+    commands.trigger(RefreshHeroUiEvent);
+}
+
+/// Feeds typed characters into the open rename popup. Enter saves.
+pub(crate) fn handle_rename_text_input(
+    mut commands: Commands,
+    mut keyboard_events: MessageReader<KeyboardInput>,
+    mut popup_query: Query<(Entity, &mut RenameHeroPopup)>,
+    mut text_query: Query<&mut Text, With<RenameInputText>>,
+) {
+    let Ok((popup_entity, mut popup)) = popup_query.single_mut() else {
+        keyboard_events.clear();
+        return;
+    };
+
+    let mut confirmed = false;
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Character(chars) => {
+                for c in chars.chars().filter(|c| !c.is_control()) {
+                    if popup.buffer.chars().count() < HeroName::MAX_LEN {
+                        popup.buffer.push(c);
+                    }
+                }
+            }
+            Key::Space if popup.buffer.chars().count() < HeroName::MAX_LEN => {
+                popup.buffer.push(' ');
+            }
+            Key::Backspace => {
+                popup.buffer.pop();
+            }
+            Key::Enter => confirmed = true,
+            _ => {}
+        }
+    }
+
+    if popup.is_changed() {
+        for mut text in text_query.iter_mut() {
+            text.0 = rename_input_label(&popup.buffer);
+        }
+    }
+
+    if confirmed {
+        confirm_rename(&mut commands, popup_entity, &popup);
+    }
+}
+
+pub(crate) fn handle_rename_popup_buttons(
+    mut commands: Commands,
+    confirm_query: Query<&Interaction, (Changed<Interaction>, With<ConfirmRenameButton>)>,
+    cancel_query: Query<&Interaction, (Changed<Interaction>, With<CancelRenameButton>)>,
+    popup_query: Query<(Entity, &RenameHeroPopup)>,
+) {
+    let Ok((popup_entity, popup)) = popup_query.single() else {
+        return;
+    };
+
+    if cancel_query.iter().any(|i| *i == Interaction::Pressed) {
+        commands.entity(popup_entity).despawn();
+    } else if confirm_query.iter().any(|i| *i == Interaction::Pressed) {
+        confirm_rename(&mut commands, popup_entity, popup);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, bevy::ecs::system::RunSystemOnce, hero_components::Hero};
+
+    #[test]
+    fn test_typing_in_rename_popup_renames_hero() {
+        let mut world = World::new();
+        world.init_resource::<Messages<KeyboardInput>>();
+        let hero = world.spawn((Hero, HeroName("Aldric".to_string()))).id();
+        spawn_rename_popup(&mut world.commands(), hero, "Aldric");
+        world.flush();
+
+        let press = |logical_key: Key| KeyboardInput {
+            key_code: KeyCode::Unidentified(bevy::input::keyboard::NativeKeyCode::Unidentified),
+            logical_key,
+            state: ButtonState::Pressed,
+            text: None,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        };
+        for _ in 0.."Aldric".len() {
+            world.write_message(press(Key::Backspace));
+        }
+        for c in ["M", "a", "r", "a"] {
+            world.write_message(press(Key::Character(c.into())));
+        }
+        world.run_system_once(handle_rename_text_input).unwrap();
+        world.resource_mut::<Messages<KeyboardInput>>().clear();
+
+        let mut input = world.query_filtered::<&Text, With<RenameInputText>>();
+        assert_eq!(input.single(&world).unwrap().0, "Mara_");
+        // Nothing is saved until the name is confirmed
+        assert_eq!(world.get::<HeroName>(hero).unwrap().0, "Aldric");
+
+        world.write_message(press(Key::Enter));
+        world.run_system_once(handle_rename_text_input).unwrap();
+
+        assert_eq!(world.get::<HeroName>(hero).unwrap().0, "Mara");
+        let mut popups = world.query::<&RenameHeroPopup>();
+        assert_eq!(popups.iter(&world).count(), 0);
+    }
+}
@@ -0,0 +1,822 @@
+//! Hero skill slots and the popup for choosing the skill of a slot.
+
+use {
+    crate::RefreshHeroUiEvent,
+    bevy::prelude::*,
+    skill_components::{EquippedSkills, UnlockedSkills},
+    skills_assets::{
+        EffectCondition, SkillDefinition, SkillEffect, SkillMap, SkillType, StatModifierMode,
+    },
+    widgets::{
+        ButtonStyle, Modal, UiTheme, despawn_all, format_number_f32, spawn_action_button,
+        spawn_action_button_with_style, spawn_item_card,
+    },
+};
+
+/// Number of skill slots shown for each hero
+pub const HERO_SKILL_SLOTS: usize = 3;
+
+/// Button to open skill change popup for one slot
+#[derive(Component)]
+pub struct ChangeSkillButton {
+    pub hero_entity: Entity,
+    pub slot_index: usize,
+}
+
+/// Marker for the skill popup, remembering which slot it equips into
+#[derive(Component)]
+#[require(Modal)]
+pub struct SkillPopup {
+    pub hero_entity: Entity,
+    pub slot_index: usize,
+}
+
+/// Close button for skill popup
+#[derive(Component)]
+pub struct CloseSkillPopupButton;
+
+/// Button to equip a specific skill
+#[derive(Component)]
+pub struct EquipSkillButton {
+    pub hero_entity: Entity,
+    pub slot_index: usize,
+    pub skill_id: String,
+}
+
+/// Small "X" button on a filled skill slot that empties it
+#[derive(Component)]
+pub struct UnequipSkillButton {
+    pub hero_entity: Entity,
+    pub slot_index: usize,
+}
+
+/// Data for displaying skill info
+#[derive(Clone)]
+pub struct SkillDisplayData {
+    pub id: String,
+    pub name: String,
+    /// True when no loaded definition matches `id` (e.g. a stale save).
+    pub missing: bool,
+}
+
+/// Resolves an equipped skill ID to display data, falling back to an
+/// "(unknown)" label when the definition is not loaded.
+pub fn skill_display_data(
+    id: &str,
+    skill_map: &SkillMap,
+    skill_definitions: &Assets<SkillDefinition>,
+) -> SkillDisplayData {
+    match skill_map
+        .handles
+        .get(id)
+        .and_then(|h| skill_definitions.get(h))
+    {
+        Some(def) => SkillDisplayData {
+            id: id.to_string(),
+            name: def.display_name.clone(),
+            missing: false,
+        },
+        None => {
+            warn!("Equipped skill '{}' has no loaded definition", id);
+            SkillDisplayData {
+                id: id.to_string(),
+                name: format!("{} (unknown)", id),
+                missing: true,
+            }
+        }
+    }
+}
+
+/// Display data for each of a hero's skill slots, in slot order; `None` for empty slots.
+pub fn equipped_skill_slots(
+    equipped: &EquippedSkills,
+    skill_map: &SkillMap,
+    skill_definitions: &Assets<SkillDefinition>,
+) -> Vec<Option<SkillDisplayData>> {
+    (0..equipped.0.len())
+        .map(|slot| {
+            equipped
+                .slot(slot)
+                .map(|id| skill_display_data(id, skill_map, skill_definitions))
+        })
+        .collect()
+}
+
+/// Data for one entry of the skill selection popup. Optional rows are hidden when `None`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SkillOptionData {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// Cooldown in seconds; `None` for passive skills and skills without one
+    pub cooldown_secs: Option<f32>,
+    /// One line per effect, e.g. "Deals 25 damage"
+    pub effects: Option<String>,
+}
+
+impl SkillOptionData {
+    pub fn from_definition(id: &str, def: &SkillDefinition) -> Self {
+        let cooldown_secs = match def.skill_type {
+            SkillType::Passive => None,
+            _ if def.cooldown_ms == 0 => None,
+            _ => Some(def.cooldown_ms as f32 / 1000.0),
+        };
+        let effects: Vec<String> = def.effects.iter().map(effect_summary).collect();
+        Self {
+            id: id.to_string(),
+            name: def.display_name.clone(),
+            description: def.description.clone().filter(|d| !d.is_empty()),
+            cooldown_secs,
+            effects: (!effects.is_empty()).then(|| effects.join("\n")),
+        }
+    }
+}
+
+fn duration_suffix(duration_ms: u32) -> String {
+    if duration_ms == 0 {
+        String::new()
+    } else {
+        format!(" for {}s", format_number_f32(duration_ms as f32 / 1000.0))
+    }
+}
+
+/// Short human-readable description of a single skill effect.
+fn effect_summary(effect: &SkillEffect) -> String {
+    match effect {
+        SkillEffect::Damage { amount } => format!("Deals {} damage", format_number_f32(*amount)),
+        SkillEffect::DamagePercent { percent } => {
+            format!("Deals {}% of max HP as damage", format_number_f32(*percent))
+        }
+        SkillEffect::StatModifier {
+            stat_key,
+            value,
+            mode,
+            duration_ms,
+        } => {
+            let sign = if *value < 0.0 { "" } else { "+" };
+            let amount = match mode {
+                StatModifierMode::Additive => format!("{}{}", sign, format_number_f32(*value)),
+                StatModifierMode::Percent => {
+                    format!("{}{}%", sign, format_number_f32(value * 100.0))
+                }
+                StatModifierMode::Multiplicative => format!("x{}", format_number_f32(*value)),
+            };
+            format!("{} {}{}", amount, stat_key, duration_suffix(*duration_ms))
+        }
+        SkillEffect::ApplyStatus {
+            status_id,
+            duration_ms,
+        } => format!("Applies {}{}", status_id, duration_suffix(*duration_ms)),
+        SkillEffect::Heal { amount } => format!("Heals {}", format_number_f32(*amount)),
+        SkillEffect::Spawn { prefab_id } => format!("Spawns {}", prefab_id),
+        SkillEffect::Projectile { damage, .. } => {
+            format!(
+                "Fires a projectile dealing {} damage",
+                format_number_f32(*damage)
+            )
+        }
+        SkillEffect::Conditional { condition, effects } => {
+            let condition = match condition {
+                EffectCondition::TargetHealthBelow(fraction) => {
+                    format!("target below {}% HP", format_number_f32(fraction * 100.0))
+                }
+                EffectCondition::Chance(chance) => {
+                    format!("{}% chance", format_number_f32(chance * 100.0))
+                }
+                EffectCondition::HasStatus(status) => format!("has {}", status),
+            };
+            let inner: Vec<String> = effects.iter().map(effect_summary).collect();
+            format!("If {}: {}", condition, inner.join(", "))
+        }
+    }
+}
+
+pub fn spawn_skills_section(
+    parent: &mut ChildSpawnerCommands,
+    hero_entity: Entity,
+    equipped_skills: &[Option<SkillDisplayData>],
+) {
+    // Skills header
+    parent.spawn((
+        Text::new("Skills"),
+        TextFont {
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(UiTheme::TEXT_HEADER),
+        Node {
+            margin: UiRect::vertical(Val::Px(8.0)),
+            ..default()
+        },
+    ));
+
+    // Skill slots container
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            column_gap: Val::Px(8.0),
+            width: Val::Percent(100.0),
+            margin: UiRect::bottom(Val::Px(10.0)),
+            ..default()
+        })
+        .with_children(|container| {
+            for slot_index in 0..HERO_SKILL_SLOTS {
+                let skill = equipped_skills.get(slot_index).cloned().flatten();
+                spawn_skill_slot(container, hero_entity, slot_index, skill);
+            }
+        });
+}
+
+fn spawn_skill_slot(
+    parent: &mut ChildSpawnerCommands,
+    hero_entity: Entity,
+    slot_index: usize,
+    skill: Option<SkillDisplayData>,
+) {
+    let filled = skill.is_some();
+    let (label, border_color) = match skill {
+        Some(s) if s.missing => (s.name, UiTheme::BORDER_ERROR),
+        Some(s) => (s.name, UiTheme::TAB_BORDER),
+        None => ("[ Empty Slot ]".to_string(), UiTheme::TEXT_SECONDARY),
+    };
+
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(4.0),
+            ..default()
+        })
+        .with_children(|slot| {
+            spawn_action_button(
+                slot,
+                &label,
+                UiTheme::TEXT_PRIMARY,
+                border_color,
+                ChangeSkillButton {
+                    hero_entity,
+                    slot_index,
+                },
+            );
+
+            if filled {
+                spawn_action_button_with_style(
+                    slot,
+                    "X",
+                    UiTheme::NOT_AFFORDABLE,
+                    UiTheme::BORDER_ERROR,
+                    ButtonStyle {
+                        width: Val::Px(30.0),
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    UnequipSkillButton {
+                        hero_entity,
+                        slot_index,
+                    },
+                );
+            }
+        });
+}
+
+pub fn spawn_skill_popup(
+    commands: &mut Commands,
+    hero_entity: Entity,
+    slot_index: usize,
+    available_skills: Vec<SkillOptionData>,
+) {
+    // Full-screen overlay
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                right: Val::Px(0.0),
+                top: Val::Px(0.0),
+                bottom: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            SkillPopup {
+                hero_entity,
+                slot_index,
+            },
+            Interaction::default(),
+        ))
+        .with_children(|overlay| {
+            // Popup panel
+            overlay
+                .spawn((
+                    Node {
+                        width: Val::Px(400.0),
+                        max_height: Val::Vh(70.0),
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(Val::Px(15.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(UiTheme::POPUP_BG),
+                    BorderColor::all(UiTheme::POPUP_BORDER),
+                ))
+                .with_children(|popup| {
+                    // Header row
+                    popup
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            margin: UiRect::bottom(Val::Px(10.0)),
+                            ..default()
+                        })
+                        .with_children(|header| {
+                            header.spawn((
+                                Text::new("Select Skill"),
+                                TextFont {
+                                    font_size: 20.0,
+                                    ..default()
+                                },
+                                TextColor(UiTheme::TEXT_HEADER),
+                            ));
+
+                            // Close button
+                            header
+                                .spawn((
+                                    Button,
+                                    Node {
+                                        width: Val::Px(24.0),
+                                        height: Val::Px(24.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..default()
+                                    },
+                                    BackgroundColor(UiTheme::CLOSE_BUTTON_BG),
+                                    CloseSkillPopupButton,
+                                ))
+                                .with_children(|btn| {
+                                    btn.spawn((
+                                        Text::new("X"),
+                                        TextFont {
+                                            font_size: 16.0,
+                                            ..default()
+                                        },
+                                        TextColor(Color::WHITE),
+                                    ));
+                                });
+                        });
+
+                    // Scrollable container for available skills
+                    popup
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            max_height: Val::Vh(40.0),
+                            overflow: Overflow::scroll_y(),
+                            ..default()
+                        })
+                        .with_children(|scroll_container| {
+                            for skill in available_skills {
+                                spawn_skill_selection_card(
+                                    scroll_container,
+                                    hero_entity,
+                                    slot_index,
+                                    skill,
+                                );
+                            }
+                        });
+                });
+        });
+}
+
+fn spawn_skill_selection_card(
+    parent: &mut ChildSpawnerCommands,
+    hero_entity: Entity,
+    slot_index: usize,
+    skill: SkillOptionData,
+) {
+    let card = spawn_item_card(parent, ());
+    parent.commands().entity(card).with_children(|card| {
+        card.spawn(Node {
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            width: Val::Percent(100.0),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Text::new(&skill.name),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(UiTheme::TEXT_PRIMARY),
+            ));
+
+            row.spawn((
+                Button,
+                Node {
+                    padding: UiRect::axes(Val::Px(10.0), Val::Px(5.0)),
+                    border: UiRect::all(Val::Px(1.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BorderColor::all(UiTheme::BORDER_SUCCESS),
+                BackgroundColor(UiTheme::BUTTON_NORMAL),
+                EquipSkillButton {
+                    hero_entity,
+                    slot_index,
+                    skill_id: skill.id,
+                },
+            ))
+            .with_children(|btn| {
+                btn.spawn((
+                    Text::new("Equip"),
+                    TextFont {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(UiTheme::TEXT_PRIMARY),
+                ));
+            });
+        });
+
+        if let Some(description) = &skill.description {
+            card.spawn((
+                Text::new(description),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(UiTheme::TEXT_SECONDARY),
+            ));
+        }
+        if let Some(cooldown) = skill.cooldown_secs {
+            card.spawn((
+                Text::new(format!("Cooldown: {}s", format_number_f32(cooldown))),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(UiTheme::TEXT_SECONDARY),
+            ));
+        }
+        if let Some(effects) = &skill.effects {
+            card.spawn((
+                Text::new(effects),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(UiTheme::TEXT_INFO),
+            ));
+        }
+    });
+}
+
+pub(crate) fn handle_change_skill_button(
+    mut commands: Commands,
+    interaction_query: Query<
+        (&Interaction, &ChangeSkillButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    existing_popup: Query<Entity, With<SkillPopup>>,
+    equipped_skills_query: Query<&EquippedSkills>,
+    skill_map: Res<SkillMap>,
+    skill_definitions: Res<Assets<SkillDefinition>>,
+    unlocked_skills: Res<UnlockedSkills>,
+) {
+    for (interaction, btn) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            // Close existing popup if any
+            despawn_all(&mut commands, &existing_popup);
+
+            let hero_entity = btn.hero_entity;
+            let equipped = equipped_skills_query.get(hero_entity).ok();
+
+            // Collect available skills (only those that are unlocked and not
+            // already sitting in one of the hero's other slots)
+            let mut available_skills = Vec::new();
+            for (id, handle) in skill_map.handles.iter() {
+                let in_other_slot = equipped.is_some_and(|e| {
+                    e.0.iter()
+                        .enumerate()
+                        .any(|(slot, equipped_id)| slot != btn.slot_index && equipped_id == id)
+                });
+                if unlocked_skills.0.contains(id)
+                    && !in_other_slot
+                    && let Some(def) = skill_definitions.get(handle)
+                {
+                    available_skills.push(SkillOptionData::from_definition(id, def));
+                }
+            }
+            available_skills.sort_by(|a, b| a.id.cmp(&b.id));
+
+            spawn_skill_popup(&mut commands, hero_entity, btn.slot_index, available_skills);
+        }
+    }
+}
+
+pub(crate) fn handle_close_skill_popup(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<CloseSkillPopupButton>)>,
+    popup_query: Query<Entity, With<SkillPopup>>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            despawn_all(&mut commands, &popup_query);
+        }
+    }
+}
+
+pub(crate) fn handle_equip_skill_button(
+    mut commands: Commands,
+    interaction_query: Query<
+        (&Interaction, &EquipSkillButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut hero_query: Query<&mut EquippedSkills>,
+    popup_query: Query<Entity, With<SkillPopup>>,
+) {
+    for (interaction, btn) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            info!(
+                "Skill {} equipped to hero {:?}",
+                btn.skill_id, btn.hero_entity
+            );
+
+            if let Ok(mut equipped) = hero_query.get_mut(btn.hero_entity) {
+                // Write to the targeted slot so other slots keep their order
+                equipped.set_slot(btn.slot_index, btn.skill_id.clone());
+            } else {
+                // If the hero doesn't have the component, something is wrong, but we can add it
+                let mut equipped = EquippedSkills::default();
+                equipped.set_slot(btn.slot_index, btn.skill_id.clone());
+                commands.entity(btn.hero_entity).insert(equipped);
+            }
+
+            // Close popup and refresh UI
+            despawn_all(&mut commands, &popup_query);
+            commands.trigger(RefreshHeroUiEvent);
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn handle_unequip_skill_button(
+    mut commands: Commands,
+    interaction_query: Query<
+        (&Interaction, &UnequipSkillButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut hero_query: Query<&mut EquippedSkills>,
+) {
+    for (interaction, btn) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed
+            && let Ok(mut equipped) = hero_query.get_mut(btn.hero_entity)
+            && let Some(skill_id) = equipped.clear_slot(btn.slot_index)
+        {
+            info!(
+                "Skill {} unequipped from hero {:?}",
+                skill_id, btn.hero_entity
+            );
+            commands.trigger(RefreshHeroUiEvent);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, bevy::ecs::system::RunSystemOnce};
+
+    #[test]
+    fn test_each_skill_slot_targets_its_own_index() {
+        let mut world = World::new();
+        let hero = world
+            .spawn(EquippedSkills(vec![
+                "fireball".to_string(),
+                "heal".to_string(),
+            ]))
+            .id();
+        let equipped: Vec<Option<SkillDisplayData>> = ["fireball", "heal"]
+            .into_iter()
+            .map(|id| {
+                Some(SkillDisplayData {
+                    id: id.to_string(),
+                    name: id.to_string(),
+                    missing: false,
+                })
+            })
+            .collect();
+
+        world
+            .commands()
+            .spawn(Node::default())
+            .with_children(|parent| {
+                spawn_skills_section(parent, hero, &equipped);
+            });
+        world.flush();
+
+        let mut slots: Vec<(usize, String)> = world
+            .query::<(&ChangeSkillButton, &Children)>()
+            .iter(&world)
+            .map(|(btn, children)| {
+                let label = children
+                    .iter()
+                    .find_map(|child| world.get::<Text>(child))
+                    .map(|text| text.0.clone())
+                    .unwrap_or_default();
+                (btn.slot_index, label)
+            })
+            .collect();
+        slots.sort();
+        assert_eq!(
+            slots,
+            vec![
+                (0, "fireball".to_string()),
+                (1, "heal".to_string()),
+                (2, "[ Empty Slot ]".to_string()),
+            ]
+        );
+
+        // Equipping from a popup opened on slot 1 only replaces that slot
+        world.spawn((
+            Button,
+            Interaction::Pressed,
+            EquipSkillButton {
+                hero_entity: hero,
+                slot_index: 1,
+                skill_id: "shield".to_string(),
+            },
+        ));
+        world.run_system_once(handle_equip_skill_button).unwrap();
+        assert_eq!(
+            world.get::<EquippedSkills>(hero).unwrap().0,
+            vec!["fireball".to_string(), "shield".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_equip_into_later_slot_of_empty_hero_keeps_its_index() {
+        let mut world = World::new();
+        let hero = world.spawn(EquippedSkills::default()).id();
+        let bare_hero = world.spawn_empty().id();
+
+        for hero_entity in [hero, bare_hero] {
+            world.spawn((
+                Button,
+                Interaction::Pressed,
+                EquipSkillButton {
+                    hero_entity,
+                    slot_index: 2,
+                    skill_id: "shield".to_string(),
+                },
+            ));
+        }
+        world.run_system_once(handle_equip_skill_button).unwrap();
+
+        for hero_entity in [hero, bare_hero] {
+            let equipped = world.get::<EquippedSkills>(hero_entity).unwrap();
+            assert_eq!(equipped.slot(0), None);
+            assert_eq!(equipped.slot(2), Some("shield"));
+        }
+    }
+
+    #[test]
+    fn test_unequip_clears_only_its_slot() {
+        let mut world = World::new();
+        world.init_resource::<SkillMap>();
+        world.init_resource::<Assets<SkillDefinition>>();
+        let hero = world
+            .spawn(EquippedSkills(vec![
+                "fireball".to_string(),
+                "heal".to_string(),
+                "shield".to_string(),
+            ]))
+            .id();
+
+        let button = world
+            .spawn((
+                Button,
+                Interaction::Pressed,
+                UnequipSkillButton {
+                    hero_entity: hero,
+                    slot_index: 1,
+                },
+            ))
+            .id();
+        world.run_system_once(handle_unequip_skill_button).unwrap();
+        world.despawn(button);
+
+        // The emptied middle slot stays put instead of pulling "shield" forward
+        let slots: Vec<Option<String>> = world
+            .run_system_once(
+                move |equipped: Query<&EquippedSkills>,
+                      skill_map: Res<SkillMap>,
+                      skill_definitions: Res<Assets<SkillDefinition>>| {
+                    equipped_skill_slots(
+                        equipped.get(hero).unwrap(),
+                        &skill_map,
+                        &skill_definitions,
+                    )
+                    .into_iter()
+                    .map(|slot| slot.map(|s| s.id))
+                    .collect::<Vec<_>>()
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            slots,
+            vec![
+                Some("fireball".to_string()),
+                None,
+                Some("shield".to_string())
+            ]
+        );
+
+        // Only filled slots offer an unequip button
+        world
+            .commands()
+            .spawn(Node::default())
+            .with_children(|parent| {
+                spawn_skills_section(
+                    parent,
+                    hero,
+                    &[
+                        None,
+                        Some(skill_display_data(
+                            "heal",
+                            &SkillMap::default(),
+                            &Assets::default(),
+                        )),
+                    ],
+                );
+            });
+        world.flush();
+        let unequip_slots: Vec<usize> = world
+            .query::<&UnequipSkillButton>()
+            .iter(&world)
+            .map(|btn| btn.slot_index)
+            .collect();
+        assert_eq!(unequip_slots, vec![1]);
+    }
+
+    #[test]
+    fn test_missing_skill_definition_falls_back_to_unknown() {
+        let skill_map = SkillMap::default();
+        let skill_definitions = Assets::<SkillDefinition>::default();
+
+        let data = skill_display_data("removed_skill", &skill_map, &skill_definitions);
+
+        assert!(data.missing);
+        assert_eq!(data.id, "removed_skill");
+        assert_eq!(data.name, "removed_skill (unknown)");
+    }
+
+    #[test]
+    fn test_skill_option_hides_missing_details() {
+        let mut def = SkillDefinition {
+            id: "fireball".to_string(),
+            display_name: "Fireball".to_string(),
+            description: Some("Hurls fire.".to_string()),
+            skill_type: SkillType::AutoActivate,
+            cooldown_ms: 2500,
+            target: default(),
+            effects: vec![SkillEffect::Projectile {
+                speed: 400.0,
+                damage: 25.0,
+            }],
+            tags: vec![],
+            unlock: None,
+        };
+        let option = SkillOptionData::from_definition("fireball", &def);
+        assert_eq!(option.description.as_deref(), Some("Hurls fire."));
+        assert_eq!(option.cooldown_secs, Some(2.5));
+        assert_eq!(
+            option.effects.as_deref(),
+            Some("Fires a projectile dealing 25 damage")
+        );
+
+        // Percent modifiers are fractions, like bonus stats
+        def.effects = vec![SkillEffect::StatModifier {
+            stat_key: "damage".to_string(),
+            value: 0.25,
+            mode: StatModifierMode::Percent,
+            duration_ms: 5000,
+        }];
+        let option = SkillOptionData::from_definition("fireball", &def);
+        assert_eq!(option.effects.as_deref(), Some("+25% damage for 5s"));
+
+        def.description = None;
+        def.skill_type = SkillType::Passive;
+        def.effects.clear();
+        let option = SkillOptionData::from_definition("fireball", &def);
+        assert_eq!(option.description, None);
+        assert_eq!(option.cooldown_secs, None);
+        assert_eq!(option.effects, None);
+    }
+}
@@ -0,0 +1,174 @@
+//! Small settings panel in the top-left corner for changing `GameSpeed`.
+
+use {
+    bevy::prelude::*,
+    states::{GameSpeed, GameState},
+    widgets::{ButtonStyle, UiTheme, spawn_action_button_with_style},
+};
+
+pub struct GameSpeedPlugin;
+
+impl Plugin for GameSpeedPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Running), spawn_game_speed_panel)
+            .add_systems(
+                Update,
+                (
+                    handle_game_speed_buttons,
+                    update_game_speed_text.run_if(resource_changed::<GameSpeed>),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Running)),
+            )
+            .add_systems(OnExit(GameState::Running), clean_up_game_speed_panel);
+    }
+}
+
+/// Halves (`false`) or doubles (`true`) the game speed
+#[derive(Component)]
+pub struct GameSpeedButton(pub bool);
+
+#[derive(Component)]
+struct GameSpeedPanel;
+
+#[derive(Component)]
+struct GameSpeedText;
+
+fn speed_label(speed: &GameSpeed) -> String {
+    format!("{}x", speed.get())
+}
+
+fn spawn_game_speed_panel(mut commands: Commands, speed: Res<GameSpeed>) {
+    let small = ButtonStyle {
+        width: Val::Px(28.0),
+        height: Val::Px(28.0),
+        ..default()
+    };
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(10.0),
+                left: Val::Px(10.0),
+                padding: UiRect::all(Val::Px(6.0)),
+                column_gap: Val::Px(6.0),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(UiTheme::PANEL_BG),
+            GameSpeedPanel,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new("Speed"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(UiTheme::TEXT_SECONDARY),
+            ));
+            spawn_action_button_with_style(
+                panel,
+                "-",
+                UiTheme::TEXT_PRIMARY,
+                UiTheme::TAB_BORDER,
+                small,
+                GameSpeedButton(false),
+            );
+            panel.spawn((
+                Text::new(speed_label(&speed)),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(UiTheme::TEXT_PRIMARY),
+                Node {
+                    min_width: Val::Px(40.0),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                GameSpeedText,
+            ));
+            spawn_action_button_with_style(
+                panel,
+                "+",
+                UiTheme::TEXT_PRIMARY,
+                UiTheme::TAB_BORDER,
+                small,
+                GameSpeedButton(true),
+            );
+        });
+}
+
+fn handle_game_speed_buttons(
+    interaction_query: Query<(&Interaction, &GameSpeedButton), Changed<Interaction>>,
+    mut speed: ResMut<GameSpeed>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            let factor = if button.0 { 2.0 } else { 0.5 };
+            let next = speed.get() * factor;
+            speed.set(next);
+        }
+    }
+}
+
+fn update_game_speed_text(speed: Res<GameSpeed>, mut query: Query<&mut Text, With<GameSpeedText>>) {
+    for mut text in query.iter_mut() {
+        text.0 = speed_label(&speed);
+    }
+}
+
+fn clean_up_game_speed_panel(mut commands: Commands, query: Query<Entity, With<GameSpeedPanel>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, bevy::state::app::StatesPlugin};
+
+    fn press(app: &mut App, faster: bool) {
+        let mut buttons = app
+            .world_mut()
+            .query::<(&mut Interaction, &GameSpeedButton)>();
+        for (mut interaction, button) in buttons.iter_mut(app.world_mut()) {
+            *interaction = if button.0 == faster {
+                Interaction::Pressed
+            } else {
+                Interaction::None
+            };
+        }
+        app.update();
+        // Release, so the next press is a change again
+        for (mut interaction, _) in buttons.iter_mut(app.world_mut()) {
+            *interaction = Interaction::None;
+        }
+    }
+
+    #[test]
+    fn test_speed_buttons_double_and_halve_within_range() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin, GameSpeedPlugin))
+            .init_resource::<GameSpeed>()
+            .insert_state(GameState::Running);
+        app.update();
+
+        press(&mut app, true);
+        assert_eq!(app.world().resource::<GameSpeed>().get(), 2.0);
+        press(&mut app, true);
+        press(&mut app, true);
+        assert_eq!(app.world().resource::<GameSpeed>().get(), GameSpeed::MAX);
+
+        press(&mut app, false);
+        assert_eq!(app.world().resource::<GameSpeed>().get(), 2.0);
+        let label = app
+            .world_mut()
+            .query_filtered::<&Text, With<GameSpeedText>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(label.0, "2x");
+    }
+}
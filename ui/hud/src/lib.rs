@@ -6,12 +6,22 @@ use {
 };
 
 pub mod components;
+mod game_speed;
+mod new_game;
+mod pause;
+
+pub use {game_speed::GameSpeedButton, new_game::NewGameButton, pause::PauseButton};
 
 pub struct HudPlugin;
 
 impl Plugin for HudPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.add_plugins((
+            pause::PausePlugin,
+            game_speed::GameSpeedPlugin,
+            new_game::NewGamePlugin,
+        ))
+        .add_systems(
             Update,
             update_enemy_status_bars.run_if(in_state(GameState::Running)),
         )
@@ -0,0 +1,122 @@
+//! "New Game" button in the top-right corner, asking for confirmation before `ResetGame`.
+
+use {
+    bevy::prelude::*,
+    save_load::ResetGame,
+    states::GameState,
+    widgets::{
+        ButtonStyle, ConfirmDialog, UiTheme, despawn_all, spawn_action_button_with_style,
+        spawn_confirm_dialog,
+    },
+};
+
+pub struct NewGamePlugin;
+
+impl Plugin for NewGamePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Running), spawn_new_game_button)
+            .add_systems(
+                Update,
+                (
+                    handle_new_game_button,
+                    handle_confirm_new_game,
+                    handle_cancel_new_game,
+                )
+                    .run_if(in_state(GameState::Running)),
+            )
+            .add_systems(OnExit(GameState::Running), clean_up_new_game);
+    }
+}
+
+/// Button opening the new game confirmation
+#[derive(Component)]
+pub struct NewGameButton;
+
+/// Root of the new game button, positioned at the top right
+#[derive(Component)]
+struct NewGameButtonRoot;
+
+/// Yes button of the confirmation dialog
+#[derive(Component)]
+struct ConfirmNewGame;
+
+/// No button of the confirmation dialog
+#[derive(Component)]
+struct CancelNewGame;
+
+fn spawn_new_game_button(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(10.0),
+                right: Val::Px(10.0),
+                ..default()
+            },
+            NewGameButtonRoot,
+        ))
+        .with_children(|root| {
+            spawn_action_button_with_style(
+                root,
+                "New Game",
+                UiTheme::TEXT_PRIMARY,
+                UiTheme::BORDER_ERROR,
+                ButtonStyle {
+                    width: Val::Px(100.0),
+                    ..default()
+                },
+                NewGameButton,
+            );
+        });
+}
+
+fn handle_new_game_button(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<NewGameButton>)>,
+    dialogs: Query<(), With<ConfirmDialog>>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed && dialogs.is_empty() {
+            spawn_confirm_dialog(
+                &mut commands,
+                "Start a new game?\nUnsaved progress will be lost.",
+                ConfirmNewGame,
+                CancelNewGame,
+            );
+        }
+    }
+}
+
+fn handle_confirm_new_game(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<ConfirmNewGame>)>,
+    dialogs: Query<Entity, With<ConfirmDialog>>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            despawn_all(&mut commands, &dialogs);
+            commands.trigger(ResetGame);
+        }
+    }
+}
+
+fn handle_cancel_new_game(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<CancelNewGame>)>,
+    dialogs: Query<Entity, With<ConfirmDialog>>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            despawn_all(&mut commands, &dialogs);
+        }
+    }
+}
+
+fn clean_up_new_game(
+    mut commands: Commands,
+    roots: Query<Entity, With<NewGameButtonRoot>>,
+    dialogs: Query<Entity, With<ConfirmDialog>>,
+) {
+    despawn_all(&mut commands, &roots);
+    despawn_all(&mut commands, &dialogs);
+}
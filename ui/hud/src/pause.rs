@@ -0,0 +1,196 @@
+//! Pause toggle: a button at the top of the screen and the Space key.
+
+use {
+    bevy::prelude::*,
+    states::{GameState, PauseState, VillageView},
+    widgets::{ButtonStyle, UiTheme, any_modal_open, spawn_action_button_with_style},
+};
+
+pub struct PausePlugin;
+
+impl Plugin for PausePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Running), spawn_pause_button)
+            .add_systems(
+                Update,
+                (
+                    // Space is typed into popups and the encyclopedia search
+                    toggle_pause_on_space
+                        .run_if(not(any_modal_open).and(not(in_state(VillageView::Encyclopedia)))),
+                    handle_pause_button,
+                    update_pause_label.run_if(state_changed::<PauseState>),
+                )
+                    .run_if(in_state(GameState::Running)),
+            )
+            .add_systems(OnEnter(PauseState::Paused), spawn_paused_banner)
+            .add_systems(OnExit(PauseState::Paused), despawn_paused_banner)
+            .add_systems(OnExit(GameState::Running), clean_up_pause_button);
+    }
+}
+
+/// Button toggling the pause
+#[derive(Component)]
+pub struct PauseButton;
+
+/// Root of the pause button, positioned at the top center
+#[derive(Component)]
+struct PauseButtonRoot;
+
+/// Banner shown in the middle of the screen while paused
+#[derive(Component)]
+struct PausedBanner;
+
+fn toggled(state: &PauseState) -> PauseState {
+    match state {
+        PauseState::Unpaused => PauseState::Paused,
+        PauseState::Paused => PauseState::Unpaused,
+    }
+}
+
+fn pause_label(state: &PauseState) -> &'static str {
+    match state {
+        PauseState::Unpaused => "Pause",
+        PauseState::Paused => "Resume",
+    }
+}
+
+fn spawn_pause_button(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(10.0),
+                left: Val::Percent(50.0),
+                margin: UiRect::left(Val::Px(-40.0)),
+                ..default()
+            },
+            PauseButtonRoot,
+        ))
+        .with_children(|root| {
+            spawn_action_button_with_style(
+                root,
+                pause_label(&PauseState::Unpaused),
+                UiTheme::TEXT_PRIMARY,
+                UiTheme::TAB_BORDER,
+                ButtonStyle {
+                    width: Val::Px(80.0),
+                    ..default()
+                },
+                PauseButton,
+            );
+        });
+}
+
+fn toggle_pause_on_space(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    state: Res<State<PauseState>>,
+    mut next_state: ResMut<NextState<PauseState>>,
+) {
+    if keyboard.just_pressed(KeyCode::Space) {
+        next_state.set(toggled(state.get()));
+    }
+}
+
+fn handle_pause_button(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<PauseButton>)>,
+    state: Res<State<PauseState>>,
+    mut next_state: ResMut<NextState<PauseState>>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            next_state.set(toggled(state.get()));
+        }
+    }
+}
+
+fn update_pause_label(
+    state: Res<State<PauseState>>,
+    buttons: Query<&Children, With<PauseButton>>,
+    mut texts: Query<&mut Text>,
+) {
+    for children in buttons.iter() {
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0 = pause_label(state.get()).to_string();
+            }
+        }
+    }
+}
+
+/// Spawns the "PAUSED" banner. It has no `Interaction`, so clicks still reach the UI.
+fn spawn_paused_banner(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(40.0),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            PausedBanner,
+        ))
+        .with_children(|banner| {
+            banner.spawn((
+                Text::new("PAUSED"),
+                TextFont {
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(UiTheme::TEXT_HEADER),
+            ));
+        });
+}
+
+fn despawn_paused_banner(mut commands: Commands, query: Query<Entity, With<PausedBanner>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn clean_up_pause_button(mut commands: Commands, query: Query<Entity, With<PauseButtonRoot>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, bevy::state::app::StatesPlugin, widgets::ModalState};
+
+    fn press_space_in(view: VillageView) -> PauseState {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin, PausePlugin))
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ModalState>()
+            .insert_state(GameState::Running)
+            .add_sub_state::<PauseState>()
+            .insert_state(view);
+        app.update();
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        app.update();
+        // No InputPlugin here to clear the press, so do it before the transition frame
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .clear();
+        app.update();
+
+        app.world().resource::<State<PauseState>>().get().clone()
+    }
+
+    #[test]
+    fn test_space_toggles_pause() {
+        assert_eq!(press_space_in(VillageView::Menu), PauseState::Paused);
+    }
+
+    #[test]
+    fn test_space_is_ignored_in_encyclopedia() {
+        assert_eq!(
+            press_space_in(VillageView::Encyclopedia),
+            PauseState::Unpaused
+        );
+    }
+}
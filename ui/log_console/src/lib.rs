@@ -0,0 +1,327 @@
+//! In-game log console overlay.
+//! Captures `info!`/`warn!`/`error!` records through a custom tracing layer into a
+//! bounded ring buffer and renders them in a toggleable panel (press `` ` ``).
+//! The panel header also shows the `UiRebuildStats` counters.
+//!
+//! Register the layer with `LogPlugin { custom_layer: log_capture_layer, .. }`.
+
+use {
+    bevy::{
+        log::{
+            BoxedLayer, Level,
+            tracing::{
+                self, Subscriber,
+                field::{Field, Visit},
+            },
+            tracing_subscriber::layer::{Context, Layer},
+        },
+        prelude::*,
+    },
+    std::{
+        collections::VecDeque,
+        fmt::Debug,
+        sync::{Arc, Mutex},
+    },
+    widgets::{UiRebuildStats, UiTheme, spawn_scrollable_container},
+};
+
+/// Maximum number of log records kept in the buffer
+pub const LOG_BUFFER_CAPACITY: usize = 200;
+/// Key that toggles the console overlay
+const TOGGLE_KEY: KeyCode = KeyCode::Backquote;
+
+pub struct LogConsolePlugin;
+
+impl Plugin for LogConsolePlugin {
+    fn build(&self, app: &mut App) {
+        // Keeps the buffer inserted by `log_capture_layer` if present
+        app.init_resource::<LogBuffer>()
+            .init_resource::<UiRebuildStats>()
+            .add_systems(
+                Update,
+                (
+                    toggle_log_console,
+                    (update_log_console, update_rebuild_stats_text),
+                )
+                    .chain(),
+            );
+    }
+}
+
+// ============================================================================
+// Log Buffer
+// ============================================================================
+
+/// A single captured log record
+#[derive(Clone, Debug)]
+pub struct LogEntry {
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+/// Bounded ring buffer of recent log records.
+///
+/// Shared between the tracing layer (any thread) and the ECS, so it is a cheap
+/// clonable handle around a mutex.
+#[derive(Resource, Clone)]
+pub struct LogBuffer {
+    inner: Arc<Mutex<LogBufferInner>>,
+}
+
+struct LogBufferInner {
+    entries: VecDeque<LogEntry>,
+    capacity: usize,
+    /// Incremented on every push so readers can skip redundant redraws
+    generation: u64,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::new(LOG_BUFFER_CAPACITY)
+    }
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(LogBufferInner {
+                entries: VecDeque::with_capacity(capacity),
+                capacity,
+                generation: 0,
+            })),
+        }
+    }
+
+    /// Appends a record, dropping the oldest one when full.
+    pub fn push(&self, entry: LogEntry) {
+        let Ok(mut inner) = self.inner.lock() else {
+            return;
+        };
+        if inner.entries.len() >= inner.capacity {
+            inner.entries.pop_front();
+        }
+        inner.entries.push_back(entry);
+        inner.generation += 1;
+    }
+
+    /// Returns a snapshot of the buffered records, oldest first.
+    pub fn entries(&self) -> Vec<LogEntry> {
+        self.inner
+            .lock()
+            .map(|inner| inner.entries.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.inner.lock().map(|inner| inner.generation).unwrap_or(0)
+    }
+}
+
+// ============================================================================
+// Tracing Layer
+// ============================================================================
+
+/// Tracing layer that copies `INFO` and more severe records into a `LogBuffer`.
+pub struct LogCaptureLayer {
+    buffer: LogBuffer,
+}
+
+impl LogCaptureLayer {
+    pub fn new(buffer: LogBuffer) -> Self {
+        Self { buffer }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for LogCaptureLayer {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        // More verbose levels compare greater
+        if *metadata.level() > Level::INFO {
+            return;
+        }
+
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        self.buffer.push(LogEntry {
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            message: visitor.0,
+        });
+    }
+}
+
+/// Extracts the formatted `message` field from a tracing event
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{:?}", value);
+        }
+    }
+}
+
+/// `LogPlugin::custom_layer` hook that inserts the shared `LogBuffer` and returns the capture layer.
+pub fn log_capture_layer(app: &mut App) -> Option<BoxedLayer> {
+    let buffer = LogBuffer::default();
+    app.insert_resource(buffer.clone());
+    Some(Box::new(LogCaptureLayer::new(buffer)))
+}
+
+// ============================================================================
+// Console UI
+// ============================================================================
+
+/// Root of the console overlay
+#[derive(Component)]
+struct LogConsoleRoot;
+
+/// Scrollable container holding one text line per record
+#[derive(Component)]
+struct LogConsoleLines;
+
+/// Header line showing UI rebuild counters
+#[derive(Component)]
+struct RebuildStatsText;
+
+fn toggle_log_console(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    existing: Query<Entity, With<LogConsoleRoot>>,
+) {
+    if !keyboard.just_pressed(TOGGLE_KEY) {
+        return;
+    }
+
+    if let Ok(root) = existing.single() {
+        commands.entity(root).despawn();
+        return;
+    }
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                right: Val::Px(0.0),
+                bottom: Val::Px(0.0),
+                height: Val::Percent(40.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            GlobalZIndex(100),
+            LogConsoleRoot,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(UiTheme::TEXT_INFO),
+                RebuildStatsText,
+            ));
+            spawn_scrollable_container(parent, LogConsoleLines, |_| {});
+        });
+}
+
+/// Rebuilds the console lines when new records arrive while it is open
+fn update_log_console(
+    mut commands: Commands,
+    buffer: Res<LogBuffer>,
+    lines_query: Query<Entity, With<LogConsoleLines>>,
+    added_query: Query<(), Added<LogConsoleLines>>,
+    mut last_generation: Local<u64>,
+) {
+    let Ok(container) = lines_query.single() else {
+        return;
+    };
+
+    let generation = buffer.generation();
+    if added_query.is_empty() && generation == *last_generation {
+        return;
+    }
+    *last_generation = generation;
+
+    commands
+        .entity(container)
+        .despawn_related::<Children>()
+        .with_children(|parent| {
+            for entry in buffer.entries() {
+                parent.spawn((
+                    Text::new(format!(
+                        "[{}] {}: {}",
+                        entry.level, entry.target, entry.message
+                    )),
+                    TextFont {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(level_color(entry.level)),
+                ));
+            }
+        });
+}
+
+/// Refreshes the rebuild counters line while the console is open
+fn update_rebuild_stats_text(
+    stats: Res<UiRebuildStats>,
+    mut text_query: Query<(&mut Text, Ref<RebuildStatsText>)>,
+) {
+    let Ok((mut text, marker)) = text_query.single_mut() else {
+        return;
+    };
+
+    if !stats.is_changed() && !marker.is_added() {
+        return;
+    }
+
+    text.0 = format!("UI rebuilds ({}): {}", stats.total(), stats.summary());
+}
+
+fn level_color(level: Level) -> Color {
+    match level {
+        Level::ERROR => UiTheme::BORDER_ERROR,
+        Level::WARN => Color::srgba(1.0, 0.8, 0.3, 1.0),
+        _ => UiTheme::TEXT_SECONDARY,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        bevy::log::tracing_subscriber::{Registry, layer::SubscriberExt},
+    };
+
+    #[test]
+    fn test_log_record_populates_buffer() {
+        let buffer = LogBuffer::new(2);
+        let subscriber = Registry::default().with(LogCaptureLayer::new(buffer.clone()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            debug!("ignored");
+            info!("first");
+            warn!("second");
+            error!("third");
+        });
+
+        let entries = buffer.entries();
+        assert_eq!(
+            entries.len(),
+            2,
+            "Oldest record should be dropped when full"
+        );
+        assert_eq!(entries[0].level, Level::WARN);
+        assert_eq!(entries[0].message, "second");
+        assert_eq!(entries[1].level, Level::ERROR);
+        assert_eq!(entries[1].message, "third");
+        assert_eq!(buffer.generation(), 3);
+    }
+}
@@ -1,10 +1,15 @@
 //! Universal notification UI system.
 //! Shows toast-style notifications at the top of the screen with stacking support.
-//! Handles unlock achievements, research completions, and other notification events.
+//! Handles unlock achievements, research and crafting completions, and other notification events.
 
 use {
-    bevy::prelude::*, research::ResearchCompleted, states::GameState, unlocks::UnlockAchieved,
-    widgets::UiTheme,
+    audio::{SfxEvent, SfxKind},
+    bevy::prelude::*,
+    crafting::{CraftingCompleted, RecipeLibrary},
+    research::ResearchCompleted,
+    states::{GameSpeed, GameState, VillageView, scaled_delta},
+    unlocks::{Topic, UnlockAchieved},
+    widgets::{ButtonStyle, UiTheme, spawn_action_button_with_style},
 };
 
 /// Duration in seconds to show each notification
@@ -25,13 +30,17 @@ impl Plugin for NotificationUiPlugin {
         app.init_resource::<NotificationQueue>()
             .add_observer(on_unlock_achieved)
             .add_observer(on_research_completed)
+            .add_observer(on_crafting_completed)
             .add_systems(OnExit(GameState::Loading), cleanup_loading_notifications)
             .add_systems(
                 Update,
                 (
+                    handle_notification_actions,
+                    dismiss_clicked_notifications,
                     spawn_pending_notifications,
                     update_notification_positions,
-                    despawn_expired_notifications,
+                    // Paused notifications stay up instead of expiring unread
+                    despawn_expired_notifications.run_if(states::not_paused),
                 )
                     .chain()
                     .run_if(in_state(GameState::Running)),
@@ -49,10 +58,67 @@ pub struct NotificationData {
     pub title: String,
     pub message: String,
     pub notification_type: NotificationType,
+    /// Optional button on the toast, e.g. "Show" for a new recipe
+    pub action: Option<NotificationAction>,
+    /// How many identical notifications this one stands for, shown as "(x3)"
+    pub count: u32,
+}
+
+impl NotificationData {
+    /// Whether `other` would show the same toast, so the two can be stacked
+    fn same_as(&self, other: &NotificationData) -> bool {
+        self.title == other.title
+            && self.message == other.message
+            && self.notification_type == other.notification_type
+    }
+
+    /// Text shown on the toast, with the stack count once there is more than one
+    fn display_text(&self) -> String {
+        if self.count > 1 {
+            format!("{}: {} (x{})", self.title, self.message, self.count)
+        } else {
+            format!("{}: {}", self.title, self.message)
+        }
+    }
+}
+
+/// Navigation performed by a notification's action button
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NotificationAction {
+    OpenCrafting,
+    OpenBlessings,
+}
+
+impl NotificationAction {
+    /// Picks the action matching an unlock's reward, if it has a screen to show.
+    pub fn for_reward(reward_id: &str) -> Option<Self> {
+        if reward_id.starts_with(crafting::RECIPE_REWARD_PREFIX) {
+            return Some(NotificationAction::OpenCrafting);
+        }
+        if reward_id.starts_with(Topic::BLESSING_PREFIX) {
+            return Some(NotificationAction::OpenBlessings);
+        }
+        None
+    }
+
+    /// The village view this action opens
+    pub fn view(&self) -> VillageView {
+        match self {
+            NotificationAction::OpenCrafting => VillageView::Crafting,
+            NotificationAction::OpenBlessings => VillageView::Blessings,
+        }
+    }
+}
+
+/// Triggered when the player presses a notification's action button, after the
+/// `VillageView` has been switched. UIs observe it to open their panel if needed.
+#[derive(Event, Clone, Debug)]
+pub struct NotificationActionPressed {
+    pub action: NotificationAction,
 }
 
 /// Type of notification affects styling
-#[derive(Clone, Copy, Default, Debug)]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
 pub enum NotificationType {
     #[default]
     Info,
@@ -94,9 +160,29 @@ pub struct NotificationQueue {
 }
 
 impl NotificationQueue {
-    /// Queue a new notification to be displayed
-    pub fn push(&mut self, data: NotificationData) {
-        self.pending.push(data);
+    /// Queue a new notification to be displayed. An identical pending notification
+    /// is stacked instead; stacking onto a displayed one happens when it is spawned.
+    pub fn push(&mut self, mut data: NotificationData) {
+        data.count = data.count.max(1);
+        match self
+            .pending
+            .iter_mut()
+            .find(|pending| pending.same_as(&data))
+        {
+            Some(pending) => pending.count += data.count,
+            None => self.pending.push(data),
+        }
+    }
+
+    /// Queue a plain informational notification
+    pub fn push_info(&mut self, title: impl Into<String>, message: impl Into<String>) {
+        self.push(NotificationData {
+            title: title.into(),
+            message: message.into(),
+            notification_type: NotificationType::Info,
+            action: None,
+            count: 1,
+        });
     }
 }
 
@@ -104,14 +190,38 @@ impl NotificationQueue {
 #[derive(Component)]
 struct Notification {
     timer: Timer,
+    /// What the toast shows, kept to stack identical notifications onto it
+    data: NotificationData,
+}
+
+/// Action button on a notification toast
+#[derive(Component)]
+struct NotificationActionButton {
+    action: NotificationAction,
 }
 
 // ============================================================================
 // Event Observers
 // ============================================================================
 
+/// Unlock and research events are only announced during gameplay.
+/// While loading, `evaluate_unlocks` re-fires every satisfied condition to hydrate
+/// state, and those events must not reach the player.
+fn notifications_enabled(state: &State<GameState>) -> bool {
+    *state.get() == GameState::Running
+}
+
 /// Responds to UnlockAchieved events by queueing a notification
-fn on_unlock_achieved(trigger: On<UnlockAchieved>, mut queue: ResMut<NotificationQueue>) {
+fn on_unlock_achieved(
+    trigger: On<UnlockAchieved>,
+    mut commands: Commands,
+    state: Res<State<GameState>>,
+    mut queue: ResMut<NotificationQueue>,
+) {
+    if !notifications_enabled(&state) {
+        return;
+    }
+
     let event = trigger.event();
     let message = event
         .display_name
@@ -122,15 +232,58 @@ fn on_unlock_achieved(trigger: On<UnlockAchieved>, mut queue: ResMut<Notificatio
         title: "Unlocked".to_string(),
         message,
         notification_type: NotificationType::Unlock,
+        action: NotificationAction::for_reward(&event.reward_id),
+        count: 1,
+    });
+    commands.trigger(SfxEvent {
+        kind: SfxKind::Unlock,
     });
 }
 
 /// Responds to ResearchCompleted events by queueing a notification
-fn on_research_completed(trigger: On<ResearchCompleted>, mut queue: ResMut<NotificationQueue>) {
+fn on_research_completed(
+    trigger: On<ResearchCompleted>,
+    mut commands: Commands,
+    state: Res<State<GameState>>,
+    mut queue: ResMut<NotificationQueue>,
+) {
+    if !notifications_enabled(&state) {
+        return;
+    }
+
     queue.push(NotificationData {
         title: "Research Completed".to_string(),
         message: trigger.event().research_id.clone(),
         notification_type: NotificationType::Research,
+        action: None,
+        count: 1,
+    });
+    commands.trigger(SfxEvent {
+        kind: SfxKind::Research,
+    });
+}
+
+/// Responds to CraftingCompleted events by queueing a notification.
+/// Falls back to the recipe id if its definition can no longer be found.
+fn on_crafting_completed(
+    trigger: On<CraftingCompleted>,
+    mut commands: Commands,
+    state: Res<State<GameState>>,
+    library: RecipeLibrary,
+    mut queue: ResMut<NotificationQueue>,
+) {
+    if !notifications_enabled(&state) {
+        return;
+    }
+
+    let recipe_id = &trigger.event().recipe_id;
+    let name = library
+        .get_def(recipe_id)
+        .map_or(recipe_id.as_str(), |def| def.display_name.as_str());
+
+    queue.push_info("Crafted", name);
+    commands.trigger(SfxEvent {
+        kind: SfxKind::Crafted,
     });
 }
 
@@ -138,7 +291,8 @@ fn on_research_completed(trigger: On<ResearchCompleted>, mut queue: ResMut<Notif
 // Systems
 // ============================================================================
 
-/// Cleans up any notifications spawned during loading
+/// Cleans up any notifications queued during loading by sources that
+/// do not check the game state themselves
 fn cleanup_loading_notifications(
     mut commands: Commands,
     query: Query<Entity, With<Notification>>,
@@ -151,8 +305,73 @@ fn cleanup_loading_notifications(
     queue.pending.clear();
 }
 
-/// Spawns pending notifications if we have room
-fn spawn_pending_notifications(mut commands: Commands, mut queue: ResMut<NotificationQueue>) {
+/// Performs the action of a pressed notification button and dismisses its notification.
+#[allow(clippy::type_complexity)]
+fn handle_notification_actions(
+    mut commands: Commands,
+    query: Query<(&Interaction, &NotificationActionButton, &ChildOf), Changed<Interaction>>,
+    mut queue: ResMut<NotificationQueue>,
+    mut next_village_state: ResMut<NextState<VillageView>>,
+) {
+    for (interaction, button, child_of) in query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        next_village_state.set(button.action.view());
+        commands.trigger(NotificationActionPressed {
+            action: button.action.clone(),
+        });
+
+        let notification = child_of.parent();
+        queue.active.retain(|&e| e != notification);
+        commands.entity(notification).despawn();
+    }
+}
+
+/// Dismisses notifications the player clicks on. Runs before
+/// `spawn_pending_notifications` so a waiting notification takes the freed slot this frame.
+#[allow(clippy::type_complexity)]
+fn dismiss_clicked_notifications(
+    mut commands: Commands,
+    query: Query<(Entity, &Interaction), (Changed<Interaction>, With<Notification>)>,
+    mut queue: ResMut<NotificationQueue>,
+) {
+    for (entity, interaction) in query.iter() {
+        if *interaction == Interaction::Pressed {
+            queue.active.retain(|&e| e != entity);
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Spawns pending notifications if we have room. A pending notification identical
+/// to a displayed one is stacked onto it instead, restarting its timer.
+fn spawn_pending_notifications(
+    mut commands: Commands,
+    mut queue: ResMut<NotificationQueue>,
+    mut notifications: Query<(&mut Notification, &Children)>,
+    mut texts: Query<&mut Text>,
+) {
+    let active = queue.active.clone();
+    queue.pending.retain(|data| {
+        let mut displayed = notifications.iter_many_mut(&active);
+        while let Some((mut notification, children)) = displayed.fetch_next() {
+            if !notification.data.same_as(data) {
+                continue;
+            }
+            notification.data.count += data.count;
+            notification.timer.reset();
+            for child in children.iter() {
+                if let Ok(mut text) = texts.get_mut(child) {
+                    text.0 = notification.data.display_text();
+                }
+            }
+            return false;
+        }
+        true
+    });
+
     while !queue.pending.is_empty() && queue.active.len() < MAX_NOTIFICATIONS {
         let notification = queue.pending.remove(0);
         let entity = spawn_notification(&mut commands, &notification, queue.active.len());
@@ -176,13 +395,15 @@ fn update_notification_positions(
 fn despawn_expired_notifications(
     mut commands: Commands,
     time: Res<Time>,
+    speed: Res<GameSpeed>,
     mut query: Query<(Entity, &mut Notification)>,
     mut queue: ResMut<NotificationQueue>,
 ) {
     // Collect expired entities
+    let delta = scaled_delta(&time, &speed);
     let mut expired = Vec::new();
     for (entity, mut notification) in query.iter_mut() {
-        notification.timer.tick(time.delta());
+        notification.timer.tick(delta);
         if notification.timer.is_finished() {
             expired.push(entity);
         }
@@ -210,34 +431,325 @@ fn spawn_notification(
     notification: &NotificationData,
     index: usize,
 ) -> Entity {
-    let display_text = format!("{}: {}", notification.title, notification.message);
+    let display_text = notification.display_text();
 
-    commands
-        .spawn((
+    let mut toast = commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(calculate_top_position(index)),
+            left: Val::Percent(20.0),
+            right: Val::Percent(20.0),
+            height: Val::Px(NOTIFICATION_HEIGHT),
+            padding: UiRect::all(Val::Px(12.0)),
+            border: UiRect::all(Val::Px(2.0)),
+            border_radius: BorderRadius::all(Val::Px(8.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(12.0),
+            ..default()
+        },
+        BackgroundColor(notification.notification_type.background_color()),
+        BorderColor::all(notification.notification_type.border_color()),
+        Notification {
+            timer: Timer::from_seconds(NOTIFICATION_DURATION, TimerMode::Once),
+            data: notification.clone(),
+        },
+        Button,
+        Interaction::default(),
+    ));
+    toast.with_children(|parent| {
+        parent.spawn((
             Text::new(display_text),
-            Node {
-                position_type: PositionType::Absolute,
-                top: Val::Px(calculate_top_position(index)),
-                left: Val::Percent(20.0),
-                right: Val::Percent(20.0),
-                height: Val::Px(NOTIFICATION_HEIGHT),
-                padding: UiRect::all(Val::Px(12.0)),
-                border: UiRect::all(Val::Px(2.0)),
-                border_radius: BorderRadius::all(Val::Px(8.0)),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                ..default()
-            },
             TextColor(UiTheme::TEXT_PRIMARY),
             TextFont {
                 font_size: 20.0,
                 ..default()
             },
-            BackgroundColor(notification.notification_type.background_color()),
-            BorderColor::all(notification.notification_type.border_color()),
-            Notification {
-                timer: Timer::from_seconds(NOTIFICATION_DURATION, TimerMode::Once),
-            },
-        ))
-        .id()
+        ));
+
+        if let Some(action) = &notification.action {
+            spawn_action_button_with_style(
+                parent,
+                "Show",
+                UiTheme::TEXT_PRIMARY,
+                notification.notification_type.border_color(),
+                ButtonStyle {
+                    width: Val::Px(70.0),
+                    font_size: 16.0,
+                    ..default()
+                },
+                NotificationActionButton {
+                    action: action.clone(),
+                },
+            );
+        }
+    });
+    toast.id()
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, bevy::state::app::StatesPlugin};
+
+    fn fire_events(app: &mut App) {
+        app.world_mut().trigger(UnlockAchieved {
+            unlock_id: "recipe_bone_sword".to_string(),
+            display_name: None,
+            reward_id: "recipe:bone_sword".to_string(),
+        });
+        app.world_mut().trigger(ResearchCompleted {
+            research_id: "bone_sword".to_string(),
+        });
+    }
+
+    #[test]
+    fn test_events_during_loading_queue_no_notifications() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_resource::<GameSpeed>()
+            .init_state::<GameState>()
+            .init_state::<VillageView>()
+            .add_plugins(NotificationUiPlugin);
+        app.update();
+
+        fire_events(&mut app);
+        assert!(
+            app.world()
+                .resource::<NotificationQueue>()
+                .pending
+                .is_empty()
+        );
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Running);
+        app.update();
+
+        fire_events(&mut app);
+        assert_eq!(app.world().resource::<NotificationQueue>().pending.len(), 2);
+    }
+
+    #[test]
+    fn test_crafting_completed_uses_display_name_or_recipe_id() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_resource::<GameSpeed>()
+            .insert_state(GameState::Running)
+            .init_state::<VillageView>()
+            .init_resource::<crafting_resources::RecipeMap>()
+            .init_resource::<Assets<recipes_assets::RecipeDefinition>>()
+            .add_plugins(NotificationUiPlugin);
+
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<recipes_assets::RecipeDefinition>>()
+            .add(recipes_assets::RecipeDefinition {
+                id: "bone_sword".to_string(),
+                display_name: "Bone Sword".to_string(),
+                category: recipes_assets::RecipeCategory::Weapons,
+                subcategory: None,
+                craft_time: 1.0,
+                cost: Default::default(),
+                outcomes: vec![],
+                max_repeats: None,
+                cost_scaling: 1.0,
+                time_scaling: 1.0,
+                unlock: None,
+            });
+        let node = app
+            .world_mut()
+            .spawn(crafting::RecipeNode {
+                id: "bone_sword".to_string(),
+                handle,
+            })
+            .id();
+        app.world_mut()
+            .resource_mut::<crafting_resources::RecipeMap>()
+            .entities
+            .insert("bone_sword".to_string(), node);
+
+        for recipe_id in ["bone_sword", "removed_recipe"] {
+            app.world_mut().trigger(CraftingCompleted {
+                recipe_id: recipe_id.to_string(),
+            });
+        }
+
+        let pending = &app.world().resource::<NotificationQueue>().pending;
+        let shown: Vec<(&str, &str)> = pending
+            .iter()
+            .map(|n| (n.title.as_str(), n.message.as_str()))
+            .collect();
+        assert_eq!(
+            shown,
+            vec![("Crafted", "Bone Sword"), ("Crafted", "removed_recipe")]
+        );
+        assert!(
+            pending
+                .iter()
+                .all(|n| matches!(n.notification_type, NotificationType::Info))
+        );
+    }
+
+    #[test]
+    fn test_push_info_queues_info_notification() {
+        let mut queue = NotificationQueue::default();
+        queue.push_info("Game Saved", String::from("save_1.scn.ron"));
+
+        let data = &queue.pending[0];
+        assert_eq!(data.title, "Game Saved");
+        assert_eq!(data.message, "save_1.scn.ron");
+        assert!(matches!(data.notification_type, NotificationType::Info));
+    }
+
+    #[test]
+    fn test_clicked_notification_frees_slot_for_pending() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_resource::<GameSpeed>()
+            .insert_state(GameState::Running)
+            .init_state::<VillageView>()
+            .add_plugins(NotificationUiPlugin);
+
+        for i in 0..=MAX_NOTIFICATIONS {
+            app.world_mut()
+                .resource_mut::<NotificationQueue>()
+                .push(NotificationData {
+                    title: "Info".to_string(),
+                    message: i.to_string(),
+                    notification_type: NotificationType::Info,
+                    action: None,
+                    count: 1,
+                });
+        }
+        app.update();
+
+        let queue = app.world().resource::<NotificationQueue>();
+        assert_eq!(queue.active.len(), MAX_NOTIFICATIONS);
+        assert_eq!(queue.pending.len(), 1);
+        let clicked = queue.active[1];
+        let next = queue.active[2];
+
+        *app.world_mut().get_mut::<Interaction>(clicked).unwrap() = Interaction::Pressed;
+        app.update();
+
+        let queue = app.world().resource::<NotificationQueue>();
+        assert!(queue.pending.is_empty());
+        assert_eq!(queue.active.len(), MAX_NOTIFICATIONS);
+        assert!(!queue.active.contains(&clicked));
+        assert!(app.world().get_entity(clicked).is_err());
+
+        // The notification below the dismissed one moves up into its place
+        assert_eq!(queue.active[1], next);
+        let top = app.world().get::<Node>(next).unwrap().top;
+        assert_eq!(top, Val::Px(calculate_top_position(1)));
+    }
+
+    #[test]
+    fn test_unlock_reward_picks_notification_action() {
+        assert_eq!(
+            NotificationAction::for_reward("recipe:bone_sword"),
+            Some(NotificationAction::OpenCrafting)
+        );
+        assert_eq!(
+            NotificationAction::for_reward("blessing:swift_hands"),
+            Some(NotificationAction::OpenBlessings)
+        );
+        assert_eq!(NotificationAction::for_reward("stat:damage"), None);
+    }
+
+    #[test]
+    fn test_blessing_action_button_opens_blessings_view() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_resource::<GameSpeed>()
+            .insert_state(GameState::Running)
+            .init_state::<VillageView>()
+            .add_plugins(NotificationUiPlugin);
+
+        app.world_mut()
+            .resource_mut::<NotificationQueue>()
+            .push(NotificationData {
+                title: "Unlocked".to_string(),
+                message: "Swift Hands".to_string(),
+                notification_type: NotificationType::Unlock,
+                action: Some(NotificationAction::OpenBlessings),
+                count: 1,
+            });
+        app.update();
+
+        let notification = app.world().resource::<NotificationQueue>().active[0];
+        let button = app
+            .world_mut()
+            .query_filtered::<Entity, With<NotificationActionButton>>()
+            .single(app.world())
+            .unwrap();
+        *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+        app.update();
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<State<VillageView>>().get(),
+            &VillageView::Blessings
+        );
+        assert!(
+            app.world()
+                .resource::<NotificationQueue>()
+                .active
+                .is_empty()
+        );
+        assert!(app.world().get_entity(notification).is_err());
+    }
+
+    #[test]
+    fn test_identical_notifications_stack() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .init_resource::<GameSpeed>()
+            .insert_state(GameState::Running)
+            .init_state::<VillageView>()
+            .add_plugins(NotificationUiPlugin);
+
+        let push_unlock = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<NotificationQueue>()
+                .push(NotificationData {
+                    title: "Unlocked".to_string(),
+                    message: "Bone Sword".to_string(),
+                    notification_type: NotificationType::Unlock,
+                    action: None,
+                    count: 1,
+                });
+        };
+        push_unlock(&mut app);
+        push_unlock(&mut app);
+        assert_eq!(app.world().resource::<NotificationQueue>().pending.len(), 1);
+        app.update();
+
+        let queue = app.world().resource::<NotificationQueue>();
+        assert_eq!(queue.active.len(), 1);
+        let toast = queue.active[0];
+        app.world_mut()
+            .get_mut::<Notification>(toast)
+            .unwrap()
+            .timer
+            .tick(std::time::Duration::from_secs_f32(
+                NOTIFICATION_DURATION - 0.5,
+            ));
+
+        // A duplicate of a displayed toast stacks onto it and keeps it on screen
+        push_unlock(&mut app);
+        app.update();
+
+        let queue = app.world().resource::<NotificationQueue>();
+        assert_eq!(queue.active, vec![toast]);
+        assert!(queue.pending.is_empty());
+        let notification = app.world().get::<Notification>(toast).unwrap();
+        assert_eq!(notification.data.count, 3);
+        assert!(notification.timer.elapsed_secs() < 0.5);
+        let text = app.world().get::<Children>(toast).unwrap()[0];
+        assert_eq!(
+            app.world().get::<Text>(text).unwrap().0,
+            "Unlocked: Bone Sword (x3)"
+        );
+    }
 }
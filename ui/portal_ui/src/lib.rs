@@ -1,13 +1,29 @@
 use {
     bevy::{picking::events::Click, prelude::*},
     divinity_components::{CurrentDivinity, Divinity},
-    portal_components::Portal,
+    loading::GameAssets,
+    portal_assets::{SpawnEntry, SpawnTable, SpawnType},
+    portal_components::{Portal, SpawnTableId},
     states::GameState,
+    unlocks::UnlockGraph,
     unlocks_assets::{ConditionNode, UnlockDefinition},
+    unlocks_events::Topic,
     village_components::Village,
-    widgets::{PanelWrapperRef, UiTheme, spawn_menu_panel, spawn_panel_header_with_close},
+    widgets::{
+        ClickFilter, ModalState, PanelWrapperRef, Scroll, UiTheme, spawn_action_button,
+        spawn_menu_panel, spawn_panel_header_with_close,
+    },
 };
 
+/// Whether a portal may be set above the village's max divinity.
+/// The "Risky" preset is only offered when this is enabled.
+const ALLOW_OVER_LEVELING: bool = false;
+
+/// How long the current divinity text flashes after a change
+const DIVINITY_FLASH_SECS: f32 = 0.4;
+/// Color the current divinity text flashes with before fading back to white
+const DIVINITY_FLASH_COLOR: Color = Color::srgb(1.0, 0.84, 0.0);
+
 pub struct PortalUiPlugin;
 
 impl Plugin for PortalUiPlugin {
@@ -16,8 +32,11 @@ impl Plugin for PortalUiPlugin {
             Update,
             (
                 update_portal_ui,
+                update_spawn_preview,
                 handle_tier_navigation,
+                handle_preset_buttons,
                 handle_close_button,
+                (start_divinity_flash, animate_divinity_flash).chain(),
             )
                 .run_if(in_state(GameState::Running)),
         );
@@ -28,8 +47,9 @@ impl Plugin for PortalUiPlugin {
 // Components
 // ============================================================================
 
+/// Root of the portal panel
 #[derive(Component)]
-struct PortalUiRoot {
+pub struct PortalUiRoot {
     portal_entity: Entity,
 }
 
@@ -39,9 +59,25 @@ struct CurrentDivinityText;
 #[derive(Component)]
 struct MaxDivinityText;
 
+/// Short color fade on `CurrentDivinityText` after the portal divinity changed
+#[derive(Component)]
+struct DivinityFlash {
+    timer: Timer,
+}
+
 #[derive(Component)]
 struct UnlockConditionText;
 
+/// Lists what the portal spawns at its selected divinity
+#[derive(Component)]
+struct SpawnPreviewText;
+
+/// Tier navigation row; scrolling the wheel over it steps the portal's divinity
+#[derive(Component)]
+struct TierStepper {
+    portal_entity: Entity,
+}
+
 #[derive(Component)]
 struct DecreaseTierButton {
     portal_entity: Entity,
@@ -52,9 +88,111 @@ struct IncreaseTierButton {
     portal_entity: Entity,
 }
 
+/// Jumps the portal back to Tier 1 Level 1
+#[derive(Component)]
+struct FirstTierButton {
+    portal_entity: Entity,
+}
+
+/// Jumps the portal to the village's max unlocked divinity
+#[derive(Component)]
+struct MaxTierButton {
+    portal_entity: Entity,
+}
+
+#[derive(Component)]
+struct DivinityPresetButton {
+    portal_entity: Entity,
+    preset: DivinityPreset,
+}
+
 #[derive(Component)]
 struct PortalCloseButton;
 
+// ============================================================================
+// Divinity Presets
+// ============================================================================
+
+/// Quick-select targets for a portal's divinity, relative to the village's max.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DivinityPreset {
+    /// One level below the max unlocked divinity.
+    Safe,
+    /// The max unlocked divinity.
+    Recommended,
+    /// One level above the max unlocked divinity (requires over-leveling).
+    Risky,
+}
+
+impl DivinityPreset {
+    fn label(self) -> &'static str {
+        match self {
+            DivinityPreset::Safe => "Safe",
+            DivinityPreset::Recommended => "Recommended",
+            DivinityPreset::Risky => "Risky",
+        }
+    }
+
+    /// Computes the divinity this preset selects, clamped to the valid range.
+    fn target(self, max: Divinity, allow_over_leveling: bool) -> Divinity {
+        let target = match self {
+            DivinityPreset::Safe => previous_level(max),
+            DivinityPreset::Recommended => max,
+            DivinityPreset::Risky => next_level(max),
+        };
+
+        clamp_divinity(target, max, allow_over_leveling)
+    }
+}
+
+/// Steps down one level, wrapping to the previous tier. Tier 1 Level 1 stays put.
+fn previous_level(divinity: Divinity) -> Divinity {
+    if divinity.level > 1 {
+        Divinity::new(divinity.tier, divinity.level - 1)
+    } else if divinity.tier > 1 {
+        Divinity::new(divinity.tier - 1, divinity_components::MAX_LEVEL)
+    } else {
+        divinity
+    }
+}
+
+/// Steps up one level, wrapping to the next tier after `MAX_LEVEL`.
+fn next_level(divinity: Divinity) -> Divinity {
+    if divinity.level < divinity_components::MAX_LEVEL {
+        Divinity::new(divinity.tier, divinity.level + 1)
+    } else {
+        Divinity::new(divinity.tier + 1, 1)
+    }
+}
+
+/// Steps one level up or down from `current`, never above `max` or below Tier 1 Level 1.
+fn step_divinity(current: Divinity, up: bool, max: Divinity) -> Divinity {
+    if up {
+        clamp_divinity(next_level(current), max, false)
+    } else {
+        previous_level(current)
+    }
+}
+
+/// Jumps to the highest divinity allowed by `max`, or back to Tier 1 Level 1.
+fn jump_divinity(to_max: bool, max: Divinity) -> Divinity {
+    if to_max {
+        clamp_divinity(max, max, false)
+    } else {
+        Divinity::default()
+    }
+}
+
+/// Keeps `target` at or above Tier 1 Level 1 and, unless over-leveling is allowed, at or below `max`.
+fn clamp_divinity(target: Divinity, max: Divinity, allow_over_leveling: bool) -> Divinity {
+    let target = target.max(Divinity::default());
+    if allow_over_leveling {
+        target
+    } else {
+        target.min(max)
+    }
+}
+
 // ============================================================================
 // Portal Click Observer
 // ============================================================================
@@ -65,6 +203,8 @@ fn on_portal_click(
     mut commands: Commands,
     portal_query: Query<(), With<Portal>>,
     existing_ui: Query<(Entity, Option<&PanelWrapperRef>), With<PortalUiRoot>>,
+    click_filter: ClickFilter,
+    modal_state: Res<ModalState>,
 ) {
     let portal_entity = trigger.entity;
 
@@ -73,6 +213,11 @@ fn on_portal_click(
         return;
     }
 
+    // Ignore the release at the end of a camera pan, and clicks behind a popup
+    if !click_filter.is_click(trigger.event()) || modal_state.is_open() {
+        return;
+    }
+
     // Toggle: if UI exists, close it; otherwise open
     if let Ok((ui_entity, wrapper_ref)) = existing_ui.single() {
         // Despawn wrapper if it exists, otherwise just despawn the panel
@@ -100,43 +245,27 @@ fn spawn_portal_ui(commands: &mut Commands, portal_entity: Entity) {
 
         // Tier navigation row: [<] Tier X - Level Y [>]
         parent
-            .spawn(Node {
-                flex_direction: FlexDirection::Row,
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                width: Val::Percent(100.0),
-                padding: UiRect::vertical(Val::Px(15.0)),
-                column_gap: Val::Px(15.0),
-                ..default()
-            })
+            .spawn((
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    width: Val::Percent(100.0),
+                    padding: UiRect::vertical(Val::Px(15.0)),
+                    column_gap: Val::Px(15.0),
+                    ..default()
+                },
+                TierStepper { portal_entity },
+            ))
+            .observe(on_tier_stepper_scroll)
             .with_children(|row| {
-                // Decrease button [<]
-                row.spawn((
-                    Button,
-                    Node {
-                        width: Val::Px(40.0),
-                        height: Val::Px(40.0),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        border: UiRect::all(Val::Px(2.0)),
-                        ..default()
-                    },
-                    BorderColor::all(UiTheme::CARD_BORDER),
-                    BackgroundColor(UiTheme::BUTTON_NORMAL),
-                    DecreaseTierButton { portal_entity },
-                ))
-                .with_child((
-                    Text::new("<"),
-                    TextFont {
-                        font_size: 20.0,
-                        ..default()
-                    },
-                    TextColor(Color::WHITE),
-                ));
+                // Jump to Tier 1 Level 1 [<<], decrease button [<]
+                spawn_tier_button(row, "<<", FirstTierButton { portal_entity });
+                spawn_tier_button(row, "<", DecreaseTierButton { portal_entity });
 
                 // Current tier/level text
                 row.spawn((
-                    Text::new("Tier 1 - Level 1"),
+                    Text::new(Divinity::default().display()),
                     TextFont {
                         font_size: 18.0,
                         ..default()
@@ -145,29 +274,43 @@ fn spawn_portal_ui(commands: &mut Commands, portal_entity: Entity) {
                     CurrentDivinityText,
                 ));
 
-                // Increase button [>]
-                row.spawn((
-                    Button,
-                    Node {
-                        width: Val::Px(40.0),
-                        height: Val::Px(40.0),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        border: UiRect::all(Val::Px(2.0)),
-                        ..default()
-                    },
-                    BorderColor::all(UiTheme::CARD_BORDER),
-                    BackgroundColor(UiTheme::BUTTON_NORMAL),
-                    IncreaseTierButton { portal_entity },
-                ))
-                .with_child((
-                    Text::new(">"),
-                    TextFont {
-                        font_size: 20.0,
-                        ..default()
-                    },
-                    TextColor(Color::WHITE),
-                ));
+                // Increase button [>], jump to the max unlocked divinity [>>]
+                spawn_tier_button(row, ">", IncreaseTierButton { portal_entity });
+                spawn_tier_button(row, ">>", MaxTierButton { portal_entity });
+            });
+
+        // Preset row: [Safe] [Recommended] [Risky]
+        parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::Center,
+                width: Val::Percent(100.0),
+                column_gap: Val::Px(10.0),
+                ..default()
+            })
+            .with_children(|row| {
+                let presets: &[DivinityPreset] = if ALLOW_OVER_LEVELING {
+                    &[
+                        DivinityPreset::Safe,
+                        DivinityPreset::Recommended,
+                        DivinityPreset::Risky,
+                    ]
+                } else {
+                    &[DivinityPreset::Safe, DivinityPreset::Recommended]
+                };
+
+                for &preset in presets {
+                    spawn_action_button(
+                        row,
+                        preset.label(),
+                        UiTheme::TEXT_PRIMARY,
+                        UiTheme::CARD_BORDER,
+                        DivinityPresetButton {
+                            portal_entity,
+                            preset,
+                        },
+                    );
+                }
             });
 
         // Max tier available section
@@ -190,7 +333,7 @@ fn spawn_portal_ui(commands: &mut Commands, portal_entity: Entity) {
                 ));
 
                 col.spawn((
-                    Text::new("Tier 1 - Level 1"),
+                    Text::new(Divinity::default().display()),
                     TextFont {
                         font_size: 16.0,
                         ..default()
@@ -228,6 +371,31 @@ fn spawn_portal_ui(commands: &mut Commands, portal_entity: Entity) {
                     },
                 ));
             });
+
+        // Spawn preview for the selected divinity
+        parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                width: Val::Percent(100.0),
+                padding: UiRect::vertical(Val::Px(10.0)),
+                ..default()
+            })
+            .with_children(|col| {
+                col.spawn((
+                    Text::new(""),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(UiTheme::TEXT_SECONDARY),
+                    SpawnPreviewText,
+                    Node {
+                        max_width: Val::Px(300.0),
+                        ..default()
+                    },
+                ));
+            });
     });
 }
 
@@ -235,6 +403,55 @@ fn spawn_portal_ui(commands: &mut Commands, portal_entity: Entity) {
 // Systems
 // ============================================================================
 
+/// Describes the spawn table entries available at one divinity, with each entry's weight
+/// and chance. "Unknown" when the portal has no table or nothing can spawn.
+fn spawn_preview_text(table: Option<&SpawnTable>, divinity: Divinity) -> String {
+    let entries: Vec<&SpawnEntry> = table
+        .map(|table| table.entries_for(divinity).collect())
+        .unwrap_or_default();
+    let total_weight: u32 = entries.iter().map(|entry| entry.weight).sum();
+    if entries.is_empty() || total_weight == 0 {
+        return "Spawns: Unknown".to_string();
+    }
+
+    let mut text = String::from("Spawns:");
+    for entry in entries {
+        let monsters = match &entry.spawn_type {
+            SpawnType::Single(monster_id) => monster_id.clone(),
+            SpawnType::Group(monster_ids) => monster_ids.join(" + "),
+        };
+        let chance = entry.weight as f32 / total_weight as f32 * 100.0;
+        text.push_str(&format!(
+            "\n- {} (weight {}, {:.0}%)",
+            monsters, entry.weight, chance
+        ));
+    }
+    text
+}
+
+fn update_spawn_preview(
+    portal_query: Query<(&CurrentDivinity, &SpawnTableId), With<Portal>>,
+    ui_query: Query<&PortalUiRoot>,
+    mut text_query: Query<&mut Text, With<SpawnPreviewText>>,
+    game_assets: Res<GameAssets>,
+    spawn_tables: Res<Assets<SpawnTable>>,
+) {
+    for ui_root in ui_query.iter() {
+        let Ok((divinity, table_id)) = portal_query.get(ui_root.portal_entity) else {
+            continue;
+        };
+        let preview = spawn_preview_text(
+            game_assets.spawn_table(&table_id.0, &spawn_tables),
+            divinity.0,
+        );
+        for mut text in text_query.iter_mut() {
+            if text.0 != preview {
+                text.0.clone_from(&preview);
+            }
+        }
+    }
+}
+
 fn handle_close_button(
     mut commands: Commands,
     interaction_query: Query<&Interaction, (Changed<Interaction>, With<PortalCloseButton>)>,
@@ -254,6 +471,7 @@ fn handle_close_button(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn update_portal_ui(
     portal_query: Query<&CurrentDivinity, With<Portal>>,
     village_query: Query<&Divinity, With<Village>>,
@@ -269,6 +487,7 @@ fn update_portal_ui(
         ),
     >,
     unlock_definitions: Res<Assets<UnlockDefinition>>,
+    unlock_graph: UnlockGraph,
 ) {
     let Some(max_divinity) = village_query.iter().next() else {
         return;
@@ -281,12 +500,12 @@ fn update_portal_ui(
 
         // Update current divinity text
         for mut text in current_text_query.iter_mut() {
-            text.0 = format!("Tier {} - Level {}", divinity.tier, divinity.level);
+            text.0 = divinity.display();
         }
 
         // Update max divinity text
         for mut text in max_text_query.iter_mut() {
-            text.0 = format!("Tier {} - Level {}", max_divinity.tier, max_divinity.level);
+            text.0 = max_divinity.display();
         }
 
         // Update unlock condition text
@@ -296,7 +515,7 @@ fn update_portal_ui(
         } else {
             (max_divinity.tier + 1, 1)
         };
-        let target_reward_id = format!("divinity:{}-{}", target_tier, target_level);
+        let target_reward_id = Divinity::new(target_tier, target_level).reward_id();
 
         let mut condition_text: String;
 
@@ -309,7 +528,7 @@ fn update_portal_ui(
             match &def.condition {
                 ConditionNode::Completed { topic } => {
                     // Try to fix up the topic for display if it's a known format
-                    if let Some(research_name) = topic.strip_prefix("research:") {
+                    if let Some(research_name) = Topic::research_id(topic) {
                         // Basic capitalization or formatting could go here
                         condition_text.push_str(&format!("Research: {}", research_name));
                     } else {
@@ -324,7 +543,7 @@ fn update_portal_ui(
                     for node in nodes {
                         match node {
                             ConditionNode::Completed { topic } => {
-                                if let Some(research_name) = topic.strip_prefix("research:") {
+                                if let Some(research_name) = Topic::research_id(topic) {
                                     condition_text
                                         .push_str(&format!("- Research: {}\n", research_name));
                                 } else {
@@ -340,6 +559,13 @@ fn update_portal_ui(
                 }
                 _ => condition_text.push_str("Unknown condition"),
             }
+
+            if let Some(report) = unlock_graph.progress(&def.id) {
+                condition_text.push_str(&format!(
+                    "\nProgress: {}/{} conditions met",
+                    report.met, report.total
+                ));
+            }
         } else {
             // Check if we are at absolute max (no more definitions found)
             condition_text = "Max Level Reached".to_string();
@@ -363,6 +589,8 @@ fn handle_tier_navigation(
         (&Interaction, &IncreaseTierButton),
         (Changed<Interaction>, With<Button>),
     >,
+    first_query: Query<(&Interaction, &FirstTierButton), (Changed<Interaction>, With<Button>)>,
+    max_query: Query<(&Interaction, &MaxTierButton), (Changed<Interaction>, With<Button>)>,
 ) {
     let Some(max_divinity) = village_query.iter().next() else {
         return;
@@ -373,13 +601,8 @@ fn handle_tier_navigation(
             && let Ok(mut divinity) = portal_query.get_mut(btn.portal_entity)
         {
             // Decrease level, wrapping to previous tier if needed
-            if divinity.level > 1 {
-                divinity.level -= 1;
-            } else if divinity.tier > 1 {
-                divinity.tier -= 1;
-                divinity.level = divinity_components::MAX_LEVEL;
-            }
-            // If already at tier 1 level 1, do nothing
+            let target = step_divinity(divinity.0, false, *max_divinity);
+            divinity.set_if_neq(CurrentDivinity(target));
         }
     }
 
@@ -389,22 +612,439 @@ fn handle_tier_navigation(
             && let Ok(mut divinity) = portal_query.get_mut(btn.portal_entity)
         {
             // Only allow increase up to max unlocked divinity
-            let current = **divinity;
-            let max = *max_divinity;
+            let target = step_divinity(divinity.0, true, *max_divinity);
+            divinity.set_if_neq(CurrentDivinity(target));
+        }
+    }
 
-            if current < max {
-                if divinity.level < divinity_components::MAX_LEVEL {
-                    divinity.level += 1;
-                } else {
-                    divinity.tier += 1;
-                    divinity.level = 1;
-                }
+    // Handle jump buttons
+    let jumps = first_query
+        .iter()
+        .map(|(interaction, btn)| (interaction, btn.portal_entity, false))
+        .chain(
+            max_query
+                .iter()
+                .map(|(interaction, btn)| (interaction, btn.portal_entity, true)),
+        );
+    for (interaction, portal_entity, to_max) in jumps {
+        if *interaction == Interaction::Pressed
+            && let Ok(mut divinity) = portal_query.get_mut(portal_entity)
+        {
+            let target = jump_divinity(to_max, *max_divinity);
+            divinity.set_if_neq(CurrentDivinity(target));
+        }
+    }
+}
 
-                // Clamp to max
-                if divinity.0 > max {
-                    divinity.0 = max;
-                }
-            }
+/// Square button in the tier row showing `label`.
+fn spawn_tier_button(row: &mut ChildSpawnerCommands, label: &str, marker: impl Component) {
+    row.spawn((
+        Button,
+        Node {
+            width: Val::Px(40.0),
+            height: Val::Px(40.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BorderColor::all(UiTheme::CARD_BORDER),
+        BackgroundColor(UiTheme::BUTTON_NORMAL),
+        marker,
+    ))
+    .with_child((
+        Text::new(label),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+    ));
+}
+
+/// Steps the portal's divinity by one level per wheel notch over the tier row.
+fn on_tier_stepper_scroll(
+    mut scroll: On<Scroll>,
+    stepper_query: Query<&TierStepper>,
+    mut portal_query: Query<&mut CurrentDivinity, With<Portal>>,
+    village_query: Query<&Divinity, With<Village>>,
+) {
+    // The tier row is not a scroll container, so don't let the wheel scroll the panel too
+    scroll.propagate(false);
+
+    if scroll.delta.y == 0.0 {
+        return;
+    }
+    let Ok(stepper) = stepper_query.get(scroll.event_target()) else {
+        return;
+    };
+    let Some(max_divinity) = village_query.iter().next() else {
+        return;
+    };
+    if let Ok(mut divinity) = portal_query.get_mut(stepper.portal_entity) {
+        // Wheel-up arrives as a negative delta
+        let target = step_divinity(divinity.0, scroll.delta.y < 0.0, *max_divinity);
+        divinity.set_if_neq(CurrentDivinity(target));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn handle_preset_buttons(
+    mut portal_query: Query<&mut CurrentDivinity, With<Portal>>,
+    village_query: Query<&Divinity, With<Village>>,
+    interaction_query: Query<
+        (&Interaction, &DivinityPresetButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+) {
+    let Some(max_divinity) = village_query.iter().next() else {
+        return;
+    };
+
+    for (interaction, btn) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed
+            && let Ok(mut divinity) = portal_query.get_mut(btn.portal_entity)
+        {
+            let target = btn.preset.target(*max_divinity, ALLOW_OVER_LEVELING);
+            divinity.set_if_neq(CurrentDivinity(target));
+        }
+    }
+}
+
+/// Restarts the flash on the divinity text whenever the displayed portal's divinity changes.
+fn start_divinity_flash(
+    mut commands: Commands,
+    portal_query: Query<(), (With<Portal>, Changed<CurrentDivinity>)>,
+    ui_query: Query<&PortalUiRoot>,
+    text_query: Query<Entity, With<CurrentDivinityText>>,
+) {
+    if !ui_query
+        .iter()
+        .any(|ui_root| portal_query.contains(ui_root.portal_entity))
+    {
+        return;
+    }
+
+    for entity in text_query.iter() {
+        commands.entity(entity).insert((
+            DivinityFlash {
+                timer: Timer::from_seconds(DIVINITY_FLASH_SECS, TimerMode::Once),
+            },
+            TextColor(DIVINITY_FLASH_COLOR),
+        ));
+    }
+}
+
+/// Fades the flashing text back to white, then drops the flash.
+fn animate_divinity_flash(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut DivinityFlash, &mut TextColor)>,
+) {
+    for (entity, mut flash, mut color) in query.iter_mut() {
+        flash.timer.tick(time.delta());
+        color.0 = DIVINITY_FLASH_COLOR.mix(&Color::WHITE, flash.timer.fraction());
+
+        if flash.timer.is_finished() {
+            color.0 = Color::WHITE;
+            commands.entity(entity).remove::<DivinityFlash>();
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        bevy::{
+            camera::NormalizedRenderTarget,
+            picking::{
+                backend::HitData,
+                events::Press,
+                pointer::{Location, PointerButton, PointerId},
+            },
+        },
+        std::time::Duration,
+        widgets::{ClickSettings, PointerPressOrigins},
+    };
+
+    /// Runs one update with `secs` of elapsed time, leaving later updates at zero delta.
+    fn advance_time(app: &mut App, secs: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(secs));
+        app.update();
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::ZERO);
+    }
+
+    #[test]
+    fn test_divinity_change_starts_flash() {
+        let mut app = App::new();
+        app.init_resource::<Time>().add_systems(
+            Update,
+            (start_divinity_flash, animate_divinity_flash).chain(),
+        );
+
+        let portal = app
+            .world_mut()
+            .spawn((Portal, CurrentDivinity::default()))
+            .id();
+        app.world_mut().spawn(PortalUiRoot {
+            portal_entity: portal,
+        });
+        let text = app
+            .world_mut()
+            .spawn((CurrentDivinityText, TextColor(Color::WHITE)))
+            .id();
+
+        // Let the flash from spawning the portal run out
+        app.update();
+        advance_time(&mut app, DIVINITY_FLASH_SECS);
+        app.update();
+        assert!(app.world().get::<DivinityFlash>(text).is_none());
+        assert_eq!(app.world().get::<TextColor>(text).unwrap().0, Color::WHITE);
+
+        app.world_mut()
+            .get_mut::<CurrentDivinity>(portal)
+            .unwrap()
+            .0 = Divinity::new(1, 2);
+        app.update();
+        assert!(app.world().get::<DivinityFlash>(text).is_some());
+        assert_eq!(
+            app.world().get::<TextColor>(text).unwrap().0,
+            DIVINITY_FLASH_COLOR
+        );
+
+        // Halfway through the fade the color is in between
+        advance_time(&mut app, DIVINITY_FLASH_SECS / 2.0);
+        let color = app.world().get::<TextColor>(text).unwrap().0;
+        assert_ne!(color, DIVINITY_FLASH_COLOR);
+        assert_ne!(color, Color::WHITE);
+
+        advance_time(&mut app, DIVINITY_FLASH_SECS);
+        assert!(app.world().get::<DivinityFlash>(text).is_none());
+    }
+
+    #[test]
+    fn test_wheel_over_tier_row_steps_divinity() {
+        let mut world = World::new();
+        world.spawn((Village, Divinity::new(1, 3)));
+        let portal = world.spawn((Portal, CurrentDivinity::default())).id();
+        let row = world
+            .spawn(TierStepper {
+                portal_entity: portal,
+            })
+            .observe(on_tier_stepper_scroll)
+            .with_child(CurrentDivinityText)
+            .id();
+        let text = world.get::<Children>(row).unwrap()[0];
+
+        let wheel = |world: &mut World, notch: f32| {
+            world.trigger(Scroll {
+                entity: text,
+                delta: Vec2::new(0.0, -notch),
+            });
+            world.flush();
+            world.get::<CurrentDivinity>(portal).unwrap().0
+        };
+
+        // Wheel-down at the floor stays at Tier 1 Level 1
+        assert_eq!(wheel(&mut world, -1.0), Divinity::new(1, 1));
+        assert_eq!(wheel(&mut world, 1.0), Divinity::new(1, 2));
+        assert_eq!(wheel(&mut world, 1.0), Divinity::new(1, 3));
+        // Capped at the village's max divinity
+        assert_eq!(wheel(&mut world, 1.0), Divinity::new(1, 3));
+        assert_eq!(wheel(&mut world, -1.0), Divinity::new(1, 2));
+    }
+
+    #[test]
+    fn test_jump_buttons_go_to_first_or_max_tier() {
+        let mut world = World::new();
+        world.spawn((Village, Divinity::new(3, 7)));
+        let portal = world
+            .spawn((Portal, CurrentDivinity(Divinity::new(2, 4))))
+            .id();
+        let max_button = world
+            .spawn((
+                Button,
+                Interaction::None,
+                MaxTierButton {
+                    portal_entity: portal,
+                },
+            ))
+            .id();
+        let first_button = world
+            .spawn((
+                Button,
+                Interaction::None,
+                FirstTierButton {
+                    portal_entity: portal,
+                },
+            ))
+            .id();
+        let system = world.register_system(handle_tier_navigation);
+        let press = |world: &mut World, button: Entity| {
+            *world.get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+            world.run_system(system).unwrap();
+            *world.get_mut::<Interaction>(button).unwrap() = Interaction::None;
+            world.get::<CurrentDivinity>(portal).unwrap().0
+        };
+
+        assert_eq!(press(&mut world, max_button), Divinity::new(3, 7));
+        assert_eq!(press(&mut world, first_button), Divinity::new(1, 1));
+
+        // Beyond the unlocked max (e.g. after over-leveling) the jump still clamps down
+        world.get_mut::<CurrentDivinity>(portal).unwrap().0 = Divinity::new(4, 1);
+        assert_eq!(press(&mut world, max_button), Divinity::new(3, 7));
+    }
+
+    #[test]
+    fn test_spawn_preview_lists_entries_for_the_selected_divinity() {
+        let table = SpawnTable {
+            entries: vec![
+                SpawnEntry {
+                    condition: portal_assets::SpawnCondition::Min(Divinity::new(1, 1)),
+                    spawn_type: SpawnType::Single("goblin_scout".to_string()),
+                    weight: 3,
+                },
+                SpawnEntry {
+                    condition: portal_assets::SpawnCondition::Range {
+                        min: Divinity::new(1, 5),
+                        max: Divinity::new(2, 1),
+                    },
+                    spawn_type: SpawnType::Group(vec!["wolf".to_string(), "wolf".to_string()]),
+                    weight: 1,
+                },
+            ],
+        };
+
+        assert_eq!(
+            spawn_preview_text(Some(&table), Divinity::new(1, 2)),
+            "Spawns:\n- goblin_scout (weight 3, 100%)"
+        );
+        assert_eq!(
+            spawn_preview_text(Some(&table), Divinity::new(1, 5)),
+            "Spawns:\n- goblin_scout (weight 3, 75%)\n- wolf + wolf (weight 1, 25%)"
+        );
+        assert_eq!(
+            spawn_preview_text(Some(&SpawnTable::default()), Divinity::new(1, 1)),
+            "Spawns: Unknown"
+        );
+        assert_eq!(
+            spawn_preview_text(None, Divinity::new(1, 1)),
+            "Spawns: Unknown"
+        );
+    }
+
+    #[test]
+    fn test_presets_mid_tier() {
+        let max = Divinity::new(2, 5);
+
+        assert_eq!(DivinityPreset::Safe.target(max, false), Divinity::new(2, 4));
+        assert_eq!(DivinityPreset::Recommended.target(max, false), max);
+        assert_eq!(DivinityPreset::Risky.target(max, false), max);
+        assert_eq!(DivinityPreset::Risky.target(max, true), Divinity::new(2, 6));
+    }
+
+    #[test]
+    fn test_safe_preset_wraps_to_previous_tier() {
+        let max = Divinity::new(2, 1);
+
+        assert_eq!(
+            DivinityPreset::Safe.target(max, false),
+            Divinity::new(1, divinity_components::MAX_LEVEL)
+        );
+    }
+
+    #[test]
+    fn test_safe_preset_clamps_at_minimum() {
+        let max = Divinity::default();
+
+        assert_eq!(DivinityPreset::Safe.target(max, false), Divinity::new(1, 1));
+        assert_eq!(
+            DivinityPreset::Recommended.target(max, false),
+            Divinity::new(1, 1)
+        );
+    }
+
+    #[test]
+    fn test_risky_preset_wraps_to_next_tier() {
+        let max = Divinity::new(1, divinity_components::MAX_LEVEL);
+
+        assert_eq!(DivinityPreset::Risky.target(max, true), Divinity::new(2, 1));
+        assert_eq!(DivinityPreset::Risky.target(max, false), max);
+    }
+
+    fn pointer_at<E: std::fmt::Debug + Clone + Reflect>(
+        entity: Entity,
+        x: f32,
+        event: E,
+    ) -> Pointer<E> {
+        let location = Location {
+            target: NormalizedRenderTarget::None {
+                width: 800,
+                height: 600,
+            },
+            position: Vec2::new(x, 0.0),
+        };
+        Pointer::new(PointerId::Mouse, location, event, entity)
+    }
+
+    /// Presses at x = 0 and releases at `release_x` on `portal`.
+    fn press_and_click(app: &mut App, portal: Entity, release_x: f32) {
+        let hit = HitData::new(Entity::PLACEHOLDER, 0.0, None, None);
+        app.world_mut().trigger(pointer_at(
+            portal,
+            0.0,
+            Press {
+                button: PointerButton::Primary,
+                hit: hit.clone(),
+            },
+        ));
+        app.world_mut().trigger(pointer_at(
+            portal,
+            release_x,
+            Click {
+                button: PointerButton::Primary,
+                hit,
+                duration: Duration::from_millis(100),
+            },
+        ));
+        app.update();
+    }
+
+    #[test]
+    fn test_drag_release_does_not_toggle_portal_ui() {
+        let mut app = App::new();
+        app.init_resource::<ClickSettings>()
+            .init_resource::<PointerPressOrigins>()
+            .init_resource::<ModalState>()
+            .add_observer(widgets::record_pointer_press)
+            .add_observer(on_portal_click);
+
+        let portal = app
+            .world_mut()
+            .spawn((Portal, CurrentDivinity::default()))
+            .id();
+
+        // Released far away from the press: this was a pan, not a click
+        press_and_click(&mut app, portal, 200.0);
+        let open = app
+            .world_mut()
+            .query::<&PortalUiRoot>()
+            .iter(app.world())
+            .count();
+        assert_eq!(open, 0);
+
+        // A click that barely moved opens the UI
+        press_and_click(&mut app, portal, 2.0);
+        let open = app
+            .world_mut()
+            .query::<&PortalUiRoot>()
+            .iter(app.world())
+            .count();
+        assert_eq!(open, 1);
+    }
+}
@@ -1,6 +1,7 @@
 use {
-    bevy::prelude::*,
+    bevy::{picking::prelude::Pickable, prelude::*},
     crafting::CraftingInProgress,
+    crafting_events::CancelCraftingRequest,
     research::{InProgress, ResearchNode},
     research_assets::ResearchDefinition,
     states::GameState,
@@ -46,6 +47,12 @@ pub struct CraftingProgressBar {
     pub crafting_entity: Entity,
 }
 
+/// "✕" button on a crafting bar that cancels the craft
+#[derive(Component)]
+pub struct CancelCraftingButton {
+    pub crafting_entity: Entity,
+}
+
 /// The fill portion of a progress bar
 #[derive(Component)]
 struct ProgressBarFill;
@@ -65,6 +72,8 @@ const BAR_SPACING: f32 = 14.0;
 const BAR_BG_COLOR: Color = Color::srgba(0.1, 0.1, 0.1, 0.8);
 const BAR_FILL_RESEARCH: Color = Color::srgba(0.2, 0.6, 1.0, 1.0);
 const BAR_FILL_CRAFTING: Color = Color::srgba(1.0, 0.6, 0.2, 1.0);
+const CANCEL_BUTTON_SIZE: f32 = 10.0;
+const CANCEL_BUTTON_COLOR: Color = Color::srgba(0.6, 0.15, 0.15, 0.9);
 
 // ============================================================================
 // Systems - Spawn/Despawn
@@ -166,7 +175,7 @@ fn on_crafting_started(
     // Count existing bars for vertical offset
     let bar_index = research_bars.iter().count() + crafting_bars.iter().count();
 
-    spawn_progress_bar(
+    let bar = spawn_progress_bar(
         &mut commands,
         root_entity,
         &name,
@@ -174,6 +183,21 @@ fn on_crafting_started(
         BAR_FILL_CRAFTING,
         CraftingProgressBar { crafting_entity },
     );
+    spawn_cancel_button(&mut commands, bar, crafting_entity);
+}
+
+/// Observer on a cancel button: requests the cancel and keeps the click from reaching the village.
+fn on_cancel_button_clicked(
+    mut trigger: On<Pointer<Click>>,
+    mut commands: Commands,
+    buttons: Query<&CancelCraftingButton>,
+) {
+    trigger.propagate(false);
+    if let Ok(button) = buttons.get(trigger.entity) {
+        commands.trigger(CancelCraftingRequest {
+            crafting_entity: button.crafting_entity,
+        });
+    }
 }
 
 // ============================================================================
@@ -282,7 +306,7 @@ fn spawn_progress_bar<M: Component>(
     index: usize,
     fill_color: Color,
     marker: M,
-) {
+) -> Entity {
     let y_offset = -(index as f32) * BAR_SPACING;
 
     let bar = commands
@@ -341,6 +365,38 @@ fn spawn_progress_bar<M: Component>(
         .id();
 
     commands.entity(root).add_child(bar);
+    bar
+}
+
+/// Spawns the "✕" button just right of `bar`.
+fn spawn_cancel_button(commands: &mut Commands, bar: Entity, crafting_entity: Entity) {
+    commands
+        .spawn((
+            CancelCraftingButton { crafting_entity },
+            Sprite {
+                color: CANCEL_BUTTON_COLOR,
+                custom_size: Some(Vec2::splat(CANCEL_BUTTON_SIZE)),
+                ..default()
+            },
+            Transform::from_translation(Vec3::new(
+                (BAR_WIDTH + CANCEL_BUTTON_SIZE) / 2.0 + 2.0,
+                0.0,
+                0.1,
+            )),
+            Pickable::default(),
+            ChildOf(bar),
+        ))
+        .with_child((
+            Text2d::new("✕"),
+            TextFont {
+                font_size: 8.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Transform::from_translation(Vec3::new(0.0, 0.0, 0.1)),
+            Pickable::IGNORE,
+        ))
+        .observe(on_cancel_button_clicked);
 }
 
 /// Command to cleanup empty root on next frame
@@ -1,16 +1,18 @@
 use {
     bevy::prelude::*,
     research::{
-        Available, Completed, InProgress, ResearchCompletionCount, ResearchMap, ResearchNode,
-        StartResearchRequest,
+        Available, ClearResearchQueue, Completed, InProgress, ResearchCompletionCount,
+        ResearchLibrary, ResearchMap, ResearchNode, ResearchQueue, StartResearchRequest,
     },
     research_assets::ResearchDefinition,
     states::{GameState, VillageView},
+    std::collections::HashMap,
     wallet::Wallet,
     widgets::{
-        ContentContainer, UiTheme, spawn_action_button, spawn_card_title, spawn_description_text,
-        spawn_menu_button, spawn_scrollable_container, spawn_tab_bar, spawn_tab_button,
-        spawn_timer_text,
+        ClearContentContainer, ContentContainer, UiTheme, format_number, set_progress_bar_fraction,
+        spawn_action_button, spawn_card_title, spawn_description_text, spawn_menu_button,
+        spawn_progress_bar_ui, spawn_scrollable_container_with_scrollbar, spawn_tab_bar,
+        spawn_tab_button, spawn_timer_text,
     },
 };
 
@@ -27,6 +29,8 @@ impl Plugin for ResearchUiPlugin {
                 Update,
                 (
                     handle_tab_switch,
+                    handle_show_locked_toggle,
+                    handle_clear_queue_button,
                     handle_research_button,
                     handle_back_button,
                 )
@@ -34,7 +38,8 @@ impl Plugin for ResearchUiPlugin {
             )
             .add_systems(
                 Update,
-                update_research_ui.run_if(in_state(VillageView::Research)),
+                (update_research_ui, update_research_progress_bars)
+                    .run_if(in_state(VillageView::Research)),
             );
     }
 }
@@ -53,6 +58,8 @@ pub enum ResearchTab {
 #[derive(Component)]
 pub struct ResearchUiRoot {
     pub active_tab: ResearchTab,
+    /// Whether the Available tab also lists research whose prerequisites are not met
+    pub show_locked: bool,
 }
 
 /// Tab button with category
@@ -69,6 +76,20 @@ pub struct ResearchButton {
 #[derive(Component)]
 pub struct ResearchItemsContainer;
 
+/// Button toggling `ResearchUiRoot::show_locked`
+#[derive(Component)]
+pub struct ShowLockedToggle;
+
+/// Button dropping every queued research, see `ClearResearchQueue`
+#[derive(Component)]
+pub struct ClearResearchQueueButton;
+
+/// Progress bar fill showing how far along an in-progress research is
+#[derive(Component)]
+pub struct ResearchProgressFill {
+    pub research_id: String,
+}
+
 // ============================================================================
 // Research Data Builder (for external use)
 // ============================================================================
@@ -89,17 +110,28 @@ pub struct ResearchDisplayData {
     pub cost_str: String,
     pub can_afford: bool,
     pub is_completed: bool,
+    /// Locked research can't be started; `missing_prerequisites` says why
+    pub is_locked: bool,
+    /// Display names of the prerequisite research still needed
+    pub missing_prerequisites: Vec<String>,
     pub btn_text: String,
     pub btn_color: Color,
     pub btn_border: Color,
-    /// Progress info for repeatable research (e.g., "1/10"), None for one-time research
+    /// Progress info for repeatable research (e.g., "1/10 completed"), None for one-time research
     pub progress_info: Option<String>,
 }
 
+/// Completion count of a repeatable research, e.g. "3/5 completed". One-time research
+/// (`max_repeats == 1`) has none.
+fn repeat_progress(def: &ResearchDefinition, completions: u32) -> Option<String> {
+    (def.max_repeats > 1).then(|| format!("{}/{} completed", completions, def.max_repeats))
+}
+
 /// Builds research display data from entity queries
 pub fn build_research_data(
     assets: &Assets<ResearchDefinition>,
     wallet: &Wallet,
+    queue: &ResearchQueue,
     available_query: &[(Entity, &ResearchNode, &ResearchCompletionCount)],
     in_progress_query: &[(Entity, &ResearchNode, &InProgress, &ResearchCompletionCount)],
     completed_query: &[(Entity, &ResearchNode, &ResearchCompletionCount)],
@@ -108,21 +140,26 @@ pub fn build_research_data(
     let items = build_research_list(
         assets,
         wallet,
+        queue,
         active_tab,
         available_query,
         in_progress_query,
         completed_query,
+        &[],
     );
     ResearchData { active_tab, items }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn build_research_list(
     assets: &Assets<ResearchDefinition>,
     wallet: &Wallet,
+    queue: &ResearchQueue,
     active_tab: ResearchTab,
     available_query: &[(Entity, &ResearchNode, &ResearchCompletionCount)],
     in_progress_query: &[(Entity, &ResearchNode, &InProgress, &ResearchCompletionCount)],
     completed_query: &[(Entity, &ResearchNode, &ResearchCompletionCount)],
+    locked: &[ResearchDisplayData],
 ) -> Vec<ResearchDisplayData> {
     let mut research_data = Vec::new();
 
@@ -137,8 +174,13 @@ fn build_research_list(
                 let mut can_afford = true;
                 let mut cost_str = String::from("Cost: ");
                 for (res, amt) in &def.cost {
-                    let current = wallet.resources.get(res).copied().unwrap_or(0);
-                    cost_str.push_str(&format!("{}: {}/{} ", res, current, amt));
+                    let current = wallet.amount(res);
+                    cost_str.push_str(&format!(
+                        "{}: {}/{} ",
+                        res,
+                        format_number(current as u64),
+                        format_number(*amt as u64)
+                    ));
                     if current < *amt {
                         can_afford = false;
                     }
@@ -146,32 +188,30 @@ fn build_research_list(
 
                 let is_busy = !in_progress_query.is_empty();
 
-                let (btn_text, btn_color, btn_border) = if is_busy {
+                // While busy, Start queues the research behind the running one
+                let label = if is_busy { "Queue" } else { "Start" };
+                let (btn_text, btn_color, btn_border) = if queue.contains(&node.id) {
                     (
-                        "Busy".to_string(),
-                        UiTheme::BORDER_DISABLED,
-                        UiTheme::BORDER_DISABLED,
+                        "Queued".to_string(),
+                        UiTheme::TEXT_INFO,
+                        UiTheme::BORDER_INFO,
                     )
                 } else if can_afford {
                     (
-                        "Start".to_string(),
+                        label.to_string(),
                         UiTheme::AFFORDABLE,
                         UiTheme::BORDER_SUCCESS,
                     )
                 } else {
                     (
-                        "Start".to_string(),
+                        label.to_string(),
                         UiTheme::BORDER_DISABLED,
                         UiTheme::BORDER_DISABLED,
                     )
                 };
 
                 // Build progress info for repeatable research
-                let progress_info = if def.max_repeats > 1 {
-                    Some(format!("{}/{}", count.0, def.max_repeats))
-                } else {
-                    None
-                };
+                let progress_info = repeat_progress(def, count.0);
 
                 research_data.push(ResearchDisplayData {
                     id: node.id.clone(),
@@ -181,6 +221,8 @@ fn build_research_list(
                     cost_str,
                     can_afford,
                     is_completed: false,
+                    is_locked: false,
+                    missing_prerequisites: Vec::new(),
                     btn_text,
                     btn_color,
                     btn_border,
@@ -195,11 +237,7 @@ fn build_research_list(
                 };
 
                 // Build progress info for repeatable research
-                let progress_info = if def.max_repeats > 1 {
-                    Some(format!("{}/{}", count.0, def.max_repeats))
-                } else {
-                    None
-                };
+                let progress_info = repeat_progress(def, count.0);
 
                 research_data.push(ResearchDisplayData {
                     id: node.id.clone(),
@@ -209,13 +247,17 @@ fn build_research_list(
                     cost_str: String::new(),
                     can_afford: true,
                     is_completed: false,
+                    is_locked: false,
+                    missing_prerequisites: Vec::new(),
                     btn_text: "Researching...".to_string(),
                     btn_color: UiTheme::TEXT_INFO,
-                    btn_border: Color::srgba(0.4, 0.4, 1.0, 1.0),
+                    btn_border: UiTheme::BORDER_INFO,
                     progress_info,
                 });
             }
 
+            research_data.extend_from_slice(locked);
+
             // Sort by name
             research_data.sort_by(|a, b| a.name.cmp(&b.name));
         }
@@ -225,12 +267,8 @@ fn build_research_list(
                     continue;
                 };
 
-                // Build progress info for repeatable research (should show max/max)
-                let progress_info = if def.max_repeats > 1 {
-                    Some(format!("{}/{}", count.0, def.max_repeats))
-                } else {
-                    None
-                };
+                // Repeatable research only lands here once it hit max_repeats
+                let progress_info = repeat_progress(def, count.0);
 
                 research_data.push(ResearchDisplayData {
                     id: node.id.clone(),
@@ -240,7 +278,13 @@ fn build_research_list(
                     cost_str: String::new(),
                     can_afford: true,
                     is_completed: true,
-                    btn_text: "Completed".to_string(),
+                    is_locked: false,
+                    missing_prerequisites: Vec::new(),
+                    btn_text: if def.max_repeats > 1 {
+                        "Maxed".to_string()
+                    } else {
+                        "Completed".to_string()
+                    },
                     btn_color: UiTheme::TEXT_PRIMARY,
                     btn_border: UiTheme::TEXT_PRIMARY,
                     progress_info,
@@ -253,28 +297,63 @@ fn build_research_list(
     research_data
 }
 
+/// Display data for the locked research, listed on the Available tab when `show_locked` is set.
+fn build_locked_list(library: &ResearchLibrary, wallet: &Wallet) -> Vec<ResearchDisplayData> {
+    library
+        .locked()
+        .into_iter()
+        .map(|(id, def)| {
+            let mut cost_str = String::from("Cost: ");
+            for (res, amt) in &def.cost {
+                cost_str.push_str(&format!(
+                    "{}: {}/{} ",
+                    res,
+                    format_number(wallet.amount(res) as u64),
+                    format_number(*amt as u64)
+                ));
+            }
+
+            ResearchDisplayData {
+                id: id.to_string(),
+                name: def.name.clone(),
+                description: def.description.clone(),
+                time: def.time_required,
+                cost_str,
+                can_afford: false,
+                is_completed: false,
+                is_locked: true,
+                missing_prerequisites: library.missing_prerequisites(id),
+                btn_text: "Locked".to_string(),
+                btn_color: UiTheme::BORDER_DISABLED,
+                btn_border: UiTheme::BORDER_DISABLED,
+                // Locked research has never been completed
+                progress_info: repeat_progress(def, 0),
+            }
+        })
+        .collect()
+}
+
 // ============================================================================
 // Spawn Research UI System
 // ============================================================================
 
+#[allow(clippy::too_many_arguments)]
 fn spawn_research_ui(
     mut commands: Commands,
-    mut query: Query<(Entity, Option<&Children>), With<ContentContainer>>,
+    query: Query<Entity, With<ContentContainer>>,
     assets: Res<Assets<ResearchDefinition>>,
     wallet: Res<Wallet>,
+    queue: Res<ResearchQueue>,
     available_query: Query<(Entity, &ResearchNode, &ResearchCompletionCount), With<Available>>,
     in_progress_query: Query<(Entity, &ResearchNode, &InProgress, &ResearchCompletionCount)>,
     completed_query: Query<(Entity, &ResearchNode, &ResearchCompletionCount), With<Completed>>,
 ) {
-    let Some((container, children)) = query.iter_mut().next() else {
+    let Some(container) = query.iter().next() else {
         return;
     };
 
-    // Despawn existing children
-    let to_despawn: Vec<Entity> = children.map(|c| c.iter().collect()).unwrap_or_default();
-    for child in to_despawn {
-        commands.entity(child).despawn();
-    }
+    // Clear the previous view, cached village views are only hidden
+    commands.queue(ClearContentContainer(container));
 
     // Collect query results
     let available: Vec<_> = available_query.iter().collect();
@@ -284,10 +363,12 @@ fn spawn_research_ui(
     let items = build_research_list(
         &assets,
         &wallet,
+        &queue,
         ResearchTab::Available, // Default to available
         &available,
         &in_progress,
         &completed,
+        &[],
     );
 
     let research_data = ResearchData {
@@ -321,6 +402,7 @@ pub fn spawn_research_content(parent: &mut ChildSpawnerCommands, data: ResearchD
             },
             ResearchUiRoot {
                 active_tab: data.active_tab,
+                show_locked: false,
             },
         ))
         .id();
@@ -350,31 +432,29 @@ pub fn spawn_research_content(parent: &mut ChildSpawnerCommands, data: ResearchD
                 );
             });
 
+            spawn_action_button(
+                content,
+                "Show Locked",
+                UiTheme::TEXT_PRIMARY,
+                UiTheme::CARD_BORDER,
+                ShowLockedToggle,
+            );
+
+            spawn_action_button(
+                content,
+                "Clear Queue",
+                UiTheme::TEXT_PRIMARY,
+                UiTheme::CARD_BORDER,
+                ClearResearchQueueButton,
+            );
+
             // Scrollable container for research items
-            spawn_scrollable_container(content, ResearchItemsContainer, |_| {});
+            spawn_scrollable_container_with_scrollbar(content, ResearchItemsContainer, |_| {});
         });
 
     // Populate with initial research (queue command)
     parent.commands().queue(PopulateResearchDirectCommand {
-        research_data: data
-            .items
-            .into_iter()
-            .map(|r| {
-                (
-                    r.id,
-                    r.name,
-                    r.description,
-                    r.time,
-                    r.cost_str,
-                    r.can_afford,
-                    r.is_completed,
-                    r.btn_text,
-                    r.btn_color,
-                    r.btn_border,
-                    r.progress_info,
-                )
-            })
-            .collect(),
+        research_data: data.items,
     });
 }
 
@@ -398,7 +478,7 @@ fn handle_back_button(
 // Tab Switch Handler
 // ============================================================================
 
-#[allow(clippy::type_complexity)]
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 fn handle_tab_switch(
     mut commands: Commands,
     interaction_query: Query<
@@ -409,9 +489,11 @@ fn handle_tab_switch(
     mut tab_buttons: Query<(&ResearchTabButton, &mut BackgroundColor)>,
     assets: Res<Assets<ResearchDefinition>>,
     wallet: Res<Wallet>,
+    queue: Res<ResearchQueue>,
     available_query: Query<(Entity, &ResearchNode, &ResearchCompletionCount), With<Available>>,
     in_progress_query: Query<(Entity, &ResearchNode, &InProgress, &ResearchCompletionCount)>,
     completed_query: Query<(Entity, &ResearchNode, &ResearchCompletionCount), With<Completed>>,
+    library: ResearchLibrary,
 ) {
     for (interaction, tab_btn) in interaction_query.iter() {
         if *interaction == Interaction::Pressed
@@ -433,35 +515,25 @@ fn handle_tab_switch(
             let available: Vec<_> = available_query.iter().collect();
             let in_progress: Vec<_> = in_progress_query.iter().collect();
             let completed: Vec<_> = completed_query.iter().collect();
+            let locked = if ui_root.show_locked {
+                build_locked_list(&library, &wallet)
+            } else {
+                Vec::new()
+            };
 
             // Repopulate research
             let items = build_research_list(
                 &assets,
                 &wallet,
+                &queue,
                 ui_root.active_tab,
                 &available,
                 &in_progress,
                 &completed,
+                &locked,
             );
             commands.queue(PopulateResearchDirectCommand {
-                research_data: items
-                    .into_iter()
-                    .map(|r| {
-                        (
-                            r.id,
-                            r.name,
-                            r.description,
-                            r.time,
-                            r.cost_str,
-                            r.can_afford,
-                            r.is_completed,
-                            r.btn_text,
-                            r.btn_color,
-                            r.btn_border,
-                            r.progress_info,
-                        )
-                    })
-                    .collect(),
+                research_data: items,
             });
         }
     }
@@ -471,28 +543,38 @@ fn handle_tab_switch(
 // Update Research UI (on resource/state change)
 // ============================================================================
 
+#[allow(clippy::too_many_arguments)]
 fn update_research_ui(
     mut commands: Commands,
     assets: Res<Assets<ResearchDefinition>>,
     wallet: Res<Wallet>,
+    queue: Res<ResearchQueue>,
     ui_query: Query<&ResearchUiRoot>,
     available_query: Query<(Entity, &ResearchNode, &ResearchCompletionCount), With<Available>>,
     in_progress_query: Query<(Entity, &ResearchNode, &InProgress, &ResearchCompletionCount)>,
     completed_query: Query<(Entity, &ResearchNode, &ResearchCompletionCount), With<Completed>>,
+    library: ResearchLibrary,
     mut last_data: Local<Option<Vec<ResearchDisplayData>>>,
 ) {
     if let Ok(ui_root) = ui_query.single() {
         let available: Vec<_> = available_query.iter().collect();
         let in_progress: Vec<_> = in_progress_query.iter().collect();
         let completed: Vec<_> = completed_query.iter().collect();
+        let locked = if ui_root.show_locked {
+            build_locked_list(&library, &wallet)
+        } else {
+            Vec::new()
+        };
 
         let items = build_research_list(
             &assets,
             &wallet,
+            &queue,
             ui_root.active_tab,
             &available,
             &in_progress,
             &completed,
+            &locked,
         );
 
         // Check for changes to avoid unnecessary rebuilds
@@ -504,48 +586,32 @@ fn update_research_ui(
         *last_data = Some(items.clone());
 
         commands.queue(PopulateResearchDirectCommand {
-            research_data: items
-                .into_iter()
-                .map(|r| {
-                    (
-                        r.id,
-                        r.name,
-                        r.description,
-                        r.time,
-                        r.cost_str,
-                        r.can_afford,
-                        r.is_completed,
-                        r.btn_text,
-                        r.btn_color,
-                        r.btn_border,
-                        r.progress_info,
-                    )
-                })
-                .collect(),
+            research_data: items,
         });
     }
 }
 
+/// Advances in-progress research bars every frame without rebuilding the cards.
+fn update_research_progress_bars(
+    in_progress_query: Query<&InProgress>,
+    mut fill_query: Query<(&ResearchProgressFill, &mut Node)>,
+) {
+    for (fill, node) in fill_query.iter_mut() {
+        let fraction = in_progress_query
+            .iter()
+            .find(|p| p.research_id == fill.research_id)
+            .map_or(1.0, |p| p.timer.fraction());
+        set_progress_bar_fraction(node, fraction);
+    }
+}
+
 // ============================================================================
 // Populate Research Command
 // ============================================================================
 
 /// Command to populate research (deferred execution)
 struct PopulateResearchDirectCommand {
-    #[allow(clippy::type_complexity)]
-    research_data: Vec<(
-        String,         // id
-        String,         // name
-        String,         // description
-        f32,            // time
-        String,         // cost_str
-        bool,           // can_afford
-        bool,           // is_completed
-        String,         // btn_text
-        Color,          // btn_color
-        Color,          // btn_border
-        Option<String>, // progress_info
-    )>,
+    research_data: Vec<ResearchDisplayData>,
 }
 
 impl Command for PopulateResearchDirectCommand {
@@ -559,16 +625,23 @@ impl Command for PopulateResearchDirectCommand {
 
         let children_to_despawn: Vec<Entity> =
             children.map(|c| c.iter().collect()).unwrap_or_default();
+        widgets::record_ui_rebuild(world, "research");
 
         for child in children_to_despawn {
             world.commands().entity(child).despawn();
         }
 
+        let in_progress: HashMap<String, f32> = world
+            .query::<&InProgress>()
+            .iter(world)
+            .map(|p| (p.research_id.clone(), p.timer.fraction()))
+            .collect();
+
         world
             .commands()
             .entity(container_entity)
             .with_children(|parent| {
-                for (
+                for ResearchDisplayData {
                     id,
                     name,
                     description,
@@ -576,26 +649,48 @@ impl Command for PopulateResearchDirectCommand {
                     cost_str,
                     can_afford,
                     is_completed,
+                    is_locked,
+                    missing_prerequisites,
                     btn_text,
                     btn_color,
                     btn_border,
                     progress_info,
-                ) in self.research_data
+                } in self.research_data
                 {
                     let card_entity = widgets::spawn_item_card(parent, ());
                     parent.commands().entity(card_entity).with_children(|card| {
-                        // Show title with progress info if available
-                        let display_name = if let Some(ref progress) = progress_info {
-                            format!("{} ({})", name, progress)
+                        if is_locked {
+                            spawn_locked_details(card, &name, &description, &missing_prerequisites);
                         } else {
-                            name.clone()
-                        };
-                        spawn_card_title(card, &display_name);
-                        spawn_description_text(card, &description);
+                            spawn_card_title(card, &name);
+                            spawn_description_text(card, &description);
+                        }
+
+                        if let Some(progress) = progress_info {
+                            card.spawn((
+                                Text::new(progress),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(if is_locked {
+                                    UiTheme::TEXT_DISABLED
+                                } else {
+                                    UiTheme::TEXT_INFO
+                                }),
+                            ));
+                        }
 
                         if !is_completed {
                             spawn_timer_text(card, time);
 
+                            if let Some(&fraction) = in_progress.get(&id) {
+                                let fill = spawn_progress_bar_ui(card, "Researching", fraction);
+                                card.commands().entity(fill).insert(ResearchProgressFill {
+                                    research_id: id.clone(),
+                                });
+                            }
+
                             if !cost_str.is_empty() {
                                 card.spawn((
                                     Text::new(cost_str),
@@ -625,15 +720,99 @@ impl Command for PopulateResearchDirectCommand {
     }
 }
 
+/// Greyed-out title and description of a locked research, plus what it still needs.
+fn spawn_locked_details(
+    card: &mut ChildSpawnerCommands,
+    name: &str,
+    description: &str,
+    missing_prerequisites: &[String],
+) {
+    card.spawn((
+        Text::new(name),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(UiTheme::TEXT_DISABLED),
+    ));
+    card.spawn((
+        Text::new(description),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(UiTheme::TEXT_DISABLED),
+    ));
+
+    if !missing_prerequisites.is_empty() {
+        card.spawn((
+            Text::new(format!("Requires: {}", missing_prerequisites.join(", "))),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(UiTheme::NOT_AFFORDABLE),
+        ));
+    }
+}
+
 // ============================================================================
-// Research Button Handler
+// Show Locked Toggle Handler
 // ============================================================================
 
+/// Flips `show_locked`; `update_research_ui` notices the changed list and rebuilds it.
 #[allow(clippy::type_complexity)]
+fn handle_show_locked_toggle(
+    interaction_query: Query<
+        (&Interaction, &Children),
+        (Changed<Interaction>, With<ShowLockedToggle>),
+    >,
+    mut ui_query: Query<&mut ResearchUiRoot>,
+    mut text_query: Query<&mut Text>,
+) {
+    for (interaction, children) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed
+            && let Ok(mut ui_root) = ui_query.single_mut()
+        {
+            ui_root.show_locked = !ui_root.show_locked;
+
+            let label = if ui_root.show_locked {
+                "Hide Locked"
+            } else {
+                "Show Locked"
+            };
+            for child in children.iter() {
+                if let Ok(mut text) = text_query.get_mut(child) {
+                    text.0 = label.to_string();
+                }
+            }
+        }
+    }
+}
+
+fn handle_clear_queue_button(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<ClearResearchQueueButton>)>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            commands.trigger(ClearResearchQueue);
+        }
+    }
+}
+
+// ============================================================================
+// Research Button Handler
+// ============================================================================
+
+/// Starts the clicked research, or queues it while another one is running. Clicking a
+/// queued research takes it out of the queue again.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 fn handle_research_button(
     mut commands: Commands,
     assets: Res<Assets<ResearchDefinition>>,
     wallet: Res<Wallet>,
+    mut queue: ResMut<ResearchQueue>,
     available_query: Query<&ResearchNode, With<Available>>,
     in_progress_query: Query<(), With<InProgress>>,
     research_map: Res<ResearchMap>,
@@ -641,13 +820,12 @@ fn handle_research_button(
 ) {
     for (interaction, btn) in interaction_query.iter() {
         if *interaction == Interaction::Pressed {
-            // Check if any research is already in progress
-            if !in_progress_query.is_empty() {
+            let id = &btn.id;
+            if queue.remove(id) {
+                info!("Removed '{}' from the research queue", id);
                 continue;
             }
 
-            let id = &btn.id;
-
             // Check if research is available
             let Some(&entity) = research_map.entities.get(id) else {
                 continue;
@@ -661,14 +839,16 @@ fn handle_research_button(
                 continue;
             };
 
-            // Check if can afford
-            let can_afford = def
-                .cost
-                .iter()
-                .all(|(res, amt)| wallet.resources.get(res).copied().unwrap_or(0) >= *amt);
+            // Check if can afford. Queued research is paid when it starts, see
+            // `start_queued_research`
+            if !wallet.can_afford(&def.cost) {
+                continue;
+            }
 
-            if can_afford {
+            if in_progress_query.is_empty() {
                 commands.trigger(StartResearchRequest(id.clone()));
+            } else if queue.push(id) {
+                info!("Queued research: {}", def.name);
             }
         }
     }
@@ -0,0 +1,201 @@
+//! Floating "+N" / "-N" texts that rise from an anchor whenever wallet amounts change.
+
+use {
+    bevy::prelude::*, states::GameState, std::collections::HashMap, wallet::Wallet,
+    widgets::UiTheme,
+};
+
+pub struct FloatingNumbersPlugin;
+
+impl Plugin for FloatingNumbersPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FloatingNumberSettings>()
+            .init_resource::<WalletSnapshot>()
+            .add_systems(
+                Update,
+                (
+                    spawn_floating_numbers.run_if(resource_changed::<Wallet>),
+                    animate_floating_numbers,
+                )
+                    .run_if(in_state(GameState::Running)),
+            )
+            .add_systems(OnExit(GameState::Running), clean_up_floating_numbers);
+    }
+}
+
+/// Where floating numbers appear and how they move.
+#[derive(Resource, Debug, Clone)]
+pub struct FloatingNumberSettings {
+    /// Distance of the first number from the left edge of the window, in px
+    pub anchor_left: f32,
+    /// Distance of the first number from the bottom edge of the window, in px
+    pub anchor_bottom: f32,
+    /// How far a number drifts upward over its lifetime, in px
+    pub rise: f32,
+    /// Lifetime of a number in seconds
+    pub duration_secs: f32,
+}
+
+impl Default for FloatingNumberSettings {
+    fn default() -> Self {
+        Self {
+            anchor_left: 220.0,
+            anchor_bottom: 10.0,
+            rise: 40.0,
+            duration_secs: 1.2,
+        }
+    }
+}
+
+/// Vertical spacing between numbers spawned in the same frame
+const LINE_HEIGHT: f32 = 20.0;
+
+/// Wallet amounts seen on the previous change; `None` until the first one.
+#[derive(Resource, Default)]
+struct WalletSnapshot(Option<HashMap<String, u32>>);
+
+/// A rising, fading delta text.
+#[derive(Component)]
+struct FloatingNumber {
+    timer: Timer,
+    start_bottom: f32,
+    color: Color,
+}
+
+/// Changes of discovered resources between `before` and `wallet`, sorted by id.
+fn wallet_deltas(before: &HashMap<String, u32>, wallet: &Wallet) -> Vec<(String, i64)> {
+    let mut deltas: Vec<(String, i64)> = wallet
+        .unlocked_resources
+        .iter()
+        .filter_map(|id| {
+            let delta = wallet.amount(id) as i64 - before.get(id).copied().unwrap_or(0) as i64;
+            (delta != 0).then(|| (id.clone(), delta))
+        })
+        .collect();
+    deltas.sort();
+    deltas
+}
+
+fn spawn_floating_numbers(
+    mut commands: Commands,
+    wallet: Res<Wallet>,
+    settings: Res<FloatingNumberSettings>,
+    mut snapshot: ResMut<WalletSnapshot>,
+) {
+    // The first change is the wallet being filled in (new game or save), not a gain
+    let Some(before) = snapshot.0.replace(wallet.resources.clone()) else {
+        return;
+    };
+
+    for (index, (id, delta)) in wallet_deltas(&before, &wallet).into_iter().enumerate() {
+        let color = if delta > 0 {
+            UiTheme::AFFORDABLE
+        } else {
+            UiTheme::NOT_AFFORDABLE
+        };
+        let start_bottom = settings.anchor_bottom + index as f32 * LINE_HEIGHT;
+
+        commands.spawn((
+            Text::new(format!("{:+} {}", delta, id)),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(settings.anchor_left),
+                bottom: Val::Px(start_bottom),
+                ..default()
+            },
+            TextColor(color),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            FloatingNumber {
+                timer: Timer::from_seconds(settings.duration_secs, TimerMode::Once),
+                start_bottom,
+                color,
+            },
+        ));
+    }
+}
+
+/// Moves numbers up while fading them out, then despawns them.
+fn animate_floating_numbers(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<FloatingNumberSettings>,
+    mut query: Query<(Entity, &mut FloatingNumber, &mut Node, &mut TextColor)>,
+) {
+    for (entity, mut number, mut node, mut color) in query.iter_mut() {
+        number.timer.tick(time.delta());
+        let fraction = number.timer.fraction();
+        node.bottom = Val::Px(number.start_bottom + settings.rise * fraction);
+        color.0 = number.color.with_alpha(1.0 - fraction);
+
+        if number.timer.is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn clean_up_floating_numbers(
+    mut commands: Commands,
+    mut snapshot: ResMut<WalletSnapshot>,
+    query: Query<Entity, With<FloatingNumber>>,
+) {
+    snapshot.0 = None;
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, bevy::state::app::StatesPlugin};
+
+    fn wallet(amounts: &[(&str, u32)]) -> Wallet {
+        let mut wallet = Wallet::default();
+        for (id, amount) in amounts {
+            wallet.resources.insert(id.to_string(), *amount);
+            wallet.unlocked_resources.insert(id.to_string());
+        }
+        wallet
+    }
+
+    #[test]
+    fn test_wallet_deltas_skip_unchanged_and_undiscovered_resources() {
+        let before = HashMap::from([("bones".to_string(), 10), ("wood".to_string(), 5)]);
+        let mut after = wallet(&[("bones", 4), ("wood", 5), ("iron", 3)]);
+        after.resources.insert("souls".to_string(), 7);
+
+        assert_eq!(
+            wallet_deltas(&before, &after),
+            vec![("bones".to_string(), -6), ("iron".to_string(), 3)]
+        );
+    }
+
+    #[test]
+    fn test_numbers_appear_after_the_wallet_is_filled_in() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::Running)
+            .insert_resource(wallet(&[("bones", 10)]))
+            .add_plugins(FloatingNumbersPlugin);
+        app.update();
+
+        let mut numbers = app
+            .world_mut()
+            .query_filtered::<&Text, With<FloatingNumber>>();
+        assert_eq!(numbers.iter(app.world()).count(), 0);
+
+        app.world_mut()
+            .resource_mut::<Wallet>()
+            .resources
+            .insert("bones".to_string(), 15);
+        app.update();
+
+        let texts: Vec<_> = numbers
+            .iter(app.world())
+            .map(|text| text.0.clone())
+            .collect();
+        assert_eq!(texts, vec!["+5 bones"]);
+    }
+}
@@ -1,14 +1,26 @@
-use {bevy::prelude::*, states::GameState, wallet::Wallet, widgets::UiTheme};
+mod floating_numbers;
+
+pub use floating_numbers::FloatingNumberSettings;
+
+use {
+    bevy::prelude::*,
+    states::GameState,
+    wallet::{ResourceIncome, Wallet},
+    widgets::UiTheme,
+};
 
 pub struct ResourcesUiPlugin;
 
 impl Plugin for ResourcesUiPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameState::Running), setup_resources_ui)
+        app.add_plugins(floating_numbers::FloatingNumbersPlugin)
+            .add_systems(OnEnter(GameState::Running), setup_resources_ui)
             .add_systems(
                 Update,
-                update_resources_ui
-                    .run_if(in_state(GameState::Running).and(resource_changed::<Wallet>)),
+                update_resources_ui.run_if(
+                    in_state(GameState::Running)
+                        .and(resource_changed::<Wallet>.or(resource_changed::<ResourceIncome>)),
+                ),
             )
             .add_systems(OnExit(GameState::Running), clean_up_resources_ui);
     }
@@ -17,38 +29,57 @@ impl Plugin for ResourcesUiPlugin {
 #[derive(Component)]
 pub struct ResourceText;
 
+/// Root of the resources panel in the bottom-left corner.
+#[derive(Component)]
+pub struct ResourcesPanel;
+
 fn setup_resources_ui(mut commands: Commands) {
-    commands.spawn((
-        Text::new("Resources: "),
-        Node {
-            position_type: PositionType::Absolute,
-            bottom: Val::Px(10.0),
-            left: Val::Px(10.0),
-            ..default()
-        },
-        TextColor(UiTheme::TEXT_PRIMARY),
-        TextFont {
-            font_size: 20.0,
-            ..default()
-        },
-        ResourceText,
-    ));
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(10.0),
+                left: Val::Px(10.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(UiTheme::PANEL_BG),
+            ResourcesPanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Resources"),
+                TextColor(UiTheme::TEXT_PRIMARY),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                ResourceText,
+            ));
+        });
 }
 
-fn update_resources_ui(wallet: Res<Wallet>, mut query: Query<&mut Text, With<ResourceText>>) {
+fn update_resources_ui(
+    wallet: Res<Wallet>,
+    income: Res<ResourceIncome>,
+    mut query: Query<&mut Text, With<ResourceText>>,
+) {
     for mut text in query.iter_mut() {
-        let mut resources_str = String::from("Resources: ");
-        let mut sorted_resources: Vec<_> = wallet.resources.iter().collect();
-        sorted_resources.sort_by_key(|(id, _)| *id);
-
-        for (id, value) in sorted_resources {
-            resources_str.push_str(&format!("{}: {}  ", id, value));
+        let mut resources_str = String::from("Resources");
+        // Discovered resources stay listed at 0 so players know they exist
+        for id in wallet.known_resource_ids() {
+            resources_str.push_str(&format!("\n{}: {}", id, wallet.amount(id)));
+            // Rates are only tracked for discovered resources
+            if wallet.is_discovered(id) {
+                resources_str.push_str(&format!("  {:+.1}/s", income.get(id)));
+            }
         }
         text.0 = resources_str;
     }
 }
 
-pub fn clean_up_resources_ui(mut commands: Commands, query: Query<Entity, With<ResourceText>>) {
+pub fn clean_up_resources_ui(mut commands: Commands, query: Query<Entity, With<ResourcesPanel>>) {
     for entity in query.iter() {
         commands.entity(entity).despawn();
     }
@@ -2,7 +2,10 @@ use {
     bevy::{picking::events::Click, prelude::*},
     states::GameState,
     totem::Totem,
-    widgets::{PanelWrapperRef, UiTheme, spawn_menu_panel, spawn_panel_header_with_close},
+    widgets::{
+        ClickFilter, ModalState, PanelWrapperRef, UiTheme, spawn_menu_panel,
+        spawn_panel_header_with_close,
+    },
 };
 
 pub struct TotemUiPlugin;
@@ -29,10 +32,15 @@ fn on_totem_click(
     mut commands: Commands,
     totem_query: Query<(), With<Totem>>,
     existing_ui: Query<(Entity, Option<&PanelWrapperRef>), With<TotemUiRoot>>,
+    click_filter: ClickFilter,
+    modal_state: Res<ModalState>,
 ) {
     let totem_entity = trigger.entity;
 
-    if totem_query.get(totem_entity).is_err() {
+    if totem_query.get(totem_entity).is_err()
+        || !click_filter.is_click(trigger.event())
+        || modal_state.is_open()
+    {
         return;
     }
 
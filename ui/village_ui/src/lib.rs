@@ -1,17 +1,26 @@
 use {
     bevy::{picking::prelude::*, prelude::*},
+    bonus_stats::{BonusStat, BonusStats},
     buildings_components::TheMaw,
-    hero_components::{AttackRange, AttackSpeed, Damage, Hero, MeleeArc, MeleeWeapon, Weapon},
-    hero_ui::{HeroContentContainer, HeroUiRoot, spawn_hero_content},
+    hero_components::{
+        AttackRange, AttackSpeed, Damage, Hero, HeroName, MeleeArc, MeleeWeapon, Weapon,
+    },
+    hero_ui::{HeroContentContainer, HeroUiRoot, SelectedHeroTab, spawn_hero_content},
+    notification_ui::{NotificationAction, NotificationActionPressed},
     research::ResearchState,
     shared_components::DisplayName,
     skill_components::EquippedSkills,
     skills_assets::{SkillDefinition, SkillMap},
     states::{GameState, VillageView},
+    unlocks_assets::{UnlockDefinition, describe_reward},
+    unlocks_events::UnlockAchieved,
+    unlocks_resources::{UnlockHistory, UnlockHistoryEntry},
     village_components::Village,
     widgets::{
-        ContentContainer, PanelWrapperRef, spawn_menu_button, spawn_menu_panel,
-        spawn_panel_header_with_close,
+        CachedContent, ClearContentContainer, ClickFilter, ContentContainer, Draggable, ModalState,
+        PanelWrapperRef, spawn_card_title, spawn_description_text, spawn_empty_state,
+        spawn_item_card, spawn_menu_button, spawn_menu_panel, spawn_panel_header_with_close,
+        spawn_scrollable_container,
     },
 };
 
@@ -20,10 +29,19 @@ pub struct VillageUiPlugin;
 impl Plugin for VillageUiPlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<VillageView>()
+            .init_resource::<LastVillageContent>()
             .add_observer(on_village_clicked)
+            .add_observer(record_unlock_history)
+            .add_observer(open_on_notification_action)
             .add_systems(
                 Update,
-                (handle_menu_button, handle_back_button, handle_close_button)
+                (
+                    handle_menu_button,
+                    handle_back_button,
+                    handle_close_button,
+                    mark_stale_content_panes,
+                    remember_village_content,
+                )
                     .run_if(in_state(GameState::Running)),
             )
             .add_systems(OnEnter(VillageView::Menu), show_menu_content)
@@ -36,7 +54,7 @@ impl Plugin for VillageUiPlugin {
 // ============================================================================
 
 /// Current content displayed in the village UI
-#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
 pub enum VillageContent {
     #[default]
     Menu,
@@ -45,6 +63,24 @@ pub enum VillageContent {
     Encyclopedia,
     Heroes,
     Blessings,
+    RecentUnlocks,
+    StatsSummary,
+}
+
+impl VillageContent {
+    /// The `VillageView` that shows this content
+    fn view(self) -> VillageView {
+        match self {
+            VillageContent::Menu => VillageView::Menu,
+            VillageContent::Crafting => VillageView::Crafting,
+            VillageContent::Research => VillageView::Research,
+            VillageContent::Encyclopedia => VillageView::Encyclopedia,
+            VillageContent::Heroes => VillageView::Heroes,
+            VillageContent::Blessings => VillageView::Blessings,
+            VillageContent::RecentUnlocks => VillageView::RecentUnlocks,
+            VillageContent::StatsSummary => VillageView::StatsSummary,
+        }
+    }
 }
 
 /// Root of the village UI
@@ -53,6 +89,10 @@ pub struct VillageUiRoot {
     pub content: VillageContent,
 }
 
+/// Content shown when the village panel was last open; reopening the panel returns to it
+#[derive(Resource, Default)]
+struct LastVillageContent(VillageContent);
+
 /// Close button marker
 #[derive(Component)]
 struct VillageCloseButton;
@@ -67,16 +107,69 @@ struct VillageMenuButton {
 #[derive(Component)]
 struct VillageBackButton;
 
+/// Holds the content built by one of the village's own views inside the
+/// `ContentContainer`. Switching away only hides it, so going back skips the rebuild.
+#[derive(Component)]
+struct VillageContentPane {
+    content: VillageContent,
+    /// Set when the data it shows changed; the pane is rebuilt the next time it is shown
+    stale: bool,
+}
+
+/// Scroll container for the recent unlocks list
+#[derive(Component)]
+struct RecentUnlocksList;
+
+/// One row of the recent unlocks list
+#[derive(Component)]
+struct RecentUnlockRow;
+
+/// How many history entries the recent unlocks panel shows
+const RECENT_UNLOCKS_SHOWN: usize = 10;
+
+/// Scroll container for the stats summary
+#[derive(Component)]
+struct StatsSummaryList;
+
+/// Card listing the combined bonuses of one stat category (or the hero weapons)
+#[derive(Component)]
+struct StatsSummaryCard;
+
+/// Label used for bonuses stored on a category itself rather than a sub-key
+const CATEGORY_WIDE_LABEL: &str = "all";
+
 // ============================================================================
 // Village Click Observer
 // ============================================================================
 
+/// Whether `content` can be opened. Crafting needs its research and blessings need The Maw.
+fn content_unlocked(content: VillageContent, crafting_researched: bool, maw_exists: bool) -> bool {
+    match content {
+        VillageContent::Crafting => crafting_researched,
+        VillageContent::Blessings => maw_exists,
+        _ => true,
+    }
+}
+
+fn crafting_researched(research_state: &ResearchState) -> bool {
+    research_state
+        .completion_counts
+        .get("simple_crafting")
+        .is_some()
+}
+
+#[allow(clippy::too_many_arguments)]
 fn on_village_clicked(
     trigger: On<Pointer<Click>>,
     mut commands: Commands,
     village_query: Query<(), With<Village>>,
     existing_ui: Query<(Entity, Option<&PanelWrapperRef>), With<VillageUiRoot>>,
     mut next_village_state: ResMut<NextState<VillageView>>,
+    click_filter: ClickFilter,
+    modal_state: Res<ModalState>,
+    last_content: Res<LastVillageContent>,
+    research_state: Res<ResearchState>,
+    maw_query: Query<(), With<TheMaw>>,
 ) {
     // Verify this is a village entity
     let clicked_entity = trigger.entity;
@@ -84,6 +177,11 @@ fn on_village_clicked(
         return;
     }
 
+    // Ignore the release at the end of a camera pan, and clicks behind a popup
+    if !click_filter.is_click(trigger.event()) || modal_state.is_open() {
+        return;
+    }
+
     // Toggle: if UI exists, close it; otherwise open
     if let Ok((ui_entity, wrapper_ref)) = existing_ui.single() {
         next_village_state.set(VillageView::Closed);
@@ -96,25 +194,59 @@ fn on_village_clicked(
         return;
     }
 
-    next_village_state.set(VillageView::Menu);
-    spawn_village_ui(&mut commands);
+    // Back to the last content, unless it got locked again (e.g. after loading a save)
+    let content = if content_unlocked(
+        last_content.0,
+        crafting_researched(&research_state),
+        !maw_query.is_empty(),
+    ) {
+        last_content.0
+    } else {
+        VillageContent::Menu
+    };
+    next_village_state.set(content.view());
+    spawn_village_ui(&mut commands, content);
+}
+
+/// Remembers what the open village panel shows, for the next time it is opened.
+fn remember_village_content(
+    ui_query: Query<&VillageUiRoot, Changed<VillageUiRoot>>,
+    mut last_content: ResMut<LastVillageContent>,
+) {
+    if let Some(ui_root) = ui_query.iter().next() {
+        last_content.0 = ui_root.content;
+    }
+}
+
+/// Makes sure the panel is open when a notification's action button switches to one of
+/// its views; `notification_ui` already set the `VillageView`.
+fn open_on_notification_action(
+    trigger: On<NotificationActionPressed>,
+    mut commands: Commands,
+    mut ui_query: Query<&mut VillageUiRoot>,
+) {
+    let content = match trigger.event().action {
+        NotificationAction::OpenCrafting => VillageContent::Crafting,
+        NotificationAction::OpenBlessings => VillageContent::Blessings,
+    };
+
+    match ui_query.single_mut() {
+        Ok(mut ui_root) => ui_root.content = content,
+        Err(_) => spawn_village_ui(&mut commands, content),
+    }
 }
 
 // ============================================================================
 // Spawn Village UI
 // ============================================================================
 
-fn spawn_village_ui(commands: &mut Commands) {
-    let panel = spawn_menu_panel(
-        commands,
-        VillageUiRoot {
-            content: VillageContent::Menu,
-        },
-    );
+fn spawn_village_ui(commands: &mut Commands, content: VillageContent) {
+    let panel = spawn_menu_panel(commands, VillageUiRoot { content });
 
     commands.entity(panel).with_children(|parent| {
         // Header with close button
-        spawn_panel_header_with_close(parent, "Village", VillageCloseButton);
+        let header = spawn_panel_header_with_close(parent, "Village", VillageCloseButton);
+        parent.commands().entity(header).insert(Draggable);
 
         // Content container
         parent.spawn((
@@ -131,14 +263,116 @@ fn spawn_village_ui(commands: &mut Commands) {
         ));
     });
 
-    // Populate with menu content
-    // commands.queue(SpawnMenuContentCommand); // Handled by OnEnter(VillageView::Menu)
+    queue_content_command(commands, content);
+}
+
+/// Builds the content the village crate owns. The menu and the other crates' views are
+/// built on entering their `VillageView` instead.
+fn queue_content_command(commands: &mut Commands, content: VillageContent) {
+    match content {
+        VillageContent::Heroes => commands.queue(SpawnHeroesContentCommand),
+        VillageContent::RecentUnlocks => commands.queue(SpawnRecentUnlocksContentCommand),
+        VillageContent::StatsSummary => commands.queue(SpawnStatsSummaryContentCommand),
+        _ => {}
+    }
 }
 
 fn show_menu_content(mut commands: Commands) {
     commands.queue(SpawnMenuContentCommand);
 }
 
+/// Shows the pane of `content` in the `ContentContainer` and hides the others.
+/// Returns a new, empty pane to build into when there is no up-to-date one yet.
+fn show_content_pane(world: &mut World, content: VillageContent) -> Option<Entity> {
+    let mut query = world.query_filtered::<Entity, With<ContentContainer>>();
+    let container = query.iter(world).next()?;
+    ClearContentContainer(container).apply(world);
+
+    let panes: Vec<Entity> = world
+        .get::<Children>(container)
+        .map(|c| c.iter().collect())
+        .unwrap_or_default();
+    for pane in panes {
+        let Some(state) = world.get::<VillageContentPane>(pane) else {
+            continue;
+        };
+        if state.content != content {
+            continue;
+        }
+        if state.stale {
+            world.entity_mut(pane).despawn();
+            break;
+        }
+        if let Some(mut node) = world.get_mut::<Node>(pane) {
+            node.display = Display::Flex;
+        }
+        world.entity_mut(pane).insert(Visibility::Inherited);
+        return None;
+    }
+
+    let pane = world
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                flex_grow: 1.0,
+                flex_basis: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            VillageContentPane {
+                content,
+                stale: false,
+            },
+            CachedContent,
+            ChildOf(container),
+        ))
+        .id();
+    Some(pane)
+}
+
+/// Marks hidden panes whose data changed, so they are rebuilt instead of shown as is.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn mark_stale_content_panes(
+    mut panes: Query<&mut VillageContentPane>,
+    research_state: Res<ResearchState>,
+    history: Res<UnlockHistory>,
+    bonus_stats: Option<Res<BonusStats>>,
+    maw_added: Query<(), Added<TheMaw>>,
+    heroes_changed: Query<
+        (),
+        (
+            With<Hero>,
+            Or<(Added<Hero>, Changed<HeroName>, Changed<EquippedSkills>)>,
+        ),
+    >,
+    weapons_changed: Query<
+        (),
+        (
+            With<Weapon>,
+            Or<(Changed<ChildOf>, Changed<Damage>, Changed<AttackRange>)>,
+        ),
+    >,
+    mut removed_heroes: RemovedComponents<Hero>,
+) {
+    let heroes = removed_heroes.read().count() > 0
+        || !weapons_changed.is_empty()
+        || bonus_stats.is_some_and(|stats| stats.is_changed());
+    for mut pane in panes.iter_mut() {
+        let stale = match pane.content {
+            VillageContent::Menu => research_state.is_changed() || !maw_added.is_empty(),
+            VillageContent::Heroes => heroes || !heroes_changed.is_empty(),
+            VillageContent::RecentUnlocks => history.is_changed(),
+            VillageContent::StatsSummary => heroes,
+            _ => false,
+        };
+        if stale && !pane.stale {
+            pane.stale = true;
+        }
+    }
+}
+
 // ============================================================================
 // Menu Content Command
 // ============================================================================
@@ -147,31 +381,18 @@ struct SpawnMenuContentCommand;
 
 impl Command for SpawnMenuContentCommand {
     fn apply(self, world: &mut World) {
-        let mut query =
-            world.query_filtered::<(Entity, Option<&Children>), With<ContentContainer>>();
-
-        let Some((container, children)) = query.iter(world).next() else {
+        let Some(pane) = show_content_pane(world, VillageContent::Menu) else {
             return;
         };
 
-        // Despawn existing children
-        let to_despawn: Vec<Entity> = children.map(|c| c.iter().collect()).unwrap_or_default();
-        for child in to_despawn {
-            world.commands().entity(child).despawn();
-        }
-
         // Check if The Maw exists to enable Blessings
         let maw_exists = world.query::<&TheMaw>().iter(world).next().is_some();
 
         // Check if simple crafting is researched
-        let research_state = world.resource::<ResearchState>();
-        let crafting_researched = research_state
-            .completion_counts
-            .get("simple_crafting")
-            .is_some();
+        let crafting_researched = crafting_researched(world.resource::<ResearchState>());
 
         // Spawn menu buttons
-        world.commands().entity(container).with_children(|parent| {
+        world.commands().entity(pane).with_children(|parent| {
             spawn_menu_button(
                 parent,
                 "🔬 Research",
@@ -220,6 +441,22 @@ impl Command for SpawnMenuContentCommand {
                 },
                 maw_exists,
             );
+            spawn_menu_button(
+                parent,
+                "📜 Recent Unlocks",
+                VillageMenuButton {
+                    target: VillageContent::RecentUnlocks,
+                },
+                true,
+            );
+            spawn_menu_button(
+                parent,
+                "📊 Stats Summary",
+                VillageMenuButton {
+                    target: VillageContent::StatsSummary,
+                },
+                true,
+            );
         });
     }
 }
@@ -232,19 +469,10 @@ struct SpawnHeroesContentCommand;
 
 impl Command for SpawnHeroesContentCommand {
     fn apply(self, world: &mut World) {
-        let mut query =
-            world.query_filtered::<(Entity, Option<&Children>), With<ContentContainer>>();
-
-        let Some((container, children)) = query.iter(world).next() else {
+        let Some(pane) = show_content_pane(world, VillageContent::Heroes) else {
             return;
         };
 
-        // Despawn existing children
-        let to_despawn: Vec<Entity> = children.map(|c| c.iter().collect()).unwrap_or_default();
-        for child in to_despawn {
-            world.commands().entity(child).despawn();
-        }
-
         // Query all heroes
         let mut hero_query = world.query_filtered::<Entity, With<Hero>>();
         let hero_entities: Vec<Entity> = hero_query.iter(world).collect();
@@ -253,92 +481,16 @@ impl Command for SpawnHeroesContentCommand {
         let mut heroes_data: Vec<(Entity, hero_ui::HeroDisplayData)> = Vec::new();
 
         for hero_entity in &hero_entities {
-            // Placeholder hero name (heroes don't have names yet)
-            let name = "Hero".to_string();
-
-            // Find weapon child
-            let mut children_query = world.query::<&Children>();
-            let weapon_children: Vec<Entity> = children_query
-                .get(world, *hero_entity)
-                .map(|c| c.iter().collect())
-                .unwrap_or_default();
+            let name = HeroName::display(world.get::<HeroName>(*hero_entity));
 
-            let mut weapon_data = None;
-            for child in weapon_children {
-                let mut weapon_query = world.query_filtered::<(
-                    Option<&DisplayName>,
-                    &Damage,
-                    &AttackRange,
-                    &AttackSpeed,
-                    Option<&MeleeArc>,
-                ), With<Weapon>>();
-
-                if let Ok((display_name, damage, range, speed, melee_arc)) =
-                    weapon_query.get(world, child)
-                {
-                    // Extract all values before doing melee check to end the immutable borrow
-                    let weapon_name = display_name
-                        .map(|d| d.0.clone())
-                        .unwrap_or_else(|| "Unknown Weapon".to_string());
-                    let speed_secs = speed.timer.duration().as_secs_f32();
-                    let damage_val = damage.0;
-                    let range_val = range.0;
-                    let arc_radians = melee_arc.map(|arc| arc.width);
-                    let raw_tags = world
-                        .get::<hero_components::WeaponTags>(child)
-                        .map(|t| t.0.clone())
-                        .unwrap_or_default();
-
-                    let effective_damage = if let Some(bonus_stats) =
-                        world.get_resource::<bonus_stats::BonusStats>()
-                    {
-                        bonus_stats::calculate_damage(damage_val, &raw_tags, &[], bonus_stats)
-                    } else {
-                        damage_val
-                    };
-
-                    // Check if it's a melee weapon for arc display
-                    let mut melee_check = world.query_filtered::<(), With<MeleeWeapon>>();
-                    let arc_degrees = if melee_check.get(world, child).is_ok() {
-                        arc_radians.map(|arc| arc.to_degrees())
-                    } else {
-                        None
-                    };
-
-                    weapon_data = Some(hero_ui::WeaponDisplayData {
-                        entity: child,
-                        name: weapon_name,
-                        damage: damage_val,
-                        effective_damage,
-                        range: range_val,
-                        speed_secs,
-                        melee_arc: arc_degrees,
-                    });
-                    break;
-                }
-            }
+            let weapon_data = hero_weapon_display_data(world, *hero_entity);
 
             let skill_map = world.resource::<SkillMap>();
             let skill_definitions = world.resource::<Assets<SkillDefinition>>();
 
             let equipped_skills = world
                 .get::<EquippedSkills>(*hero_entity)
-                .map(|s| {
-                    s.0.iter()
-                        .map(|id| {
-                            let name = skill_map
-                                .handles
-                                .get(id)
-                                .and_then(|h| skill_definitions.get(h))
-                                .map(|def| def.display_name.clone())
-                                .unwrap_or_else(|| id.clone());
-                            hero_ui::SkillDisplayData {
-                                id: id.clone(),
-                                name,
-                            }
-                        })
-                        .collect()
-                })
+                .map(|s| hero_ui::equipped_skill_slots(s, skill_map, skill_definitions))
                 .unwrap_or_default();
 
             heroes_data.push((
@@ -352,8 +504,13 @@ impl Command for SpawnHeroesContentCommand {
             ));
         }
 
+        let recruit_hint = hero_ui::recruit_hint(world.resource::<Assets<UnlockDefinition>>());
+        let selected_tab = world
+            .get_resource::<SelectedHeroTab>()
+            .map_or(0, |tab| tab.0);
+
         // Spawn back button and heroes content
-        world.commands().entity(container).with_children(|parent| {
+        world.commands().entity(pane).with_children(|parent| {
             // Back button
             spawn_menu_button(parent, "← Back", VillageBackButton, true);
 
@@ -368,15 +525,254 @@ impl Command for SpawnHeroesContentCommand {
                         width: Val::Percent(100.0),
                         ..default()
                     },
-                    HeroContentContainer::default(),
+                    HeroContentContainer,
                 ))
                 .with_children(|content| {
-                    spawn_hero_content(content, heroes_data, 0);
+                    spawn_hero_content(content, heroes_data, selected_tab, &recruit_hint);
                 });
         });
     }
 }
 
+/// Builds display data for the weapon a hero carries, including bonus-adjusted damage.
+fn hero_weapon_display_data(
+    world: &mut World,
+    hero_entity: Entity,
+) -> Option<hero_ui::WeaponDisplayData> {
+    let mut children_query = world.query::<&Children>();
+    let weapon_children: Vec<Entity> = children_query
+        .get(world, hero_entity)
+        .map(|c| c.iter().collect())
+        .unwrap_or_default();
+
+    for child in weapon_children {
+        let mut weapon_query = world.query_filtered::<(
+            Option<&DisplayName>,
+            &Damage,
+            &AttackRange,
+            &AttackSpeed,
+            Option<&MeleeArc>,
+        ), With<Weapon>>();
+
+        if let Ok((display_name, damage, range, speed, melee_arc)) = weapon_query.get(world, child)
+        {
+            // Extract all values before doing melee check to end the immutable borrow
+            let weapon_name = display_name
+                .map(|d| d.0.clone())
+                .unwrap_or_else(|| "Unknown Weapon".to_string());
+            let speed_secs = speed.timer.duration().as_secs_f32();
+            let damage_val = damage.0;
+            let range_val = range.0;
+            let arc_radians = melee_arc.map(|arc| arc.width);
+            let raw_tags = world
+                .get::<hero_components::WeaponTags>(child)
+                .map(|t| t.0.clone())
+                .unwrap_or_default();
+
+            let target_tags = world
+                .get_resource::<hero_ui::DamageTarget>()
+                .map(|target| target.tags().to_vec())
+                .unwrap_or_default();
+
+            let effective_damage = if let Some(bonus_stats) = world.get_resource::<BonusStats>() {
+                bonus_stats::calculate_damage(damage_val, &raw_tags, &target_tags, bonus_stats)
+            } else {
+                damage_val
+            };
+
+            // Check if it's a melee weapon for arc display
+            let mut melee_check = world.query_filtered::<(), With<MeleeWeapon>>();
+            let arc_degrees = if melee_check.get(world, child).is_ok() {
+                arc_radians.map(|arc| arc.to_degrees())
+            } else {
+                None
+            };
+
+            return Some(hero_ui::WeaponDisplayData {
+                entity: child,
+                name: weapon_name,
+                damage: damage_val,
+                effective_damage,
+                range: range_val,
+                speed_secs,
+                melee_arc: arc_degrees,
+                tags: raw_tags,
+                locked_reason: None,
+            });
+        }
+    }
+
+    None
+}
+
+// ============================================================================
+// Recent Unlocks Content Command
+// ============================================================================
+
+/// Records unlocks achieved during play. Unlocks re-fired while a save is being
+/// hydrated happen outside `GameState::Running` and are already in the history.
+fn record_unlock_history(
+    trigger: On<UnlockAchieved>,
+    state: Res<State<GameState>>,
+    mut history: ResMut<UnlockHistory>,
+) {
+    if *state.get() != GameState::Running {
+        return;
+    }
+
+    let event = trigger.event();
+    history.record(UnlockHistoryEntry {
+        unlock_id: event.unlock_id.clone(),
+        display_name: event.display_name.clone(),
+        reward_id: event.reward_id.clone(),
+    });
+}
+
+struct SpawnRecentUnlocksContentCommand;
+
+impl Command for SpawnRecentUnlocksContentCommand {
+    fn apply(self, world: &mut World) {
+        let Some(pane) = show_content_pane(world, VillageContent::RecentUnlocks) else {
+            return;
+        };
+
+        // Newest first: (title, reward effect)
+        let rows: Vec<(String, String)> = world
+            .resource::<UnlockHistory>()
+            .recent(RECENT_UNLOCKS_SHOWN)
+            .map(|entry| {
+                let title = entry
+                    .display_name
+                    .clone()
+                    .unwrap_or_else(|| entry.unlock_id.clone());
+                (title, describe_reward(&entry.reward_id))
+            })
+            .collect();
+
+        world.commands().entity(pane).with_children(|parent| {
+            spawn_menu_button(parent, "← Back", VillageBackButton, true);
+
+            spawn_scrollable_container(parent, RecentUnlocksList, |list| {
+                if rows.is_empty() {
+                    spawn_empty_state(list, "Nothing unlocked yet.");
+                    return;
+                }
+
+                for (title, effect) in rows {
+                    let card = spawn_item_card(list, RecentUnlockRow);
+                    list.commands().entity(card).with_children(|card| {
+                        spawn_card_title(card, &title);
+                        spawn_description_text(card, &effect);
+                    });
+                }
+            });
+        });
+    }
+}
+
+// ============================================================================
+// Stats Summary Content Command
+// ============================================================================
+
+/// Describes an aggregated bonus, e.g. "+5.0, +30%, x2.00". Stages without a bonus are omitted.
+fn describe_bonus(stat: &BonusStat) -> String {
+    let mut parts = Vec::new();
+    if stat.additive != 0.0 {
+        parts.push(format!("{:+.1}", stat.additive));
+    }
+    if stat.total_percent() != 0.0 {
+        parts.push(format!("{:+.0}%", stat.total_percent() * 100.0));
+    }
+    if stat.multiplicative != 0.0 {
+        parts.push(format!("x{:.2}", stat.multiplicative));
+    }
+    parts.join(", ")
+}
+
+/// Combined bonuses from all sources, grouped by category as `(category, lines)`.
+/// Categories and sub-keys are sorted; keys whose bonuses cancelled out are skipped.
+fn stats_summary(bonus_stats: &BonusStats) -> Vec<(String, Vec<String>)> {
+    let mut stats: Vec<(&str, &str, &BonusStat)> = bonus_stats
+        .iter()
+        .filter(|(_, _, stat)| !stat.is_empty())
+        .collect();
+    stats.sort_by_key(|(category, subkey, _)| (*category, *subkey));
+
+    stats.into_iter().fold(
+        Vec::<(String, Vec<String>)>::new(),
+        |mut groups, (category, subkey, stat)| {
+            let label = if subkey.is_empty() {
+                CATEGORY_WIDE_LABEL
+            } else {
+                subkey
+            };
+            let line = format!("{}: {}", label, describe_bonus(stat));
+            match groups.last_mut() {
+                Some((last, lines)) if last == category => lines.push(line),
+                _ => groups.push((category.to_string(), vec![line])),
+            }
+            groups
+        },
+    )
+}
+
+struct SpawnStatsSummaryContentCommand;
+
+impl Command for SpawnStatsSummaryContentCommand {
+    fn apply(self, world: &mut World) {
+        let Some(pane) = show_content_pane(world, VillageContent::StatsSummary) else {
+            return;
+        };
+
+        let categories = world
+            .get_resource::<BonusStats>()
+            .map(stats_summary)
+            .unwrap_or_default();
+
+        // Resulting values for each hero's weapon
+        let mut hero_query = world.query_filtered::<Entity, With<Hero>>();
+        let hero_entities: Vec<Entity> = hero_query.iter(world).collect();
+        let weapon_lines: Vec<String> = hero_entities
+            .into_iter()
+            .filter_map(|hero| hero_weapon_display_data(world, hero))
+            .map(|weapon| {
+                format!(
+                    "{}: {:.1} damage ({:.1} base), attacks every {:.2}s, range {:.1}",
+                    weapon.name,
+                    weapon.effective_damage,
+                    weapon.damage,
+                    weapon.speed_secs,
+                    weapon.range
+                )
+            })
+            .collect();
+
+        world.commands().entity(pane).with_children(|parent| {
+            spawn_menu_button(parent, "← Back", VillageBackButton, true);
+
+            spawn_scrollable_container(parent, StatsSummaryList, |list| {
+                if categories.is_empty() && weapon_lines.is_empty() {
+                    spawn_empty_state(list, "No bonuses active yet.");
+                    return;
+                }
+
+                let sections = categories.into_iter().chain(
+                    (!weapon_lines.is_empty()).then(|| ("Hero Weapons".to_string(), weapon_lines)),
+                );
+                for (title, lines) in sections {
+                    let card = spawn_item_card(list, StatsSummaryCard);
+                    list.commands().entity(card).with_children(|card| {
+                        spawn_card_title(card, &title);
+                        for line in &lines {
+                            spawn_description_text(card, line);
+                        }
+                    });
+                }
+            });
+        });
+    }
+}
+
 // ============================================================================
 // Button Handlers
 // ============================================================================
@@ -396,22 +792,8 @@ fn handle_menu_button(
             && let Ok(mut ui_root) = ui_query.single_mut()
         {
             ui_root.content = btn.target;
-
-            match btn.target {
-                VillageContent::Crafting => next_village_state.set(VillageView::Crafting),
-                VillageContent::Research => next_village_state.set(VillageView::Research),
-                VillageContent::Encyclopedia => next_village_state.set(VillageView::Encyclopedia),
-                VillageContent::Heroes => next_village_state.set(VillageView::Heroes),
-                VillageContent::Blessings => next_village_state.set(VillageView::Blessings),
-                VillageContent::Menu => next_village_state.set(VillageView::Menu),
-            }
-
-            match btn.target {
-                VillageContent::Heroes => {
-                    commands.queue(SpawnHeroesContentCommand);
-                }
-                _ => {} // Other views handle their own content via state monitoring
-            }
+            next_village_state.set(btn.target.view());
+            queue_content_command(&mut commands, btn.target);
         }
     }
 }
@@ -466,3 +848,292 @@ pub fn clean_up_village_ui(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        bevy::{
+            camera::NormalizedRenderTarget,
+            picking::{
+                backend::HitData,
+                pointer::{Location, PointerButton, PointerId},
+            },
+            state::app::StatesPlugin,
+        },
+        std::time::Duration,
+        widgets::{ClickSettings, Modal, ModalPlugin, PointerPressOrigins, any_modal_open},
+    };
+
+    fn click(entity: Entity) -> Pointer<Click> {
+        let location = Location {
+            target: NormalizedRenderTarget::None {
+                width: 800,
+                height: 600,
+            },
+            position: Vec2::ZERO,
+        };
+        Pointer::new(
+            PointerId::Mouse,
+            location,
+            Click {
+                button: PointerButton::Primary,
+                hit: HitData::new(Entity::PLACEHOLDER, 0.0, None, None),
+                duration: Duration::from_millis(100),
+            },
+            entity,
+        )
+    }
+
+    #[test]
+    fn test_village_click_suppressed_while_modal_open() {
+        let mut app = App::new();
+        app.add_plugins((StatesPlugin, ModalPlugin))
+            .init_state::<VillageView>()
+            .init_resource::<ClickSettings>()
+            .init_resource::<PointerPressOrigins>()
+            .init_resource::<LastVillageContent>()
+            .init_resource::<ResearchState>()
+            .add_observer(on_village_clicked);
+
+        let village = app.world_mut().spawn(Village).id();
+        let popup = app.world_mut().spawn(Modal).id();
+        app.update();
+
+        let modal_open = app.world_mut().run_system_cached(any_modal_open).unwrap();
+        assert!(modal_open);
+
+        app.world_mut().trigger(click(village));
+        app.update();
+        let open_panels = app
+            .world_mut()
+            .query::<&VillageUiRoot>()
+            .iter(app.world())
+            .count();
+        assert_eq!(open_panels, 0);
+
+        // Once the popup is gone the same click opens the village menu
+        app.world_mut().entity_mut(popup).despawn();
+        app.world_mut().trigger(click(village));
+        app.update();
+        let open_panels = app
+            .world_mut()
+            .query::<&VillageUiRoot>()
+            .iter(app.world())
+            .count();
+        assert_eq!(open_panels, 1);
+        assert!(!app.world_mut().run_system_cached(any_modal_open).unwrap());
+    }
+
+    #[test]
+    fn test_recent_unlocks_lists_newest_entries_first() {
+        let mut world = World::new();
+        let mut history = UnlockHistory::default();
+        for i in 0..(RECENT_UNLOCKS_SHOWN + 2) {
+            history.record(UnlockHistoryEntry {
+                unlock_id: format!("unlock_{}", i),
+                display_name: None,
+                reward_id: format!("recipe:item_{}", i),
+            });
+        }
+        world.insert_resource(history);
+        world.spawn((Node::default(), ContentContainer));
+
+        SpawnRecentUnlocksContentCommand.apply(&mut world);
+        world.flush();
+
+        // Each row shows the title followed by the reward effect
+        let mut rows = world.query_filtered::<&Children, With<RecentUnlockRow>>();
+        let listed: Vec<Vec<&str>> = rows
+            .iter(&world)
+            .map(|children| {
+                children
+                    .iter()
+                    .filter_map(|child| world.get::<Text>(child))
+                    .map(|text| text.0.as_str())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(listed.len(), RECENT_UNLOCKS_SHOWN);
+        assert_eq!(listed[0], vec!["unlock_11", "Recipe available: item_11"]);
+        assert_eq!(listed[1][0], "unlock_10");
+        assert_eq!(listed[RECENT_UNLOCKS_SHOWN - 1][0], "unlock_2");
+    }
+
+    #[test]
+    fn test_stats_summary_aggregates_bonuses_across_sources() {
+        let mut world = World::new();
+        let mut bonus_stats = BonusStats::default();
+        // Two blessings both boosting global damage, plus a goblin-specific bonus
+        for value in [0.1, 0.2] {
+            bonus_stats.add(
+                "damage:global",
+                bonus_stats::StatBonus {
+                    value,
+                    mode: bonus_stats::StatMode::Percent,
+                },
+            );
+        }
+        bonus_stats.add(
+            "damage:race:goblins",
+            bonus_stats::StatBonus {
+                value: 5.0,
+                mode: bonus_stats::StatMode::Additive,
+            },
+        );
+        world.insert_resource(bonus_stats);
+
+        let hero = world.spawn(Hero).id();
+        world.spawn((
+            Weapon,
+            DisplayName("Bone Sword".to_string()),
+            Damage(10.0),
+            AttackRange(1.5),
+            AttackSpeed {
+                timer: Timer::from_seconds(1.0, TimerMode::Once),
+            },
+            hero_components::WeaponTags(vec!["damage:global".to_string()]),
+            ChildOf(hero),
+        ));
+        world.spawn((Node::default(), ContentContainer));
+
+        SpawnStatsSummaryContentCommand.apply(&mut world);
+        world.flush();
+
+        let mut cards = world.query_filtered::<&Children, With<StatsSummaryCard>>();
+        let sections: Vec<Vec<&str>> = cards
+            .iter(&world)
+            .map(|children| {
+                children
+                    .iter()
+                    .filter_map(|child| world.get::<Text>(child))
+                    .map(|text| text.0.as_str())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            sections,
+            vec![
+                vec!["damage", "global: +30%", "race:goblins: +5.0"],
+                vec![
+                    "Hero Weapons",
+                    "Bone Sword: 13.0 damage (10.0 base), attacks every 1.00s, range 1.5"
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_describe_bonus_includes_diminishing_percent() {
+        let mut stat = BonusStat::default();
+        for (value, mode) in [
+            (0.25, bonus_stats::StatMode::Percent),
+            (0.5, bonus_stats::StatMode::DiminishingPercent),
+        ] {
+            stat.add(&bonus_stats::StatBonus { value, mode });
+        }
+        assert_eq!(describe_bonus(&stat), "+75%");
+    }
+
+    #[test]
+    fn test_notification_action_opens_closed_village_panel() {
+        let mut world = World::new();
+        world.add_observer(open_on_notification_action);
+
+        for _ in 0..2 {
+            world.trigger(NotificationActionPressed {
+                action: NotificationAction::OpenBlessings,
+            });
+            world.flush();
+        }
+
+        // The second press reuses the panel opened by the first
+        let contents: Vec<VillageContent> = world
+            .query::<&VillageUiRoot>()
+            .iter(&world)
+            .map(|root| root.content)
+            .collect();
+        assert_eq!(contents, vec![VillageContent::Blessings]);
+        let containers = world
+            .query_filtered::<(), With<ContentContainer>>()
+            .iter(&world)
+            .count();
+        assert_eq!(containers, 1);
+    }
+
+    #[test]
+    fn test_switching_back_reuses_cached_pane_until_stale() {
+        let mut world = World::new();
+        world.init_resource::<UnlockHistory>();
+        world.spawn((Node::default(), ContentContainer));
+        let rows = |world: &mut World| -> Vec<Entity> {
+            world
+                .query_filtered::<Entity, With<RecentUnlocksList>>()
+                .iter(world)
+                .collect()
+        };
+
+        SpawnRecentUnlocksContentCommand.apply(&mut world);
+        world.flush();
+        let first = rows(&mut world);
+        let pane = world.get::<ChildOf>(first[0]).unwrap().parent();
+
+        SpawnStatsSummaryContentCommand.apply(&mut world);
+        world.flush();
+        assert_eq!(world.get::<Node>(pane).unwrap().display, Display::None);
+
+        // Shown again as it was, without a rebuild
+        SpawnRecentUnlocksContentCommand.apply(&mut world);
+        world.flush();
+        assert_eq!(rows(&mut world), first);
+        assert_eq!(world.get::<Node>(pane).unwrap().display, Display::Flex);
+
+        world.get_mut::<VillageContentPane>(pane).unwrap().stale = true;
+        SpawnStatsSummaryContentCommand.apply(&mut world);
+        SpawnRecentUnlocksContentCommand.apply(&mut world);
+        world.flush();
+        let rebuilt = rows(&mut world);
+        assert_eq!(rebuilt.len(), 1);
+        assert_ne!(rebuilt, first);
+        assert!(world.get_entity(pane).is_err());
+    }
+
+    #[test]
+    fn test_reopening_village_returns_to_last_unlocked_content() {
+        let mut app = App::new();
+        app.add_plugins((StatesPlugin, ModalPlugin))
+            .init_state::<VillageView>()
+            .init_resource::<ClickSettings>()
+            .init_resource::<PointerPressOrigins>()
+            .init_resource::<ResearchState>()
+            .insert_resource(LastVillageContent(VillageContent::Crafting))
+            .add_observer(on_village_clicked);
+        let village = app.world_mut().spawn(Village).id();
+        app.update();
+
+        let open = |app: &mut App| {
+            app.world_mut().trigger(click(village));
+            app.update();
+            let content = app
+                .world_mut()
+                .query::<&VillageUiRoot>()
+                .single(app.world())
+                .unwrap()
+                .content;
+            let view = app.world().resource::<State<VillageView>>().get().clone();
+            // Close again for the next attempt
+            app.world_mut().trigger(click(village));
+            app.update();
+            (content, view)
+        };
+
+        // Crafting is not researched in this world, so the menu opens instead
+        assert_eq!(open(&mut app), (VillageContent::Menu, VillageView::Menu));
+
+        app.insert_resource(LastVillageContent(VillageContent::Encyclopedia));
+        assert_eq!(
+            open(&mut app),
+            (VillageContent::Encyclopedia, VillageView::Encyclopedia)
+        );
+    }
+}
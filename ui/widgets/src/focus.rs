@@ -0,0 +1,202 @@
+//! Arrow key and Enter navigation of menu buttons.
+
+use {
+    crate::{UiTheme, any_modal_open},
+    bevy::prelude::*,
+};
+
+/// A button reachable with the arrow keys. Added to every enabled `spawn_menu_button`.
+#[derive(Component, Default)]
+pub struct Focusable;
+
+/// Spawn order of a `Focusable`, assigned the first frame it is seen
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct FocusOrder(u64);
+
+/// Border the focused button had before the focus outline replaced it
+#[derive(Component)]
+struct FocusRestoreBorder(BorderColor);
+
+/// The `Focusable` currently selected with the keyboard, if any.
+#[derive(Resource, Default, Debug)]
+pub struct FocusedButton(pub Option<Entity>);
+
+/// Arrow key and Enter navigation of `Focusable` buttons. Added by `WidgetsPlugin`.
+/// Paused while a modal is open, so the keys don't reach the buttons behind it.
+pub struct KeyboardFocusPlugin;
+
+impl Plugin for KeyboardFocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FocusedButton>()
+            .add_systems(Update, keyboard_focus_system.run_if(not(any_modal_open)));
+    }
+}
+
+/// Arrow keys move the focus between visible `Focusable`s in spawn order, wrapping around.
+/// Enter presses the focused button by setting its `Interaction` to `Pressed` for one frame.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn keyboard_focus_system(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut focused: ResMut<FocusedButton>,
+    mut next_order: Local<u64>,
+    mut pressed: Local<Option<Entity>>,
+    unordered: Query<Entity, (With<Focusable>, Without<FocusOrder>)>,
+    focusables: Query<(Entity, &FocusOrder, Option<&InheritedVisibility>), With<Focusable>>,
+    mut buttons: Query<(
+        &mut Interaction,
+        Option<&mut BorderColor>,
+        Option<&FocusRestoreBorder>,
+    )>,
+) {
+    for entity in unordered.iter() {
+        commands.entity(entity).insert(FocusOrder(*next_order));
+        *next_order += 1;
+    }
+
+    // Release the press synthesized last frame
+    if let Some(entity) = pressed.take()
+        && let Ok((mut interaction, ..)) = buttons.get_mut(entity)
+        && *interaction == Interaction::Pressed
+    {
+        *interaction = Interaction::None;
+    }
+
+    let mut order: Vec<(FocusOrder, Entity)> = focusables
+        .iter()
+        .filter(|(_, _, visibility)| visibility.is_none_or(|v| v.get()))
+        .map(|(entity, order, _)| (*order, entity))
+        .collect();
+    order.sort();
+
+    let current = focused
+        .0
+        .and_then(|entity| order.iter().position(|(_, e)| *e == entity));
+    let step: isize = if keys.any_just_pressed([KeyCode::ArrowDown, KeyCode::ArrowRight]) {
+        1
+    } else if keys.any_just_pressed([KeyCode::ArrowUp, KeyCode::ArrowLeft]) {
+        -1
+    } else {
+        0
+    };
+
+    let target = if order.is_empty() {
+        None
+    } else if step == 0 {
+        current.map(|i| order[i].1)
+    } else {
+        let len = order.len() as isize;
+        let next = match current {
+            Some(i) => (i as isize + step).rem_euclid(len),
+            None if step > 0 => 0,
+            None => len - 1,
+        };
+        Some(order[next as usize].1)
+    };
+
+    if target != focused.0 {
+        if let Some(previous) = focused.0
+            && let Ok((_, Some(mut border), Some(restore))) = buttons.get_mut(previous)
+        {
+            *border = restore.0;
+            commands.entity(previous).remove::<FocusRestoreBorder>();
+        }
+        if let Some(entity) = target
+            && let Ok((_, Some(mut border), _)) = buttons.get_mut(entity)
+        {
+            commands.entity(entity).insert(FocusRestoreBorder(*border));
+            *border = BorderColor::all(UiTheme::BORDER_FOCUS);
+        }
+        focused.0 = target;
+    }
+
+    if let Some(entity) = focused.0
+        && keys.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter])
+        && let Ok((mut interaction, ..)) = buttons.get_mut(entity)
+    {
+        *interaction = Interaction::Pressed;
+        *pressed = Some(entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{Modal, ModalPlugin},
+    };
+
+    #[test]
+    fn test_arrow_keys_move_focus_and_enter_presses() {
+        let mut world = World::new();
+        world.init_resource::<FocusedButton>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        let system = world.register_system(keyboard_focus_system);
+        let [first, second] = [(); 2].map(|_| {
+            world
+                .spawn((
+                    Interaction::None,
+                    BorderColor::all(UiTheme::TAB_BORDER),
+                    Focusable,
+                ))
+                .id()
+        });
+        let tap = |world: &mut World, key: KeyCode| {
+            let mut keys = world.resource_mut::<ButtonInput<KeyCode>>();
+            keys.reset_all();
+            keys.press(key);
+            world.run_system(system).unwrap();
+        };
+        let border = |world: &World, entity: Entity| world.get::<BorderColor>(entity).unwrap().top;
+
+        // First run only assigns the spawn order
+        world.run_system(system).unwrap();
+        tap(&mut world, KeyCode::ArrowDown);
+        assert_eq!(world.resource::<FocusedButton>().0, Some(first));
+        assert_eq!(border(&world, first), UiTheme::BORDER_FOCUS);
+
+        tap(&mut world, KeyCode::ArrowDown);
+        assert_eq!(world.resource::<FocusedButton>().0, Some(second));
+        assert_eq!(border(&world, first), UiTheme::TAB_BORDER);
+        assert_eq!(border(&world, second), UiTheme::BORDER_FOCUS);
+
+        // Wraps around
+        tap(&mut world, KeyCode::ArrowDown);
+        assert_eq!(world.resource::<FocusedButton>().0, Some(first));
+        tap(&mut world, KeyCode::ArrowUp);
+        assert_eq!(world.resource::<FocusedButton>().0, Some(second));
+
+        tap(&mut world, KeyCode::Enter);
+        assert_eq!(
+            *world.get::<Interaction>(second).unwrap(),
+            Interaction::Pressed
+        );
+        world.resource_mut::<ButtonInput<KeyCode>>().reset_all();
+        world.run_system(system).unwrap();
+        assert_eq!(
+            *world.get::<Interaction>(second).unwrap(),
+            Interaction::None
+        );
+    }
+
+    #[test]
+    fn test_keyboard_focus_waits_for_modal_to_close() {
+        let mut app = App::new();
+        app.add_plugins((ModalPlugin, KeyboardFocusPlugin))
+            .init_resource::<ButtonInput<KeyCode>>();
+        let button = app.world_mut().spawn((Interaction::None, Focusable)).id();
+        // First run only assigns the spawn order
+        app.update();
+        let modal = app.world_mut().spawn(Modal).id();
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ArrowDown);
+        app.update();
+        assert_eq!(app.world().resource::<FocusedButton>().0, None);
+
+        app.world_mut().entity_mut(modal).despawn();
+        app.update();
+        assert_eq!(app.world().resource::<FocusedButton>().0, Some(button));
+    }
+}
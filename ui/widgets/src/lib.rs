@@ -1,7 +1,39 @@
-use bevy::{
-    input::mouse::{MouseScrollUnit, MouseWheel},
-    picking::hover::HoverMap,
-    prelude::*,
+mod focus;
+mod modal;
+mod number_format;
+mod progress_bar;
+mod scrollbar;
+mod tooltip;
+
+pub use {
+    focus::{Focusable, FocusedButton, KeyboardFocusPlugin},
+    modal::{
+        ConfirmDialog, Modal, ModalPlugin, ModalState, any_modal_open, despawn_all,
+        spawn_confirm_dialog,
+    },
+    number_format::{NumberFormat, format_number, format_number_f32, format_number_with},
+    progress_bar::{
+        ProgressBarFill, progress_bar_fill_width, set_progress_bar_fraction, spawn_progress_bar,
+        spawn_progress_bar_ui, update_progress_bar_fill,
+    },
+    scrollbar::{
+        ScrollbarThumb, ScrollbarTrack, scrollbar_thumb, spawn_scrollable_container_with_scrollbar,
+        thumb_drag_to_scroll,
+    },
+    tooltip::{Tooltip, TooltipLine, TooltipLines, TooltipPopup},
+};
+
+use {
+    bevy::{
+        ecs::system::SystemParam,
+        input::mouse::{MouseScrollUnit, MouseWheel},
+        picking::{hover::HoverMap, pointer::PointerId},
+        platform::collections::HashMap,
+        prelude::*,
+        ui::UiSystems,
+        window::PrimaryWindow,
+    },
+    std::{marker::PhantomData, time::Duration},
 };
 
 /// Line height for scroll calculations (pixels per line)
@@ -11,8 +43,25 @@ pub struct WidgetsPlugin;
 
 impl Plugin for WidgetsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (button_interaction_system, send_scroll_events))
-            .add_observer(on_scroll_handler);
+        app.add_systems(PreUpdate, suppress_disabled_presses.after(UiSystems::Focus))
+            .add_systems(PostUpdate, clamp_restored_scroll.after(UiSystems::Layout))
+            .add_systems(
+                Update,
+                (
+                    button_interaction_system,
+                    send_scroll_events,
+                    tooltip::tooltip_system,
+                    scrollbar::update_scrollbars,
+                ),
+            )
+            .add_plugins((ModalPlugin, KeyboardFocusPlugin))
+            .init_resource::<ClickSettings>()
+            .init_resource::<PointerPressOrigins>()
+            .init_resource::<UiRebuildStats>()
+            .add_observer(on_scroll_handler)
+            .add_observer(scrollbar::on_scrollbar_thumb_drag)
+            .add_observer(on_panel_header_drag)
+            .add_observer(record_pointer_press);
     }
 }
 
@@ -21,14 +70,17 @@ impl Plugin for WidgetsPlugin {
 // ============================================================================
 
 /// UI scrolling event that propagates through the hierarchy.
+///
+/// Besides driving scrollable containers, widgets can observe it to react to the
+/// mouse wheel while hovered (e.g. steppers).
 #[derive(EntityEvent, Debug, Clone)]
 #[entity_event(propagate, auto_propagate)]
-struct Scroll {
+pub struct Scroll {
     /// The target entity for this scroll event.
     #[event_target]
-    entity: Entity,
-    /// Scroll delta in logical coordinates.
-    delta: Vec2,
+    pub entity: Entity,
+    /// Scroll delta in logical coordinates. Wheel-up yields a negative `y`.
+    pub delta: Vec2,
 }
 
 /// Converts mouse wheel input into scroll events on hovered UI entities.
@@ -69,7 +121,7 @@ fn on_scroll_handler(
         return;
     };
 
-    let max_offset = (computed.content_size() - computed.size()) * computed.inverse_scale_factor();
+    let max_offset = max_scroll_offset(computed);
 
     let delta = scroll.delta;
 
@@ -100,6 +152,11 @@ fn on_scroll_handler(
     }
 }
 
+/// Largest `ScrollPosition` of a container, in logical pixels. Negative when the content fits.
+fn max_scroll_offset(computed: &ComputedNode) -> Vec2 {
+    (computed.content_size() - computed.size()) * computed.inverse_scale_factor()
+}
+
 // ============================================================================
 // Theme / Colors
 // ============================================================================
@@ -113,6 +170,8 @@ impl UiTheme {
     pub const CARD_BG: Color = Color::srgba(0.15, 0.15, 0.15, 1.0);
     pub const CARD_BORDER: Color = Color::srgba(0.3, 0.3, 0.3, 1.0);
     pub const POPUP_BORDER: Color = Color::srgba(0.3, 0.3, 0.5, 1.0);
+    /// Full-screen dim behind modal dialogs
+    pub const OVERLAY_DIM: Color = Color::srgba(0.0, 0.0, 0.0, 0.5);
 
     pub const TEXT_PRIMARY: Color = Color::WHITE;
     pub const TEXT_SECONDARY: Color = Color::srgba(0.8, 0.8, 0.8, 1.0);
@@ -132,6 +191,9 @@ impl UiTheme {
     pub const BORDER_SUCCESS: Color = Color::srgba(0.0, 1.0, 0.0, 1.0);
     pub const BORDER_ERROR: Color = Color::srgba(1.0, 0.0, 0.0, 1.0);
     pub const BORDER_DISABLED: Color = Color::srgba(0.5, 0.5, 0.5, 1.0);
+    pub const BORDER_FOCUS: Color = Color::srgba(1.0, 0.85, 0.3, 1.0);
+    /// Border of buttons showing a pending state, e.g. running or queued research
+    pub const BORDER_INFO: Color = Color::srgba(0.4, 0.4, 1.0, 1.0);
 
     pub const TAB_ACTIVE_BG: Color = Color::srgba(0.3, 0.3, 0.4, 1.0);
     pub const TAB_INACTIVE_BG: Color = Color::srgba(0.15, 0.15, 0.2, 1.0);
@@ -155,6 +217,77 @@ pub struct AnimatedButton {
     pub normal_color: Color,
     pub hover_color: Color,
     pub pressed_color: Color,
+    /// Disabled buttons keep their normal color with a `BORDER_DISABLED` border and
+    /// never report `Interaction::Pressed` to other systems
+    pub disabled: bool,
+}
+
+/// Dimensions and background colors for the button widgets.
+///
+/// `Default` matches `spawn_action_button`; `icon()` and `menu()` match the
+/// other button widgets so their `_with_style` variants can start from the same look.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ButtonStyle {
+    pub width: Val,
+    pub height: Val,
+    pub font_size: f32,
+    pub normal: Color,
+    pub hover: Color,
+    pub pressed: Color,
+}
+
+impl Default for ButtonStyle {
+    fn default() -> Self {
+        Self {
+            width: Val::Px(100.0),
+            height: Val::Px(30.0),
+            font_size: 16.0,
+            normal: UiTheme::BUTTON_NORMAL,
+            hover: UiTheme::BUTTON_HOVER,
+            pressed: UiTheme::BUTTON_PRESSED,
+        }
+    }
+}
+
+impl ButtonStyle {
+    /// Style used by `spawn_icon_button` (40x40).
+    pub fn icon() -> Self {
+        Self {
+            width: Val::Px(40.0),
+            height: Val::Px(40.0),
+            font_size: 22.0,
+            ..default()
+        }
+    }
+
+    /// Style used by `spawn_menu_button` (full width, 50px tall).
+    pub fn menu() -> Self {
+        Self {
+            width: Val::Percent(100.0),
+            height: Val::Px(50.0),
+            font_size: 20.0,
+            ..default()
+        }
+    }
+
+    fn animated(&self) -> AnimatedButton {
+        AnimatedButton {
+            normal_color: self.normal,
+            hover_color: self.hover,
+            pressed_color: self.pressed,
+            disabled: false,
+        }
+    }
+}
+
+/// Turns presses on disabled buttons back into `Interaction::None` right after the
+/// UI focus update, so click handlers in `Update` never see them.
+fn suppress_disabled_presses(mut query: Query<(&mut Interaction, &AnimatedButton)>) {
+    for (mut interaction, anim) in query.iter_mut() {
+        if anim.disabled {
+            interaction.set_if_neq(Interaction::None);
+        }
+    }
 }
 
 #[allow(clippy::type_complexity)]
@@ -164,19 +297,29 @@ fn button_interaction_system(
             &Interaction,
             &mut BackgroundColor,
             &AnimatedButton,
+            Option<&mut BorderColor>,
             Option<&mut Transform>,
         ),
-        (Changed<Interaction>, With<Button>),
+        (
+            Or<(Changed<Interaction>, Changed<AnimatedButton>)>,
+            With<Button>,
+        ),
     >,
 ) {
-    for (interaction, mut bg_color, anim, mut transform) in query.iter_mut() {
+    for (interaction, mut bg_color, anim, border, mut transform) in query.iter_mut() {
         let (color, scale) = match *interaction {
+            _ if anim.disabled => (anim.normal_color, 1.0),
             Interaction::Pressed => (anim.pressed_color, 0.98),
             Interaction::Hovered => (anim.hover_color, 1.05),
             Interaction::None => (anim.normal_color, 1.0),
         };
 
         *bg_color = BackgroundColor(color);
+        if anim.disabled
+            && let Some(mut border) = border
+        {
+            *border = BorderColor::all(UiTheme::BORDER_DISABLED);
+        }
 
         if let Some(tf) = transform.as_mut() {
             tf.scale = Vec3::splat(scale);
@@ -285,7 +428,7 @@ pub fn spawn_panel_header_with_close<M: Component>(
     parent: &mut ChildSpawnerCommands,
     title: &str,
     close_marker: M,
-) {
+) -> Entity {
     parent
         .spawn(Node {
             display: Display::Flex,
@@ -324,7 +467,66 @@ pub fn spawn_panel_header_with_close<M: Component>(
                 .with_children(|right| {
                     spawn_close_button(right, close_marker);
                 });
-        });
+        })
+        .id()
+}
+
+// ============================================================================
+// Draggable Panels
+// ============================================================================
+
+/// Put on a panel header to let the player drag its panel (the header's parent) around.
+/// A panel centered by `spawn_menu_panel` switches to absolute positioning on the first
+/// drag. The header is kept on screen.
+#[derive(Component)]
+pub struct Draggable;
+
+/// Moves the panel of a dragged `Draggable` header.
+fn on_panel_header_drag(
+    drag: On<Pointer<Drag>>,
+    headers: Query<(&ChildOf, &ComputedNode, &UiGlobalTransform), With<Draggable>>,
+    mut panels: Query<(&mut Node, &ComputedNode, &UiGlobalTransform)>,
+    window: Query<&Window, With<PrimaryWindow>>,
+) {
+    let Ok((child_of, header_computed, header_transform)) = headers.get(drag.entity) else {
+        return;
+    };
+    let Ok((mut node, computed, transform)) = panels.get_mut(child_of.parent()) else {
+        return;
+    };
+    let Ok(window) = window.single() else {
+        return;
+    };
+
+    let panel_top_left = node_top_left(computed, transform);
+    // Several drags can arrive before layout runs again, so build on the last position set
+    let position = match (node.position_type, node.left, node.top) {
+        (PositionType::Absolute, Val::Px(left), Val::Px(top)) => Vec2::new(left, top),
+        _ => panel_top_left,
+    };
+    let header_min = node_top_left(header_computed, header_transform) - panel_top_left;
+    let header = Rect::from_corners(
+        header_min,
+        header_min + header_computed.size() * header_computed.inverse_scale_factor(),
+    );
+    let position = clamp_panel_position(position + drag.delta, header, window.size());
+
+    node.position_type = PositionType::Absolute;
+    node.left = Val::Px(position.x);
+    node.top = Val::Px(position.y);
+}
+
+/// Top-left corner of a laid out node, in logical pixels.
+fn node_top_left(computed: &ComputedNode, transform: &UiGlobalTransform) -> Vec2 {
+    (transform.affine().translation - computed.size() / 2.0) * computed.inverse_scale_factor()
+}
+
+/// Clamps a panel's top-left `position` so its `header` (relative to the panel) stays
+/// inside a viewport of `viewport` size. A header wider than the screen stays left-aligned.
+pub fn clamp_panel_position(position: Vec2, header: Rect, viewport: Vec2) -> Vec2 {
+    let min = -header.min;
+    let max = (viewport - header.max).max(min);
+    position.clamp(min, max)
 }
 
 // ============================================================================
@@ -358,6 +560,60 @@ pub fn spawn_close_button<M: Component>(parent: &mut ChildSpawnerCommands, marke
         });
 }
 
+// ============================================================================
+// Click vs Drag
+// ============================================================================
+
+/// Thresholds separating a deliberate click on a world entity from the end of a drag or pan.
+#[derive(Resource, Debug, Clone)]
+pub struct ClickSettings {
+    /// Maximum pointer travel between press and release, in logical pixels.
+    pub max_drag_distance: f32,
+    /// Maximum time the button may be held down.
+    pub max_press_duration: Duration,
+}
+
+impl Default for ClickSettings {
+    fn default() -> Self {
+        Self {
+            max_drag_distance: 8.0,
+            max_press_duration: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Where each pointer was last pressed, so travel can be measured on release.
+#[derive(Resource, Default, Debug)]
+pub struct PointerPressOrigins(HashMap<PointerId, Vec2>);
+
+/// Observer registered by `WidgetsPlugin`; records the press position for `ClickFilter`.
+pub fn record_pointer_press(trigger: On<Pointer<Press>>, mut origins: ResMut<PointerPressOrigins>) {
+    let event = trigger.event();
+    origins
+        .0
+        .insert(event.pointer_id, event.pointer_location.position);
+}
+
+/// Lets click observers ignore clicks that were really short drags or long holds.
+#[derive(SystemParam)]
+pub struct ClickFilter<'w> {
+    settings: Res<'w, ClickSettings>,
+    origins: Res<'w, PointerPressOrigins>,
+}
+
+impl ClickFilter<'_> {
+    /// Returns true if `click` stayed within the configured distance and duration.
+    pub fn is_click(&self, click: &Pointer<Click>) -> bool {
+        if click.event.duration > self.settings.max_press_duration {
+            return false;
+        }
+
+        self.origins.0.get(&click.pointer_id).is_none_or(|origin| {
+            origin.distance(click.pointer_location.position) <= self.settings.max_drag_distance
+        })
+    }
+}
+
 // ============================================================================
 // Scrollable Container Widget
 // ============================================================================
@@ -386,6 +642,64 @@ pub fn spawn_scrollable_container<M: Component>(
         .id()
 }
 
+/// Scroll offset of the first container marked `M`, zero if there is none.
+/// Read it before rebuilding a list and hand it to `RestoreScroll` afterwards.
+pub fn scroll_offset<M: Component>(world: &mut World) -> Vec2 {
+    world
+        .query_filtered::<&ScrollPosition, With<M>>()
+        .iter(world)
+        .next()
+        .map_or(Vec2::ZERO, |position| position.0)
+}
+
+/// Scrolls the container marked `M` back to a saved offset after its content was
+/// rebuilt (or the container respawned). Queue it after the commands that repopulate
+/// the list; the offset is clamped to the new content once layout has run.
+pub struct RestoreScroll<M: Component> {
+    offset: Vec2,
+    marker: PhantomData<M>,
+}
+
+impl<M: Component> RestoreScroll<M> {
+    pub fn new(offset: Vec2) -> Self {
+        Self {
+            offset,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<M: Component> Command for RestoreScroll<M> {
+    fn apply(self, world: &mut World) {
+        let mut query = world.query_filtered::<Entity, With<M>>();
+        let Some(container) = query.iter(world).next() else {
+            return;
+        };
+        world
+            .entity_mut(container)
+            .insert((ScrollPosition(self.offset), ClampScrollAfterLayout));
+    }
+}
+
+/// A restored `ScrollPosition` that may be past the end of the rebuilt content
+#[derive(Component)]
+struct ClampScrollAfterLayout;
+
+/// Clamps restored offsets to the content size the rebuilt list ended up with.
+fn clamp_restored_scroll(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut ScrollPosition, &ComputedNode), With<ClampScrollAfterLayout>>,
+) {
+    for (entity, mut scroll_position, computed) in query.iter_mut() {
+        let max_offset = max_scroll_offset(computed).max(Vec2::ZERO);
+        let clamped = scroll_position.0.clamp(Vec2::ZERO, max_offset);
+        if scroll_position.0 != clamped {
+            scroll_position.0 = clamped;
+        }
+        commands.entity(entity).remove::<ClampScrollAfterLayout>();
+    }
+}
+
 // ============================================================================
 // Item Card Widget
 // ============================================================================
@@ -466,6 +780,75 @@ pub fn spawn_timer_text(parent: &mut ChildSpawnerCommands, seconds: f32) {
     ));
 }
 
+/// Spawns a centered placeholder message for lists or screens with nothing to show.
+pub fn spawn_empty_state(parent: &mut ChildSpawnerCommands, message: &str) {
+    parent
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            padding: UiRect::all(Val::Px(24.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .with_children(|container| {
+            container.spawn((
+                Text::new(message),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(UiTheme::TEXT_SECONDARY),
+                TextLayout::new_with_justify(Justify::Center),
+            ));
+        });
+}
+
+// ============================================================================
+// UI Rebuild Stats
+// ============================================================================
+
+/// Counts full list rebuilds per UI, shown in the debug log console to spot rebuild churn.
+#[derive(Resource, Default, Debug)]
+pub struct UiRebuildStats {
+    counts: HashMap<&'static str, u64>,
+    total: u64,
+}
+
+impl UiRebuildStats {
+    /// Records one full rebuild of the `list` UI.
+    pub fn record(&mut self, list: &'static str) {
+        *self.counts.entry(list).or_insert(0) += 1;
+        self.total += 1;
+    }
+
+    pub fn count(&self, list: &str) -> u64 {
+        self.counts.get(list).copied().unwrap_or(0)
+    }
+
+    /// Total rebuilds across all lists; readers can use it to skip redundant redraws.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// One-line summary sorted by list name, e.g. "crafting: 3, research: 12".
+    pub fn summary(&self) -> String {
+        let mut counts: Vec<_> = self.counts.iter().collect();
+        counts.sort_by_key(|(list, _)| **list);
+        counts
+            .iter()
+            .map(|(list, count)| format!("{}: {}", list, count))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Records a full rebuild of `list` from an exclusive command.
+pub fn record_ui_rebuild(world: &mut World, list: &'static str) {
+    if let Some(mut stats) = world.get_resource_mut::<UiRebuildStats>() {
+        stats.record(list);
+    }
+}
+
 // ============================================================================
 // Action Button Widget
 // ============================================================================
@@ -478,12 +861,32 @@ pub fn spawn_action_button<M: Component>(
     border_color: Color,
     marker: M,
 ) {
+    spawn_action_button_with_style(
+        parent,
+        text,
+        text_color,
+        border_color,
+        ButtonStyle::default(),
+        marker,
+    );
+}
+
+/// Same as `spawn_action_button`, but with custom dimensions and background colors.
+/// `style.width` is applied as a minimum width so longer labels still fit.
+pub fn spawn_action_button_with_style<M: Component>(
+    parent: &mut ChildSpawnerCommands,
+    text: &str,
+    text_color: Color,
+    border_color: Color,
+    style: ButtonStyle,
+    marker: M,
+) -> Entity {
     parent
         .spawn((
             Button,
             Node {
-                min_width: Val::Px(100.0),
-                height: Val::Px(30.0),
+                min_width: style.width,
+                height: style.height,
                 padding: UiRect::axes(Val::Px(10.0), Val::Px(5.0)),
                 margin: UiRect::top(Val::Px(5.0)),
                 border: UiRect::all(Val::Px(2.0)),
@@ -492,12 +895,8 @@ pub fn spawn_action_button<M: Component>(
                 ..default()
             },
             BorderColor::all(border_color),
-            BackgroundColor(UiTheme::BUTTON_NORMAL),
-            AnimatedButton {
-                normal_color: UiTheme::BUTTON_NORMAL,
-                hover_color: UiTheme::BUTTON_HOVER,
-                pressed_color: UiTheme::BUTTON_PRESSED,
-            },
+            BackgroundColor(style.normal),
+            style.animated(),
             Interaction::default(),
             marker,
         ))
@@ -505,12 +904,13 @@ pub fn spawn_action_button<M: Component>(
             btn.spawn((
                 Text::new(text),
                 TextFont {
-                    font_size: 16.0,
+                    font_size: style.font_size,
                     ..default()
                 },
                 TextColor(text_color),
             ));
-        });
+        })
+        .id()
 }
 
 // ============================================================================
@@ -578,12 +978,22 @@ pub fn spawn_icon_button<M: Component>(
     icon_text: &str,
     marker: M,
 ) {
+    spawn_icon_button_with_style(parent, icon_text, ButtonStyle::icon(), marker);
+}
+
+/// Same as `spawn_icon_button`, but with custom dimensions and background colors.
+pub fn spawn_icon_button_with_style<M: Component>(
+    parent: &mut ChildSpawnerCommands,
+    icon_text: &str,
+    style: ButtonStyle,
+    marker: M,
+) -> Entity {
     parent
         .spawn((
             Button,
             Node {
-                width: Val::Px(40.0),
-                height: Val::Px(40.0),
+                width: style.width,
+                height: style.height,
                 margin: UiRect::all(Val::Px(5.0)),
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
@@ -591,24 +1001,21 @@ pub fn spawn_icon_button<M: Component>(
                 ..default()
             },
             BorderColor::all(UiTheme::CARD_BORDER),
-            BackgroundColor(UiTheme::BUTTON_NORMAL),
-            AnimatedButton {
-                normal_color: UiTheme::BUTTON_NORMAL,
-                hover_color: UiTheme::BUTTON_HOVER,
-                pressed_color: UiTheme::BUTTON_PRESSED,
-            },
+            BackgroundColor(style.normal),
+            style.animated(),
             marker,
         ))
         .with_children(|btn| {
             btn.spawn((
                 Text::new(icon_text),
                 TextFont {
-                    font_size: 22.0,
+                    font_size: style.font_size,
                     ..default()
                 },
                 TextColor(UiTheme::TEXT_PRIMARY),
             ));
-        });
+        })
+        .id()
 }
 
 // ============================================================================
@@ -619,46 +1026,90 @@ pub fn spawn_icon_button<M: Component>(
 #[derive(Component)]
 pub struct ContentContainer;
 
-/// Spawns a large menu button for navigation (e.g., in village menu)
+/// Content that stays in the `ContentContainer` while another view is shown. It is
+/// hidden instead of despawned, so its owner can show it again without a rebuild.
+#[derive(Component)]
+pub struct CachedContent;
+
+/// Empties a `ContentContainer` for new content: `CachedContent` children are hidden,
+/// everything else is despawned.
+pub struct ClearContentContainer(pub Entity);
+
+impl Command for ClearContentContainer {
+    fn apply(self, world: &mut World) {
+        let children: Vec<Entity> = world
+            .get::<Children>(self.0)
+            .map(|c| c.iter().collect())
+            .unwrap_or_default();
+        for child in children {
+            if world.get::<CachedContent>(child).is_none() {
+                world.entity_mut(child).despawn();
+                continue;
+            }
+            if let Some(mut node) = world.get_mut::<Node>(child) {
+                node.display = Display::None;
+            }
+            // Hidden too, so keyboard focus skips its buttons
+            world.entity_mut(child).insert(Visibility::Hidden);
+        }
+    }
+}
+
+/// Spawns a large menu button for navigation (e.g., in village menu).
+/// A button that is not `enabled` is drawn disabled and ignores clicks.
 pub fn spawn_menu_button<M: Component>(
     parent: &mut ChildSpawnerCommands,
     text: &str,
     marker: M,
     enabled: bool,
 ) {
+    spawn_menu_button_with_style(parent, text, ButtonStyle::menu(), marker, enabled);
+}
+
+/// Same as `spawn_menu_button`, but with custom dimensions and background colors.
+pub fn spawn_menu_button_with_style<M: Component>(
+    parent: &mut ChildSpawnerCommands,
+    text: &str,
+    style: ButtonStyle,
+    marker: M,
+    enabled: bool,
+) -> Entity {
     let mut cmd = parent.spawn((
         Node {
-            width: Val::Percent(100.0),
-            height: Val::Px(50.0),
+            width: style.width,
+            height: style.height,
             margin: UiRect::bottom(Val::Px(10.0)),
             border: UiRect::all(Val::Px(2.0)),
             justify_content: JustifyContent::Center,
             align_items: AlignItems::Center,
             ..default()
         },
-        BackgroundColor(UiTheme::BUTTON_NORMAL),
+        BackgroundColor(style.normal),
+        Button,
         marker,
     ));
 
     if enabled {
         cmd.insert((
-            Button,
             BorderColor::all(UiTheme::TAB_BORDER),
+            style.animated(),
+            Focusable,
+        ));
+    } else {
+        cmd.insert((
+            BorderColor::all(UiTheme::BORDER_DISABLED),
             AnimatedButton {
-                normal_color: UiTheme::BUTTON_NORMAL,
-                hover_color: UiTheme::BUTTON_HOVER,
-                pressed_color: UiTheme::BUTTON_PRESSED,
+                disabled: true,
+                ..style.animated()
             },
         ));
-    } else {
-        cmd.insert(BorderColor::all(UiTheme::BORDER_DISABLED));
     }
 
     cmd.with_children(|btn| {
         btn.spawn((
             Text::new(text),
             TextFont {
-                font_size: 20.0,
+                font_size: style.font_size,
                 ..default()
             },
             TextColor(if enabled {
@@ -667,5 +1118,223 @@ pub fn spawn_menu_button<M: Component>(
                 UiTheme::TEXT_SECONDARY
             }),
         ));
-    });
+    })
+    .id()
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, bevy::ecs::system::RunSystemOnce};
+
+    #[derive(Component)]
+    struct TestButton;
+
+    #[test]
+    fn test_action_button_with_custom_style() {
+        let mut world = World::new();
+        let style = ButtonStyle {
+            width: Val::Px(200.0),
+            height: Val::Px(24.0),
+            font_size: 12.0,
+            normal: UiTheme::CARD_BG,
+            hover: UiTheme::TAB_ACTIVE_BG,
+            pressed: UiTheme::TAB_INACTIVE_BG,
+        };
+
+        world
+            .commands()
+            .spawn(Node::default())
+            .with_children(|parent| {
+                spawn_action_button_with_style(
+                    parent,
+                    "Wide",
+                    UiTheme::TEXT_PRIMARY,
+                    UiTheme::CARD_BORDER,
+                    style,
+                    TestButton,
+                );
+            });
+        world.flush();
+
+        let (button, node, bg, anim, children) = world
+            .query_filtered::<(Entity, &Node, &BackgroundColor, &AnimatedButton, &Children), With<TestButton>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(node.min_width, Val::Px(200.0));
+        assert_eq!(node.height, Val::Px(24.0));
+        assert_eq!(bg.0, UiTheme::CARD_BG);
+        assert_eq!(anim.hover_color, UiTheme::TAB_ACTIVE_BG);
+        assert_eq!(anim.pressed_color, UiTheme::TAB_INACTIVE_BG);
+
+        let label = children[0];
+        assert_eq!(world.get::<TextFont>(label).unwrap().font_size, 12.0);
+        assert_eq!(world.get::<ChildOf>(label).unwrap().parent(), button);
+    }
+
+    #[test]
+    fn test_default_style_matches_action_button() {
+        let style = ButtonStyle::default();
+
+        assert_eq!(style.width, Val::Px(100.0));
+        assert_eq!(style.height, Val::Px(30.0));
+        assert_eq!(style.font_size, 16.0);
+        assert_eq!(style.normal, UiTheme::BUTTON_NORMAL);
+    }
+
+    #[test]
+    fn test_disabled_menu_button_ignores_presses() {
+        let mut world = World::new();
+        world
+            .commands()
+            .spawn(Node::default())
+            .with_children(|parent| {
+                spawn_menu_button(parent, "Crafting (Locked)", TestButton, false);
+            });
+        world.flush();
+        let button = world
+            .query_filtered::<Entity, With<TestButton>>()
+            .single(&world)
+            .unwrap();
+
+        *world.get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+        world.run_system_once(suppress_disabled_presses).unwrap();
+        world.run_system_once(button_interaction_system).unwrap();
+
+        assert_eq!(world.get::<Interaction>(button), Some(&Interaction::None));
+        assert_eq!(
+            world.get::<BackgroundColor>(button).unwrap().0,
+            UiTheme::BUTTON_NORMAL
+        );
+        assert_eq!(
+            world.get::<BorderColor>(button),
+            Some(&BorderColor::all(UiTheme::BORDER_DISABLED))
+        );
+        assert!(world.get::<Focusable>(button).is_none());
+    }
+
+    #[test]
+    fn test_clear_content_container_hides_cached_content() {
+        let mut world = World::new();
+        let container = world.spawn((Node::default(), ContentContainer)).id();
+        let cached = world
+            .spawn((Node::default(), CachedContent, ChildOf(container)))
+            .id();
+        let other = world.spawn((Node::default(), ChildOf(container))).id();
+
+        ClearContentContainer(container).apply(&mut world);
+
+        assert!(world.get_entity(other).is_err());
+        assert_eq!(world.get::<Node>(cached).unwrap().display, Display::None);
+        assert_eq!(world.get::<Visibility>(cached), Some(&Visibility::Hidden));
+        assert_eq!(&world.get::<Children>(container).unwrap()[..], &[cached]);
+    }
+
+    #[test]
+    fn test_restored_scroll_is_clamped_to_rebuilt_content() {
+        let mut world = World::new();
+        let list = world
+            .spawn((
+                TestButton,
+                ScrollPosition(Vec2::new(0.0, 300.0)),
+                ComputedNode {
+                    size: Vec2::new(100.0, 200.0),
+                    content_size: Vec2::new(100.0, 320.0),
+                    inverse_scale_factor: 1.0,
+                    ..default()
+                },
+            ))
+            .id();
+        let offset = scroll_offset::<TestButton>(&mut world);
+        assert_eq!(offset, Vec2::new(0.0, 300.0));
+
+        // The rebuild reset the offset, and the new content only overflows by 120px
+        world.entity_mut(list).insert(ScrollPosition::default());
+        RestoreScroll::<TestButton>::new(offset).apply(&mut world);
+        assert_eq!(world.get::<ScrollPosition>(list).unwrap().0, offset);
+
+        world.run_system_once(clamp_restored_scroll).unwrap();
+        assert_eq!(
+            world.get::<ScrollPosition>(list).unwrap().0,
+            Vec2::new(0.0, 120.0)
+        );
+        assert!(world.get::<ClampScrollAfterLayout>(list).is_none());
+    }
+
+    #[test]
+    fn test_panel_position_keeps_header_on_screen() {
+        // A 300x40 header 10px inside its panel, on an 800x600 screen
+        let header = Rect::new(10.0, 10.0, 310.0, 50.0);
+        let viewport = Vec2::new(800.0, 600.0);
+        let clamp = |x, y| clamp_panel_position(Vec2::new(x, y), header, viewport);
+
+        assert_eq!(clamp(100.0, 200.0), Vec2::new(100.0, 200.0));
+        assert_eq!(clamp(-50.0, -50.0), Vec2::new(-10.0, -10.0));
+        assert_eq!(clamp(900.0, 900.0), Vec2::new(490.0, 550.0));
+
+        let wide = Rect::new(0.0, 0.0, 1000.0, 40.0);
+        assert_eq!(
+            clamp_panel_position(Vec2::new(50.0, 0.0), wide, viewport),
+            Vec2::ZERO
+        );
+    }
+
+    #[test]
+    fn test_dragging_header_moves_centered_panel() {
+        let mut world = World::new();
+        world.add_observer(on_panel_header_drag);
+        world.spawn((Window::default(), PrimaryWindow));
+        let laid_out = |center: Vec2, size: Vec2| {
+            (
+                ComputedNode {
+                    size,
+                    inverse_scale_factor: 1.0,
+                    ..default()
+                },
+                UiGlobalTransform::from_translation(center),
+            )
+        };
+        // An 800x400 panel centered on the 1280x720 window, its header along the top
+        let panel = world
+            .spawn((
+                Node::default(),
+                laid_out(Vec2::new(640.0, 360.0), Vec2::new(800.0, 400.0)),
+            ))
+            .id();
+        let header = world
+            .spawn((
+                Draggable,
+                laid_out(Vec2::new(640.0, 180.0), Vec2::new(800.0, 40.0)),
+                ChildOf(panel),
+            ))
+            .id();
+        let drag = |world: &mut World, delta: Vec2| {
+            let location = bevy::picking::pointer::Location {
+                target: bevy::camera::NormalizedRenderTarget::None {
+                    width: 1280,
+                    height: 720,
+                },
+                position: Vec2::ZERO,
+            };
+            world.trigger(Pointer::new(
+                PointerId::Mouse,
+                location,
+                Drag {
+                    button: PointerButton::Primary,
+                    distance: delta,
+                    delta,
+                },
+                header,
+            ));
+        };
+
+        drag(&mut world, Vec2::new(-30.0, 20.0));
+        let node = world.get::<Node>(panel).unwrap();
+        assert_eq!(node.position_type, PositionType::Absolute);
+        assert_eq!((node.left, node.top), (Val::Px(210.0), Val::Px(180.0)));
+
+        // Dragged far up, the header stops at the top edge
+        drag(&mut world, Vec2::new(0.0, -500.0));
+        let node = world.get::<Node>(panel).unwrap();
+        assert_eq!((node.left, node.top), (Val::Px(210.0), Val::Px(0.0)));
+    }
 }
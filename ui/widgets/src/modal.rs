@@ -0,0 +1,208 @@
+//! Modal popups: open-modal tracking, bulk despawning and the Yes/No confirm dialog.
+
+use {
+    crate::{UiTheme, spawn_action_button},
+    bevy::{prelude::*, ui::FocusPolicy},
+};
+
+/// Marks the root of a modal popup. While any exists, `any_modal_open` is true.
+/// Popup markers can pull it in with `#[require(Modal)]`.
+#[derive(Component, Default)]
+pub struct Modal;
+
+/// Number of modal popups currently spawned, kept in sync by `ModalPlugin`.
+#[derive(Resource, Default, Debug)]
+pub struct ModalState {
+    pub open: usize,
+}
+
+impl ModalState {
+    pub fn is_open(&self) -> bool {
+        self.open > 0
+    }
+}
+
+/// Run condition: true while a modal popup is open.
+/// Use `run_if(not(any_modal_open))` to keep gameplay input from bleeding through.
+pub fn any_modal_open(state: Res<ModalState>) -> bool {
+    state.is_open()
+}
+
+/// Tracks `Modal` entities in `ModalState`. Added by `WidgetsPlugin`.
+pub struct ModalPlugin;
+
+impl Plugin for ModalPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ModalState>()
+            .add_observer(|_: On<Add, Modal>, mut state: ResMut<ModalState>| state.open += 1)
+            .add_observer(|_: On<Remove, Modal>, mut state: ResMut<ModalState>| {
+                state.open = state.open.saturating_sub(1)
+            });
+    }
+}
+
+/// Despawns every entity carrying marker `M` (and its descendants).
+/// Use it to close popups/modals so no wrapper entity is left behind.
+pub fn despawn_all<M: Component>(commands: &mut Commands, query: &Query<Entity, With<M>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Root of a dialog spawned by `spawn_confirm_dialog`.
+/// Despawn it (e.g. with `despawn_all`) from the Yes/No handlers to close the dialog.
+#[derive(Component)]
+#[require(Modal)]
+pub struct ConfirmDialog;
+
+/// Spawns a modal Yes/No dialog over a full-screen dim overlay.
+///
+/// The overlay blocks interaction, so clicks don't reach the game or the UI below it.
+/// The Yes button carries `on_confirm` and the No button `on_cancel`; callers react to
+/// those markers with their own handlers. Returns the `ConfirmDialog` root.
+pub fn spawn_confirm_dialog<C: Component, X: Component>(
+    commands: &mut Commands,
+    prompt: &str,
+    on_confirm: C,
+    on_cancel: X,
+) -> Entity {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(UiTheme::OVERLAY_DIM),
+            GlobalZIndex(60),
+            Interaction::default(),
+            FocusPolicy::Block,
+            ConfirmDialog,
+        ))
+        .with_children(|overlay| {
+            overlay
+                .spawn((
+                    Node {
+                        width: Val::Px(360.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(15.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        row_gap: Val::Px(10.0),
+                        ..default()
+                    },
+                    BackgroundColor(UiTheme::POPUP_BG),
+                    BorderColor::all(UiTheme::POPUP_BORDER),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new(prompt),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(UiTheme::TEXT_PRIMARY),
+                        TextLayout::new_with_justify(Justify::Center),
+                    ));
+
+                    panel
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            column_gap: Val::Px(20.0),
+                            ..default()
+                        })
+                        .with_children(|buttons| {
+                            spawn_action_button(
+                                buttons,
+                                "Yes",
+                                UiTheme::TEXT_PRIMARY,
+                                UiTheme::BORDER_ERROR,
+                                on_confirm,
+                            );
+                            spawn_action_button(
+                                buttons,
+                                "No",
+                                UiTheme::TEXT_PRIMARY,
+                                UiTheme::POPUP_BORDER,
+                                on_cancel,
+                            );
+                        });
+                });
+        })
+        .id()
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, bevy::ecs::system::RunSystemOnce};
+
+    #[derive(Component)]
+    struct TestModal;
+
+    #[test]
+    fn test_despawn_all_removes_every_marked_entity() {
+        let mut world = World::new();
+        let first = world.spawn(TestModal).id();
+        let second = world.spawn(TestModal).with_child(Node::default()).id();
+        let unrelated = world.spawn(Node::default()).id();
+
+        world
+            .run_system_once(
+                |mut commands: Commands, query: Query<Entity, With<TestModal>>| {
+                    despawn_all(&mut commands, &query);
+                },
+            )
+            .unwrap();
+
+        assert!(world.get_entity(first).is_err());
+        assert!(world.get_entity(second).is_err());
+        assert!(world.get_entity(unrelated).is_ok());
+        assert_eq!(world.query::<&Node>().iter(&world).count(), 1);
+    }
+
+    #[test]
+    fn test_confirm_dialog_is_a_blocking_modal_with_tagged_buttons() {
+        #[derive(Component)]
+        struct ConfirmReset;
+        #[derive(Component)]
+        struct CancelReset;
+
+        let mut app = App::new();
+        app.add_plugins(ModalPlugin);
+        let dialog = spawn_confirm_dialog(
+            &mut app.world_mut().commands(),
+            "Reset the game?",
+            ConfirmReset,
+            CancelReset,
+        );
+        app.update();
+
+        let world = app.world_mut();
+        assert!(world.resource::<ModalState>().is_open());
+        assert_eq!(world.get::<FocusPolicy>(dialog), Some(&FocusPolicy::Block));
+
+        let is_in_dialog = |world: &World, mut entity: Entity| {
+            while let Some(parent) = world.get::<ChildOf>(entity) {
+                entity = parent.parent();
+            }
+            entity == dialog
+        };
+        let confirm = world
+            .query_filtered::<Entity, (With<ConfirmReset>, With<Button>)>()
+            .single(world)
+            .unwrap();
+        let cancel = world
+            .query_filtered::<Entity, (With<CancelReset>, With<Button>)>()
+            .single(world)
+            .unwrap();
+        assert!(is_in_dialog(world, confirm));
+        assert!(is_in_dialog(world, cancel));
+
+        world.entity_mut(dialog).despawn();
+        assert!(!world.resource::<ModalState>().is_open());
+        assert!(world.get_entity(confirm).is_err());
+    }
+}
@@ -0,0 +1,136 @@
+//! Readable formatting of resource amounts and other large numbers.
+
+/// How `format_number_with` renders a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberFormat {
+    /// Abbreviated with a K/M/B/T suffix from 1000 up, e.g. "1.5M"
+    #[default]
+    Abbreviated,
+    /// Full value with thousands separators, e.g. "1,500,000"
+    Grouped,
+}
+
+/// Suffixes for each power of 1000, starting at 1000^1
+const SUFFIXES: [&str; 4] = ["K", "M", "B", "T"];
+
+/// Formats `n` abbreviated, e.g. 999 -> "999", 1_000 -> "1K", 1_500_000 -> "1.5M".
+///
+/// Keeps three significant digits and truncates the rest, so a displayed amount is never
+/// more than what is actually held. Values past the trillions stay in "T".
+pub fn format_number(n: u64) -> String {
+    format_number_with(n, NumberFormat::Abbreviated)
+}
+
+/// Formats `n` in the given style.
+pub fn format_number_with(n: u64, format: NumberFormat) -> String {
+    match format {
+        NumberFormat::Abbreviated => abbreviate(n),
+        NumberFormat::Grouped => group_digits(n),
+    }
+}
+
+/// Formats a possibly fractional or negative value, e.g. a per-second rate.
+///
+/// Below 1000 one decimal is kept ("2.5", "-0.3", "4"); larger values are abbreviated
+/// like `format_number`.
+pub fn format_number_f32(value: f32) -> String {
+    let sign = if value < 0.0 { "-" } else { "" };
+    let magnitude = value.abs();
+    if magnitude < 1000.0 {
+        let text = format!("{:.1}", magnitude);
+        let text = text.strip_suffix(".0").unwrap_or(&text);
+        // Keep "-0.04" from rendering as "-0"
+        if text == "0" {
+            return "0".to_string();
+        }
+        return format!("{}{}", sign, text);
+    }
+    format!("{}{}", sign, abbreviate(magnitude as u64))
+}
+
+fn abbreviate(n: u64) -> String {
+    if n < 1000 {
+        return n.to_string();
+    }
+
+    let mut unit = 1000u64;
+    let mut suffix = 0;
+    while suffix + 1 < SUFFIXES.len() && n / unit >= 1000 {
+        unit *= 1000;
+        suffix += 1;
+    }
+
+    let whole = n / unit;
+    let decimals = match whole {
+        0..10 => 2,
+        10..100 => 1,
+        _ => 0,
+    };
+    // Integer math so e.g. 1_230_000 doesn't turn into "1.22M" through float rounding
+    let fraction = (n % unit) as u128 * 10u128.pow(decimals) / unit as u128;
+
+    let mut text = whole.to_string();
+    if fraction > 0 {
+        let digits = format!("{:0width$}", fraction, width = decimals as usize);
+        text.push('.');
+        text.push_str(digits.trim_end_matches('0'));
+    }
+    text.push_str(SUFFIXES[suffix]);
+    text
+}
+
+fn group_digits(n: u64) -> String {
+    let digits = n.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_number_boundaries() {
+        assert_eq!(format_number(0), "0");
+        assert_eq!(format_number(999), "999");
+        assert_eq!(format_number(1_000), "1K");
+        assert_eq!(format_number(1_050), "1.05K");
+        assert_eq!(format_number(12_345), "12.3K");
+        assert_eq!(format_number(999_999), "999K");
+        assert_eq!(format_number(1_000_000), "1M");
+        assert_eq!(format_number(1_230_000), "1.23M");
+        assert_eq!(format_number(1_500_000), "1.5M");
+        assert_eq!(format_number(2_000_000_000), "2B");
+        assert_eq!(format_number(4_560_000_000_000), "4.56T");
+        assert_eq!(format_number(1_500_000_000_000_000), "1500T");
+        assert_eq!(format_number(u64::MAX), "18446744T");
+    }
+
+    #[test]
+    fn test_grouped_digits() {
+        let grouped = |n| format_number_with(n, NumberFormat::Grouped);
+        assert_eq!(grouped(0), "0");
+        assert_eq!(grouped(999), "999");
+        assert_eq!(grouped(1_000), "1,000");
+        assert_eq!(grouped(1_500_000), "1,500,000");
+        assert_eq!(grouped(123_456_789), "123,456,789");
+    }
+
+    #[test]
+    fn test_format_number_f32() {
+        assert_eq!(format_number_f32(0.0), "0");
+        assert_eq!(format_number_f32(2.5), "2.5");
+        assert_eq!(format_number_f32(4.0), "4");
+        assert_eq!(format_number_f32(-0.3), "-0.3");
+        assert_eq!(format_number_f32(-0.04), "0");
+        assert_eq!(format_number_f32(999.9), "999.9");
+        assert_eq!(format_number_f32(1_500.0), "1.5K");
+        assert_eq!(format_number_f32(-2_500_000.0), "-2.5M");
+    }
+}
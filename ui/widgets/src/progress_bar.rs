@@ -0,0 +1,176 @@
+//! Horizontal progress bars, either labelled or driven by a marker component.
+
+use {crate::UiTheme, bevy::prelude::*, std::ops::DerefMut};
+
+/// Marker for the fill node of a UI-space progress bar.
+#[derive(Component)]
+pub struct ProgressBarFill;
+
+/// Width of a progress bar fill for `fraction`, clamped to `0.0..=1.0`.
+pub fn progress_bar_fill_width(fraction: f32) -> Val {
+    let fraction = if fraction.is_nan() {
+        0.0
+    } else {
+        fraction.clamp(0.0, 1.0)
+    };
+    Val::Percent(fraction * 100.0)
+}
+
+/// Spawns a UI progress bar with an optional label above it.
+/// Returns the fill entity, which can be updated with [`set_progress_bar_fraction`].
+pub fn spawn_progress_bar_ui(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    fraction: f32,
+) -> Entity {
+    let mut fill = Entity::PLACEHOLDER;
+
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            width: Val::Percent(100.0),
+            margin: UiRect::vertical(Val::Px(4.0)),
+            ..default()
+        })
+        .with_children(|bar| {
+            if !label.is_empty() {
+                bar.spawn((
+                    Text::new(label),
+                    TextFont {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(UiTheme::TEXT_SECONDARY),
+                ));
+            }
+
+            fill = spawn_progress_track(bar, fraction, UiTheme::TEXT_INFO, ()).1;
+        });
+
+    fill
+}
+
+/// Spawns a bare horizontal progress bar filled to `fraction` with `fill_color`.
+/// Returns the bar entity, which carries `marker`; its only child is the `ProgressBarFill`.
+/// Update it with [`update_progress_bar_fill`].
+pub fn spawn_progress_bar(
+    parent: &mut ChildSpawnerCommands,
+    fraction: f32,
+    fill_color: Color,
+    marker: impl Bundle,
+) -> Entity {
+    spawn_progress_track(parent, fraction, fill_color, marker).0
+}
+
+/// Spawns the bordered track and its fill, returning `(track, fill)`.
+fn spawn_progress_track(
+    parent: &mut ChildSpawnerCommands,
+    fraction: f32,
+    fill_color: Color,
+    marker: impl Bundle,
+) -> (Entity, Entity) {
+    let mut fill = Entity::PLACEHOLDER;
+    let track = parent
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(8.0),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(UiTheme::BUTTON_PRESSED),
+            BorderColor::all(UiTheme::CARD_BORDER),
+            marker,
+        ))
+        .with_children(|track| {
+            fill = track
+                .spawn((
+                    Node {
+                        width: progress_bar_fill_width(fraction),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(fill_color),
+                    ProgressBarFill,
+                ))
+                .id();
+        })
+        .id();
+    (track, fill)
+}
+
+/// Sets the fill of every bar marked with `M` to the fraction `fraction_of` returns for it.
+pub fn update_progress_bar_fill<M: Component>(
+    bars: &Query<(&M, &Children)>,
+    fills: &mut Query<&mut Node, With<ProgressBarFill>>,
+    mut fraction_of: impl FnMut(&M) -> f32,
+) {
+    for (marker, children) in bars.iter() {
+        let fraction = fraction_of(marker);
+        for &child in children {
+            if let Ok(fill) = fills.get_mut(child) {
+                set_progress_bar_fraction(fill, fraction);
+            }
+        }
+    }
+}
+
+/// Sets a progress bar fill node to `fraction` of the bar width.
+/// Accepts `Mut<Node>` and only writes (triggering change detection) when the width differs.
+pub fn set_progress_bar_fraction(mut fill: impl DerefMut<Target = Node>, fraction: f32) {
+    let width = progress_bar_fill_width(fraction);
+    if fill.width != width {
+        fill.width = width;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, bevy::ecs::system::RunSystemOnce};
+
+    #[test]
+    fn test_progress_bar_fill_width_from_fraction() {
+        assert_eq!(progress_bar_fill_width(0.0), Val::Percent(0.0));
+        assert_eq!(progress_bar_fill_width(0.25), Val::Percent(25.0));
+        assert_eq!(progress_bar_fill_width(1.0), Val::Percent(100.0));
+        // Out-of-range fractions are clamped
+        assert_eq!(progress_bar_fill_width(-0.5), Val::Percent(0.0));
+        assert_eq!(progress_bar_fill_width(1.5), Val::Percent(100.0));
+        assert_eq!(progress_bar_fill_width(f32::NAN), Val::Percent(0.0));
+
+        let mut fill = Node::default();
+        set_progress_bar_fraction(&mut fill, 0.5);
+        assert_eq!(fill.width, Val::Percent(50.0));
+    }
+
+    #[derive(Component)]
+    struct TestBar(f32);
+
+    #[test]
+    fn test_progress_bar_fill_follows_its_marker() {
+        let mut world = World::new();
+        world
+            .commands()
+            .spawn(Node::default())
+            .with_children(|parent| {
+                spawn_progress_bar(parent, 0.0, UiTheme::AFFORDABLE, TestBar(0.75));
+            });
+        world.flush();
+
+        world
+            .run_system_once(
+                |bars: Query<(&TestBar, &Children)>,
+                 mut fills: Query<&mut Node, With<ProgressBarFill>>| {
+                    update_progress_bar_fill(&bars, &mut fills, |bar| bar.0);
+                },
+            )
+            .unwrap();
+
+        let (fill, color) = world
+            .query_filtered::<(&Node, &BackgroundColor), With<ProgressBarFill>>()
+            .single(&world)
+            .unwrap();
+        assert_eq!(fill.width, Val::Percent(75.0));
+        assert_eq!(color.0, UiTheme::AFFORDABLE);
+    }
+}
@@ -0,0 +1,185 @@
+//! Thin, auto-hiding scrollbars for scrollable lists; the thumb can be dragged.
+
+use {
+    crate::{UiTheme, max_scroll_offset, spawn_scrollable_container},
+    bevy::prelude::*,
+};
+
+/// Width of the track spawned by `spawn_scrollable_container_with_scrollbar`
+const SCROLLBAR_WIDTH: f32 = 6.0;
+
+/// Track of a scrollbar, showing the scroll state of `container`.
+#[derive(Component)]
+pub struct ScrollbarTrack {
+    pub container: Entity,
+}
+
+/// The part of a `ScrollbarTrack` sized to the visible share of the content.
+/// Dragging it scrolls the container.
+#[derive(Component)]
+pub struct ScrollbarThumb;
+
+/// Like [`spawn_scrollable_container`], with a thin scrollbar to the right of the list.
+/// The scrollbar is hidden while the content fits. Returns the container entity.
+pub fn spawn_scrollable_container_with_scrollbar<M: Component>(
+    parent: &mut ChildSpawnerCommands,
+    marker: M,
+    spawn_children: impl FnOnce(&mut ChildSpawnerCommands),
+) -> Entity {
+    let mut container = Entity::PLACEHOLDER;
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            flex_grow: 1.0,
+            flex_basis: Val::Px(0.0),
+            height: Val::Percent(100.0),
+            column_gap: Val::Px(2.0),
+            ..default()
+        })
+        .with_children(|row| {
+            container = spawn_scrollable_container(row, marker, spawn_children);
+            row.spawn((
+                Node {
+                    width: Val::Px(SCROLLBAR_WIDTH),
+                    height: Val::Percent(100.0),
+                    flex_shrink: 0.0,
+                    display: Display::None,
+                    ..default()
+                },
+                BackgroundColor(UiTheme::BUTTON_PRESSED),
+                ScrollbarTrack { container },
+            ))
+            .with_child((
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(UiTheme::CARD_BORDER),
+                Interaction::default(),
+                Pickable::default(),
+                ScrollbarThumb,
+            ));
+        });
+    container
+}
+
+/// Thumb placement as `(top, height)` fractions of the track, or `None` when the content fits.
+/// All values are in the same unit; `offset` is the current scroll offset.
+pub fn scrollbar_thumb(content: f32, viewport: f32, offset: f32) -> Option<(f32, f32)> {
+    if content <= viewport || viewport <= 0.0 {
+        return None;
+    }
+    let height = viewport / content;
+    let progress = (offset / (content - viewport)).clamp(0.0, 1.0);
+    Some((progress * (1.0 - height), height))
+}
+
+/// Scroll distance for dragging a thumb by `drag` along a track of length `track`.
+/// The thumb's full travel maps onto the full overflow `content - viewport`.
+pub fn thumb_drag_to_scroll(drag: f32, content: f32, viewport: f32, track: f32) -> f32 {
+    let Some((_, height)) = scrollbar_thumb(content, viewport, 0.0) else {
+        return 0.0;
+    };
+    let travel = track * (1.0 - height);
+    if travel <= 0.0 {
+        return 0.0;
+    }
+    drag * (content - viewport) / travel
+}
+
+/// Scrolls a container while its scrollbar thumb is dragged.
+pub(crate) fn on_scrollbar_thumb_drag(
+    drag: On<Pointer<Drag>>,
+    thumbs: Query<&ChildOf, With<ScrollbarThumb>>,
+    tracks: Query<(&ScrollbarTrack, &ComputedNode)>,
+    mut containers: Query<(&mut ScrollPosition, &ComputedNode), Without<ScrollbarTrack>>,
+) {
+    let Ok(child_of) = thumbs.get(drag.entity) else {
+        return;
+    };
+    let Ok((track, track_computed)) = tracks.get(child_of.parent()) else {
+        return;
+    };
+    let Ok((mut scroll_position, computed)) = containers.get_mut(track.container) else {
+        return;
+    };
+
+    let scale = computed.inverse_scale_factor();
+    let delta = thumb_drag_to_scroll(
+        drag.delta.y,
+        computed.content_size().y * scale,
+        computed.size().y * scale,
+        track_computed.size().y * track_computed.inverse_scale_factor(),
+    );
+    if delta != 0.0 {
+        let max_offset = max_scroll_offset(computed);
+        scroll_position.y = (scroll_position.y + delta).clamp(0.0, max_offset.y.max(0.0));
+    }
+}
+
+/// Shows, hides and positions every scrollbar from its container's layout and `ScrollPosition`.
+pub(crate) fn update_scrollbars(
+    mut tracks: Query<(&ScrollbarTrack, &mut Node, &Children)>,
+    containers: Query<(&ComputedNode, &ScrollPosition)>,
+    mut thumbs: Query<&mut Node, (With<ScrollbarThumb>, Without<ScrollbarTrack>)>,
+) {
+    for (track, mut track_node, children) in tracks.iter_mut() {
+        let Ok((computed, scroll)) = containers.get(track.container) else {
+            continue;
+        };
+        let scale = computed.inverse_scale_factor();
+        let thumb = scrollbar_thumb(
+            computed.content_size().y * scale,
+            computed.size().y * scale,
+            scroll.y,
+        );
+
+        let display = if thumb.is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if track_node.display != display {
+            track_node.display = display;
+        }
+
+        let Some((top, height)) = thumb else {
+            continue;
+        };
+        for &child in children {
+            if let Ok(mut thumb_node) = thumbs.get_mut(child) {
+                let (top, height) = (Val::Percent(top * 100.0), Val::Percent(height * 100.0));
+                if thumb_node.top != top || thumb_node.height != height {
+                    thumb_node.top = top;
+                    thumb_node.height = height;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scrollbar_thumb_tracks_offset_and_hides_when_content_fits() {
+        assert_eq!(scrollbar_thumb(100.0, 200.0, 0.0), None);
+        assert_eq!(scrollbar_thumb(200.0, 200.0, 0.0), None);
+
+        // Half the content visible: the thumb is half the track and slides over the other half
+        assert_eq!(scrollbar_thumb(400.0, 200.0, 0.0), Some((0.0, 0.5)));
+        assert_eq!(scrollbar_thumb(400.0, 200.0, 100.0), Some((0.25, 0.5)));
+        assert_eq!(scrollbar_thumb(400.0, 200.0, 200.0), Some((0.5, 0.5)));
+        assert_eq!(scrollbar_thumb(400.0, 200.0, 900.0), Some((0.5, 0.5)));
+    }
+
+    #[test]
+    fn test_thumb_drag_spans_the_whole_overflow() {
+        // Half the content visible on a 200px track: the thumb travels 100px over 200px overflow
+        assert_eq!(thumb_drag_to_scroll(10.0, 400.0, 200.0, 200.0), 20.0);
+        assert_eq!(thumb_drag_to_scroll(-100.0, 400.0, 200.0, 200.0), -200.0);
+        assert_eq!(thumb_drag_to_scroll(10.0, 100.0, 200.0, 200.0), 0.0);
+    }
+}
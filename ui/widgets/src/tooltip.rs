@@ -0,0 +1,167 @@
+//! Hover popups with one or more colored lines of text.
+
+use {
+    crate::UiTheme,
+    bevy::{prelude::*, ui::FocusPolicy},
+};
+
+/// Hover text for an interactive UI node (e.g. a `Button`).
+/// While the node is hovered a small popup with this text is shown above it.
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct Tooltip(pub String);
+
+/// One line of a `TooltipLines` popup
+#[derive(Clone, Debug, PartialEq)]
+pub struct TooltipLine {
+    pub text: String,
+    pub color: Color,
+}
+
+/// Multi-line hover text with its own color per line, e.g. a per-resource cost breakdown.
+/// Shown and removed the same way as `Tooltip`.
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct TooltipLines(pub Vec<TooltipLine>);
+
+/// The popup spawned for a hovered `Tooltip` or `TooltipLines` owner
+#[derive(Component)]
+pub struct TooltipPopup;
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn tooltip_system(
+    mut commands: Commands,
+    query: Query<
+        (
+            Entity,
+            &Interaction,
+            Option<&Tooltip>,
+            Option<&TooltipLines>,
+            Option<&Children>,
+        ),
+        (
+            Changed<Interaction>,
+            Or<(With<Tooltip>, With<TooltipLines>)>,
+        ),
+    >,
+    popups: Query<(), With<TooltipPopup>>,
+) {
+    for (entity, interaction, tooltip, lines, children) in query.iter() {
+        // Always drop the previous popup, it is re-spawned while still hovered
+        for child in children.into_iter().flatten() {
+            if popups.contains(*child) {
+                commands.entity(*child).despawn();
+            }
+        }
+
+        if *interaction == Interaction::None {
+            continue;
+        }
+
+        let lines = match (tooltip, lines) {
+            (_, Some(lines)) => lines.0.clone(),
+            (Some(tooltip), None) => vec![TooltipLine {
+                text: tooltip.0.clone(),
+                color: UiTheme::TEXT_PRIMARY,
+            }],
+            (None, None) => continue,
+        };
+
+        commands.entity(entity).with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        flex_direction: FlexDirection::Column,
+                        bottom: Val::Percent(100.0),
+                        left: Val::Px(0.0),
+                        padding: UiRect::all(Val::Px(4.0)),
+                        margin: UiRect::bottom(Val::Px(4.0)),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BackgroundColor(UiTheme::POPUP_BG),
+                    BorderColor::all(UiTheme::POPUP_BORDER),
+                    GlobalZIndex(50),
+                    // Let hover and clicks reach whatever is beneath the popup
+                    FocusPolicy::Pass,
+                    Pickable::IGNORE,
+                    TooltipPopup,
+                ))
+                .with_children(|popup| {
+                    for line in lines {
+                        popup.spawn((
+                            Text::new(line.text),
+                            TextFont {
+                                font_size: 12.0,
+                                ..default()
+                            },
+                            TextColor(line.color),
+                            TextLayout::new_with_no_wrap(),
+                        ));
+                    }
+                });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, bevy::ecs::system::RunSystemOnce};
+
+    #[test]
+    fn test_tooltip_shown_only_while_hovered() {
+        let mut world = World::new();
+        let button = world
+            .spawn((Interaction::Hovered, Tooltip("Need +1 bones".to_string())))
+            .id();
+
+        world.run_system_once(tooltip_system).unwrap();
+        let popups = world
+            .query_filtered::<&ChildOf, With<TooltipPopup>>()
+            .iter(&world)
+            .map(|child_of| child_of.parent())
+            .collect::<Vec<_>>();
+        assert_eq!(popups, vec![button]);
+
+        *world.get_mut::<Interaction>(button).unwrap() = Interaction::None;
+        world.run_system_once(tooltip_system).unwrap();
+        assert_eq!(
+            world
+                .query_filtered::<(), With<TooltipPopup>>()
+                .iter(&world)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_tooltip_lines_keep_their_colors() {
+        let mut world = World::new();
+        world.spawn((
+            Interaction::Hovered,
+            TooltipLines(vec![
+                TooltipLine {
+                    text: "bones: 3/5".to_string(),
+                    color: UiTheme::NOT_AFFORDABLE,
+                },
+                TooltipLine {
+                    text: "stone: 9/2".to_string(),
+                    color: UiTheme::AFFORDABLE,
+                },
+            ]),
+        ));
+
+        world.run_system_once(tooltip_system).unwrap();
+        let mut lines = world.query::<(&Text, &TextColor)>();
+        let lines = lines
+            .iter(&world)
+            .map(|(text, color)| (text.0.clone(), color.0))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                ("bones: 3/5".to_string(), UiTheme::NOT_AFFORDABLE),
+                ("stone: 9/2".to_string(), UiTheme::AFFORDABLE),
+            ]
+        );
+    }
+}
@@ -165,8 +165,10 @@ pub fn build_condition_node(
                         operator: LogicOperator::Not,
                         required_signals: 1,
                         current_signals: 0,
-                        was_active: true, // NOT starts as "true" when child is false
+                        // Nothing sent to the parent yet, see `evaluate_pending_not_gates`
+                        was_active: false,
                     },
+                    PendingNotGate,
                 ))
                 .id();
 
@@ -35,20 +35,43 @@
 //! - `ValueChanged { topic, value }` - Trigger when numeric values change
 //! - `StatusCompleted { topic }` - Trigger when something is completed
 //! - `UnlockAchieved { unlock_id, display_name, reward_id }` - Emitted when unlock conditions are met
+//!
+//! ## Public API
+//!
+//! Most consumers only need `use unlocks::prelude::*;`. The crate root additionally exposes
+//! the graph root types used by loading/save code. Internal sensor and gate components
+//! (`LogicGate`, `ValueSensor`, ...) are not re-exported; depend on `unlocks_components`
+//! directly if you really need them.
 
 pub mod compiler;
+mod progress;
 mod systems;
+#[cfg(test)]
+mod tests;
 
 use {bevy::prelude::*, systems::*};
 pub use {
     compiler::compile_unlock_definition,
-    systems::{clean_up_unlocks, compile_pending_unlocks},
-    unlocks_assets::*,
-    unlocks_components::*,
-    unlocks_events::*,
-    unlocks_resources::*,
+    progress::{UnlockGraph, UnlockProgressReport},
+    systems::{
+        clean_up_unlocks, compile_pending_unlocks, despawn_orphaned_unlocks,
+        evaluate_pending_not_gates,
+    },
+    unlocks_assets::{ConditionNode, RepeatMode, UnlockDefinition},
+    unlocks_components::{ComparisonOp, CompiledUnlock, RepeatableUnlock, UnlockRoot},
+    unlocks_events::{StatusCompleted, Topic, UnlockAchieved, ValueChanged},
+    unlocks_resources::{TopicMap, UnlockHistory, UnlockHistoryEntry, UnlockProgress, UnlockState},
 };
 
+/// The stable, intended-public surface of the unlocks framework.
+pub mod prelude {
+    pub use crate::{
+        ComparisonOp, ConditionNode, RepeatMode, StatusCompleted, TopicMap, UnlockAchieved,
+        UnlockDefinition, UnlockGraph, UnlockProgress, UnlockProgressReport, UnlockState,
+        UnlocksPlugin, ValueChanged, compile_unlock_definition,
+    };
+}
+
 pub struct UnlocksPlugin;
 
 impl Plugin for UnlocksPlugin {
@@ -58,10 +81,12 @@ impl Plugin for UnlocksPlugin {
             .init_resource::<TopicMap>()
             .init_resource::<UnlockState>()
             .init_resource::<UnlockProgress>()
+            .init_resource::<UnlockHistory>()
             // Registration
             .register_type::<UnlockState>()
             .register_type::<UnlockProgress>()
-            .register_type::<TopicSubscribers>()
+            .register_type::<UnlockHistory>()
+            .register_type::<unlocks_components::TopicSubscribers>()
             // Observers for gate logic
             .add_observer(propagate_logic_signal)
             .add_observer(handle_unlock_completion)
@@ -0,0 +1,89 @@
+//! Read-only view of how far a compiled unlock is from firing.
+
+use {
+    bevy::{ecs::system::SystemParam, prelude::*},
+    unlocks_assets::ConditionNode,
+    unlocks_components::*,
+};
+
+/// Satisfied leaf conditions of one compiled unlock, e.g. "3/5 conditions met".
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnlockProgressReport {
+    /// Leaf conditions currently satisfied
+    pub met: usize,
+    /// All leaf conditions of the unlock, excluding `True`
+    pub total: usize,
+    /// Descriptions of the unmet conditions, e.g. "kills:goblin >= 10"
+    pub pending: Vec<String>,
+}
+
+/// Walks the compiled condition graphs spawned by the compiler.
+#[derive(SystemParam)]
+pub struct UnlockGraph<'w, 's> {
+    roots: Query<'w, 's, (Entity, &'static UnlockRoot)>,
+    children: Query<'w, 's, &'static Children>,
+    gates: Query<'w, 's, &'static LogicGate>,
+    sensors: Query<
+        'w,
+        's,
+        (
+            &'static ConditionSensor,
+            Option<&'static ValueSensor>,
+            Option<&'static CompletionSensor>,
+        ),
+    >,
+}
+
+impl UnlockGraph<'_, '_> {
+    /// Returns the live progress of `unlock_id`.
+    ///
+    /// `None` if the unlock is not compiled, e.g. because it already fired and was cleaned up.
+    /// A condition below a NOT counts as met while its sensor is low.
+    pub fn progress(&self, unlock_id: &str) -> Option<UnlockProgressReport> {
+        let (root, _) = self.roots.iter().find(|(_, root)| root.id == unlock_id)?;
+
+        let mut report = UnlockProgressReport::default();
+        self.collect(root, false, &mut report);
+        Some(report)
+    }
+
+    fn collect(&self, entity: Entity, negated: bool, report: &mut UnlockProgressReport) {
+        if let Ok((sensor, value, completion)) = self.sensors.get(entity) {
+            let leaf = match (value, completion) {
+                (Some(value), _) => ConditionNode::Value {
+                    topic: value.topic.clone(),
+                    op: value.op,
+                    target: value.target,
+                },
+                (None, Some(completion)) => ConditionNode::Completed {
+                    topic: completion.topic.clone(),
+                },
+                // `True` sensors are not something the player can work towards
+                (None, None) => return,
+            };
+
+            report.total += 1;
+            if sensor.is_met != negated {
+                report.met += 1;
+            } else if negated {
+                report
+                    .pending
+                    .push(ConditionNode::Not(Box::new(leaf)).describe());
+            } else {
+                report.pending.push(leaf.describe());
+            }
+            return;
+        }
+
+        let negated = negated
+            ^ self
+                .gates
+                .get(entity)
+                .is_ok_and(|gate| gate.operator == LogicOperator::Not);
+        if let Ok(children) = self.children.get(entity) {
+            for &child in children {
+                self.collect(child, negated, report);
+            }
+        }
+    }
+}
@@ -42,6 +42,38 @@ pub fn compile_pending_unlocks(
     }
 }
 
+// ============================================================================
+// Load Reconciliation
+// ============================================================================
+
+/// Despawns `CompiledUnlock` entities that can no longer work, so recompilation starts clean.
+///
+/// An entity is orphaned when it lost its `UnlockRoot`, has no condition tree below it,
+/// or repeats a definition id that is already compiled (the lowest entity wins).
+/// Run it before any compile system; otherwise a stale marker makes the compiler skip
+/// the definition and the unlock silently never fires.
+pub fn despawn_orphaned_unlocks(
+    mut commands: Commands,
+    compiled: Query<(Entity, &CompiledUnlock, Has<UnlockRoot>, Option<&Children>)>,
+) {
+    let mut entries: Vec<_> = compiled.iter().collect();
+    entries.sort_by_key(|(entity, ..)| *entity);
+
+    let mut seen = std::collections::HashSet::new();
+    for (entity, unlock, has_root, children) in entries {
+        let has_tree = children.is_some_and(|c| !c.is_empty());
+        if has_root && has_tree && seen.insert(unlock.definition_id.as_str()) {
+            continue;
+        }
+
+        warn!(
+            definition_id = %unlock.definition_id,
+            has_root, has_tree, "Despawning orphaned compiled unlock"
+        );
+        commands.entity(entity).despawn();
+    }
+}
+
 // ============================================================================
 // Logic Signal Propagation
 // ============================================================================
@@ -83,15 +115,13 @@ pub fn propagate_logic_signal(
         }
 
         // Determine new state
-        let is_active = match gate.operator {
-            LogicOperator::And => gate.current_signals >= gate.required_signals,
-            LogicOperator::Or => gate.current_signals > 0,
-            LogicOperator::Not => gate.current_signals == 0, // Inverts child
-        };
+        let is_active = gate_output(&gate);
 
         // Only propagate if state changed
         if is_active != gate.was_active {
             gate.was_active = is_active;
+            // The parent sees this gate's output, not the child's signal (matters for NOT)
+            trigger.event_mut().is_high = is_active;
             // Continue bubbling to parent via ChildOf
             trigger.propagate(true);
         } else {
@@ -104,6 +134,55 @@ pub fn propagate_logic_signal(
     }
 }
 
+/// Sends the initial output of NOT gates compiled since the last call.
+///
+/// Compilation leaves NOT gates silent, because a NOT over a not yet hydrated sensor
+/// would fire its parent for a condition the save already broke. Run this after the
+/// hydration events (`ValueChanged`, `StatusCompleted`). Deeper gates go first, so a
+/// NOT above another NOT sees its child's real output.
+pub fn evaluate_pending_not_gates(world: &mut World) {
+    let mut pending = world.query_filtered::<Entity, With<PendingNotGate>>();
+    let mut gates: Vec<(usize, Entity)> = pending
+        .iter(world)
+        .map(|entity| {
+            let depth = std::iter::successors(Some(entity), |&e| {
+                world.get::<ChildOf>(e).map(ChildOf::parent)
+            })
+            .count();
+            (depth, entity)
+        })
+        .collect();
+    gates.sort_by(|a, b| b.cmp(a));
+
+    for (_, entity) in gates {
+        world.entity_mut(entity).remove::<PendingNotGate>();
+        let Some(mut gate) = world.get_mut::<LogicGate>(entity) else {
+            continue;
+        };
+        if !gate_output(&gate) {
+            continue;
+        }
+        gate.was_active = true;
+
+        if let Some(parent) = world.get::<ChildOf>(entity).map(ChildOf::parent) {
+            world.trigger(LogicSignalEvent {
+                entity: parent,
+                is_high: true,
+            });
+            world.flush();
+        }
+    }
+}
+
+/// Output of a gate for its current signal count.
+fn gate_output(gate: &LogicGate) -> bool {
+    match gate.operator {
+        LogicOperator::And => gate.current_signals >= gate.required_signals,
+        LogicOperator::Or => gate.current_signals > 0,
+        LogicOperator::Not => gate.current_signals == 0, // Inverts child
+    }
+}
+
 // ============================================================================
 // Unlock Completion Handler
 // ============================================================================
@@ -135,7 +214,7 @@ pub fn handle_unlock_completion(
     }
 
     // Notify sensors waiting for this unlock as a dependency
-    let topic_key = format!("unlock:{}", event.unlock_id);
+    let topic_key: String = Topic::unlock(&event.unlock_id).into();
     if topic_map.topics.contains_key(&topic_key) {
         // Trigger StatusCompleted so dependent sensors update
         commands.trigger(StatusCompleted { topic: topic_key });
@@ -288,7 +367,7 @@ pub fn handle_unlock_lifecycle(
         debug!(unlock_id = %unlock_id, "Cleaning up finished unlock");
 
         // Remove unlock topic
-        let topic_key = format!("unlock:{}", unlock_id);
+        let topic_key: String = Topic::unlock(unlock_id).into();
         if let Some(entity) = topic_map.topics.remove(&topic_key) {
             commands.entity(entity).despawn();
         }
@@ -309,21 +388,23 @@ fn reset_condition_tree(
         sensor.is_met = false;
     }
 
-    // Reset gate
-    if let Ok(mut gate) = gates.get_mut(entity) {
-        gate.current_signals = 0;
-        gate.was_active = match gate.operator {
-            LogicOperator::Not => true, // NOT starts true when 0 signals
-            _ => false,
-        };
-    }
-
-    // Recurse
+    // Recurse first: a gate's signals come from the reset state of its children.
+    // Sensors reset low, so only gates can start high (e.g. NOT with a low child).
+    let mut initial_signals = 0;
     if let Ok(children) = children_query.get(entity) {
         for &child in children {
             reset_condition_tree(child, children_query, sensors, gates);
+            if gates.get(child).is_ok_and(|gate| gate.was_active) {
+                initial_signals += 1;
+            }
         }
     }
+
+    // Reset gate
+    if let Ok(mut gate) = gates.get_mut(entity) {
+        gate.current_signals = initial_signals;
+        gate.was_active = gate_output(&gate);
+    }
 }
 
 pub fn clean_up_unlocks(
@@ -331,6 +412,7 @@ pub fn clean_up_unlocks(
     mut topic_map: ResMut<TopicMap>,
     mut unlock_state: ResMut<UnlockState>,
     mut unlock_progress: ResMut<UnlockProgress>,
+    mut unlock_history: ResMut<UnlockHistory>,
     unlock_roots: Query<Entity, With<UnlockRoot>>,
     topic_entities: Query<Entity, With<TopicEntity>>,
 ) {
@@ -350,4 +432,5 @@ pub fn clean_up_unlocks(
     topic_map.topics.clear();
     unlock_state.completed.clear();
     unlock_progress.counts.clear();
+    unlock_history.entries.clear();
 }
@@ -0,0 +1,315 @@
+//! Integration tests driving compiled condition graphs through the public events.
+
+use {
+    crate::{evaluate_pending_not_gates, prelude::*},
+    bevy::{ecs::system::RunSystemOnce, prelude::*},
+    std::collections::HashSet,
+};
+
+/// Ids of every `UnlockAchieved` fired so far, in order.
+#[derive(Resource, Default)]
+struct Achieved(Vec<String>);
+
+fn setup() -> App {
+    let mut app = App::new();
+    app.add_plugins(UnlocksPlugin)
+        .init_resource::<Achieved>()
+        .add_observer(
+            |trigger: On<UnlockAchieved>, mut achieved: ResMut<Achieved>| {
+                achieved.0.push(trigger.event().unlock_id.clone());
+            },
+        );
+    app
+}
+
+fn compile(app: &mut App, id: &str, condition: ConditionNode, repeat_mode: RepeatMode) {
+    let definition = UnlockDefinition {
+        id: id.to_string(),
+        display_name: None,
+        condition,
+        reward_id: format!("recipe:{}", id),
+        repeat_mode,
+    };
+    app.world_mut()
+        .run_system_once(
+            move |mut commands: Commands,
+                  mut topic_map: ResMut<TopicMap>,
+                  state: Res<UnlockState>,
+                  progress: Res<UnlockProgress>| {
+                compile_unlock_definition(
+                    &mut commands,
+                    &mut topic_map,
+                    &definition,
+                    &HashSet::new(),
+                    &state,
+                    &progress,
+                );
+            },
+        )
+        .unwrap();
+}
+
+fn value(topic: &str, target: f32) -> ConditionNode {
+    ConditionNode::Value {
+        topic: topic.to_string(),
+        op: ComparisonOp::Ge,
+        target,
+    }
+}
+
+fn completed(topic: &str) -> ConditionNode {
+    ConditionNode::Completed {
+        topic: topic.to_string(),
+    }
+}
+
+/// Sends the NOT gates' initial output, as loading does once the sensors are hydrated.
+fn evaluate_not_gates(app: &mut App) {
+    app.world_mut()
+        .run_system_once(evaluate_pending_not_gates)
+        .unwrap();
+}
+
+fn set_value(app: &mut App, topic: &str, value: f32) {
+    app.world_mut().trigger(ValueChanged {
+        topic: topic.to_string(),
+        value,
+    });
+    app.world_mut().flush();
+}
+
+fn complete(app: &mut App, topic: &str) {
+    app.world_mut().trigger(StatusCompleted {
+        topic: topic.to_string(),
+    });
+    app.world_mut().flush();
+}
+
+fn achieved(app: &App) -> Vec<String> {
+    app.world().resource::<Achieved>().0.clone()
+}
+
+#[test]
+fn test_or_fires_on_any_child() {
+    let mut app = setup();
+    let condition = || ConditionNode::Or(vec![value("kills:goblin", 5.0), completed("research:a")]);
+    compile(&mut app, "by_kills", condition(), RepeatMode::Once);
+    compile(&mut app, "by_research", condition(), RepeatMode::Once);
+    assert!(achieved(&app).is_empty());
+
+    // Both unlocks share the topics, so each child alone must be enough for both
+    set_value(&mut app, "kills:goblin", 4.0);
+    assert!(achieved(&app).is_empty());
+    set_value(&mut app, "kills:goblin", 5.0);
+    assert_eq!(achieved(&app), vec!["by_kills", "by_research"]);
+}
+
+#[test]
+fn test_or_fires_once_while_children_stay_high() {
+    let mut app = setup();
+    compile(
+        &mut app,
+        "either",
+        ConditionNode::And(vec![
+            value("resource:bones", 1.0),
+            ConditionNode::Or(vec![value("kills:goblin", 5.0), completed("research:a")]),
+        ]),
+        RepeatMode::Once,
+    );
+
+    set_value(&mut app, "kills:goblin", 5.0);
+    complete(&mut app, "research:a");
+    // A second high child must not push the OR's signal upward twice
+    set_value(&mut app, "kills:goblin", 0.0);
+    assert!(achieved(&app).is_empty());
+
+    set_value(&mut app, "resource:bones", 1.0);
+    assert_eq!(achieved(&app), vec!["either"]);
+}
+
+#[test]
+fn test_or_drops_low_with_its_last_child() {
+    let mut app = setup();
+    compile(
+        &mut app,
+        "either",
+        ConditionNode::And(vec![
+            value("resource:bones", 1.0),
+            ConditionNode::Or(vec![value("kills:goblin", 5.0), value("kills:rat", 5.0)]),
+        ]),
+        RepeatMode::Once,
+    );
+
+    set_value(&mut app, "kills:goblin", 5.0);
+    set_value(&mut app, "kills:rat", 5.0);
+    set_value(&mut app, "kills:goblin", 0.0);
+    set_value(&mut app, "kills:rat", 0.0);
+    set_value(&mut app, "resource:bones", 1.0);
+    assert!(achieved(&app).is_empty(), "OR has no high child left");
+
+    set_value(&mut app, "kills:rat", 6.0);
+    assert_eq!(achieved(&app), vec!["either"]);
+}
+
+#[test]
+fn test_not_at_root_fires_once_evaluated() {
+    let mut app = setup();
+    compile(
+        &mut app,
+        "untouched",
+        ConditionNode::Not(Box::new(completed("research:a"))),
+        RepeatMode::Once,
+    );
+    app.world_mut().flush();
+    // Sensors are not hydrated yet, so compiling alone must not fire
+    assert!(achieved(&app).is_empty());
+
+    // Nothing completed the child, so the NOT is satisfied
+    evaluate_not_gates(&mut app);
+    assert_eq!(achieved(&app), vec!["untouched"]);
+}
+
+#[test]
+fn test_not_over_hydrated_child_never_fires() {
+    let mut app = setup();
+    compile(
+        &mut app,
+        "untouched",
+        ConditionNode::Not(Box::new(completed("research:a"))),
+        RepeatMode::Once,
+    );
+
+    // The save already completed the child
+    complete(&mut app, "research:a");
+    evaluate_not_gates(&mut app);
+    assert!(achieved(&app).is_empty());
+}
+
+#[test]
+fn test_not_blocks_and_until_child_drops() {
+    let mut app = setup();
+    compile(
+        &mut app,
+        "peaceful",
+        ConditionNode::And(vec![
+            value("resource:bones", 10.0),
+            ConditionNode::Not(Box::new(value("kills:goblin", 3.0))),
+        ]),
+        RepeatMode::Once,
+    );
+
+    set_value(&mut app, "kills:goblin", 3.0);
+    set_value(&mut app, "resource:bones", 10.0);
+    assert!(achieved(&app).is_empty(), "NOT child is high");
+
+    set_value(&mut app, "kills:goblin", 1.0);
+    assert_eq!(achieved(&app), vec!["peaceful"]);
+}
+
+#[test]
+fn test_not_completion_permanently_blocks() {
+    let mut app = setup();
+    compile(
+        &mut app,
+        "before_research",
+        ConditionNode::And(vec![
+            value("resource:bones", 10.0),
+            ConditionNode::Not(Box::new(completed("research:a"))),
+        ]),
+        RepeatMode::Once,
+    );
+    evaluate_not_gates(&mut app);
+
+    complete(&mut app, "research:a");
+    set_value(&mut app, "resource:bones", 10.0);
+    set_value(&mut app, "resource:bones", 0.0);
+    set_value(&mut app, "resource:bones", 20.0);
+    assert!(achieved(&app).is_empty());
+}
+
+#[test]
+fn test_repeatable_not_refires_after_child_drops() {
+    let mut app = setup();
+    compile(
+        &mut app,
+        "calm",
+        ConditionNode::And(vec![
+            value("resource:bones", 10.0),
+            ConditionNode::Not(Box::new(value("kills:goblin", 3.0))),
+        ]),
+        RepeatMode::Infinite,
+    );
+    evaluate_not_gates(&mut app);
+
+    set_value(&mut app, "resource:bones", 10.0);
+    assert_eq!(achieved(&app), vec!["calm"]);
+
+    // After the reset the NOT counts as high again; it has to drop and recover to re-fire
+    set_value(&mut app, "kills:goblin", 3.0);
+    set_value(&mut app, "resource:bones", 0.0);
+    set_value(&mut app, "resource:bones", 10.0);
+    assert_eq!(achieved(&app), vec!["calm"]);
+
+    set_value(&mut app, "kills:goblin", 1.0);
+    assert_eq!(achieved(&app), vec!["calm", "calm"]);
+}
+
+#[test]
+fn test_double_not_follows_child() {
+    let mut app = setup();
+    compile(
+        &mut app,
+        "double",
+        ConditionNode::Not(Box::new(ConditionNode::Not(Box::new(value(
+            "kills:goblin",
+            3.0,
+        ))))),
+        RepeatMode::Once,
+    );
+    evaluate_not_gates(&mut app);
+    // The inner NOT goes high first, which keeps the outer one low
+    assert!(achieved(&app).is_empty());
+
+    set_value(&mut app, "kills:goblin", 3.0);
+    assert_eq!(achieved(&app), vec!["double"]);
+}
+
+#[test]
+fn test_progress_report_counts_met_leaves() {
+    let mut app = setup();
+    compile(
+        &mut app,
+        "progress",
+        ConditionNode::And(vec![
+            value("kills:goblin", 5.0),
+            ConditionNode::Not(Box::new(completed("quest:betrayal"))),
+            ConditionNode::Or(vec![completed("research:a"), completed("research:b")]),
+        ]),
+        RepeatMode::Once,
+    );
+    set_value(&mut app, "kills:goblin", 5.0);
+    complete(&mut app, "quest:betrayal");
+
+    let report = app
+        .world_mut()
+        .run_system_once(|graph: UnlockGraph| graph.progress("progress"))
+        .unwrap();
+    assert_eq!(
+        report,
+        Some(UnlockProgressReport {
+            met: 1,
+            total: 4,
+            pending: vec![
+                "not (complete quest:betrayal)".to_string(),
+                "research a".to_string(),
+                "research b".to_string(),
+            ],
+        })
+    );
+
+    let missing = app
+        .world_mut()
+        .run_system_once(|graph: UnlockGraph| graph.progress("missing"))
+        .unwrap();
+    assert_eq!(missing, None);
+}
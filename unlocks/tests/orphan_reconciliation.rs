@@ -0,0 +1,69 @@
+use {bevy::prelude::*, std::collections::HashMap, unlocks::*};
+
+fn setup_app() -> (App, Handle<UnlockDefinition>) {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(UnlocksPlugin)
+        .add_plugins(AssetPlugin::default())
+        .init_asset::<UnlockDefinition>();
+
+    let handle = app
+        .world_mut()
+        .resource_mut::<Assets<UnlockDefinition>>()
+        .add(UnlockDefinition {
+            id: "kill_goblins".to_string(),
+            display_name: None,
+            condition: ConditionNode::Completed {
+                topic: "kills:goblin".to_string(),
+            },
+            reward_id: "recipe:bone_sword".to_string(),
+            repeat_mode: RepeatMode::Once,
+        });
+
+    (app, handle)
+}
+
+/// Mirrors a load: tear down the old session, then reconcile and recompile.
+/// A stray marker simulates a compiled entity that survived the teardown.
+fn simulate_load(app: &mut App) {
+    let world = app.world_mut();
+    world.run_system_cached(clean_up_unlocks).unwrap();
+    world.spawn(CompiledUnlock {
+        definition_id: "kill_goblins".to_string(),
+    });
+    world.run_system_cached(despawn_orphaned_unlocks).unwrap();
+    world.run_system_cached(compile_pending_unlocks).unwrap();
+}
+
+fn compiled_counts(app: &mut App) -> HashMap<String, usize> {
+    let mut query = app
+        .world_mut()
+        .query_filtered::<&CompiledUnlock, With<UnlockRoot>>();
+    let rooted = query.iter(app.world()).count();
+
+    let mut counts = HashMap::new();
+    let mut query = app.world_mut().query::<&CompiledUnlock>();
+    for compiled in query.iter(app.world()) {
+        *counts.entry(compiled.definition_id.clone()).or_insert(0) += 1;
+    }
+    assert_eq!(
+        rooted,
+        counts.values().sum::<usize>(),
+        "unrooted CompiledUnlock survived"
+    );
+    counts
+}
+
+#[test]
+fn test_loading_twice_leaves_one_compiled_unlock_per_definition() {
+    // Keep the handle alive so the definition isn't unloaded
+    let (mut app, _handle) = setup_app();
+    app.update();
+
+    simulate_load(&mut app);
+    simulate_load(&mut app);
+
+    let counts = compiled_counts(&mut app);
+    assert_eq!(counts.get("kill_goblins"), Some(&1));
+    assert_eq!(counts.len(), 1);
+}
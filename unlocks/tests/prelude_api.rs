@@ -0,0 +1,77 @@
+//! Compile check for the public API: the core unlock flow using only `unlocks::prelude`.
+
+use {
+    bevy::{ecs::system::RunSystemOnce, prelude::*},
+    unlocks::prelude::*,
+};
+
+#[derive(Resource, Default)]
+struct Achieved(Vec<String>);
+
+#[test]
+fn prelude_covers_core_flow() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(UnlocksPlugin)
+        .init_resource::<Achieved>()
+        .add_observer(
+            |trigger: On<UnlockAchieved>, mut achieved: ResMut<Achieved>| {
+                achieved.0.push(trigger.event().reward_id.clone());
+            },
+        );
+
+    let definition = UnlockDefinition {
+        id: "prelude_test".to_string(),
+        display_name: None,
+        reward_id: "reward:prelude".to_string(),
+        condition: ConditionNode::And(vec![
+            ConditionNode::Value {
+                topic: "resource:bones".to_string(),
+                op: ComparisonOp::Ge,
+                target: 5.0,
+            },
+            ConditionNode::Completed {
+                topic: "research:autopsy".to_string(),
+            },
+        ]),
+        repeat_mode: RepeatMode::Once,
+    };
+
+    app.world_mut()
+        .run_system_once(
+            move |mut commands: Commands,
+                  mut topic_map: ResMut<TopicMap>,
+                  unlock_state: Res<UnlockState>,
+                  unlock_progress: Res<UnlockProgress>| {
+                compile_unlock_definition(
+                    &mut commands,
+                    &mut topic_map,
+                    &definition,
+                    &Default::default(),
+                    &unlock_state,
+                    &unlock_progress,
+                );
+            },
+        )
+        .unwrap();
+    app.update();
+
+    app.world_mut().trigger(ValueChanged {
+        topic: "resource:bones".to_string(),
+        value: 5.0,
+    });
+    app.update();
+    assert!(app.world().resource::<Achieved>().0.is_empty());
+
+    app.world_mut().trigger(StatusCompleted {
+        topic: "research:autopsy".to_string(),
+    });
+    app.update();
+
+    assert_eq!(app.world().resource::<Achieved>().0, vec!["reward:prelude"]);
+    assert!(
+        app.world()
+            .resource::<UnlockState>()
+            .is_unlocked("prelude_test")
+    );
+}
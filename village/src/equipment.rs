@@ -1,15 +1,24 @@
 use {
     bevy::prelude::*,
+    divinity_components::Divinity,
     equipment_events::{EquipWeaponRequest, UnequipWeaponRequest},
     hero_components::{EquippedWeaponId, Hero, Weapon, WeaponId},
+    research::ResearchState,
+    village_components::Village,
+    weapon_assets::{WeaponDefinition, WeaponMap},
 };
 
+#[allow(clippy::too_many_arguments)]
 pub fn handle_equip_weapon(
     trigger: On<EquipWeaponRequest>,
     mut commands: Commands,
     weapon_query: Query<&WeaponId, With<Weapon>>,
     hero_children_query: Query<&Children, With<Hero>>,
     child_weapon_query: Query<Entity, With<Weapon>>,
+    weapon_map: Res<WeaponMap>,
+    weapon_definitions: Res<Assets<WeaponDefinition>>,
+    research_state: Res<ResearchState>,
+    village_query: Query<&Divinity, With<Village>>,
 ) {
     let event = trigger.event();
 
@@ -23,6 +32,19 @@ pub fn handle_equip_weapon(
     };
     let weapon_id_str = weapon_id.0.clone();
 
+    // Reject weapons the player has not progressed far enough to use
+    let divinity_tier = village_query.iter().next().map_or(1, |d| d.tier);
+    if let Some(requirement) = weapon_map.requirement(&weapon_id_str, &weapon_definitions)
+        && !requirement.is_met(divinity_tier, &research_state.completion_counts)
+    {
+        warn!(
+            "Cannot equip weapon {}: {}",
+            weapon_id_str,
+            requirement.describe()
+        );
+        return;
+    }
+
     // Unequip current weapon if hero has one
     if let Ok(children) = hero_children_query.get(event.hero) {
         for child in children.iter() {
@@ -69,3 +91,76 @@ pub fn handle_unequip_weapon(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        weapon_assets::{WeaponRequirement, WeaponType},
+    };
+
+    fn setup(village_tier: u32) -> (World, Entity, Entity) {
+        let mut world = World::new();
+        world.init_resource::<ResearchState>();
+        world.init_resource::<WeaponMap>();
+        world.init_resource::<Assets<WeaponDefinition>>();
+        world.add_observer(handle_equip_weapon);
+
+        let handle = world
+            .resource_mut::<Assets<WeaponDefinition>>()
+            .add(WeaponDefinition {
+                id: "god_blade".to_string(),
+                display_name: "God Blade".to_string(),
+                weapon_type: WeaponType::Melee { arc_width: 1.0 },
+                damage: 50.0,
+                attack_range: 100.0,
+                attack_speed_ms: 1000,
+                tags: vec![],
+                requirement: Some(WeaponRequirement::MinDivinityTier(2)),
+            });
+        world
+            .resource_mut::<WeaponMap>()
+            .handles
+            .insert("god_blade".to_string(), handle);
+
+        world.spawn((Village, Divinity::new(village_tier, 1)));
+        let hero = world.spawn(Hero).id();
+        let weapon = world
+            .spawn((Weapon, WeaponId("god_blade".to_string())))
+            .id();
+
+        (world, hero, weapon)
+    }
+
+    #[test]
+    fn test_equip_rejected_when_requirement_unmet() {
+        let (mut world, hero, weapon) = setup(1);
+
+        world.trigger(EquipWeaponRequest { hero, weapon });
+        world.flush();
+
+        assert!(world.get::<ChildOf>(weapon).is_none());
+        assert_eq!(
+            world
+                .get::<EquippedWeaponId>(hero)
+                .and_then(|e| e.0.clone()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_equip_allowed_when_requirement_met() {
+        let (mut world, hero, weapon) = setup(2);
+
+        world.trigger(EquipWeaponRequest { hero, weapon });
+        world.flush();
+
+        assert_eq!(world.get::<ChildOf>(weapon).map(|c| c.parent()), Some(hero));
+        assert_eq!(
+            world
+                .get::<EquippedWeaponId>(hero)
+                .and_then(|e| e.0.clone()),
+            Some("god_blade".to_string())
+        );
+    }
+}
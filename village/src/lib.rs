@@ -58,7 +58,7 @@ fn update_encyclopedia(
 
         // Notify unlock system about kill count change
         commands.trigger(unlocks_events::ValueChanged {
-            topic: format!("kills:{}", monster_id.0),
+            topic: unlocks_events::Topic::kills(&monster_id.0).into(),
             value: kill_count as f32,
         });
     }
@@ -92,7 +92,7 @@ fn update_encyclopedia_on_escape(
 
         // Notify unlock system about escape count change
         commands.trigger(unlocks_events::ValueChanged {
-            topic: format!("escapes:{}", monster_id.0),
+            topic: unlocks_events::Topic::escapes(&monster_id.0).into(),
             value: escape_count as f32,
         });
     }
@@ -106,7 +106,7 @@ fn divinity_increase_unlock(
 ) {
     let event = event.event();
 
-    let Some(divinity_event) = event.reward_id.strip_prefix("divinity:") else {
+    let Some(divinity_event) = event.reward_id.strip_prefix(Divinity::REWARD_PREFIX) else {
         return;
     };
 
@@ -136,7 +136,7 @@ fn divinity_increase_unlock(
             );
 
             cmd.trigger(unlocks_events::ValueChanged {
-                topic: "divinity".into(),
+                topic: unlocks_events::Topic::divinity().into(),
                 value: (divinity.tier * 100 + divinity.level) as f32,
             });
         }
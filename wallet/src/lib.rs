@@ -4,7 +4,8 @@ use {
     hero_events::EnemyKilled,
     states,
     std::collections::{HashMap, HashSet},
-    unlocks_events::{UnlockAchieved, ValueChanged},
+    unlocks_events::{Topic, UnlockAchieved, ValueChanged},
+    wallet_events::ResourceUnlocked,
 };
 
 /// Central storage for all collected player resources (the game's economy state).
@@ -22,6 +23,75 @@ pub struct Wallet {
     pub unlocked_resources: HashSet<String>,
 }
 
+impl Wallet {
+    /// Returns how much of a resource is held. Missing entries count as 0.
+    ///
+    /// Use this for affordability only; an amount of 0 says nothing about whether
+    /// the player has discovered the resource (see `is_discovered`).
+    pub fn amount(&self, resource_id: &str) -> u32 {
+        self.resources.get(resource_id).copied().unwrap_or(0)
+    }
+
+    /// Returns true if the resource has been unlocked, regardless of how much is held.
+    pub fn is_discovered(&self, resource_id: &str) -> bool {
+        self.unlocked_resources.contains(resource_id)
+    }
+
+    /// Returns the held amount of a discovered resource, or `None` if it is still undiscovered.
+    /// A discovered resource the player has none of yields `Some(0)`.
+    pub fn known_amount(&self, resource_id: &str) -> Option<u32> {
+        self.is_discovered(resource_id)
+            .then(|| self.amount(resource_id))
+    }
+
+    /// Ids of every resource worth showing: all discovered ones (even at 0)
+    /// plus anything held without having been discovered. Sorted by id.
+    pub fn known_resource_ids(&self) -> Vec<&String> {
+        let mut ids: Vec<&String> = self
+            .unlocked_resources
+            .iter()
+            .chain(
+                self.resources
+                    .iter()
+                    .filter(|(id, amount)| **amount > 0 && !self.is_discovered(id))
+                    .map(|(id, _)| id),
+            )
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// Returns true if every resource in `cost` is held in at least the listed amount.
+    pub fn can_afford<'a>(&self, cost: impl IntoIterator<Item = (&'a String, &'a u32)>) -> bool {
+        cost.into_iter()
+            .all(|(res_id, amt)| self.amount(res_id) >= *amt)
+    }
+
+    /// Deducts `cost` if the wallet can afford all of it; otherwise leaves the wallet untouched.
+    /// Returns whether the resources were spent.
+    pub fn try_spend<'a>(
+        &mut self,
+        cost: impl IntoIterator<Item = (&'a String, &'a u32)> + Clone,
+    ) -> bool {
+        if !self.can_afford(cost.clone()) {
+            return false;
+        }
+        for (res_id, amt) in cost {
+            if let Some(current) = self.resources.get_mut(res_id) {
+                *current -= *amt;
+            }
+        }
+        true
+    }
+
+    /// Gives back resources spent through `try_spend`, e.g. when a paid action is cancelled.
+    pub fn refund<'a>(&mut self, cost: impl IntoIterator<Item = (&'a String, &'a u32)>) {
+        for (res_id, amt) in cost {
+            *self.resources.entry(res_id.clone()).or_insert(0) += *amt;
+        }
+    }
+}
+
 /// Stores drop rate modifiers for resources.
 ///
 /// Values represent multipliers: 1.0 = base rate, 1.2 = +20%, etc.
@@ -66,17 +136,122 @@ impl ResourceRates {
     }
 }
 
+/// Net passive production per resource, in units per second.
+///
+/// Unlike `ResourceRates` (drop multipliers), this describes income that accrues without
+/// player action. It is recomputed from live sources whenever they change, so the HUD and
+/// offline progress see the same numbers. Resources without passive income are absent.
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Resource, Default)]
+pub struct NetResourceRates {
+    /// Maps resource IDs to their net per-second rate.
+    pub per_second: HashMap<String, f32>,
+}
+
+impl NetResourceRates {
+    /// Gets the net per-second rate for a resource. Returns 0.0 if it has no passive income.
+    pub fn get(&self, resource_id: &str) -> f32 {
+        self.per_second.get(resource_id).copied().unwrap_or(0.0)
+    }
+}
+
+/// Observed income per resource, in units per second, measured from `Wallet` changes.
+///
+/// Unlike `NetResourceRates` (passive producers only), this includes every source of
+/// resources (kills, buildings, refunds) minus spending, sampled over the last
+/// `ResourceIncome::SAMPLE_SECS`. Only discovered resources get a rate.
+#[derive(Resource, Debug, Clone)]
+pub struct ResourceIncome {
+    /// Maps resource IDs to their per-second change over the last sample window.
+    pub per_second: HashMap<String, f32>,
+    /// Wallet amounts at the start of the current window; `None` before the first sample
+    snapshot: Option<HashMap<String, u32>>,
+    timer: Timer,
+}
+
+impl Default for ResourceIncome {
+    fn default() -> Self {
+        Self {
+            per_second: HashMap::new(),
+            snapshot: None,
+            timer: Timer::from_seconds(Self::SAMPLE_SECS, TimerMode::Repeating),
+        }
+    }
+}
+
+impl ResourceIncome {
+    /// Length of the sample window in seconds
+    pub const SAMPLE_SECS: f32 = 1.0;
+
+    /// Gets the observed per-second change of a resource. Returns 0.0 if it has none.
+    pub fn get(&self, resource_id: &str) -> f32 {
+        self.per_second.get(resource_id).copied().unwrap_or(0.0)
+    }
+
+    /// Closes a window of `elapsed_secs`: recomputes the rates against the previous
+    /// snapshot and starts the next window from the current wallet.
+    pub fn sample(&mut self, wallet: &Wallet, elapsed_secs: f32) {
+        // The first sample only records a baseline, so the starting wallet isn't "income"
+        if let Some(snapshot) = &self.snapshot
+            && elapsed_secs > 0.0
+        {
+            self.per_second = wallet
+                .unlocked_resources
+                .iter()
+                .map(|id| {
+                    let before = snapshot.get(id).copied().unwrap_or(0) as f32;
+                    (
+                        id.clone(),
+                        (wallet.amount(id) as f32 - before) / elapsed_secs,
+                    )
+                })
+                .collect();
+        }
+        self.snapshot = Some(wallet.resources.clone());
+    }
+}
+
+fn sample_resource_income(
+    time: Res<Time>,
+    wallet: Res<Wallet>,
+    mut income: ResMut<ResourceIncome>,
+) {
+    // Only a taken sample counts as a change, so the resource panel isn't rebuilt every frame
+    let timer = &mut income.bypass_change_detection().timer;
+    timer.tick(time.delta());
+    if timer.just_finished() {
+        let elapsed = timer.duration().as_secs_f32() * timer.times_finished_this_tick() as f32;
+        income.sample(&wallet, elapsed);
+    }
+}
+
+fn clean_up_resource_income(mut income: ResMut<ResourceIncome>) {
+    *income = ResourceIncome::default();
+}
+
 pub struct WalletPlugin;
 
 impl Plugin for WalletPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<Wallet>()
             .register_type::<ResourceRates>()
+            .register_type::<NetResourceRates>()
             .init_resource::<Wallet>()
             .init_resource::<ResourceRates>()
+            .init_resource::<NetResourceRates>()
+            .init_resource::<ResourceIncome>()
             .add_observer(process_enemy_killed_rewards)
             .add_observer(on_resource_unlock_achieved)
-            .add_systems(OnExit(states::GameState::Running), clean_up_wallet);
+            .add_systems(
+                Update,
+                // Sampling a pause would report zero income for it
+                sample_resource_income
+                    .run_if(in_state(states::GameState::Running).and(states::not_paused)),
+            )
+            .add_systems(
+                OnExit(states::GameState::Running),
+                (clean_up_wallet, clean_up_resource_income),
+            );
     }
 }
 
@@ -96,7 +271,7 @@ fn process_enemy_killed_rewards(
             }
 
             // Check resource is unlocked and thus can actually be rewarded
-            if !wallet.unlocked_resources.contains(&drop.id) {
+            if !wallet.is_discovered(&drop.id) {
                 continue;
             }
 
@@ -107,7 +282,7 @@ fn process_enemy_killed_rewards(
 
             // Notify unlock system about resource value change
             commands.trigger(ValueChanged {
-                topic: format!("resource:{}", drop.id),
+                topic: Topic::resource(&drop.id).into(),
                 value: *current as f32,
             });
 
@@ -120,8 +295,13 @@ fn process_enemy_killed_rewards(
 }
 
 /// Observer for UnlockAchieved events with `resource_` prefix.
-/// Adds the resource ID to the wallet's unlocked_resources set.
-fn on_resource_unlock_achieved(trigger: On<UnlockAchieved>, mut wallet: ResMut<Wallet>) {
+/// Adds the resource ID to the wallet's unlocked_resources set and fires
+/// `ResourceUnlocked` the first time it gets there.
+fn on_resource_unlock_achieved(
+    trigger: On<UnlockAchieved>,
+    mut wallet: ResMut<Wallet>,
+    mut commands: Commands,
+) {
     let event = trigger.event();
     const RESOURCE_REWARD_PREFIX: &str = "resource:";
 
@@ -129,11 +309,139 @@ fn on_resource_unlock_achieved(trigger: On<UnlockAchieved>, mut wallet: ResMut<W
         return;
     };
 
-    wallet.unlocked_resources.insert(resource_id.to_string());
+    if !wallet.unlocked_resources.insert(resource_id.to_string()) {
+        return;
+    }
+
     info!("Resource '{}' is now unlocked", resource_id);
+    commands.trigger(ResourceUnlocked {
+        id: resource_id.to_string(),
+    });
 }
 
 pub fn clean_up_wallet(mut wallet: ResMut<Wallet>) {
     debug!("Cleaning up wallet");
     *wallet = Wallet::default();
 }
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::time::Duration};
+
+    #[derive(Resource, Default)]
+    struct UnlockedLog(Vec<String>);
+
+    fn grant(app: &mut App, resource_id: &str) {
+        app.world_mut().trigger(UnlockAchieved {
+            unlock_id: format!("resource_{}_unlock", resource_id),
+            display_name: None,
+            reward_id: format!("resource:{}", resource_id),
+        });
+        app.update();
+    }
+
+    #[test]
+    fn test_resource_unlocked_fires_once() {
+        let mut app = App::new();
+        app.init_resource::<Wallet>()
+            .init_resource::<UnlockedLog>()
+            .add_observer(on_resource_unlock_achieved)
+            .add_observer(
+                |trigger: On<ResourceUnlocked>, mut log: ResMut<UnlockedLog>| {
+                    log.0.push(trigger.event().id.clone());
+                },
+            );
+
+        grant(&mut app, "bones");
+        grant(&mut app, "bones");
+
+        assert_eq!(app.world().resource::<UnlockedLog>().0, vec!["bones"]);
+        assert!(
+            app.world()
+                .resource::<Wallet>()
+                .unlocked_resources
+                .contains("bones")
+        );
+    }
+
+    #[test]
+    fn test_discovered_resource_at_zero_is_known_but_empty() {
+        let mut wallet = Wallet::default();
+        wallet.unlocked_resources.insert("bones".to_string());
+        wallet.resources.insert("sinew".to_string(), 0);
+        let cost = HashMap::from([("bones".to_string(), 1)]);
+
+        // Discovered but never collected: known, empty, unaffordable
+        assert!(wallet.is_discovered("bones"));
+        assert_eq!(wallet.known_amount("bones"), Some(0));
+        assert!(!wallet.can_afford(&cost));
+
+        // A zero entry in `resources` does not make a resource discovered
+        assert!(!wallet.is_discovered("sinew"));
+        assert_eq!(wallet.known_amount("sinew"), None);
+        assert_eq!(wallet.known_amount("entropy"), None);
+        assert_eq!(wallet.known_resource_ids(), vec!["bones"]);
+
+        wallet.resources.insert("bones".to_string(), 3);
+        assert_eq!(wallet.known_amount("bones"), Some(3));
+        assert!(wallet.try_spend(&cost));
+        assert_eq!(wallet.known_amount("bones"), Some(2));
+    }
+
+    #[test]
+    fn test_income_is_measured_between_samples_for_discovered_resources() {
+        let mut wallet = Wallet::default();
+        wallet.unlocked_resources.insert("bones".to_string());
+        wallet.resources.insert("bones".to_string(), 100);
+        wallet.resources.insert("sinew".to_string(), 5);
+        let mut income = ResourceIncome::default();
+
+        // The first sample is only a baseline
+        income.sample(&wallet, 1.0);
+        assert!(income.per_second.is_empty());
+
+        wallet.resources.insert("bones".to_string(), 106);
+        wallet.resources.insert("sinew".to_string(), 50);
+        income.sample(&wallet, 2.0);
+        assert_eq!(income.get("bones"), 3.0);
+        // Undiscovered resources stay hidden
+        assert!(!income.per_second.contains_key("sinew"));
+
+        // Spending shows up as a negative rate
+        wallet.resources.insert("bones".to_string(), 96);
+        income.sample(&wallet, 1.0);
+        assert_eq!(income.get("bones"), -10.0);
+    }
+
+    #[test]
+    fn test_income_only_changes_when_a_sample_is_taken() {
+        #[derive(Resource, Default)]
+        struct Changes(Vec<bool>);
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<Wallet>()
+            .init_resource::<ResourceIncome>()
+            .init_resource::<Changes>()
+            .add_systems(
+                Update,
+                (
+                    sample_resource_income,
+                    |income: Res<ResourceIncome>, mut changes: ResMut<Changes>| {
+                        changes.0.push(income.is_changed())
+                    },
+                )
+                    .chain(),
+            );
+
+        for _ in 0..3 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(400));
+            app.update();
+        }
+
+        // The first frame sees the freshly added resource; the window closes in the third
+        assert_eq!(app.world().resource::<Changes>().0, vec![true, false, true]);
+    }
+}